@@ -4,8 +4,9 @@
 use std::env;
 use std::fs;
 use std::process;
+use std::sync::Arc;
 
-use qollective::security::{SecurityConfig, SimpleJwtValidator};
+use qollective::security::{JwksKeyStore, SecurityConfig, SimpleJwtValidator};
 
 #[tokio::main]
 async fn main() {
@@ -48,7 +49,26 @@ async fn main() {
     // Create validator with config settings
     let verify_signature = config.jwt_validation.verify_signature;
     let verify_expiry = config.jwt_validation.verify_expiry;
-    let validator = SimpleJwtValidator::new(verify_signature, verify_expiry);
+    let mut validator = SimpleJwtValidator::new(verify_signature, verify_expiry);
+
+    // Attach verification keys when the config provides a JWKS
+    let key_store = match (
+        &config.jwt_validation.jwks_path,
+        &config.jwt_validation.jwks,
+    ) {
+        (Some(path), _) => Some(JwksKeyStore::from_file(path)),
+        (None, Some(jwks)) => Some(Ok(JwksKeyStore::from_jwk_set(jwks.clone()))),
+        (None, None) => None,
+    };
+    if let Some(key_store) = key_store {
+        match key_store {
+            Ok(key_store) => validator = validator.with_key_store(Arc::new(key_store)),
+            Err(e) => {
+                eprintln!("ERROR: Failed to load JWKS - {}", e);
+                process::exit(1);
+            }
+        }
+    }
 
     // Validate the token
     match validator.validate(&jwt_token).await {
@@ -8,6 +8,7 @@
 //! - Agent discovery and capability queries
 //! - Broadcasting to capabilities
 //! - A2A metadata integration via envelope extensions
+//! - A2A task status queries, cancellation and update streams
 
 use crate::{
     client::nats::NatsClient,
@@ -17,7 +18,10 @@ use crate::{
     error::Result,
     traits::senders::UnifiedEnvelopeSender,
     transport::{HybridTransportClient, TransportDetectionConfig},
-    types::a2a::{AgentInfo, CapabilityQuery, RegistryEvent},
+    types::a2a::{
+        A2ATask, AgentInfo, CapabilityQuery, HealthStatus, Heartbeat, RegistryEvent,
+        RoutingDecision, TaskIdParams, TaskUpdate,
+    },
 };
 
 use chrono;
@@ -27,6 +31,7 @@ use std::{
     sync::Arc,
     time::{Duration, SystemTime},
 };
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
 // ============================================================================
@@ -176,17 +181,161 @@ pub trait AgentProvider: Send + Sync {
     fn get_capabilities(&self) -> Vec<String>;
 }
 
-/// Simplified agent registry for managing agent discovery
-#[derive(Debug)]
+/// Agents known to a registry, keyed by agent id
+#[derive(Debug, Default)]
+struct AgentTable {
+    agents: HashMap<Uuid, (AgentInfo, AgentMetadata)>,
+}
+
+impl AgentTable {
+    /// Insert or replace an agent, returning whether it was unknown before
+    fn upsert(
+        &mut self,
+        agent_info: AgentInfo,
+        metadata: AgentMetadata,
+        max_agents: usize,
+    ) -> Result<bool> {
+        let added = !self.agents.contains_key(&agent_info.id);
+        if added && self.agents.len() >= max_agents {
+            return Err(crate::error::QollectiveError::validation(format!(
+                "Agent registry is full ({} agents), rejecting agent {}",
+                max_agents, agent_info.id
+            )));
+        }
+        self.agents.insert(agent_info.id, (agent_info, metadata));
+        Ok(added)
+    }
+
+    /// Record a heartbeat, returning the updated agent and whether its health changed
+    fn heartbeat(&mut self, heartbeat: &Heartbeat, now: SystemTime) -> Option<(AgentInfo, bool)> {
+        let (agent_info, _) = self.agents.get_mut(&heartbeat.agent_id)?;
+        let health_changed = agent_info.health_status != heartbeat.health_status;
+        agent_info.health_status = heartbeat.health_status.clone();
+        agent_info.last_heartbeat = now;
+        if let Some(metadata) = &heartbeat.metadata {
+            agent_info.metadata.extend(metadata.clone());
+        }
+        Some((agent_info.clone(), health_changed))
+    }
+
+    fn remove(&mut self, agent_id: &Uuid) -> Option<AgentInfo> {
+        self.agents.remove(agent_id).map(|(info, _)| info)
+    }
+
+    /// Remove agents whose last heartbeat is older than `ttl`
+    fn expire(&mut self, ttl: Duration, now: SystemTime) -> Vec<AgentInfo> {
+        let stale: Vec<Uuid> = self
+            .agents
+            .values()
+            .filter(|(info, _)| {
+                now.duration_since(info.last_heartbeat)
+                    .is_ok_and(|since_heartbeat| since_heartbeat > ttl)
+            })
+            .map(|(info, _)| info.id)
+            .collect();
+        stale.iter().filter_map(|id| self.remove(id)).collect()
+    }
+
+    /// Mirror an event published by another registry, returning whether anything changed
+    ///
+    /// Registrations of unknown agents are skipped once the table holds `max_agents`.
+    fn apply(&mut self, event: &RegistryEvent, max_agents: usize) -> bool {
+        match event.event_type.as_str() {
+            RegistryEvent::AGENT_REGISTERED => {
+                if !self.agents.contains_key(&event.agent_id) && self.agents.len() >= max_agents {
+                    tracing::warn!(
+                        "Agent registry is full ({} agents), not mirroring agent {}",
+                        max_agents,
+                        event.agent_id
+                    );
+                    return false;
+                }
+                let agent_info = agent_from_event(event);
+                let metadata = self
+                    .agents
+                    .remove(&event.agent_id)
+                    .map(|(_, metadata)| metadata)
+                    .unwrap_or_default();
+                self.agents.insert(event.agent_id, (agent_info, metadata));
+                true
+            }
+            RegistryEvent::AGENT_HEALTH_CHANGED => {
+                let Some((agent_info, _)) = self.agents.get_mut(&event.agent_id) else {
+                    return false;
+                };
+                agent_info.health_status = agent_from_event(event).health_status;
+                agent_info.last_heartbeat = event.timestamp;
+                true
+            }
+            RegistryEvent::AGENT_DEREGISTERED | RegistryEvent::AGENT_EXPIRED => {
+                self.remove(&event.agent_id).is_some()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Build the event describing a change to `agent_info`
+fn registry_event(event_type: &str, agent_info: &AgentInfo, origin: Uuid) -> RegistryEvent {
+    let mut metadata = agent_info.metadata.clone();
+    metadata.insert(
+        RegistryEvent::ORIGIN_METADATA_KEY.to_string(),
+        origin.to_string(),
+    );
+    if let Ok(serde_json::Value::String(health)) = serde_json::to_value(&agent_info.health_status) {
+        metadata.insert(
+            RegistryEvent::HEALTH_STATUS_METADATA_KEY.to_string(),
+            health,
+        );
+    }
+
+    RegistryEvent {
+        event_type: event_type.to_string(),
+        agent_id: agent_info.id,
+        agent_name: agent_info.name.clone(),
+        capabilities: agent_info.capabilities.clone(),
+        timestamp: SystemTime::now(),
+        metadata: Some(metadata),
+    }
+}
+
+/// Reconstruct the agent described by a registry event
+fn agent_from_event(event: &RegistryEvent) -> AgentInfo {
+    let mut metadata = event.metadata.clone().unwrap_or_default();
+    metadata.remove(RegistryEvent::ORIGIN_METADATA_KEY);
+    let health_status = metadata
+        .remove(RegistryEvent::HEALTH_STATUS_METADATA_KEY)
+        .and_then(|health| serde_json::from_value(serde_json::Value::String(health)).ok())
+        .unwrap_or(HealthStatus::Unknown);
+
+    AgentInfo {
+        id: event.agent_id,
+        name: event.agent_name.clone(),
+        capabilities: event.capabilities.clone(),
+        health_status,
+        last_heartbeat: event.timestamp,
+        metadata,
+    }
+}
+
+/// Agent registry shared by discovery handlers, servers and background tasks
+///
+/// Clones share the same agents, so a registry passed to
+/// [`NatsServer::enable_discovery`](crate::server::nats::NatsServer::enable_discovery)
+/// is updated by the announcements, heartbeats and deregistrations it receives.
+/// Every change is published as a [`RegistryEvent`] on
+/// [`subjects::AGENT_REGISTRY_EVENTS`] so registries on other nodes can mirror it
+/// with [`apply_event`](Self::apply_event).
+#[derive(Debug, Clone)]
 pub struct AgentRegistry {
     nats_client: NatsClient,
-    registered_agents: HashMap<uuid::Uuid, (AgentInfo, AgentMetadata)>,
-    #[allow(dead_code)] // Stored for debugging and future configuration access
+    node_id: Uuid,
+    agents: Arc<RwLock<AgentTable>>,
     config: crate::config::a2a::RegistryConfig,
     // Metrics tracking
     creation_time: SystemTime,
-    response_times: Vec<Duration>,
-    last_cleanup: Option<SystemTime>,
+    response_times: Arc<RwLock<Vec<Duration>>>,
+    last_cleanup: Arc<RwLock<Option<SystemTime>>>,
 }
 
 impl AgentRegistry {
@@ -201,24 +350,30 @@ impl AgentRegistry {
 
         Ok(Self {
             nats_client,
-            registered_agents: HashMap::new(),
+            node_id: Uuid::now_v7(),
+            agents: Arc::new(RwLock::new(AgentTable::default())),
             config,
             // Initialize metrics tracking
             creation_time: SystemTime::now(),
-            response_times: Vec::new(),
-            last_cleanup: None,
+            response_times: Arc::new(RwLock::new(Vec::new())),
+            last_cleanup: Arc::new(RwLock::new(None)),
         })
     }
 
-    /// Register an agent
+    /// Identifier this registry stamps on the events it publishes
+    pub fn node_id(&self) -> Uuid {
+        self.node_id
+    }
+
+    /// Register an agent and announce it to other registries
     pub async fn register_agent(
-        &mut self,
+        &self,
         agent_info: AgentInfo,
         metadata: AgentMetadata,
     ) -> crate::error::Result<()> {
         // Store locally
-        self.registered_agents
-            .insert(agent_info.id, (agent_info.clone(), metadata.clone()));
+        self.record_registration(agent_info.clone(), metadata.clone())
+            .await?;
 
         // Create envelope for registration announcement
         let mut meta = Meta::default();
@@ -243,10 +398,10 @@ impl AgentRegistry {
             .await
     }
 
-    /// Deregister an agent
-    pub async fn deregister_agent(&mut self, agent_id: uuid::Uuid) -> crate::error::Result<()> {
+    /// Deregister an agent and announce the deregistration to other registries
+    pub async fn deregister_agent(&self, agent_id: uuid::Uuid) -> crate::error::Result<()> {
         // Remove locally
-        self.registered_agents.remove(&agent_id);
+        self.remove_agent(&agent_id).await;
 
         // Create envelope for deregistration
         let mut meta = Meta::default();
@@ -271,25 +426,119 @@ impl AgentRegistry {
             .await
     }
 
+    /// Store an announced agent without re-announcing it
+    ///
+    /// Returns whether the agent was new. Re-announcements refresh the stored
+    /// entry without publishing a registry event. Fails when the registry already
+    /// holds `max_agents` agents.
+    pub async fn record_registration(
+        &self,
+        agent_info: AgentInfo,
+        metadata: AgentMetadata,
+    ) -> crate::error::Result<bool> {
+        let added = self.agents.write().await.upsert(
+            agent_info.clone(),
+            metadata,
+            self.config.max_agents,
+        )?;
+        if added {
+            tracing::info!(
+                "Registered agent: {} (ID: {})",
+                agent_info.name,
+                agent_info.id
+            );
+            self.publish_event(RegistryEvent::AGENT_REGISTERED, &agent_info)
+                .await;
+        }
+        Ok(added)
+    }
+
+    /// Record an agent heartbeat, returning `false` if the agent is unknown
+    pub async fn record_heartbeat(&self, heartbeat: &Heartbeat) -> bool {
+        let updated = self
+            .agents
+            .write()
+            .await
+            .heartbeat(heartbeat, SystemTime::now());
+        let Some((agent_info, health_changed)) = updated else {
+            return false;
+        };
+        if health_changed {
+            tracing::info!(
+                "Agent {} (ID: {}) is now {:?}",
+                agent_info.name,
+                agent_info.id,
+                agent_info.health_status
+            );
+            self.publish_event(RegistryEvent::AGENT_HEALTH_CHANGED, &agent_info)
+                .await;
+        }
+        true
+    }
+
+    /// Remove an agent without announcing a deregistration request
+    pub async fn remove_agent(&self, agent_id: &uuid::Uuid) -> Option<AgentInfo> {
+        let removed = self.agents.write().await.remove(agent_id)?;
+        tracing::info!("Deregistered agent: {} (ID: {})", removed.name, removed.id);
+        self.publish_event(RegistryEvent::AGENT_DEREGISTERED, &removed)
+            .await;
+        Some(removed)
+    }
+
+    /// Mirror a registry event published by another node
+    ///
+    /// Events this registry published itself are ignored. Returns whether the
+    /// event changed the registry. Mirrored changes are not published again.
+    pub async fn apply_event(&self, event: &RegistryEvent) -> bool {
+        if event.origin() == Some(self.node_id.to_string().as_str()) {
+            return false;
+        }
+        self.agents
+            .write()
+            .await
+            .apply(event, self.config.max_agents)
+    }
+
     /// Get all registered agents
-    pub fn get_agents(&self) -> Vec<&AgentInfo> {
-        self.registered_agents
+    pub async fn get_agents(&self) -> Vec<AgentInfo> {
+        self.agents
+            .read()
+            .await
+            .agents
             .values()
-            .map(|(info, _)| info)
+            .map(|(info, _)| info.clone())
             .collect()
     }
 
     /// Get agent by ID
-    pub fn get_agent_by_id(&self, agent_id: &uuid::Uuid) -> Option<&AgentInfo> {
-        self.registered_agents.get(agent_id).map(|(info, _)| info)
+    pub async fn get_agent_by_id(&self, agent_id: &uuid::Uuid) -> Option<AgentInfo> {
+        self.agents
+            .read()
+            .await
+            .agents
+            .get(agent_id)
+            .map(|(info, _)| info.clone())
+    }
+
+    /// Get the metadata an agent registered with
+    pub async fn get_agent_metadata(&self, agent_id: &uuid::Uuid) -> Option<AgentMetadata> {
+        self.agents
+            .read()
+            .await
+            .agents
+            .get(agent_id)
+            .map(|(_, metadata)| metadata.clone())
     }
 
     /// Find agents by capability
-    pub fn find_agents_by_capability(&self, capability: &str) -> Vec<&AgentInfo> {
-        self.registered_agents
+    pub async fn find_agents_by_capability(&self, capability: &str) -> Vec<AgentInfo> {
+        self.agents
+            .read()
+            .await
+            .agents
             .values()
-            .filter(|(info, _)| info.capabilities.contains(&capability.to_string()))
-            .map(|(info, _)| info)
+            .filter(|(info, _)| info.capabilities.iter().any(|cap| cap == capability))
+            .map(|(info, _)| info.clone())
             .collect()
     }
 
@@ -300,7 +549,7 @@ impl AgentRegistry {
     ) -> crate::error::Result<Vec<AgentInfo>> {
         let mut matching_agents = Vec::new();
 
-        for (agent_info, _) in self.registered_agents.values() {
+        for (agent_info, _) in self.agents.read().await.agents.values() {
             // Check if agent has all required capabilities
             let has_required = query
                 .required_capabilities
@@ -329,33 +578,34 @@ impl AgentRegistry {
     }
 
     /// Record a response time for metrics tracking
-    pub fn record_response_time(&mut self, response_time: Duration) {
-        self.response_times.push(response_time);
+    pub async fn record_response_time(&self, response_time: Duration) {
+        let mut response_times = self.response_times.write().await;
+        response_times.push(response_time);
 
         // Keep only the last 1000 response times to prevent unbounded growth
-        if self.response_times.len() > 1000 {
-            self.response_times.drain(0..500); // Remove oldest 500 entries
+        if response_times.len() > 1000 {
+            response_times.drain(0..500); // Remove oldest 500 entries
         }
     }
 
     /// Mark that a cleanup operation has been performed
-    pub fn mark_cleanup_performed(&mut self) {
-        self.last_cleanup = Some(SystemTime::now());
+    pub async fn mark_cleanup_performed(&self) {
+        *self.last_cleanup.write().await = Some(SystemTime::now());
     }
 
     /// Calculate average response time in milliseconds
-    fn calculate_avg_response_time_ms(&self) -> f64 {
-        if self.response_times.is_empty() {
+    async fn calculate_avg_response_time_ms(&self) -> f64 {
+        let response_times = self.response_times.read().await;
+        if response_times.is_empty() {
             return 0.0;
         }
 
-        let total_ms: u128 = self
-            .response_times
+        let total_ms: u128 = response_times
             .iter()
             .map(|duration| duration.as_millis())
             .sum();
 
-        total_ms as f64 / self.response_times.len() as f64
+        total_ms as f64 / response_times.len() as f64
     }
 
     /// Calculate uptime since registry creation
@@ -367,65 +617,118 @@ impl AgentRegistry {
 
     /// Get registry statistics
     pub async fn get_stats(&self) -> crate::error::Result<crate::server::a2a::RegistryStats> {
-        let total_agents = self.registered_agents.len();
-        let healthy_agents = self
-            .registered_agents
-            .values()
-            .filter(|(info, _)| {
-                matches!(info.health_status, crate::types::a2a::HealthStatus::Healthy)
-            })
-            .count();
-
-        let mut unique_capabilities = std::collections::HashSet::new();
-        for (agent_info, _) in self.registered_agents.values() {
-            for cap in &agent_info.capabilities {
-                unique_capabilities.insert(cap);
+        let (total_agents, healthy_agents, unique_capabilities) = {
+            let table = self.agents.read().await;
+            let healthy_agents = table
+                .agents
+                .values()
+                .filter(|(info, _)| matches!(info.health_status, HealthStatus::Healthy))
+                .count();
+
+            let mut unique_capabilities = std::collections::HashSet::new();
+            for (agent_info, _) in table.agents.values() {
+                for cap in &agent_info.capabilities {
+                    unique_capabilities.insert(cap);
+                }
             }
-        }
+            (
+                table.agents.len(),
+                healthy_agents,
+                unique_capabilities.len(),
+            )
+        };
 
         Ok(crate::server::a2a::RegistryStats {
             total_agents,
             healthy_agents,
-            unique_capabilities: unique_capabilities.len(),
-            avg_response_time_ms: self.calculate_avg_response_time_ms(),
+            unique_capabilities,
+            avg_response_time_ms: self.calculate_avg_response_time_ms().await,
             uptime: self.calculate_uptime(),
-            last_cleanup: self.last_cleanup,
+            last_cleanup: *self.last_cleanup.read().await,
         })
     }
 
-    /// Clean up stale agents based on last heartbeat time
-    pub async fn cleanup_stale_agents(&mut self) -> crate::error::Result<usize> {
-        use crate::constants::timeouts;
+    /// Remove agents that have not sent a heartbeat within the configured agent TTL
+    pub async fn cleanup_stale_agents(&self) -> crate::error::Result<usize> {
+        let expired = self
+            .agents
+            .write()
+            .await
+            .expire(self.config.agent_ttl, SystemTime::now());
 
-        let now = SystemTime::now();
-        let stale_threshold = timeouts::DEFAULT_AGENT_TIMEOUT;
-        let mut stale_agents = Vec::new();
-
-        // Find agents that haven't sent a heartbeat within the threshold
-        for (agent_id, (agent_info, _metadata)) in &self.registered_agents {
-            if let Ok(duration_since_heartbeat) = now.duration_since(agent_info.last_heartbeat) {
-                if duration_since_heartbeat > stale_threshold {
-                    stale_agents.push(*agent_id);
-                }
-            }
+        for agent_info in &expired {
+            tracing::info!(
+                "Cleaned up stale agent: {} (ID: {})",
+                agent_info.name,
+                agent_info.id
+            );
+            self.publish_event(RegistryEvent::AGENT_EXPIRED, agent_info)
+                .await;
         }
 
-        // Remove stale agents
-        let removed_count = stale_agents.len();
-        for agent_id in stale_agents {
-            if let Some((agent_info, _)) = self.registered_agents.remove(&agent_id) {
-                tracing::info!(
-                    "Cleaned up stale agent: {} (ID: {})",
-                    agent_info.name,
-                    agent_id
-                );
+        // Mark cleanup as performed
+        self.mark_cleanup_performed().await;
+
+        Ok(expired.len())
+    }
+
+    /// Spawn a task running [`cleanup_stale_agents`](Self::cleanup_stale_agents)
+    /// every configured cleanup interval
+    pub fn start_cleanup_task(&self) -> tokio::task::JoinHandle<()> {
+        let registry = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(registry.config.cleanup_interval);
+            // The first tick completes immediately
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                match registry.cleanup_stale_agents().await {
+                    Ok(removed_count) if removed_count > 0 => {
+                        tracing::info!(
+                            "Registry cleanup completed: removed {} stale agents",
+                            removed_count
+                        );
+                    }
+                    Ok(_) => {
+                        tracing::debug!("Registry cleanup completed: no stale agents found");
+                    }
+                    Err(e) => {
+                        tracing::error!("Registry cleanup failed: {}", e);
+                    }
+                }
             }
-        }
+        })
+    }
 
-        // Mark cleanup as performed
-        self.mark_cleanup_performed();
+    /// Publish a registry change for other nodes to mirror
+    ///
+    /// The local change already happened, so a failed publish is only logged.
+    async fn publish_event(&self, event_type: &str, agent_info: &AgentInfo) {
+        let meta = Meta {
+            request_id: Some(Uuid::now_v7()),
+            timestamp: Some(chrono::Utc::now()),
+            tenant: Some("agents".to_string()),
+            ..Default::default()
+        };
+
+        let envelope = Envelope {
+            meta,
+            payload: registry_event(event_type, agent_info, self.node_id),
+            error: None,
+        };
 
-        Ok(removed_count)
+        if let Err(e) = self
+            .nats_client
+            .publish(subjects::AGENT_REGISTRY_EVENTS, envelope)
+            .await
+        {
+            tracing::warn!(
+                "Failed to publish registry event {} for agent {}: {}",
+                event_type,
+                agent_info.id,
+                e
+            );
+        }
     }
 }
 
@@ -434,12 +737,21 @@ impl AgentRegistry {
 // ============================================================================
 
 /// Clean, envelope-first A2A client following standard pattern
+///
+/// Routing decisions recorded with
+/// [`record_routing_decision`](Self::record_routing_decision) give every selected
+/// agent a list of fallbacks. [`send_envelope`](Self::send_envelope) tries them in
+/// order when the target fails or, with
+/// [`with_load_balancing`](Self::with_load_balancing), when its circuit is open.
 #[derive(Debug)]
 pub struct A2AClient {
     transport: std::sync::Arc<crate::transport::HybridTransportClient>,
     agent_id: String,
     #[allow(dead_code)] // Stored for debugging and future configuration access
     config: A2AClientConfig,
+    router: Option<Arc<crate::server::a2a::CapabilityRouter>>,
+    health_monitor: Option<Arc<tokio::sync::Mutex<crate::server::a2a::AgentHealthMonitor>>>,
+    fallbacks: std::sync::RwLock<HashMap<String, Vec<String>>>,
 }
 
 impl A2AClient {
@@ -465,6 +777,9 @@ impl A2AClient {
             transport: Arc::new(transport),
             agent_id: config.client.agent_id.clone(),
             config,
+            router: None,
+            health_monitor: None,
+            fallbacks: std::sync::RwLock::new(HashMap::new()),
         })
     }
 
@@ -477,9 +792,60 @@ impl A2AClient {
             transport,
             agent_id: config.client.agent_id.clone(),
             config,
+            router: None,
+            health_monitor: None,
+            fallbacks: std::sync::RwLock::new(HashMap::new()),
         })
     }
 
+    /// Share load balancing state with a capability router and health monitor
+    ///
+    /// Requests are then counted as in flight on the router, their latency feeds
+    /// its latency estimates, their outcome feeds the agent's circuit breaker, and
+    /// agents with an open circuit are skipped in favour of their fallbacks.
+    pub fn with_load_balancing(
+        mut self,
+        router: Arc<crate::server::a2a::CapabilityRouter>,
+        health_monitor: Arc<tokio::sync::Mutex<crate::server::a2a::AgentHealthMonitor>>,
+    ) -> Self {
+        self.router = Some(router);
+        self.health_monitor = Some(health_monitor);
+        self
+    }
+
+    /// Remember the fallbacks of a routing decision for later sends
+    ///
+    /// Each selected agent falls back to the other selected agents, then to the
+    /// decision's fallback agents.
+    pub fn record_routing_decision(&self, decision: &RoutingDecision) {
+        let candidates: Vec<String> = decision
+            .selected_agents
+            .iter()
+            .chain(&decision.fallback_agents)
+            .map(|agent| agent.id.to_string())
+            .collect();
+
+        let mut fallbacks = self.fallbacks.write().unwrap();
+        for (position, primary) in candidates
+            .iter()
+            .take(decision.selected_agents.len())
+            .enumerate()
+        {
+            let mut alternatives = candidates.clone();
+            alternatives.remove(position);
+            fallbacks.insert(primary.clone(), alternatives);
+        }
+    }
+
+    /// Target agent followed by its recorded fallbacks
+    fn delivery_candidates(&self, target_agent: &str) -> Vec<String> {
+        let mut candidates = vec![target_agent.to_string()];
+        if let Some(fallbacks) = self.fallbacks.read().unwrap().get(target_agent) {
+            candidates.extend(fallbacks.iter().cloned());
+        }
+        candidates
+    }
+
     /// Get reference to transport for testing
     pub fn transport(&self) -> Option<&std::sync::Arc<HybridTransportClient>> {
         Some(&self.transport)
@@ -507,32 +873,71 @@ impl A2AClient {
         T: Serialize + Send + Sync + 'static,
         R: for<'de> Deserialize<'de> + Send + Sync + 'static,
     {
-        // Add A2A metadata to envelope extensions
-        let enhanced_envelope = self.add_a2a_metadata(
-            envelope,
-            Some(target_agent.to_string()),
-            A2AMessageType::DirectMessage,
-        )?;
-
         // Use A2A transport delegation pattern
-        if let Some(a2a_transport) = self.transport.internal_a2a_client() {
-            // Convert to JSON envelope for internal routing
-            let (meta, data) = enhanced_envelope.extract();
-            let json_data = serde_json::to_value(data).map_err(|e| {
-                crate::error::QollectiveError::transport(format!("Serialization failed: {}", e))
-            })?;
-            let json_envelope = Envelope::new(meta, json_data);
-
-            // Send directly to target agent using agent ID
-            let result: Result<Envelope<R>> = a2a_transport
-                .send_envelope(target_agent, json_envelope)
-                .await;
-            result
-        } else {
-            Err(crate::error::QollectiveError::transport(
+        let Some(a2a_transport) = self.transport.internal_a2a_client() else {
+            return Err(crate::error::QollectiveError::transport(
                 "A2A transport not available in transport layer".to_string(),
-            ))
+            ));
+        };
+
+        // Convert to JSON once so the envelope can be resent to fallback agents
+        let (meta, data) = envelope.extract();
+        let json_data = serde_json::to_value(data).map_err(|e| {
+            crate::error::QollectiveError::transport(format!("Serialization failed: {}", e))
+        })?;
+
+        let mut last_error = None;
+        for candidate in self.delivery_candidates(target_agent) {
+            let agent_id = Uuid::parse_str(&candidate).ok();
+
+            // Skip agents whose circuit is open
+            if let (Some(health_monitor), Some(agent_id)) = (&self.health_monitor, agent_id) {
+                if health_monitor.lock().await.is_circuit_open(&agent_id).await {
+                    tracing::debug!("Skipping agent {} with open circuit", candidate);
+                    last_error = Some(crate::error::QollectiveError::transport(format!(
+                        "Circuit open for agent {}",
+                        candidate
+                    )));
+                    continue;
+                }
+            }
+
+            // Add A2A metadata to envelope extensions
+            let json_envelope = self.add_a2a_metadata(
+                Envelope::new(meta.clone(), json_data.clone()),
+                Some(candidate.clone()),
+                A2AMessageType::DirectMessage,
+            )?;
+
+            // Send directly to the candidate agent using agent ID
+            let in_flight = self
+                .router
+                .as_ref()
+                .zip(agent_id)
+                .map(|(router, agent_id)| router.begin_request(agent_id));
+            let result: Result<Envelope<R>> =
+                a2a_transport.send_envelope(&candidate, json_envelope).await;
+            if let Some(in_flight) = in_flight {
+                in_flight.finish(result.is_ok());
+            }
+            if let (Some(health_monitor), Some(agent_id)) = (&self.health_monitor, agent_id) {
+                health_monitor
+                    .lock()
+                    .await
+                    .record_health_result(&agent_id, result.is_ok())
+                    .await;
+            }
+
+            match result {
+                Ok(response) => return Ok(response),
+                Err(e) => {
+                    tracing::warn!("A2A request to agent {} failed: {}", candidate, e);
+                    last_error = Some(e);
+                }
+            }
         }
+
+        Err(last_error.expect("delivery candidates always include the target agent"))
     }
 
     /// Broadcast envelope to capability
@@ -1010,6 +1415,99 @@ impl A2AClient {
         Ok(subject) // Return subject for manual subscription
     }
 
+    /// Get an A2A task from the server tracking it (`tasks/get`)
+    ///
+    /// Returns `None` if the server does not know the task.
+    pub async fn get_task(&self, task_id: &str) -> Result<Option<A2ATask>> {
+        self.request_task(subjects::A2A_TASKS_GET, task_id).await
+    }
+
+    /// Cancel an A2A task (`tasks/cancel`)
+    ///
+    /// Returns the canceled task, the unchanged task if it had already finished,
+    /// or `None` if the server does not know the task.
+    pub async fn cancel_task(&self, task_id: &str) -> Result<Option<A2ATask>> {
+        self.request_task(subjects::A2A_TASKS_CANCEL, task_id).await
+    }
+
+    /// Stream the status and artifact updates of an A2A task
+    ///
+    /// The stream ends after the update moving the task into a terminal state.
+    /// Subscribe before submitting the task to not miss its first updates.
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub async fn subscribe_to_task(
+        &self,
+        task_id: &str,
+    ) -> Result<tokio::sync::mpsc::Receiver<TaskUpdate>> {
+        use futures::StreamExt;
+
+        let nats_client = self.transport.internal_nats_client().ok_or_else(|| {
+            crate::error::QollectiveError::transport(
+                "NATS client not available in transport layer".to_string(),
+            )
+        })?;
+        let subject = crate::constants::helpers::a2a_task_updates_subject(task_id);
+        let mut subscriber = nats_client.subscribe(&subject, None).await?;
+
+        let (sender, receiver) = tokio::sync::mpsc::channel(32);
+        tokio::spawn(async move {
+            while let Some(message) = subscriber.next().await {
+                let update = match crate::envelope::NatsEnvelopeCodec::decode::<TaskUpdate>(
+                    &message.payload,
+                ) {
+                    Ok(envelope) => envelope.payload,
+                    Err(e) => {
+                        tracing::warn!("Ignoring malformed task update on {}: {}", subject, e);
+                        continue;
+                    }
+                };
+                let is_final = update.is_final();
+                if sender.send(update).await.is_err() || is_final {
+                    break;
+                }
+            }
+        });
+        Ok(receiver)
+    }
+
+    #[cfg(not(any(feature = "nats-client", feature = "nats-server")))]
+    pub async fn subscribe_to_task(
+        &self,
+        _task_id: &str,
+    ) -> Result<tokio::sync::mpsc::Receiver<TaskUpdate>> {
+        Err(crate::error::QollectiveError::feature_not_enabled(
+            "A2A task updates require nats-client or nats-server feature",
+        ))
+    }
+
+    /// Send a task request to the server over NATS
+    async fn request_task(&self, subject: &str, task_id: &str) -> Result<Option<A2ATask>> {
+        let nats_client = self.transport.internal_nats_client().ok_or_else(|| {
+            crate::error::QollectiveError::transport(
+                "NATS client not available in transport layer".to_string(),
+            )
+        })?;
+        let meta = Meta {
+            request_id: Some(Uuid::now_v7()),
+            timestamp: Some(chrono::Utc::now()),
+            tenant: Some("agents".to_string()),
+            ..Default::default()
+        };
+        let envelope = self.add_a2a_metadata(
+            Envelope::new(
+                meta,
+                TaskIdParams {
+                    task_id: task_id.to_string(),
+                },
+            ),
+            None,
+            A2AMessageType::DirectMessage,
+        )?;
+        let response: Envelope<Option<A2ATask>> =
+            nats_client.send_envelope(subject, envelope).await?;
+        Ok(response.payload)
+    }
+
     /// Helper method to add A2A metadata to envelope extensions
     fn add_a2a_metadata<T>(
         &self,
@@ -1338,4 +1836,117 @@ mod tests {
         assert!(envelope.meta.timestamp.is_some());
         assert!(envelope.error.is_none());
     }
+
+    #[test]
+    fn test_agent_table_tracks_registrations_and_heartbeats() {
+        let mut table = AgentTable::default();
+        let agent = create_test_agent_info();
+
+        assert!(table
+            .upsert(agent.clone(), AgentMetadata::default(), 1)
+            .unwrap());
+        // Re-announcing a known agent refreshes it even when the table is full
+        assert!(!table
+            .upsert(agent.clone(), AgentMetadata::default(), 1)
+            .unwrap());
+        assert!(table
+            .upsert(create_test_agent_info(), AgentMetadata::default(), 1)
+            .is_err());
+
+        let mut heartbeat = Heartbeat {
+            agent_id: agent.id,
+            health_status: HealthStatus::Healthy,
+            timestamp: SystemTime::now(),
+            metadata: Some(HashMap::from([("load".to_string(), "0.4".to_string())])),
+        };
+        let (updated, health_changed) = table.heartbeat(&heartbeat, SystemTime::now()).unwrap();
+        assert!(!health_changed);
+        assert_eq!(
+            updated.metadata.get("load").map(String::as_str),
+            Some("0.4")
+        );
+
+        heartbeat.health_status = HealthStatus::Warning;
+        let (updated, health_changed) = table.heartbeat(&heartbeat, SystemTime::now()).unwrap();
+        assert!(health_changed);
+        assert_eq!(updated.health_status, HealthStatus::Warning);
+
+        heartbeat.agent_id = Uuid::now_v7();
+        assert!(table.heartbeat(&heartbeat, SystemTime::now()).is_none());
+    }
+
+    #[test]
+    fn test_agent_table_expires_agents_past_ttl() {
+        let mut table = AgentTable::default();
+        let now = SystemTime::now();
+        let mut stale = create_test_agent_info();
+        stale.last_heartbeat = now - Duration::from_secs(120);
+        let fresh = create_test_agent_info();
+        table
+            .upsert(stale.clone(), AgentMetadata::default(), 10)
+            .unwrap();
+        table
+            .upsert(fresh.clone(), AgentMetadata::default(), 10)
+            .unwrap();
+
+        let expired = table.expire(Duration::from_secs(60), now);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, stale.id);
+        assert!(table.agents.contains_key(&fresh.id));
+        assert!(!table.agents.contains_key(&stale.id));
+    }
+
+    #[test]
+    fn test_registry_events_mirror_agents() {
+        let origin = Uuid::now_v7();
+        let mut agent = create_test_agent_info();
+        agent
+            .metadata
+            .insert("region".to_string(), "alpha-quadrant".to_string());
+
+        let event = registry_event(RegistryEvent::AGENT_REGISTERED, &agent, origin);
+        assert_eq!(event.origin(), Some(origin.to_string().as_str()));
+
+        let mut mirror = AgentTable::default();
+        assert!(mirror.apply(&event, 10));
+        let (mirrored, _) = &mirror.agents[&agent.id];
+        assert_eq!(mirrored.name, agent.name);
+        assert_eq!(mirrored.capabilities, agent.capabilities);
+        assert_eq!(mirrored.health_status, HealthStatus::Healthy);
+        // Registry bookkeeping keys are not copied into the agent metadata
+        assert_eq!(mirrored.metadata, agent.metadata);
+
+        agent.health_status = HealthStatus::Unhealthy;
+        let event = registry_event(RegistryEvent::AGENT_HEALTH_CHANGED, &agent, origin);
+        assert!(mirror.apply(&event, 10));
+        assert_eq!(
+            mirror.agents[&agent.id].0.health_status,
+            HealthStatus::Unhealthy
+        );
+
+        let event = registry_event(RegistryEvent::AGENT_EXPIRED, &agent, origin);
+        assert!(mirror.apply(&event, 10));
+        assert!(mirror.agents.is_empty());
+        // Events for unknown agents leave the mirror untouched
+        assert!(!mirror.apply(&event, 10));
+    }
+
+    #[test]
+    fn test_mirrored_registrations_respect_max_agents() {
+        let origin = Uuid::now_v7();
+        let mut mirror = AgentTable::default();
+        let first = create_test_agent_info();
+        let event = registry_event(RegistryEvent::AGENT_REGISTERED, &first, origin);
+        assert!(mirror.apply(&event, 1));
+
+        // A full table refuses new agents but still refreshes known ones
+        let second = create_test_agent_info();
+        let event = registry_event(RegistryEvent::AGENT_REGISTERED, &second, origin);
+        assert!(!mirror.apply(&event, 1));
+        assert!(!mirror.agents.contains_key(&second.id));
+
+        let event = registry_event(RegistryEvent::AGENT_REGISTERED, &first, origin);
+        assert!(mirror.apply(&event, 1));
+        assert_eq!(mirror.agents.len(), 1);
+    }
 }
@@ -3,7 +3,7 @@
 
 //! Common client traits and utilities for protocol abstraction.
 
-use crate::constants::{circuit_breaker, network};
+use crate::constants::{limits, network};
 
 /// Client configuration
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -50,7 +50,7 @@ impl Default for ClientConfig {
                 format!("http://{}:{}", network::DEFAULT_LOCALHOST, port)
             },
             timeout_seconds: 30,
-            retry_attempts: circuit_breaker::DEFAULT_MAX_RETRIES,
+            retry_attempts: limits::DEFAULT_MAX_RETRIES,
             tenant_config: TenantClientConfig::default(),
         }
     }
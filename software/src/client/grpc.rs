@@ -10,11 +10,13 @@
 #[cfg(feature = "grpc-client")]
 use {
     crate::{
+        constants::endpoints,
         envelope::Envelope,
         error::{QollectiveError, Result},
         generated::qollective::HealthCheckResponse,
+        transport::grpc::{normalize_grpc_route, EnvelopeStream},
     },
-    futures_util::Stream,
+    futures_util::{Stream, StreamExt},
     serde::{Deserialize, Serialize},
     std::sync::Arc,
 };
@@ -41,8 +43,37 @@ impl GrpcClient {
 
     /// Create a gRPC client with its own transport layer
     pub async fn new(config: GrpcClientConfig) -> Result<Self> {
-        // Create transport configuration from gRPC config (CONFIG FIRST PRINCIPLE)
-        let transport_config = crate::transport::TransportDetectionConfig {
+        let transport_config = Self::transport_config(&config);
+
+        // Create the actual internal gRPC client that the transport will use
+        let internal_grpc_client = crate::transport::grpc::InternalGrpcClient::new(config).await?;
+
+        Ok(Self::with_internal_client(
+            transport_config,
+            internal_grpc_client,
+        ))
+    }
+
+    /// Create a gRPC client that authenticates every call with tokens from `provider`
+    #[cfg(feature = "security")]
+    pub async fn with_credentials(
+        config: GrpcClientConfig,
+        provider: Arc<dyn crate::security::CredentialProvider>,
+    ) -> Result<Self> {
+        let transport_config = Self::transport_config(&config);
+        let internal_grpc_client = crate::transport::grpc::InternalGrpcClient::new(config)
+            .await?
+            .with_credential_provider(provider);
+
+        Ok(Self::with_internal_client(
+            transport_config,
+            internal_grpc_client,
+        ))
+    }
+
+    /// Create transport configuration from gRPC config (CONFIG FIRST PRINCIPLE)
+    fn transport_config(config: &GrpcClientConfig) -> crate::transport::TransportDetectionConfig {
+        crate::transport::TransportDetectionConfig {
             enable_auto_detection: true,
             detection_timeout: std::time::Duration::from_millis(config.timeout_ms),
             capability_cache_ttl: std::time::Duration::from_millis(
@@ -50,18 +81,20 @@ impl GrpcClient {
             ),
             retry_failed_detections: config.retry_attempts > 0,
             max_detection_retries: config.retry_attempts,
-        };
+        }
+    }
 
+    fn with_internal_client(
+        transport_config: crate::transport::TransportDetectionConfig,
+        internal_grpc_client: crate::transport::grpc::InternalGrpcClient,
+    ) -> Self {
         // Create transport with gRPC client injected
-        let mut transport = crate::transport::HybridTransportClient::new(transport_config);
+        let transport = crate::transport::HybridTransportClient::new(transport_config)
+            .with_internal_grpc_client(Arc::new(internal_grpc_client));
 
-        // Create the actual internal gRPC client that the transport will use
-        let internal_grpc_client = crate::transport::grpc::InternalGrpcClient::new(config).await?;
-        transport = transport.with_internal_grpc_client(Arc::new(internal_grpc_client));
-
-        Ok(Self {
+        Self {
             transport: Arc::new(transport),
-        })
+        }
     }
 
     // Old constructor methods removed - now using transport delegation pattern
@@ -75,6 +108,9 @@ impl GrpcClient {
     {
         // Delegate to transport layer - get internal gRPC client and call its method
         if let Some(grpc_client) = self.transport.internal_grpc_client() {
+            let request = self
+                .transport
+                .apply_meta_policy(request, endpoints::GRPC_UNARY_METHOD);
             grpc_client.send_envelope(request).await
         } else {
             Err(QollectiveError::transport(
@@ -83,21 +119,89 @@ impl GrpcClient {
         }
     }
 
+    /// Send a unary request to the handler registered at `route` on the server
+    pub async fn send_envelope_to<Req, Res>(
+        &self,
+        route: &str,
+        request: Envelope<Req>,
+    ) -> Result<Envelope<Res>>
+    where
+        Req: Serialize,
+        Res: for<'de> Deserialize<'de>,
+    {
+        // Delegate to transport layer - get internal gRPC client and call its method
+        if let Some(grpc_client) = self.transport.internal_grpc_client() {
+            let request = self
+                .transport
+                .apply_meta_policy(request, &normalize_grpc_route(route));
+            grpc_client.send_envelope_to(route, request).await
+        } else {
+            Err(QollectiveError::transport(
+                "No gRPC client configured in transport layer",
+            ))
+        }
+    }
+
     /// Send a server streaming request (single request -> stream of responses)
     pub async fn send_server_streaming<Req, Res>(
         &self,
-        _request: Envelope<Req>,
-    ) -> Result<Box<dyn Stream<Item = Result<Envelope<Res>>> + Send + Unpin>>
+        request: Envelope<Req>,
+    ) -> Result<EnvelopeStream<Res>>
     where
         Req: Serialize,
+        Res: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        // Delegate to transport layer - get internal gRPC client and call its method
+        if let Some(grpc_client) = self.transport.internal_grpc_client() {
+            let request = self
+                .transport
+                .apply_meta_policy(request, endpoints::GRPC_SERVER_STREAMING_METHOD);
+            grpc_client.send_server_streaming(request).await
+        } else {
+            Err(QollectiveError::transport(
+                "No gRPC client configured in transport layer",
+            ))
+        }
+    }
+
+    /// Send a client streaming request (stream of requests -> single response)
+    pub async fn send_client_streaming<Req, Res, S>(&self, requests: S) -> Result<Envelope<Res>>
+    where
+        Req: Serialize + Send + 'static,
         Res: for<'de> Deserialize<'de>,
+        S: Stream<Item = Envelope<Req>> + Send + 'static,
     {
         // Delegate to transport layer - get internal gRPC client and call its method
-        if let Some(_grpc_client) = self.transport.internal_grpc_client() {
-            // For now, return an error since streaming delegation needs more complex type handling
+        if let Some(grpc_client) = self.transport.internal_grpc_client() {
+            grpc_client
+                .send_client_streaming(
+                    self.outgoing_stream(endpoints::GRPC_CLIENT_STREAMING_METHOD, requests),
+                )
+                .await
+        } else {
             Err(QollectiveError::transport(
-                "gRPC streaming delegation not yet fully implemented",
+                "No gRPC client configured in transport layer",
             ))
+        }
+    }
+
+    /// Send a bidirectional streaming request (stream of requests -> stream of responses)
+    pub async fn send_bidirectional_streaming<Req, Res, S>(
+        &self,
+        requests: S,
+    ) -> Result<EnvelopeStream<Res>>
+    where
+        Req: Serialize + Send + 'static,
+        Res: for<'de> Deserialize<'de> + Send + 'static,
+        S: Stream<Item = Envelope<Req>> + Send + 'static,
+    {
+        // Delegate to transport layer - get internal gRPC client and call its method
+        if let Some(grpc_client) = self.transport.internal_grpc_client() {
+            grpc_client
+                .send_bidirectional_streaming(
+                    self.outgoing_stream(endpoints::GRPC_BIDIRECTIONAL_STREAMING_METHOD, requests),
+                )
+                .await
         } else {
             Err(QollectiveError::transport(
                 "No gRPC client configured in transport layer",
@@ -105,6 +209,20 @@ impl GrpcClient {
         }
     }
 
+    /// Enforce the transport's meta policy on each envelope of an outgoing request stream
+    fn outgoing_stream<Req, S>(
+        &self,
+        method: &'static str,
+        requests: S,
+    ) -> impl Stream<Item = Envelope<Req>> + Send + 'static
+    where
+        Req: Send + 'static,
+        S: Stream<Item = Envelope<Req>> + Send + 'static,
+    {
+        let transport = Arc::clone(&self.transport);
+        requests.map(move |request| transport.apply_meta_policy(request, method))
+    }
+
     /// Perform a health check on the gRPC service
     pub async fn health_check(&self) -> Result<HealthCheckResponse> {
         // Delegate to transport layer - get internal gRPC client and call its method
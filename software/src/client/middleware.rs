@@ -635,6 +635,8 @@ mod tests {
             version: None,
             duration: None,
             tenant: Some("original-tenant".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -667,6 +669,8 @@ mod tests {
             version: None,
             duration: None,
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -699,6 +703,8 @@ mod tests {
             version: None,
             duration: None,
             tenant: Some("existing-tenant".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: Some(OnBehalfOfMeta {
                 original_user: "user123".to_string(),
                 delegating_user: "delegator789".to_string(),
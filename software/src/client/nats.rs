@@ -28,6 +28,9 @@ use tokio::sync::mpsc;
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 pub use crate::transport::nats::{ConnectionEvent, ConnectionMetrics, ConnectionState};
 
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+pub use crate::transport::nats_jetstream::DurablePublishAck;
+
 #[cfg(not(any(feature = "nats-client", feature = "nats-server")))]
 #[derive(Clone)]
 pub struct NatsClient;
@@ -79,6 +82,7 @@ impl NatsClient {
                 ttl_ms: config.discovery.ttl_ms,
                 ..Default::default()
             },
+            jetstream: config.jetstream,
         };
 
         // Create the actual internal NATS client that the transport will use
@@ -127,6 +131,7 @@ impl NatsClient {
     {
         // Delegate to transport layer - get internal NATS client and call its method
         if let Some(nats_client) = self.transport.internal_nats_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, subject);
             nats_client.send_envelope(subject, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -149,6 +154,7 @@ impl NatsClient {
     {
         // Delegate to transport layer - use the new publish_envelope method
         if let Some(nats_client) = self.transport.internal_nats_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, subject);
             nats_client.publish_envelope(subject, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -190,6 +196,55 @@ impl NatsClient {
         ))
     }
 
+    /// Publish an envelope durably to a JetStream stream
+    ///
+    /// Resolves once the stream has stored the envelope, so the message survives
+    /// consumer and server restarts. Fails if no stream captures `subject`.
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub async fn publish_durable<T>(
+        &self,
+        subject: &str,
+        envelope: Envelope<T>,
+    ) -> Result<DurablePublishAck>
+    where
+        T: serde::Serialize,
+    {
+        if let Some(nats_client) = self.transport.internal_nats_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, subject);
+            nats_client.publish_durable(subject, envelope).await
+        } else {
+            Err(QollectiveError::transport(
+                "No NATS client configured in transport layer",
+            ))
+        }
+    }
+
+    #[cfg(not(any(feature = "nats-client", feature = "nats-server")))]
+    pub async fn publish_durable<T, A>(&self, _subject: &str, _envelope: T) -> Result<A> {
+        Err(QollectiveError::feature_not_enabled(
+            "NATS client requires nats-client or nats-server feature",
+        ))
+    }
+
+    /// Create or update the JetStream streams defined in `NatsConfig::jetstream`
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub async fn ensure_streams(&self) -> Result<()> {
+        if let Some(nats_client) = self.transport.internal_nats_client() {
+            nats_client.ensure_streams().await
+        } else {
+            Err(QollectiveError::transport(
+                "No NATS client configured in transport layer",
+            ))
+        }
+    }
+
+    #[cfg(not(any(feature = "nats-client", feature = "nats-server")))]
+    pub async fn ensure_streams(&self) -> Result<()> {
+        Err(QollectiveError::feature_not_enabled(
+            "NATS client requires nats-client or nats-server feature",
+        ))
+    }
+
     /// Publish raw bytes to a NATS subject (for ecosystem compatibility)
     #[cfg(any(feature = "nats-client", feature = "nats-server"))]
     pub async fn publish_raw(&self, subject: &str, payload: &[u8]) -> Result<()> {
@@ -356,6 +411,8 @@ mod tests {
                 version: Some("1.0.0".to_string()),
                 duration: None,
                 tenant: Some("test-tenant".to_string()),
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
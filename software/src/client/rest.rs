@@ -47,6 +47,10 @@ pub struct JwtConfig {
     pub tenant_header_name: String,
     /// Custom onBehalfOf header name when no JWT is available (default: "X-On-Behalf-Of")
     pub on_behalf_of_header_name: String,
+    /// Provider of access tokens sent in the JWT header; requests rejected with
+    /// 401 are retried once with a fresh token
+    #[cfg(feature = "security")]
+    pub credential_provider: Option<Arc<dyn crate::security::CredentialProvider>>,
 }
 
 /// TLS configuration for future support
@@ -74,6 +78,8 @@ impl Default for JwtConfig {
             header_prefix: "Bearer ".to_string(),
             tenant_header_name: "X-Tenant-ID".to_string(),
             on_behalf_of_header_name: "X-On-Behalf-Of".to_string(),
+            #[cfg(feature = "security")]
+            credential_provider: None,
         }
     }
 }
@@ -135,6 +141,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.post(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -151,6 +158,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.get(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -177,6 +185,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.put(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -197,6 +206,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.delete(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -227,6 +237,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.options(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -257,6 +268,7 @@ impl RestClient {
     {
         // Delegate to transport layer - get internal REST client and call its method
         if let Some(rest_client) = self.transport.internal_rest_client() {
+            let envelope = self.transport.apply_meta_policy(envelope, path);
             rest_client.patch(path, envelope).await
         } else {
             Err(QollectiveError::transport(
@@ -544,6 +556,15 @@ impl RestClientBuilder {
         self
     }
 
+    #[cfg(feature = "security")]
+    pub fn credential_provider(
+        mut self,
+        provider: Arc<dyn crate::security::CredentialProvider>,
+    ) -> Self {
+        self.config.jwt_config.credential_provider = Some(provider);
+        self
+    }
+
     /// Add custom configuration to the underlying reqwest ClientBuilder
     pub fn customize_client_builder<F>(mut self, customize: F) -> Self
     where
@@ -591,6 +612,15 @@ impl JwtConfig {
         self.on_behalf_of_header_name = header_name.into();
         self
     }
+
+    #[cfg(feature = "security")]
+    pub fn with_credential_provider(
+        mut self,
+        provider: Arc<dyn crate::security::CredentialProvider>,
+    ) -> Self {
+        self.credential_provider = Some(provider);
+        self
+    }
 }
 
 #[cfg(feature = "rest-client")]
@@ -706,10 +736,7 @@ mod tests {
 
         #[cfg(feature = "rest-client")]
         {
-            let client = builder
-                .build()
-                .await
-                .expect("Failed to build client");
+            let client = builder.build().await.expect("Failed to build client");
             let client_config = client.config().expect("Should have config");
             assert_eq!(client_config.base.base_url, "https://api.example.com");
             assert_eq!(client_config.base.timeout_seconds, 60);
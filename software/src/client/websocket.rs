@@ -53,6 +53,29 @@ pub enum WebSocketMessageType {
     },
     #[serde(rename = "error")]
     Error { message: String, code: Option<u32> },
+    /// Join a server-side room; the server acknowledges with the same message
+    #[serde(rename = "join")]
+    Join { room: String },
+    /// Leave a server-side room; the server acknowledges with the same message
+    #[serde(rename = "leave")]
+    Leave { room: String },
+}
+
+/// WebSocket frame pairing a protocol message with an optional correlation id
+///
+/// Requests carry a fresh id that the server echoes on the matching response, so
+/// many requests can share one connection. Server-initiated pushes carry no id.
+#[cfg(feature = "websocket-client")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSocketFrame {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Time the caller grants a request, in milliseconds; the server rebuilds the
+    /// deadline from it on its own clock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    #[serde(flatten)]
+    pub message: WebSocketMessageType,
 }
 
 #[cfg(feature = "websocket-client")]
@@ -186,6 +209,41 @@ impl WebSocketClient {
         ))
     }
 
+    /// Subscribe to envelopes the server pushes over the client's connection
+    pub async fn subscribe(
+        &self,
+    ) -> Result<crate::transport::websocket_connection::EnvelopePushStream> {
+        self.websocket_transport()?
+            .subscribe(&self.endpoint_url)
+            .await
+    }
+
+    /// Join a server-side room so envelopes pushed to it reach this client
+    pub async fn join_room(&self, room: &str) -> Result<()> {
+        self.websocket_transport()?
+            .join_room(&self.endpoint_url, room)
+            .await
+    }
+
+    /// Leave a server-side room
+    pub async fn leave_room(&self, room: &str) -> Result<()> {
+        self.websocket_transport()?
+            .leave_room(&self.endpoint_url, room)
+            .await
+    }
+
+    fn websocket_transport(&self) -> Result<&crate::transport::websocket::WebSocketTransport> {
+        self.transport
+            .as_ref()
+            .and_then(|transport| transport.websocket_transport())
+            .map(|transport| transport.as_ref())
+            .ok_or_else(|| {
+                QollectiveError::transport(
+                    "No WebSocket transport configured in transport layer".to_string(),
+                )
+            })
+    }
+
     /// Get client configuration
     pub fn config(&self) -> &WebSocketClientConfig {
         &self.config
@@ -1004,6 +1004,25 @@ mod tests {
             assert!(nats_config.connection.tls.enabled); // Production should use TLS
         }
 
+        // Servers' default meta policy follows the detected preset
+        let mut meta = crate::envelope::Meta {
+            debug: Some(crate::envelope::meta::DebugMeta {
+                trace_enabled: Some(true),
+                db_queries: Vec::new(),
+                memory_usage: None,
+                stack_trace: Some("warp core breach".to_string()),
+                environment_vars: HashMap::new(),
+                request_headers: HashMap::new(),
+                log_level: None,
+                profiling_data: None,
+            }),
+            ..Default::default()
+        };
+        crate::envelope::meta::OutgoingMetaPolicy::for_environment()
+            .unwrap()
+            .apply(&mut meta, "/");
+        assert!(meta.debug.is_none());
+
         env::remove_var("ENVIRONMENT");
     }
 
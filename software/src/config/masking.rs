@@ -6,9 +6,22 @@
 //! This module provides a configurable field masking system that can protect PII
 //! and sensitive data when serializing envelopes to logs, debug output, or other
 //! non-secure contexts.
+//!
+//! Field paths are dotted and matched case- and underscore-insensitively, so the
+//! rule `security.userId` covers the serialized `security.user_id`. Metadata
+//! sections sit at the root (`security.*`, `tracing.baggage.*`), envelope
+//! payloads under `data` and error details under `error.details`.
 
+use crate::envelope::{Envelope, EnvelopeError, Meta};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::FormatFields;
 
 /// Configuration for field masking behavior
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -48,6 +61,9 @@ pub struct MaskingRule {
     /// Type of masking to apply
     pub mask_type: MaskType,
     /// Optional condition for when to apply this rule
+    ///
+    /// Clauses of the form `key=value` or `key!=value`, joined by `&&`, over the
+    /// keys `tenant` and `environment` (e.g. `environment=production && tenant!=internal`).
     pub condition: Option<String>,
     /// Priority for rule resolution (higher wins)
     pub priority: u8,
@@ -77,6 +93,7 @@ pub enum MaskType {
 pub struct FieldMasker {
     config: MaskingConfig,
     compiled_rules: Vec<CompiledRule>,
+    environment: Option<String>,
 }
 
 /// Internal compiled version of masking rule for performance
@@ -85,6 +102,35 @@ struct CompiledRule {
     pattern: glob::Pattern,
     mask_type: MaskType,
     priority: u8,
+    condition: Option<RuleCondition>,
+}
+
+/// Parsed form of [`MaskingRule::condition`]; every clause must hold
+#[derive(Debug, Clone)]
+struct RuleCondition {
+    clauses: Vec<ConditionClause>,
+}
+
+#[derive(Debug, Clone)]
+struct ConditionClause {
+    key: ConditionKey,
+    negated: bool,
+    value: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ConditionKey {
+    Tenant,
+    Environment,
+}
+
+/// What rule conditions are evaluated against
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaskingContext {
+    /// Tenant the masked data belongs to
+    pub tenant: Option<String>,
+    /// Deployment environment (e.g. "production")
+    pub environment: Option<String>,
 }
 
 /// Trait for types that can have their fields masked
@@ -174,6 +220,86 @@ impl MaskingRule {
     }
 }
 
+impl MaskingContext {
+    /// Create an empty context; conditions on absent keys only hold when negated
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the tenant conditions are evaluated against
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Set the environment conditions are evaluated against
+    pub fn with_environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// Context of data carried under `meta`: its tenant and monitoring environment
+    pub fn from_meta(meta: &Meta) -> Self {
+        Self {
+            tenant: meta.tenant.clone(),
+            environment: meta
+                .monitoring
+                .as_ref()
+                .and_then(|monitoring| monitoring.environment.as_ref())
+                .and_then(|environment| match serde_json::to_value(environment) {
+                    Ok(Value::String(name)) => Some(name),
+                    _ => None,
+                }),
+        }
+    }
+}
+
+impl RuleCondition {
+    fn parse(condition: &str) -> Result<Self, MaskingError> {
+        let invalid = |reason: &str| MaskingError::InvalidConfig {
+            message: format!("Invalid rule condition '{}': {}", condition, reason),
+        };
+
+        let clauses = condition
+            .split("&&")
+            .map(|clause| {
+                let (key, negated, value) = match clause.split_once("!=") {
+                    Some((key, value)) => (key, true, value),
+                    None => {
+                        let (key, value) = clause
+                            .split_once('=')
+                            .ok_or_else(|| invalid("expected key=value or key!=value"))?;
+                        (key, false, value)
+                    }
+                };
+                let key = match key.trim() {
+                    "tenant" => ConditionKey::Tenant,
+                    "environment" => ConditionKey::Environment,
+                    other => return Err(invalid(&format!("unknown key '{}'", other))),
+                };
+                Ok(ConditionClause {
+                    key,
+                    negated,
+                    value: value.trim().to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { clauses })
+    }
+
+    fn matches(&self, context: &MaskingContext) -> bool {
+        self.clauses.iter().all(|clause| {
+            let actual = match clause.key {
+                ConditionKey::Tenant => context.tenant.as_deref(),
+                ConditionKey::Environment => context.environment.as_deref(),
+            };
+            let equal = actual.is_some_and(|actual| actual.eq_ignore_ascii_case(&clause.value));
+            equal != clause.negated
+        })
+    }
+}
+
 impl FieldMasker {
     /// Create a new field masker with given configuration
     pub fn new(config: MaskingConfig) -> Result<Self, MaskingError> {
@@ -181,6 +307,7 @@ impl FieldMasker {
             return Ok(Self {
                 config,
                 compiled_rules: Vec::new(),
+                environment: None,
             });
         }
 
@@ -193,9 +320,16 @@ impl FieldMasker {
         Ok(Self {
             config,
             compiled_rules: rules,
+            environment: None,
         })
     }
 
+    /// Set the environment this process runs in, used when the data itself names none
+    pub fn with_environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
     /// Check if masking is enabled
     pub fn is_enabled(&self) -> bool {
         self.config.enabled
@@ -203,33 +337,76 @@ impl FieldMasker {
 
     /// Apply masking to a field value based on its path
     pub fn mask_value(&self, field_path: &str, value: &str) -> String {
-        if !self.config.enabled {
-            return value.to_string();
-        }
+        self.mask_value_in(field_path, value, &MaskingContext::default())
+    }
 
-        // Find first matching rule by priority
-        for rule in &self.compiled_rules {
-            if rule.pattern.matches(field_path) {
+    /// Apply masking to a field value, evaluating rule conditions against `context`
+    pub fn mask_value_in(&self, field_path: &str, value: &str, context: &MaskingContext) -> String {
+        match self.matching_rule(field_path, context) {
+            Some(rule) => {
                 if self.config.audit_access {
                     tracing::info!("Masking sensitive field: {}", field_path);
                 }
-                return self.apply_mask_type(&rule.mask_type, value);
+                self.apply_mask_type(&rule.mask_type, value)
             }
+            // No rule matched, return original value
+            None => value.to_string(),
         }
-
-        // No rule matched, return original value
-        value.to_string()
     }
 
     /// Check if a field should be masked
     pub fn should_mask(&self, field_path: &str) -> bool {
+        self.matching_rule(field_path, &MaskingContext::default())
+            .is_some()
+    }
+
+    /// Mask every leaf of a JSON value whose path under `path` matches a rule
+    ///
+    /// Objects extend the path with their keys; array elements share the array's path.
+    pub fn mask_json(&self, path: &str, value: &mut Value, context: &MaskingContext) {
+        self.mask_json_leaves(path, value, context, false);
+    }
+
+    /// Mask a serialized envelope: metadata at the root, payload under `data`
+    pub fn mask_envelope_json(&self, envelope: &mut Value) {
+        let context = envelope
+            .get("meta")
+            .and_then(|meta| serde_json::from_value::<Meta>(meta.clone()).ok())
+            .map(|meta| MaskingContext::from_meta(&meta))
+            .unwrap_or_default();
+
+        if let Some(meta) = envelope.get_mut("meta") {
+            self.mask_json("", meta, &context);
+        }
+        if let Some(payload) = envelope.get_mut("payload") {
+            self.mask_json("data", payload, &context);
+        }
+        if let Some(error) = envelope.get_mut("error") {
+            self.mask_json("error", error, &context);
+        }
+    }
+
+    /// Mask a value recorded on a log event or span under the field `name`
+    ///
+    /// JSON values are walked: serialized envelopes as envelopes, `meta` from the
+    /// metadata root and anything else below `name`. Other values are masked as a whole.
+    pub fn mask_log_field(&self, name: &str, value: &str) -> String {
         if !self.config.enabled {
-            return false;
+            return value.to_string();
         }
 
-        self.compiled_rules
-            .iter()
-            .any(|rule| rule.pattern.matches(field_path))
+        match serde_json::from_str::<Value>(value) {
+            Ok(mut json @ (Value::Object(_) | Value::Array(_))) => {
+                if json.get("meta").is_some() && json.get("payload").is_some() {
+                    self.mask_envelope_json(&mut json);
+                } else {
+                    let path = if name == "meta" { "" } else { name };
+                    self.mask_json(path, &mut json, &MaskingContext::default());
+                }
+                json.to_string()
+            }
+            _ => self.mask_value(name, value),
+        }
     }
 
     /// Get the masking level
@@ -238,6 +415,129 @@ impl FieldMasker {
     }
 
     // Private implementation methods
+    fn matching_rule(&self, field_path: &str, context: &MaskingContext) -> Option<&CompiledRule> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let field_path = normalize_path(field_path);
+        let environment = context
+            .environment
+            .clone()
+            .or_else(|| self.environment.clone());
+        let context = MaskingContext {
+            tenant: context.tenant.clone(),
+            environment,
+        };
+
+        // Rules are sorted by priority, so the first match wins
+        self.compiled_rules.iter().find(|rule| {
+            rule.pattern.matches(&field_path)
+                && rule
+                    .condition
+                    .as_ref()
+                    .is_none_or(|condition| condition.matches(&context))
+        })
+    }
+
+    fn mask_json_leaves(
+        &self,
+        path: &str,
+        value: &mut Value,
+        context: &MaskingContext,
+        strings_only: bool,
+    ) {
+        match value {
+            Value::Object(fields) => {
+                for (key, field) in fields.iter_mut() {
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    self.mask_json_leaves(&path, field, context, strings_only);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.mask_json_leaves(path, item, context, strings_only);
+                }
+            }
+            Value::String(text) => {
+                *text = self.mask_value_in(path, text, context);
+            }
+            Value::Number(_) | Value::Bool(_)
+                if !strings_only && self.matching_rule(path, context).is_some() =>
+            {
+                *value = Value::String(self.mask_value_in(path, &value.to_string(), context));
+            }
+            _ => {}
+        }
+    }
+
+    /// Masked copy of a typed value; only string fields are touched so the copy keeps its type
+    ///
+    /// String fields that cannot hold a masked value, such as enums, keep their value.
+    fn mask_typed<T>(&self, path: &str, value: &T, context: &MaskingContext) -> Option<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let original = serde_json::to_value(value).ok()?;
+        let mut masked = original.clone();
+        self.mask_json_leaves(path, &mut masked, context, true);
+        if let Ok(typed) = serde_json::from_value(masked.clone()) {
+            return Some(typed);
+        }
+
+        // Apply the masks one field at a time, keeping those the type accepts
+        let mut changed = Vec::new();
+        changed_leaves(&original, &masked, String::new(), &mut changed);
+        let mut accepted = original;
+        for (pointer, leaf) in changed {
+            let mut candidate = accepted.clone();
+            if let Some(field) = candidate.pointer_mut(&pointer) {
+                *field = leaf;
+            }
+            if serde_json::from_value::<T>(candidate.clone()).is_ok() {
+                accepted = candidate;
+            } else {
+                tracing::debug!("Keeping typed field {}{} unmasked", path, pointer);
+            }
+        }
+        serde_json::from_value(accepted).ok()
+    }
+
+    /// Masked copy of an optional metadata section, dropped if it cannot be masked in place
+    fn mask_section<T>(
+        &self,
+        path: &str,
+        section: &Option<T>,
+        context: &MaskingContext,
+    ) -> Option<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        section.as_ref().and_then(|section| {
+            let masked = self.mask_typed(path, section, context);
+            if masked.is_none() {
+                tracing::warn!(
+                    "Dropping metadata section {} that could not be masked",
+                    path
+                );
+            }
+            masked
+        })
+    }
+
+    fn masked_json_string<T: Serialize>(&self, value: &T, mask: impl FnOnce(&mut Value)) -> String {
+        match serde_json::to_value(value) {
+            Ok(mut json) => {
+                mask(&mut json);
+                json.to_string()
+            }
+            Err(e) => format!("<unserializable: {}>", e),
+        }
+    }
     fn build_default_rules(level: &MaskingLevel) -> Vec<CompiledRule> {
         match level {
             MaskingLevel::None => Vec::new(),
@@ -274,6 +574,8 @@ impl FieldMasker {
             // Tenant information
             Self::compile_rule("onBehalfOf.originalUserId", MaskType::Hash, 80),
             Self::compile_rule("onBehalfOf.delegatedBy", MaskType::Hash, 80),
+            Self::compile_rule("onBehalfOf.originalUser", MaskType::Hash, 80),
+            Self::compile_rule("onBehalfOf.delegatingUser", MaskType::Hash, 80),
             // Common sensitive extension patterns
             Self::compile_rule("extensions.*.email", MaskType::Partial, 70),
             Self::compile_rule("extensions.*.userId", MaskType::Hash, 70),
@@ -310,17 +612,25 @@ impl FieldMasker {
         rules
             .iter()
             .map(|rule| {
-                let pattern = glob::Pattern::new(&rule.field_path).map_err(|e| {
-                    MaskingError::InvalidPattern {
-                        pattern: rule.field_path.clone(),
-                        error: e.to_string(),
-                    }
-                })?;
+                let pattern =
+                    glob::Pattern::new(&normalize_path(&rule.field_path)).map_err(|e| {
+                        MaskingError::InvalidPattern {
+                            pattern: rule.field_path.clone(),
+                            error: e.to_string(),
+                        }
+                    })?;
+
+                let condition = rule
+                    .condition
+                    .as_deref()
+                    .map(RuleCondition::parse)
+                    .transpose()?;
 
                 Ok(CompiledRule {
                     pattern,
                     mask_type: rule.mask_type.clone(),
                     priority: rule.priority,
+                    condition,
                 })
             })
             .collect()
@@ -328,9 +638,10 @@ impl FieldMasker {
 
     fn compile_rule(pattern: &str, mask_type: MaskType, priority: u8) -> CompiledRule {
         CompiledRule {
-            pattern: glob::Pattern::new(pattern).expect("Valid pattern"),
+            pattern: glob::Pattern::new(&normalize_path(pattern)).expect("Valid pattern"),
             mask_type,
             priority,
+            condition: None,
         }
     }
 
@@ -389,6 +700,190 @@ impl FieldMasker {
     }
 }
 
+/// Field paths compare case- and underscore-insensitively (`userId` == `user_id`)
+fn normalize_path(path: &str) -> String {
+    path.chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// JSON pointers and masked values of the leaves that differ between `original` and `masked`
+fn changed_leaves(
+    original: &Value,
+    masked: &Value,
+    pointer: String,
+    out: &mut Vec<(String, Value)>,
+) {
+    match (original, masked) {
+        (Value::Object(original), Value::Object(masked)) => {
+            for (key, field) in original {
+                if let Some(masked_field) = masked.get(key) {
+                    let key = key.replace('~', "~0").replace('/', "~1");
+                    changed_leaves(field, masked_field, format!("{}/{}", pointer, key), out);
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(masked)) => {
+            for (index, (item, masked_item)) in original.iter().zip(masked).enumerate() {
+                changed_leaves(item, masked_item, format!("{}/{}", pointer, index), out);
+            }
+        }
+        (original, masked) if original != masked => out.push((pointer, masked.clone())),
+        _ => {}
+    }
+}
+
+impl Maskable for Meta {
+    fn mask_fields(&self, masker: &FieldMasker) -> Self {
+        let context = MaskingContext::from_meta(self);
+        let mut masked = self.clone();
+        masked.tenant = self
+            .tenant
+            .as_ref()
+            .map(|tenant| masker.mask_value_in("tenant", tenant, &context));
+        masked.on_behalf_of = masker.mask_section("onBehalfOf", &self.on_behalf_of, &context);
+        masked.security = masker.mask_section("security", &self.security, &context);
+        masked.debug = masker.mask_section("debug", &self.debug, &context);
+        masked.performance = masker.mask_section("performance", &self.performance, &context);
+        masked.monitoring = masker.mask_section("monitoring", &self.monitoring, &context);
+        masked.tracing = masker.mask_section("tracing", &self.tracing, &context);
+        masked.extensions = masker.mask_section("extensions", &self.extensions, &context);
+        masked
+    }
+
+    fn to_masked_string(&self, masker: &FieldMasker) -> String {
+        let context = MaskingContext::from_meta(self);
+        masker.masked_json_string(self, |json| masker.mask_json("", json, &context))
+    }
+}
+
+impl Maskable for EnvelopeError {
+    fn mask_fields(&self, masker: &FieldMasker) -> Self {
+        let context = MaskingContext::default();
+        let mut masked = self.clone();
+        if let Some(details) = masked.details.as_mut() {
+            masker.mask_json("error.details", details, &context);
+        }
+        masked.trace = self
+            .trace
+            .as_ref()
+            .map(|trace| masker.mask_value_in("error.trace", trace, &context));
+        masked
+    }
+
+    fn to_masked_string(&self, masker: &FieldMasker) -> String {
+        masker.masked_json_string(self, |json| {
+            masker.mask_json("error", json, &MaskingContext::default())
+        })
+    }
+}
+
+/// Payload fields are masked in place when their masked value still fits the payload type
+/// (string fields); use [`Maskable::to_masked_string`] to mask every field for logging.
+impl<T> Maskable for Envelope<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    fn mask_fields(&self, masker: &FieldMasker) -> Self {
+        let context = MaskingContext::from_meta(&self.meta);
+        let payload = masker
+            .mask_typed("data", &self.payload, &context)
+            .unwrap_or_else(|| {
+                tracing::warn!("Envelope payload could not be masked in place");
+                self.payload.clone()
+            });
+
+        Envelope {
+            meta: self.meta.mask_fields(masker),
+            payload,
+            error: self.error.as_ref().map(|error| {
+                let mut masked = error.mask_fields(masker);
+                if let Some(details) = masked.details.as_mut() {
+                    // Re-mask with the envelope's tenant and environment
+                    masker.mask_json("error.details", details, &context);
+                }
+                masked
+            }),
+        }
+    }
+
+    fn to_masked_string(&self, masker: &FieldMasker) -> String {
+        masker.masked_json_string(self, |json| masker.mask_envelope_json(json))
+    }
+}
+
+/// Field formatter for `tracing_subscriber::fmt` that masks event and span fields
+///
+/// Install with `tracing_subscriber::fmt().fmt_fields(MaskingFields::new(masker))`.
+/// The `message` field is written as is; every other field goes through
+/// [`FieldMasker::mask_log_field`], so serialized envelopes and metadata logged as
+/// fields are masked before they reach the sink.
+#[derive(Debug, Clone)]
+pub struct MaskingFields {
+    masker: Arc<FieldMasker>,
+}
+
+impl MaskingFields {
+    /// Create a formatter masking with `masker`
+    pub fn new(masker: FieldMasker) -> Self {
+        Self {
+            masker: Arc::new(masker),
+        }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for MaskingFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> std::fmt::Result {
+        let mut visitor = MaskingVisitor {
+            masker: &self.masker,
+            writer,
+            result: Ok(()),
+            empty: true,
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+struct MaskingVisitor<'a, 'writer> {
+    masker: &'a FieldMasker,
+    writer: Writer<'writer>,
+    result: std::fmt::Result,
+    empty: bool,
+}
+
+impl MaskingVisitor<'_, '_> {
+    fn write(&mut self, field: &Field, value: &str) {
+        if self.result.is_err() {
+            return;
+        }
+
+        let separator = if self.empty { "" } else { " " };
+        self.empty = false;
+        self.result = if field.name() == "message" {
+            write!(self.writer, "{}{}", separator, value)
+        } else {
+            let masked = self.masker.mask_log_field(field.name(), value);
+            write!(self.writer, "{}{}={}", separator, field.name(), masked)
+        };
+    }
+}
+
+impl Visit for MaskingVisitor<'_, '_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.write(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.write(field, &format!("{:?}", value));
+    }
+}
+
 /// Errors that can occur during masking operations
 #[derive(Debug, Clone, PartialEq)]
 pub enum MaskingError {
@@ -707,4 +1202,167 @@ mod tests {
         // Should complete very quickly when disabled
         assert!(duration.as_millis() < 10);
     }
+
+    fn envelope_with_user(user_id: &str) -> Envelope<serde_json::Value> {
+        let mut meta = Meta::default();
+        meta.tenant = Some("enterprise".to_string());
+        meta.security = Some(crate::envelope::meta::SecurityMeta {
+            user_id: Some(user_id.to_string()),
+            ..Default::default()
+        });
+        Envelope::new(
+            meta,
+            serde_json::json!({ "customer": { "email": "picard@starfleet.local" }, "count": 3 }),
+        )
+    }
+
+    #[test]
+    fn test_meta_masking_matches_snake_case_fields() {
+        let masker = FieldMasker::new(MaskingConfig::default()).unwrap();
+        let envelope = envelope_with_user("picard");
+
+        let masked = envelope.meta.mask_fields(&masker);
+        let user_id = masked.security.unwrap().user_id.unwrap();
+        assert!(user_id.starts_with("sha256:"));
+        assert_eq!(masked.tenant.as_deref(), Some("enterprise"));
+
+        let logged = envelope.meta.to_masked_string(&masker);
+        assert!(!logged.contains("\"picard\""));
+    }
+
+    #[test]
+    fn test_envelope_payload_and_error_details_masking() {
+        let config = MaskingConfig::default()
+            .with_rule(MaskingRule::new("data.customer.email", MaskType::Partial))
+            .with_rule(MaskingRule::new("data.count", MaskType::Full))
+            .with_rule(MaskingRule::new("error.details.card", MaskType::Suffix(4)));
+        let masker = FieldMasker::new(config).unwrap();
+
+        let mut envelope = envelope_with_user("picard");
+        envelope.error = Some(EnvelopeError {
+            code: "PAYMENT_FAILED".to_string(),
+            message: "card declined".to_string(),
+            details: Some(serde_json::json!({ "card": "4111111111111111" })),
+            trace: None,
+            http_status_code: None,
+        });
+
+        let masked = envelope.mask_fields(&masker);
+        assert_eq!(masked.payload["customer"]["email"], "pi***@***ocal");
+        // Non-string payload fields keep their type when masking in place
+        assert_eq!(masked.payload["count"], 3);
+        let details = masked.error.unwrap().details.unwrap();
+        assert_eq!(details["card"], "***1111");
+
+        let logged = envelope.to_masked_string(&masker);
+        assert!(logged.contains("\"count\":\"***MASKED***\""));
+        assert!(!logged.contains("4111111111111111"));
+        assert!(!logged.contains("picard@starfleet.local"));
+    }
+
+    #[test]
+    fn test_rule_conditions() {
+        let config = MaskingConfig::new(MaskingLevel::None).with_rule(
+            MaskingRule::new("data.email", MaskType::Redact)
+                .with_condition("environment=production && tenant!=internal"),
+        );
+        let masker = FieldMasker::new(config).unwrap();
+
+        let production = MaskingContext::new()
+            .with_environment("Production")
+            .with_tenant("acme");
+        assert_eq!(
+            masker.mask_value_in("data.email", "a@b.c", &production),
+            "[REDACTED]"
+        );
+
+        let internal = production.clone().with_tenant("internal");
+        assert_eq!(
+            masker.mask_value_in("data.email", "a@b.c", &internal),
+            "a@b.c"
+        );
+        assert_eq!(masker.mask_value("data.email", "a@b.c"), "a@b.c");
+
+        // The masker's own environment applies when the data names none
+        let masker = masker.with_environment("production");
+        assert_eq!(masker.mask_value("data.email", "a@b.c"), "[REDACTED]");
+
+        let invalid = MaskingConfig::default()
+            .with_rule(MaskingRule::new("data.email", MaskType::Full).with_condition("region=eu"));
+        assert!(matches!(
+            FieldMasker::new(invalid),
+            Err(MaskingError::InvalidConfig { .. })
+        ));
+    }
+
+    #[test]
+    fn test_meta_section_keeps_typed_fields_it_cannot_mask() {
+        use crate::envelope::meta::{Environment, MonitoringMeta};
+
+        let config = MaskingConfig::new(MaskingLevel::Custom).with_rule(
+            MaskingRule::new("monitoring.*", MaskType::Redact)
+                .with_condition("environment=production"),
+        );
+        let masker = FieldMasker::new(config).unwrap();
+        let monitoring: MonitoringMeta = serde_json::from_value(serde_json::json!({
+            "server_id": "bridge-01",
+            "environment": "Production"
+        }))
+        .unwrap();
+        let meta = Meta {
+            monitoring: Some(monitoring),
+            ..Default::default()
+        };
+
+        let monitoring = meta.mask_fields(&masker).monitoring.unwrap();
+        assert_eq!(monitoring.server_id.as_deref(), Some("[REDACTED]"));
+        assert_eq!(monitoring.environment, Some(Environment::Production));
+    }
+
+    #[test]
+    fn test_masking_fields_formatter() {
+        use tracing_subscriber::fmt::MakeWriter;
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl<'a> MakeWriter<'a> for Buffer {
+            type Writer = Buffer;
+
+            fn make_writer(&'a self) -> Self::Writer {
+                self.clone()
+            }
+        }
+
+        let masker = FieldMasker::new(MaskingConfig::default()).unwrap();
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .fmt_fields(MaskingFields::new(masker))
+            .finish();
+
+        let envelope = envelope_with_user("picard");
+        let envelope_json = serde_json::to_string(&envelope).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(envelope = %envelope_json, user.password = "hunter2", "sending envelope");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("sending envelope"));
+        assert!(output.contains("user.password=***MASKED***"));
+        assert!(!output.contains("hunter2"));
+        assert!(!output.contains("\"picard\""));
+    }
 }
@@ -3,7 +3,11 @@
 
 //! Metadata configuration structures and builders.
 
+use super::presets::{ConfigPreset, QollectiveConfig};
+use crate::envelope::Meta;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 /// Configuration for metadata inclusion and properties
@@ -50,3 +54,370 @@ impl Default for MetaConfig {
         Self::new()
     }
 }
+
+impl PropertyConfig {
+    /// Whether `property` may be sent; `Specific` acts as an allowlist
+    pub fn allows(&self, property: &str) -> bool {
+        match self {
+            PropertyConfig::All => true,
+            PropertyConfig::None => false,
+            PropertyConfig::Specific(properties) => {
+                properties.get(property).copied().unwrap_or(false)
+            }
+        }
+    }
+}
+
+impl MetaSectionConfig {
+    /// Strip a typed metadata section down to what this config allows
+    fn filter<T>(&self, section: &mut Option<T>)
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let Some(current) = section.take() else {
+            return;
+        };
+        *section = match &self.properties {
+            _ if !self.enabled => None,
+            PropertyConfig::All => Some(current),
+            PropertyConfig::None => None,
+            PropertyConfig::Specific(_) => serde_json::to_value(&current)
+                .ok()
+                .and_then(|value| self.filter_value(value))
+                // Fail closed: a section that cannot be filtered is not sent at all
+                .and_then(|value| serde_json::from_value(value).ok()),
+        };
+    }
+
+    /// Strip a JSON metadata section down to what this config allows
+    fn filter_value(&self, value: Value) -> Option<Value> {
+        if !self.enabled {
+            return None;
+        }
+        match (&self.properties, value) {
+            (PropertyConfig::All, value) => Some(value),
+            (PropertyConfig::None, _) => None,
+            (properties, Value::Object(fields)) => Some(Value::Object(
+                fields
+                    .into_iter()
+                    .filter_map(|(name, value)| match value {
+                        _ if properties.allows(&name) => Some((name, value)),
+                        // Collections are required fields on some sections; empty them instead
+                        Value::Array(_) => Some((name, Value::Array(Vec::new()))),
+                        Value::Object(_) => Some((name, Value::Object(Map::new()))),
+                        _ => None,
+                    })
+                    .collect(),
+            )),
+            // Scalar extensions have no properties to pick from
+            (PropertyConfig::Specific(_), _) => None,
+        }
+    }
+}
+
+impl MetaConfig {
+    /// Remove every section and property this config does not allow from `meta`
+    ///
+    /// Sections without a config are left untouched.
+    pub fn apply(&self, meta: &mut Meta) {
+        if let Some(config) = &self.security {
+            config.filter(&mut meta.security);
+        }
+        if let Some(config) = &self.debug {
+            config.filter(&mut meta.debug);
+        }
+        if let Some(config) = &self.performance {
+            config.filter(&mut meta.performance);
+        }
+        if let Some(config) = &self.monitoring {
+            config.filter(&mut meta.monitoring);
+        }
+        if let Some(config) = &self.tracing {
+            config.filter(&mut meta.tracing);
+        }
+
+        let (Some(configs), Some(extensions)) = (&self.extensions, meta.extensions.as_mut()) else {
+            return;
+        };
+        for (name, config) in configs {
+            let Some(section) = extensions.sections.remove(name) else {
+                continue;
+            };
+            match config.filter_value(section) {
+                Some(Value::Object(fields)) if fields.is_empty() => {}
+                Some(section) => {
+                    extensions.sections.insert(name.clone(), section);
+                }
+                None => {}
+            }
+        }
+        if extensions.sections.is_empty() {
+            meta.extensions = None;
+        }
+    }
+
+    /// Layer `overrides` on top of this config, section by section
+    pub fn overlay(&self, overrides: &MetaConfig) -> MetaConfig {
+        let extensions = match (&self.extensions, &overrides.extensions) {
+            (Some(base), Some(overrides)) => {
+                let mut merged = base.clone();
+                merged.extend(overrides.clone());
+                Some(merged)
+            }
+            (base, overrides) => overrides.clone().or_else(|| base.clone()),
+        };
+
+        MetaConfig {
+            security: overrides.security.clone().or_else(|| self.security.clone()),
+            debug: overrides.debug.clone().or_else(|| self.debug.clone()),
+            performance: overrides
+                .performance
+                .clone()
+                .or_else(|| self.performance.clone()),
+            monitoring: overrides
+                .monitoring
+                .clone()
+                .or_else(|| self.monitoring.clone()),
+            tracing: overrides.tracing.clone().or_else(|| self.tracing.clone()),
+            extensions,
+        }
+    }
+}
+
+/// Metadata policy enforced on outgoing envelopes
+///
+/// The environment's [`MetaConfig`] applies everywhere. Route overrides match by
+/// prefix (longest wins) and tenant overrides match `meta.tenant`; both are layered
+/// on top section by section, tenant last.
+#[derive(Debug, Clone, Default)]
+pub struct MetaPolicy {
+    base: MetaConfig,
+    routes: Vec<(String, MetaConfig)>,
+    tenants: HashMap<String, MetaConfig>,
+}
+
+impl MetaPolicy {
+    /// Create a policy that applies `base` to every envelope
+    pub fn new(base: MetaConfig) -> Self {
+        Self {
+            base,
+            routes: Vec::new(),
+            tenants: HashMap::new(),
+        }
+    }
+
+    /// Create a policy from the metadata settings of a loaded configuration
+    pub fn from_config(config: &QollectiveConfig) -> Self {
+        Self::new(config.meta.clone())
+    }
+
+    /// Create a policy from an environment preset
+    pub fn for_preset(preset: &ConfigPreset) -> Self {
+        Self::from_config(&preset.to_config())
+    }
+
+    /// Override sections for routes, subjects or tools starting with `prefix`
+    pub fn with_route(mut self, prefix: impl Into<String>, config: MetaConfig) -> Self {
+        self.routes.push((prefix.into(), config));
+        self
+    }
+
+    /// Override sections for envelopes belonging to `tenant`
+    pub fn with_tenant(mut self, tenant: impl Into<String>, config: MetaConfig) -> Self {
+        self.tenants.insert(tenant.into(), config);
+        self
+    }
+
+    /// Config in effect for `route` and `tenant`
+    pub fn resolve(&self, route: &str, tenant: Option<&str>) -> MetaConfig {
+        let mut config = self.base.clone();
+        if let Some((_, overrides)) = self
+            .routes
+            .iter()
+            .filter(|(prefix, _)| route.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+        {
+            config = config.overlay(overrides);
+        }
+        if let Some(overrides) = tenant.and_then(|tenant| self.tenants.get(tenant)) {
+            config = config.overlay(overrides);
+        }
+        config
+    }
+
+    /// Enforce the policy on metadata about to be sent for `route`
+    pub fn apply(&self, meta: &mut Meta, route: &str) {
+        self.resolve(route, meta.tenant.as_deref()).apply(meta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envelope::meta::{DebugMeta, MonitoringMeta, PerformanceMeta, SecurityMeta};
+
+    fn section(properties: PropertyConfig) -> Option<MetaSectionConfig> {
+        Some(MetaSectionConfig {
+            enabled: true,
+            properties,
+        })
+    }
+
+    fn specific(properties: &[(&str, bool)]) -> PropertyConfig {
+        PropertyConfig::Specific(
+            properties
+                .iter()
+                .map(|(name, allowed)| (name.to_string(), *allowed))
+                .collect(),
+        )
+    }
+
+    fn populated_meta() -> Meta {
+        let mut meta = Meta::default();
+        meta.tenant = Some("enterprise".to_string());
+        meta.security = Some(SecurityMeta {
+            user_id: Some("picard".to_string()),
+            ip_address: Some("10.0.0.1".to_string()),
+            roles: vec!["captain".to_string()],
+            ..Default::default()
+        });
+        meta.debug = Some(DebugMeta {
+            trace_enabled: Some(true),
+            db_queries: Vec::new(),
+            memory_usage: None,
+            stack_trace: Some("warp core breach".to_string()),
+            environment_vars: HashMap::new(),
+            request_headers: HashMap::new(),
+            log_level: None,
+            profiling_data: None,
+        });
+        meta.performance = Some(PerformanceMeta {
+            db_query_time: Some(12.5),
+            db_query_count: Some(3),
+            cache_hit_ratio: None,
+            cache_operations: None,
+            memory_allocated: None,
+            memory_peak: None,
+            cpu_usage: Some(0.4),
+            network_latency: None,
+            external_calls: Vec::new(),
+            gc_collections: None,
+            gc_time: None,
+            thread_count: None,
+            processing_time_ms: None,
+        });
+        meta.monitoring = Some(MonitoringMeta {
+            server_id: Some("ncc-1701-d-core-3".to_string()),
+            datacenter: Some("starbase-74".to_string()),
+            build_version: Some("1.2.3".to_string()),
+            deployment_id: None,
+            instance_type: None,
+            load_balancer: None,
+            environment: None,
+            cluster_id: Some("cluster-7".to_string()),
+            namespace: None,
+            health_status: None,
+            uptime: None,
+        });
+        meta.set_retry_attempt(2);
+        meta
+    }
+
+    #[test]
+    fn test_production_policy_strips_internal_metadata() {
+        let mut meta = populated_meta();
+        MetaPolicy::for_preset(&ConfigPreset::Production).apply(&mut meta, "/orders");
+
+        assert!(meta.debug.is_none());
+
+        let security = meta.security.as_ref().unwrap();
+        assert_eq!(security.user_id.as_deref(), Some("picard"));
+        assert!(security.ip_address.is_none());
+        assert!(security.roles.is_empty());
+
+        let performance = meta.performance.as_ref().unwrap();
+        assert_eq!(performance.db_query_time, Some(12.5));
+        assert!(performance.db_query_count.is_none());
+        assert!(performance.cpu_usage.is_none());
+
+        let monitoring = meta.monitoring.as_ref().unwrap();
+        assert_eq!(monitoring.build_version.as_deref(), Some("1.2.3"));
+        assert!(monitoring.server_id.is_none());
+        assert!(monitoring.datacenter.is_none());
+        assert!(monitoring.cluster_id.is_none());
+
+        // Extensions without a config pass through
+        assert_eq!(meta.retry_attempt(), Some(2));
+    }
+
+    #[test]
+    fn test_route_and_tenant_overrides() {
+        let debug_route = MetaConfig {
+            debug: section(PropertyConfig::All),
+            ..MetaConfig::new()
+        };
+        let no_security = MetaConfig {
+            security: Some(MetaSectionConfig {
+                enabled: false,
+                properties: PropertyConfig::All,
+            }),
+            ..MetaConfig::new()
+        };
+        let policy = MetaPolicy::for_preset(&ConfigPreset::Production)
+            .with_route("/internal", MetaConfig::new())
+            .with_route("/internal/diagnostics", debug_route)
+            .with_tenant("enterprise", no_security);
+
+        let mut meta = populated_meta();
+        policy.apply(&mut meta, "/internal/diagnostics/warp");
+        assert!(meta.debug.is_some());
+        assert!(meta.security.is_none());
+
+        let mut meta = populated_meta();
+        meta.tenant = Some("voyager".to_string());
+        policy.apply(&mut meta, "/internal/status");
+        assert!(meta.debug.is_none());
+        assert!(meta.security.is_some());
+    }
+
+    #[test]
+    fn test_extension_sections_are_filtered() {
+        let config = MetaConfig {
+            extensions: Some(
+                [
+                    (
+                        "retry".to_string(),
+                        section(specific(&[("attempt", false)])).unwrap(),
+                    ),
+                    (
+                        "holodeck".to_string(),
+                        section(specific(&[("program", true)])).unwrap(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..MetaConfig::new()
+        };
+
+        let mut meta = Meta::default();
+        meta.set_retry_attempt(3);
+        config.apply(&mut meta);
+        assert!(meta.extensions.is_none());
+
+        let mut meta = Meta::default();
+        meta.extensions = Some(crate::envelope::meta::ExtensionsMeta {
+            sections: [(
+                "holodeck".to_string(),
+                serde_json::json!({ "program": "dixon-hill", "safety": false }),
+            )]
+            .into_iter()
+            .collect(),
+        });
+        config.apply(&mut meta);
+        assert_eq!(
+            meta.extensions.unwrap().sections["holodeck"],
+            serde_json::json!({ "program": "dixon-hill" })
+        );
+    }
+}
@@ -36,9 +36,10 @@ pub mod websocket;
 pub mod wasm;
 
 pub use masking::{
-    FieldMasker, MaskType, Maskable, MaskingConfig, MaskingError, MaskingLevel, MaskingRule,
+    FieldMasker, MaskType, Maskable, MaskingConfig, MaskingContext, MaskingError, MaskingFields,
+    MaskingLevel, MaskingRule,
 };
-pub use meta::{MetaConfig, MetaSectionConfig, PropertyConfig};
+pub use meta::{MetaConfig, MetaPolicy, MetaSectionConfig, PropertyConfig};
 pub use presets::{
     ConfigPreset, CorsConfig, LoggingConfig, PerformanceConfig, QollectiveConfig,
     QollectiveConfigBuilder, RestClientConfig, RestConfig, RestServerConfig, TenantClientConfig,
@@ -71,7 +72,9 @@ pub use grpc::{
 
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 pub use nats::{
-    NatsClientConfig, NatsConfig, NatsConnectionConfig, NatsDiscoveryConfig, NatsServerConfig,
+    JetStreamConsumerConfig, JetStreamDelivery, JetStreamRetention, JetStreamStorage,
+    JetStreamStreamConfig, NatsClientConfig, NatsConfig, NatsConnectionConfig, NatsDiscoveryConfig,
+    NatsJetStreamConfig, NatsServerConfig,
 };
 
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
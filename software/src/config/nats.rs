@@ -45,6 +45,9 @@ pub struct NatsConfig {
     pub server: NatsServerConfig,
     /// Service discovery configuration for agent registration
     pub discovery: NatsDiscoveryConfig,
+    /// JetStream stream and consumer definitions for durable messaging
+    #[serde(default)]
+    pub jetstream: NatsJetStreamConfig,
 }
 
 /// NATS connection configuration managing how to connect to NATS servers.
@@ -201,6 +204,140 @@ pub struct NatsDiscoveryConfig {
     pub auto_register: bool,
 }
 
+/// JetStream configuration for durable envelope messaging.
+///
+/// Streams listed here are created (or updated) when a `NatsServer` starts or
+/// `NatsClient::ensure_streams` is called. Consumers are durable and are
+/// referenced by name when attaching an envelope handler with
+/// `NatsServer::consume`.
+///
+/// # Examples
+///
+/// ```rust
+/// use qollective::config::nats::{
+///     JetStreamConsumerConfig, JetStreamRetention, JetStreamStreamConfig, NatsJetStreamConfig,
+/// };
+///
+/// let mut stream = JetStreamStreamConfig::new("ORDERS", vec!["orders.>".to_string()]);
+/// stream.retention = JetStreamRetention::WorkQueue;
+///
+/// let mut consumer = JetStreamConsumerConfig::new("order-workers", "ORDERS");
+/// consumer.max_deliver = 3;
+/// consumer.dead_letter_subject = Some("orders.dead".to_string());
+///
+/// let config = NatsJetStreamConfig {
+///     streams: vec![stream],
+///     consumers: vec![consumer],
+///     ..Default::default()
+/// };
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NatsJetStreamConfig {
+    /// Streams to create or update before publishing or consuming
+    pub streams: Vec<JetStreamStreamConfig>,
+    /// Durable consumers that envelope handlers can be attached to
+    pub consumers: Vec<JetStreamConsumerConfig>,
+    /// Timeout for a durable publish acknowledgement in milliseconds
+    pub publish_ack_timeout_ms: u64,
+}
+
+/// Storage backend of a JetStream stream
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JetStreamStorage {
+    /// Messages are persisted to disk and survive server restarts
+    #[default]
+    File,
+    /// Messages are kept in memory only
+    Memory,
+}
+
+/// Retention policy of a JetStream stream
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JetStreamRetention {
+    /// Messages are kept until stream limits are reached
+    #[default]
+    Limits,
+    /// Messages are kept while there are consumers that have not acknowledged them
+    Interest,
+    /// Each message is removed once a consumer acknowledges it
+    WorkQueue,
+}
+
+/// Definition of a JetStream stream
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JetStreamStreamConfig {
+    /// Stream name
+    pub name: String,
+    /// Subjects captured by the stream
+    pub subjects: Vec<String>,
+    /// Storage backend
+    #[serde(default)]
+    pub storage: JetStreamStorage,
+    /// Retention policy
+    #[serde(default)]
+    pub retention: JetStreamRetention,
+    /// Maximum message age in milliseconds (unlimited if not set)
+    #[serde(default)]
+    pub max_age_ms: Option<u64>,
+    /// Maximum number of messages kept (unlimited if not set)
+    #[serde(default)]
+    pub max_messages: Option<i64>,
+    /// Number of replicas in a clustered deployment
+    #[serde(default = "default_stream_replicas")]
+    pub replicas: usize,
+    /// Window in which messages with the same request id are deduplicated, in milliseconds
+    #[serde(default)]
+    pub duplicate_window_ms: Option<u64>,
+}
+
+/// How a JetStream consumer receives messages
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum JetStreamDelivery {
+    /// The consumer fetches messages from the stream on demand
+    #[default]
+    Pull,
+    /// The stream pushes messages to a delivery subject
+    Push {
+        /// Subject the stream delivers messages to
+        deliver_subject: String,
+        /// Queue group sharing the delivered messages (optional)
+        #[serde(default)]
+        deliver_group: Option<String>,
+    },
+}
+
+/// Definition of a durable JetStream consumer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JetStreamConsumerConfig {
+    /// Durable consumer name
+    pub name: String,
+    /// Stream the consumer reads from
+    pub stream: String,
+    /// Only deliver messages on this subject (all stream subjects if not set)
+    #[serde(default)]
+    pub filter_subject: Option<String>,
+    /// Pull or push delivery
+    #[serde(default)]
+    pub delivery: JetStreamDelivery,
+    /// Time the server waits for an acknowledgement before redelivering, in milliseconds
+    pub ack_wait_ms: u64,
+    /// Maximum delivery attempts before a message is dead-lettered
+    pub max_deliver: i64,
+    /// Delay before a failed message is redelivered, in milliseconds (immediate if not set)
+    #[serde(default)]
+    pub redelivery_delay_ms: Option<u64>,
+    /// Subject that messages are published to once `max_deliver` attempts failed
+    #[serde(default)]
+    pub dead_letter_subject: Option<String>,
+    /// Maximum number of unacknowledged messages in flight (server default if not set)
+    #[serde(default)]
+    pub max_ack_pending: Option<i64>,
+}
+
 /// Standalone NATS client configuration for API consistency.
 ///
 /// This configuration provides everything a NATS client needs in a single config,
@@ -369,6 +506,7 @@ impl Default for NatsConfig {
             client: NatsClientBehaviorConfig::default(),
             server: NatsServerConfig::default(),
             discovery: NatsDiscoveryConfig::default(),
+            jetstream: NatsJetStreamConfig::default(),
         }
     }
 }
@@ -400,7 +538,7 @@ impl Default for NatsClientBehaviorConfig {
         Self {
             request_timeout_ms: timeouts::DEFAULT_NATS_REQUEST_TIMEOUT_MS,
             max_pending_messages: limits::DEFAULT_NATS_MAX_PENDING_MESSAGES,
-            retry_attempts: circuit_breaker::DEFAULT_MAX_RETRIES,
+            retry_attempts: limits::DEFAULT_MAX_RETRIES,
             retry_delay_ms: timeouts::DEFAULT_NATS_RETRY_DELAY_MS,
             connection_pool_size: circuit_breaker::DEFAULT_FAILURE_THRESHOLD as usize,
         }
@@ -435,6 +573,54 @@ impl Default for NatsDiscoveryConfig {
     }
 }
 
+/// Default JetStream configuration without streams or consumers
+impl Default for NatsJetStreamConfig {
+    fn default() -> Self {
+        Self {
+            streams: Vec::new(),
+            consumers: Vec::new(),
+            publish_ack_timeout_ms: timeouts::DEFAULT_JETSTREAM_PUBLISH_ACK_TIMEOUT_MS,
+        }
+    }
+}
+
+fn default_stream_replicas() -> usize {
+    1
+}
+
+impl JetStreamStreamConfig {
+    /// Creates a file-backed stream definition with limits retention
+    pub fn new(name: impl Into<String>, subjects: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            subjects,
+            storage: JetStreamStorage::default(),
+            retention: JetStreamRetention::default(),
+            max_age_ms: None,
+            max_messages: None,
+            replicas: default_stream_replicas(),
+            duplicate_window_ms: None,
+        }
+    }
+}
+
+impl JetStreamConsumerConfig {
+    /// Creates a pull consumer definition with default redelivery settings
+    pub fn new(name: impl Into<String>, stream: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            stream: stream.into(),
+            filter_subject: None,
+            delivery: JetStreamDelivery::default(),
+            ack_wait_ms: timeouts::DEFAULT_JETSTREAM_ACK_WAIT_MS,
+            max_deliver: limits::DEFAULT_JETSTREAM_MAX_DELIVER,
+            redelivery_delay_ms: None,
+            dead_letter_subject: None,
+            max_ack_pending: None,
+        }
+    }
+}
+
 impl NatsConnectionConfig {
     /// Validates the connection configuration
     pub fn validate(&self) -> Result<(), String> {
@@ -569,6 +755,146 @@ impl NatsDiscoveryConfig {
     }
 }
 
+impl NatsJetStreamConfig {
+    /// Looks up a stream definition by name
+    pub fn stream(&self, name: &str) -> Option<&JetStreamStreamConfig> {
+        self.streams.iter().find(|stream| stream.name == name)
+    }
+
+    /// Looks up a consumer definition by name
+    pub fn consumer(&self, name: &str) -> Option<&JetStreamConsumerConfig> {
+        self.consumers.iter().find(|consumer| consumer.name == name)
+    }
+
+    /// Validates the JetStream configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.publish_ack_timeout_ms == 0 {
+            return Err("Publish ack timeout must be greater than 0".to_string());
+        }
+
+        for (index, stream) in self.streams.iter().enumerate() {
+            stream.validate()?;
+            if self.streams[..index].iter().any(|s| s.name == stream.name) {
+                return Err(format!("Duplicate JetStream stream name: {}", stream.name));
+            }
+        }
+
+        for (index, consumer) in self.consumers.iter().enumerate() {
+            consumer.validate()?;
+            if self.consumers[..index]
+                .iter()
+                .any(|c| c.name == consumer.name)
+            {
+                return Err(format!(
+                    "Duplicate JetStream consumer name: {}",
+                    consumer.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks a stream or consumer name against the characters NATS rejects
+fn validate_jetstream_name(kind: &str, name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(format!("JetStream {} name cannot be empty", kind));
+    }
+
+    if name
+        .chars()
+        .any(|c| c.is_whitespace() || matches!(c, '.' | '*' | '>' | '/' | '\\'))
+    {
+        return Err(format!(
+            "JetStream {} name '{}' cannot contain whitespace, '.', '*', '>' or path separators",
+            kind, name
+        ));
+    }
+
+    Ok(())
+}
+
+impl JetStreamStreamConfig {
+    /// Validates the stream definition
+    pub fn validate(&self) -> Result<(), String> {
+        validate_jetstream_name("stream", &self.name)?;
+
+        if self.subjects.is_empty() {
+            return Err(format!(
+                "JetStream stream '{}' needs at least one subject",
+                self.name
+            ));
+        }
+
+        if self
+            .subjects
+            .iter()
+            .any(|subject| subject.trim().is_empty())
+        {
+            return Err(format!(
+                "JetStream stream '{}' has an empty subject",
+                self.name
+            ));
+        }
+
+        if self.replicas == 0 {
+            return Err(format!(
+                "JetStream stream '{}' replicas must be greater than 0",
+                self.name
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl JetStreamConsumerConfig {
+    /// Validates the consumer definition
+    pub fn validate(&self) -> Result<(), String> {
+        validate_jetstream_name("consumer", &self.name)?;
+        validate_jetstream_name("stream", &self.stream)?;
+
+        if self.ack_wait_ms == 0 {
+            return Err(format!(
+                "JetStream consumer '{}' ack wait must be greater than 0",
+                self.name
+            ));
+        }
+
+        if self.max_deliver <= 0 {
+            return Err(format!(
+                "JetStream consumer '{}' max deliver must be greater than 0",
+                self.name
+            ));
+        }
+
+        if let Some(ref subject) = self.dead_letter_subject {
+            if subject.trim().is_empty() || subject.contains('*') || subject.contains('>') {
+                return Err(format!(
+                    "JetStream consumer '{}' dead letter subject must be a concrete subject",
+                    self.name
+                ));
+            }
+        }
+
+        if let JetStreamDelivery::Push {
+            ref deliver_subject,
+            ..
+        } = self.delivery
+        {
+            if deliver_subject.trim().is_empty() {
+                return Err(format!(
+                    "JetStream consumer '{}' push delivery needs a deliver subject",
+                    self.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Builder for creating NATS configurations with fluent API
 pub struct NatsConfigBuilder {
     config: NatsConfig,
@@ -674,6 +1000,18 @@ impl NatsConfigBuilder {
         self
     }
 
+    /// Adds a JetStream stream definition
+    pub fn with_jetstream_stream(mut self, stream: JetStreamStreamConfig) -> Self {
+        self.config.jetstream.streams.push(stream);
+        self
+    }
+
+    /// Adds a durable JetStream consumer definition
+    pub fn with_jetstream_consumer(mut self, consumer: JetStreamConsumerConfig) -> Self {
+        self.config.jetstream.consumers.push(consumer);
+        self
+    }
+
     /// Builds and validates the configuration
     pub fn build(self) -> Result<NatsConfig, String> {
         // Validate all sub-configurations
@@ -681,6 +1019,7 @@ impl NatsConfigBuilder {
         self.config.client.validate()?;
         self.config.server.validate()?;
         self.config.discovery.validate()?;
+        self.config.jetstream.validate()?;
 
         Ok(self.config)
     }
@@ -756,6 +1095,21 @@ mod tests {
                 ttl_ms: 180000,
                 auto_register: true,
             },
+            jetstream: NatsJetStreamConfig {
+                streams: vec![JetStreamStreamConfig::new(
+                    "ORDERS",
+                    vec!["orders.>".to_string()],
+                )],
+                consumers: vec![JetStreamConsumerConfig {
+                    delivery: JetStreamDelivery::Push {
+                        deliver_subject: "deliver.orders".to_string(),
+                        deliver_group: Some("workers".to_string()),
+                    },
+                    dead_letter_subject: Some("dead.orders".to_string()),
+                    ..JetStreamConsumerConfig::new("order-workers", "ORDERS")
+                }],
+                ..Default::default()
+            },
         };
 
         // Test serialization to JSON
@@ -775,6 +1129,60 @@ mod tests {
         );
         assert_eq!(deserialized.server.enabled, config.server.enabled);
         assert_eq!(deserialized.discovery.ttl_ms, config.discovery.ttl_ms);
+        assert_eq!(deserialized.jetstream, config.jetstream);
+
+        // Configurations written before JetStream support still deserialize
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy.as_object_mut().unwrap().remove("jetstream");
+        let legacy: NatsConfig = serde_json::from_value(legacy).expect("Should deserialize");
+        assert_eq!(legacy.jetstream, NatsJetStreamConfig::default());
+    }
+
+    #[test]
+    fn test_nats_jetstream_config_validation() {
+        assert!(NatsJetStreamConfig::default().validate().is_ok());
+
+        let stream = JetStreamStreamConfig::new("ORDERS", vec!["orders.>".to_string()]);
+        let consumer = JetStreamConsumerConfig::new("order-workers", "ORDERS");
+        let config = NatsConfig::builder()
+            .with_jetstream_stream(stream.clone())
+            .with_jetstream_consumer(consumer.clone())
+            .build()
+            .expect("valid JetStream configuration");
+        assert_eq!(config.jetstream.consumer("order-workers"), Some(&consumer));
+        assert_eq!(config.jetstream.stream("ORDERS"), Some(&stream));
+
+        // Names NATS rejects
+        assert!(
+            JetStreamStreamConfig::new("orders.v1", vec!["orders".to_string()])
+                .validate()
+                .is_err()
+        );
+        assert!(JetStreamStreamConfig::new("ORDERS", vec![])
+            .validate()
+            .is_err());
+
+        // Dead-lettering needs a finite delivery count and a concrete subject
+        let mut unbounded = consumer.clone();
+        unbounded.max_deliver = -1;
+        assert!(unbounded.validate().is_err());
+        let mut wildcard = consumer.clone();
+        wildcard.dead_letter_subject = Some("dead.>".to_string());
+        assert!(wildcard.validate().is_err());
+
+        let mut push = consumer.clone();
+        push.delivery = JetStreamDelivery::Push {
+            deliver_subject: String::new(),
+            deliver_group: None,
+        };
+        assert!(push.validate().is_err());
+
+        // Duplicates are rejected by the builder
+        let duplicate = NatsConfig::builder()
+            .with_jetstream_consumer(consumer.clone())
+            .with_jetstream_consumer(consumer)
+            .build();
+        assert!(duplicate.unwrap_err().contains("Duplicate"));
     }
 
     #[test]
@@ -316,7 +316,23 @@ fn production_config() -> QollectiveConfig {
             }),
             monitoring: Some(MetaSectionConfig {
                 enabled: true,
-                properties: PropertyConfig::All,
+                properties: PropertyConfig::Specific(
+                    [
+                        ("build_version".to_string(), true),
+                        ("environment".to_string(), true),
+                        ("health_status".to_string(), true),
+                        ("uptime".to_string(), true),
+                        ("server_id".to_string(), false),
+                        ("datacenter".to_string(), false),
+                        ("deployment_id".to_string(), false),
+                        ("instance_type".to_string(), false),
+                        ("load_balancer".to_string(), false),
+                        ("cluster_id".to_string(), false),
+                        ("namespace".to_string(), false),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
             }),
             tracing: Some(MetaSectionConfig {
                 enabled: true,
@@ -558,6 +574,7 @@ fn production_config() -> QollectiveConfig {
                 ttl_ms: 90000,
                 auto_register: true,
             },
+            jetstream: super::nats::NatsJetStreamConfig::default(),
         }),
 
         #[cfg(feature = "tenant-extraction")]
@@ -583,6 +600,7 @@ fn production_config() -> QollectiveConfig {
                 "org_id".to_string(),
             ],
             auth_header_patterns: vec!["Bearer".to_string(), "JWT".to_string()],
+            jwt_verification: None,
         }),
     }
 }
@@ -826,6 +844,7 @@ fn development_config() -> QollectiveConfig {
                 ttl_ms: 90000,
                 auto_register: true,
             },
+            jetstream: super::nats::NatsJetStreamConfig::default(),
         }),
 
         #[cfg(feature = "tenant-extraction")]
@@ -851,6 +870,7 @@ fn development_config() -> QollectiveConfig {
                 "org_id".to_string(),
             ],
             auth_header_patterns: vec!["Bearer".to_string(), "JWT".to_string()],
+            jwt_verification: None,
         }),
     }
 }
@@ -1112,6 +1132,7 @@ fn high_performance_config() -> QollectiveConfig {
                 ttl_ms: 120000,                  // Shorter TTL for performance
                 auto_register: false,            // Manual registration for performance
             },
+            jetstream: super::nats::NatsJetStreamConfig::default(),
         }),
 
         #[cfg(feature = "tenant-extraction")]
@@ -1122,6 +1143,7 @@ fn high_performance_config() -> QollectiveConfig {
             tenant_payload_paths: vec!["tenant".to_string(), "tenant_id".to_string()],
             tenant_query_params: vec!["tenant".to_string()],
             auth_header_patterns: vec!["Bearer".to_string()],
+            jwt_verification: None,
         }),
     }
 }
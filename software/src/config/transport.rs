@@ -107,7 +107,7 @@ pub struct EndpointTransportConfig {
 /// Retry configuration for transport operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetryConfig {
-    /// Maximum number of retry attempts
+    /// Maximum number of attempts per request, counting the first one
     pub max_attempts: u32,
     /// Initial delay between retries in milliseconds
     pub initial_delay_ms: u64,
@@ -117,6 +117,20 @@ pub struct RetryConfig {
     pub backoff_multiplier: f64,
     /// Whether to use jitter in retry delays
     pub use_jitter: bool,
+    /// Retries allowed per request, averaged over all requests of a client
+    #[serde(default = "default_retry_budget_ratio")]
+    pub retry_budget_ratio: f64,
+    /// Retries per second always allowed regardless of the retry budget
+    #[serde(default = "default_min_retries_per_second")]
+    pub min_retries_per_second: u32,
+}
+
+fn default_retry_budget_ratio() -> f64 {
+    crate::constants::limits::DEFAULT_RETRY_BUDGET_RATIO
+}
+
+fn default_min_retries_per_second() -> u32 {
+    crate::constants::limits::DEFAULT_MIN_RETRIES_PER_SECOND
 }
 
 impl Default for TransportConfig {
@@ -215,11 +229,13 @@ impl Default for GlobalTransportConfig {
 impl Default for RetryConfig {
     fn default() -> Self {
         Self {
-            max_attempts: 3,
+            max_attempts: crate::constants::limits::DEFAULT_MAX_RETRIES + 1,
             initial_delay_ms: 1000,
             max_delay_ms: 30000,
             backoff_multiplier: 2.0,
             use_jitter: true,
+            retry_budget_ratio: default_retry_budget_ratio(),
+            min_retries_per_second: default_min_retries_per_second(),
         }
     }
 }
@@ -524,6 +540,7 @@ mod tests {
                 max_delay_ms: 60000,
                 backoff_multiplier: 3.0,
                 use_jitter: false,
+                ..Default::default()
             }),
             force_protocol: Some("grpc".to_string()),
             headers: [("X-Custom".to_string(), "value".to_string())].into(),
@@ -246,6 +246,18 @@ impl ConfigValidator {
             }
         }
 
+        if self.environment == "production"
+            && jwt_config.enabled
+            && jwt_config.jwt_verification.is_none()
+        {
+            warnings.push(ValidationWarning {
+                field_path: "jwt_extraction.jwt_verification".to_string(),
+                message: "JWT tenant claims are trusted without signature verification".to_string(),
+                recommendation: "Configure jwt_verification unless tokens are verified upstream"
+                    .to_string(),
+            });
+        }
+
         if self.strict_mode && jwt_config.jwt_debug_logging && self.environment == "production" {
             warnings.push(ValidationWarning {
                 field_path: "jwt_extraction.jwt_debug_logging".to_string(),
@@ -78,6 +78,18 @@ pub mod timeouts {
     #[cfg(feature = "security")]
     pub const DEFAULT_JWT_REFRESH_THRESHOLD_SECS: u64 = 300;
 
+    /// Default clock skew tolerated when validating JWT time claims in seconds
+    #[cfg(feature = "security")]
+    pub const DEFAULT_JWT_LEEWAY_SECS: u64 = 60;
+
+    /// Default timeout for OAuth2 and OIDC requests to the authorization server
+    #[cfg(feature = "security")]
+    pub const DEFAULT_OAUTH_REQUEST_TIMEOUT_MS: u64 = 10000;
+
+    /// Minimum time between JWKS refetches triggered by tokens with an unknown key id
+    #[cfg(feature = "security")]
+    pub const DEFAULT_JWKS_REFETCH_INTERVAL_SECS: u64 = 60;
+
     /// Default security TTL in seconds
     #[cfg(feature = "security")]
     pub const DEFAULT_SECURITY_TTL_SECS: u64 = 3600;
@@ -114,6 +126,14 @@ pub mod timeouts {
     #[cfg(any(feature = "nats-client", feature = "nats-server"))]
     pub const DEFAULT_NATS_RETRY_DELAY_MS: u64 = 1000;
 
+    /// Default JetStream acknowledgement wait before redelivery in milliseconds
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const DEFAULT_JETSTREAM_ACK_WAIT_MS: u64 = 30000;
+
+    /// Default timeout for a JetStream publish acknowledgement in milliseconds
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const DEFAULT_JETSTREAM_PUBLISH_ACK_TIMEOUT_MS: u64 = 5000;
+
     /// Default REST retry delay in milliseconds
     #[cfg(any(feature = "rest-client", feature = "wasm-client"))]
     pub const DEFAULT_REST_RETRY_DELAY_MS: u64 = 1000;
@@ -138,6 +158,24 @@ pub mod endpoints {
     /// Default local domain for Qollective services
     pub const DEFAULT_QOLLECTIVE_DOMAIN: &str = "qollective.local";
 
+    /// gRPC method path of unary envelope calls
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_UNARY_METHOD: &str = "/qollective.v1.QollectiveService/UnaryCall";
+
+    /// gRPC method path of server streaming envelope calls
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_SERVER_STREAMING_METHOD: &str =
+        "/qollective.v1.QollectiveService/ServerStreaming";
+
+    /// gRPC method path of client streaming envelope calls
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_CLIENT_STREAMING_METHOD: &str =
+        "/qollective.v1.QollectiveService/ClientStreaming";
+
+    /// gRPC method path of bidirectional streaming envelope calls
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_BIDIRECTIONAL_STREAMING_METHOD: &str =
+        "/qollective.v1.QollectiveService/BidirectionalStreaming";
 }
 
 /// NATS subject patterns with clean, consistent prefixing
@@ -176,6 +214,15 @@ pub mod subjects {
     #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
     pub const AGENT_DIRECT_PATTERN: &str = "qollective.a2a.v1.agent.{agent_id}.direct";
 
+    #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
+    pub const A2A_TASKS_GET: &str = "qollective.a2a.v1.tasks.get";
+
+    #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
+    pub const A2A_TASKS_CANCEL: &str = "qollective.a2a.v1.tasks.cancel";
+
+    #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
+    pub const A2A_TASK_UPDATES_PATTERN: &str = "qollective.a2a.v1.tasks.{task_id}.updates";
+
     // MCP (Model Context Protocol) subjects with consistent prefix
     #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
     pub const MCP_TOOL_DISCOVER: &str = "qollective.mcp.v1.tool.discover";
@@ -207,11 +254,17 @@ pub mod circuit_breaker {
     /// Default failure threshold to open circuit
     pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
 
-    /// Default maximum retry attempts
-    pub const DEFAULT_MAX_RETRIES: u32 = 3;
-
     /// Default circuit breaker enabled state
     pub const DEFAULT_ENABLED: bool = true;
+
+    /// Default failure rate within the window that opens a circuit
+    pub const DEFAULT_FAILURE_RATE_THRESHOLD: f64 = 0.5;
+
+    /// Default sliding window over which the failure rate is measured, in seconds
+    pub const DEFAULT_FAILURE_WINDOW_SECS: u64 = 60;
+
+    /// Default probe requests let through while a circuit is half-open
+    pub const DEFAULT_HALF_OPEN_MAX_REQUESTS: u32 = 1;
 }
 
 /// Transport configuration defaults
@@ -293,6 +346,10 @@ pub mod limits {
     #[cfg(feature = "websocket-client")]
     pub const DEFAULT_MAX_WEBSOCKET_CONNECTIONS: usize = 100;
 
+    /// Default number of outgoing frames queued per WebSocket connection
+    #[cfg(any(feature = "websocket-client", feature = "websocket-server"))]
+    pub const DEFAULT_WEBSOCKET_SEND_QUEUE_CAPACITY: usize = 256;
+
     /// Default maximum number of agents in registry
     pub const DEFAULT_MAX_AGENTS: usize = 10000;
 
@@ -304,6 +361,10 @@ pub mod limits {
     #[cfg(any(feature = "nats-client", feature = "nats-server"))]
     pub const DEFAULT_NATS_MAX_RECONNECT_ATTEMPTS: u32 = 5;
 
+    /// Default number of JetStream delivery attempts before a message is dead-lettered
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const DEFAULT_JETSTREAM_MAX_DELIVER: i64 = 5;
+
     /// Default retry attempts for gRPC
     #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
     pub const DEFAULT_GRPC_RETRY_ATTEMPTS: u32 = 3;
@@ -332,6 +393,15 @@ pub mod limits {
     /// Default retry attempts for REST clients and general purpose retries
     #[cfg(any(feature = "rest-client", feature = "wasm-client"))]
     pub const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
+
+    /// Default retries after the first attempt of a request, for all transports
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// Default retries allowed per request by a client's retry budget
+    pub const DEFAULT_RETRY_BUDGET_RATIO: f64 = 0.2;
+
+    /// Default retries per second allowed regardless of the retry budget
+    pub const DEFAULT_MIN_RETRIES_PER_SECOND: u32 = 10;
 }
 
 /// Network addresses and ports
@@ -374,6 +444,10 @@ pub mod network {
     #[cfg(feature = "security")]
     pub const DEFAULT_REDIS_CONNECTION_STRING: &str = "redis://localhost:6379";
 
+    /// Default NATS key-value bucket for shared token storage
+    #[cfg(feature = "security")]
+    pub const DEFAULT_TOKEN_STORAGE_BUCKET: &str = "qollective_tokens";
+
     /// Default localhost hostname
     pub const DEFAULT_LOCALHOST: &str = "localhost";
 
@@ -508,7 +582,11 @@ pub mod http {
     pub const HEADER_ON_BEHALF_OF: &str = "x-on-behalf-of";
 
     /// Qollective envelope metadata headers for REST transport
-    #[cfg(any(feature = "rest-server", feature = "rest-client"))]
+    #[cfg(any(
+        feature = "rest-server",
+        feature = "rest-client",
+        feature = "a2a-standard"
+    ))]
     pub mod envelope_headers {
         /// Qollective request ID header (envelope metadata)
         pub const QOLLECTIVE_REQUEST_ID: &str = "X-Qollective-Request-Id";
@@ -539,6 +617,9 @@ pub mod http {
 
         /// Qollective correlation ID header (request correlation)
         pub const QOLLECTIVE_CORRELATION_ID: &str = "X-Qollective-Correlation-Id";
+
+        /// Qollective retry attempt header (set on retried requests only)
+        pub const QOLLECTIVE_RETRY_ATTEMPT: &str = "X-Qollective-Retry-Attempt";
     }
 
     /// Qollective query parameters for REST transport (fallback when headers too large)
@@ -752,6 +833,12 @@ pub mod env_vars {
     pub const QOLLECTIVE_NATS_DISCOVERY_AUTO_REGISTER: &str =
         "QOLLECTIVE_NATS_DISCOVERY_AUTO_REGISTER";
 
+    /// Service name recorded in the envelope service chain
+    pub const QOLLECTIVE_SERVICE_NAME: &str = "QOLLECTIVE_SERVICE_NAME";
+
+    /// Service version recorded in the envelope service chain
+    pub const QOLLECTIVE_SERVICE_VERSION: &str = "QOLLECTIVE_SERVICE_VERSION";
+
     /// Redis URL environment variable
     pub const QOLLECTIVE_REDIS_URL: &str = "QOLLECTIVE_REDIS_URL";
 
@@ -763,6 +850,18 @@ pub mod env_vars {
 
     /// TLS certificate base path environment variable
     pub const QOLLECTIVE_TLS_CERT_BASE_PATH: &str = "QOLLECTIVE_TLS_CERT_BASE_PATH";
+
+    /// Host name, set by container runtimes and most shells
+    pub const HOSTNAME: &str = "HOSTNAME";
+
+    /// Instance identifier reported in monitoring metadata, e.g. the pod name
+    pub const QOLLECTIVE_INSTANCE_ID: &str = "QOLLECTIVE_INSTANCE_ID";
+
+    /// Build version reported in monitoring metadata
+    pub const QOLLECTIVE_BUILD_VERSION: &str = "QOLLECTIVE_BUILD_VERSION";
+
+    /// Deployment environment reported in monitoring metadata, e.g. `production`
+    pub const QOLLECTIVE_ENVIRONMENT: &str = "QOLLECTIVE_ENVIRONMENT";
 }
 
 /// Version and metadata constants
@@ -780,6 +879,30 @@ pub mod metadata {
     /// Extension key for protocol metadata in envelope extensions
     pub const PROTOCOL_EXTENSION_KEY: &str = "protocol";
 
+    /// Extension key carrying the id of the WebSocket connection a request arrived on
+    #[cfg(feature = "websocket-server")]
+    pub const WEBSOCKET_CONNECTION_ID_EXTENSION_KEY: &str = "websocket_connection_id";
+
+    /// NATS header carrying the subject a dead-lettered JetStream message was published on
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const JETSTREAM_DEAD_LETTER_SUBJECT_HEADER: &str = "Qollective-Dead-Letter-Subject";
+
+    /// NATS header carrying the stream of a dead-lettered JetStream message
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const JETSTREAM_DEAD_LETTER_STREAM_HEADER: &str = "Qollective-Dead-Letter-Stream";
+
+    /// NATS header carrying the stream sequence of a dead-lettered JetStream message
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const JETSTREAM_DEAD_LETTER_SEQUENCE_HEADER: &str = "Qollective-Dead-Letter-Sequence";
+
+    /// NATS header carrying how often a dead-lettered JetStream message was delivered
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const JETSTREAM_DEAD_LETTER_DELIVERIES_HEADER: &str = "Qollective-Dead-Letter-Deliveries";
+
+    /// NATS header carrying the handler error of the last delivery attempt
+    #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+    pub const JETSTREAM_DEAD_LETTER_ERROR_HEADER: &str = "Qollective-Dead-Letter-Error";
+
     /// Framework version
     pub const QOLLECTIVE_VERSION: &str = "0.1.0";
 
@@ -797,6 +920,112 @@ pub mod metadata {
 
     /// Health check status: not serving (gRPC)
     pub const HEALTH_STATUS_NOT_SERVING: i32 = 0;
+
+    /// gRPC metadata key carrying the handler type key used for server-side dispatch
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_HANDLER_KEY_METADATA: &str = "x-qollective-handler-key";
+
+    /// gRPC metadata key carrying the route used for route-based server-side dispatch
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    pub const GRPC_ROUTE_METADATA: &str = "x-qollective-route";
+}
+
+/// Metric names, labels and histogram buckets for the monitoring subsystem
+pub mod metrics {
+    /// Default path of the Prometheus text-exposition endpoint
+    pub const DEFAULT_METRICS_PATH: &str = "/metrics";
+
+    /// Total requests handled, labelled by transport, route, status and tenant
+    pub const REQUESTS_TOTAL: &str = "qollective_requests_total";
+
+    /// Request latency histogram in seconds
+    pub const REQUEST_DURATION_SECONDS: &str = "qollective_request_duration_seconds";
+
+    /// Requests currently being handled, labelled by transport and route
+    pub const REQUESTS_IN_FLIGHT: &str = "qollective_requests_in_flight";
+
+    /// Total envelope operations, labelled by operation and outcome
+    pub const ENVELOPE_OPERATIONS_TOTAL: &str = "qollective_envelope_operations_total";
+
+    /// Envelope operation latency histogram in seconds
+    pub const ENVELOPE_OPERATION_DURATION_SECONDS: &str =
+        "qollective_envelope_operation_duration_seconds";
+
+    /// NATS connection state gauge, 1 for the current state and 0 for all others
+    pub const NATS_CONNECTION_STATE: &str = "qollective_nats_connection_state";
+
+    /// NATS connection attempts made by the client
+    pub const NATS_CONNECTION_ATTEMPTS_TOTAL: &str = "qollective_nats_connection_attempts_total";
+
+    /// NATS connection attempts that failed
+    pub const NATS_CONNECTION_FAILURES_TOTAL: &str = "qollective_nats_connection_failures_total";
+
+    /// NATS reconnection attempts made by the client
+    pub const NATS_RECONNECTION_ATTEMPTS_TOTAL: &str =
+        "qollective_nats_reconnection_attempts_total";
+
+    /// NATS circuit breaker state changes
+    pub const NATS_CIRCUIT_BREAKER_STATE_CHANGES_TOTAL: &str =
+        "qollective_nats_circuit_breaker_state_changes_total";
+
+    /// Client circuit breaker state gauge, 1 for the current state and 0 for all others
+    pub const CIRCUIT_BREAKER_STATE: &str = "qollective_circuit_breaker_state";
+
+    /// Client circuit breaker state transitions, labelled by circuit and new state
+    pub const CIRCUIT_BREAKER_TRANSITIONS_TOTAL: &str =
+        "qollective_circuit_breaker_transitions_total";
+
+    /// Requests rejected by an open client circuit breaker
+    pub const CIRCUIT_BREAKER_REJECTIONS_TOTAL: &str =
+        "qollective_circuit_breaker_rejections_total";
+
+    /// Transport label name
+    pub const LABEL_TRANSPORT: &str = "transport";
+
+    /// Route label name (HTTP path, gRPC route or NATS subject)
+    pub const LABEL_ROUTE: &str = "route";
+
+    /// Status label name (HTTP status code or ok/error)
+    pub const LABEL_STATUS: &str = "status";
+
+    /// Tenant label name
+    pub const LABEL_TENANT: &str = "tenant";
+
+    /// Envelope operation label name
+    pub const LABEL_OPERATION: &str = "operation";
+
+    /// Envelope operation outcome label name
+    pub const LABEL_OUTCOME: &str = "outcome";
+
+    /// NATS connection state label name
+    pub const LABEL_STATE: &str = "state";
+
+    /// Circuit breaker label name
+    pub const LABEL_CIRCUIT: &str = "circuit";
+
+    /// Tenant label value used when a request carries no tenant, or one that is
+    /// not allowed as a label value
+    pub const NO_TENANT: &str = "none";
+
+    /// Route label value for HTTP requests that matched no registered route
+    pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+    /// Distinct tenant label values recorded before further tenants fall back to `NO_TENANT`
+    pub const MAX_TENANT_LABELS: usize = 100;
+
+    /// Longest tenant accepted as a label value
+    pub const MAX_TENANT_LABEL_LEN: usize = 64;
+
+    /// Status label value for successful non-HTTP requests
+    pub const STATUS_OK: &str = "ok";
+
+    /// Status label value for failed non-HTTP requests
+    pub const STATUS_ERROR: &str = "error";
+
+    /// Latency histogram bucket upper bounds in seconds
+    pub const LATENCY_BUCKETS_SECONDS: &[f64] = &[
+        0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+    ];
 }
 
 /// WASM-specific constants and limits
@@ -950,6 +1179,12 @@ pub mod helpers {
         DEFAULT_MCP_SERVER_PATTERN.replace("{server_id}", server_id)
     }
 
+    /// Generate the subject streaming status and artifact updates of an A2A task
+    #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
+    pub fn a2a_task_updates_subject(task_id: &str) -> String {
+        super::subjects::A2A_TASK_UPDATES_PATTERN.replace("{task_id}", task_id)
+    }
+
     /// Check if URL looks like a Qollective endpoint
     pub fn is_qollective_endpoint(url: &str) -> bool {
         url.contains(DEFAULT_QOLLECTIVE_DOMAIN) || url.contains("qollective")
@@ -984,6 +1219,12 @@ mod tests {
             assert!(AGENT_HEALTH.starts_with("qollective.a2a.v1."));
             assert!(AGENT_REGISTRY_EVENTS.starts_with("qollective.a2a.v1."));
             assert!(AGENT_REGISTRY_ANNOUNCE.starts_with("qollective.a2a.v1."));
+            assert!(A2A_TASKS_GET.starts_with("qollective.a2a.v1."));
+            assert!(A2A_TASKS_CANCEL.starts_with("qollective.a2a.v1."));
+            assert_eq!(
+                super::helpers::a2a_task_updates_subject("task-1"),
+                "qollective.a2a.v1.tasks.task-1.updates"
+            );
         }
 
         #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
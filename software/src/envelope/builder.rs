@@ -131,6 +131,8 @@ impl<T> EnvelopeBuilder<T> {
                 version: None,
                 duration: None,
                 tenant: None,
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
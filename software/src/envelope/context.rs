@@ -9,9 +9,14 @@
 //! - Context propagation across service boundaries
 //! - Builder pattern for fluent context construction
 
+use super::enrichment::{
+    MetaEnricher, MonitoringEnricher, PerformanceEnricher, ResponseInfo, ServiceIdentity,
+    TracingEnricher,
+};
 use super::meta::{ExtensionsMeta, Meta};
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use tokio::task_local;
 use uuid::Uuid;
 
@@ -43,6 +48,8 @@ impl Context {
                 version: None,
                 duration: None,
                 tenant: None,
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
@@ -146,6 +153,8 @@ impl ContextBuilder {
                 version: None,
                 duration: None,
                 tenant: None,
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
@@ -216,37 +225,49 @@ impl Default for ContextBuilder {
 }
 
 /// Trait for context propagation behavior
+///
+/// The `enrich_*` methods run the built-in enrichers of
+/// [`enrichment`](super::enrichment) on a copy of the metadata. Monitoring data
+/// comes from [`ServiceIdentity::from_env`]; servers can instead run enrichers
+/// on every response, see their `with_enrichers` methods.
 pub trait ContextPropagation {
     fn as_context(&self) -> Context;
+    /// Add process memory, CPU usage and thread count
     fn enrich_performance(&self) -> Meta;
+    /// Add host, instance, build version, environment and uptime
     fn enrich_monitoring(&self) -> Meta;
+    /// Add the IDs of the current span
     fn enrich_tracing(&self) -> Meta;
 }
 
+/// Enrich a copy of `meta`; handler duration and response size are unknown here
+fn enrich_with(enricher: &dyn MetaEnricher, meta: &Meta) -> Meta {
+    let mut enriched = meta.clone();
+    enricher.enrich(&mut enriched, &ResponseInfo::default());
+    enriched
+}
+
 impl ContextPropagation for Meta {
     fn as_context(&self) -> Context {
         Context::new(self.clone())
     }
 
     fn enrich_performance(&self) -> Meta {
-        // Extension point: Implement custom performance metadata enrichment
-        // Example: Add current CPU usage, memory metrics, or request timing data
-        // Default implementation returns metadata unchanged
-        self.clone()
+        // Shared so CPU usage is measured between calls
+        static PERFORMANCE: OnceLock<PerformanceEnricher> = OnceLock::new();
+        enrich_with(PERFORMANCE.get_or_init(PerformanceEnricher::new), self)
     }
 
     fn enrich_monitoring(&self) -> Meta {
-        // Extension point: Implement custom monitoring metadata enrichment
-        // Example: Add server_id, datacenter, build_version, or health status
-        // Default implementation returns metadata unchanged
-        self.clone()
+        // Shared so uptime counts from the first call
+        static MONITORING: OnceLock<MonitoringEnricher> = OnceLock::new();
+        let enricher =
+            MONITORING.get_or_init(|| MonitoringEnricher::new(ServiceIdentity::from_env()));
+        enrich_with(enricher, self)
     }
 
     fn enrich_tracing(&self) -> Meta {
-        // Extension point: Implement custom tracing metadata enrichment
-        // Example: Add trace_id, span_id, baggage, or OpenTelemetry context
-        // Default implementation returns metadata unchanged
-        self.clone()
+        enrich_with(&TracingEnricher, self)
     }
 }
 
@@ -270,6 +291,8 @@ mod tests {
             version: Some("1.0.0".to_string()),
             duration: Some(123.45),
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -323,6 +346,8 @@ mod tests {
             version: Some("2.0.0".to_string()),
             duration: None,
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -349,6 +374,8 @@ mod tests {
             version: Some("3.0.0".to_string()),
             duration: Some(456.78),
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -527,6 +554,8 @@ mod tests {
             version: Some("propagation-test".to_string()),
             duration: None,
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -542,7 +571,7 @@ mod tests {
         // ASSERT: Verify conversion
         assert_eq!(context.meta().version.as_ref().unwrap(), "propagation-test");
 
-        // Test enrichment methods (currently just clones)
+        // Test enrichment methods keep the metadata and add their sections
         let enriched_perf = meta.enrich_performance();
         let enriched_mon = meta.enrich_monitoring();
         let enriched_trace = meta.enrich_tracing();
@@ -550,6 +579,9 @@ mod tests {
         assert_eq!(enriched_perf.version, meta.version);
         assert_eq!(enriched_mon.version, meta.version);
         assert_eq!(enriched_trace.version, meta.version);
+        assert!(enriched_perf.performance.is_some());
+        assert!(enriched_mon.monitoring.unwrap().uptime.is_some());
+        assert!(meta.performance.is_none());
     }
 
     #[test]
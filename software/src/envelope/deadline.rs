@@ -0,0 +1,340 @@
+// ABOUTME: Request deadline propagation for envelope metadata across transports
+// ABOUTME: Maps Meta.deadline to grpc-timeout and timeout headers and enforces it around handlers
+
+//! Request deadline propagation.
+//!
+//! A deadline is the point in time by which the original caller needs its
+//! response, carried in [`Meta::deadline`]. The absolute deadline never leaves
+//! the host: on the wire it travels as the time remaining, so clock skew between
+//! hosts does not move it:
+//!
+//! - gRPC uses the standard `grpc-timeout` metadata (e.g. `1500m`).
+//! - REST and NATS use the `x-qollective-timeout-ms` header.
+//! - WebSocket request frames carry it in their `timeout_ms` field.
+//!
+//! Servers run handlers through [`scope`]: expired requests are rejected
+//! without running the handler, the handler future is dropped once the deadline
+//! passes, and the request context is made current so outbound calls made by
+//! the handler inherit the remaining time through [`outgoing`].
+
+use super::context::Context;
+use super::meta::Meta;
+use super::middleware::HeaderLike;
+use crate::error::{QollectiveError, Result};
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::time::Duration;
+
+/// Header carrying the remaining time in milliseconds on REST and NATS requests
+pub const TIMEOUT_HEADER: &str = "x-qollective-timeout-ms";
+
+/// Standard gRPC timeout metadata key
+pub const GRPC_TIMEOUT_HEADER: &str = "grpc-timeout";
+
+/// `grpc-timeout` values have at most eight digits
+const GRPC_TIMEOUT_MAX_VALUE: u128 = 99_999_999;
+
+/// `grpc-timeout` units from finest to coarsest, with their length in nanoseconds
+const GRPC_TIMEOUT_UNITS: [(char, u128); 6] = [
+    ('n', 1),
+    ('u', 1_000),
+    ('m', 1_000_000),
+    ('S', 1_000_000_000),
+    ('M', 60_000_000_000),
+    ('H', 3_600_000_000_000),
+];
+
+/// Deadline `timeout` from now
+pub fn after(timeout: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(timeout)
+        .ok()
+        .and_then(|timeout| Utc::now().checked_add_signed(timeout))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Time left until `deadline`, zero once it has passed
+pub fn remaining(deadline: DateTime<Utc>) -> Duration {
+    (deadline - Utc::now()).to_std().unwrap_or(Duration::ZERO)
+}
+
+/// Whether `deadline` has passed
+pub fn is_expired(deadline: DateTime<Utc>) -> bool {
+    deadline <= Utc::now()
+}
+
+/// The earlier of two optional deadlines
+pub fn earliest(a: Option<DateTime<Utc>>, b: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Fail with [`QollectiveError::DeadlineExceeded`] if the deadline has passed
+pub fn check(deadline: Option<DateTime<Utc>>) -> Result<()> {
+    match deadline {
+        Some(deadline) if is_expired(deadline) => Err(exceeded(deadline)),
+        _ => Ok(()),
+    }
+}
+
+/// Format a timeout as a `grpc-timeout` value
+///
+/// Uses the finest unit that fits into eight digits, rounding down so the
+/// receiver never waits longer than the sender.
+pub fn format_grpc_timeout(timeout: Duration) -> String {
+    let nanos = timeout.as_nanos();
+    GRPC_TIMEOUT_UNITS
+        .iter()
+        .map(|(unit, length)| (unit, nanos / length))
+        .find(|(_, value)| *value <= GRPC_TIMEOUT_MAX_VALUE)
+        .map(|(unit, value)| format!("{}{}", value, unit))
+        .unwrap_or_else(|| format!("{}H", GRPC_TIMEOUT_MAX_VALUE))
+}
+
+/// Parse a `grpc-timeout` value such as `100m` or `5S`
+pub fn parse_grpc_timeout(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let digits = &value[..value.len() - unit.len_utf8()];
+    if digits.is_empty() || digits.len() > 8 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let length = GRPC_TIMEOUT_UNITS
+        .iter()
+        .find(|(candidate, _)| *candidate == unit)?
+        .1;
+    let nanos = digits.parse::<u128>().ok()? * length;
+    Some(Duration::from_nanos(u64::try_from(nanos).ok()?))
+}
+
+/// Time left until `deadline` in whole milliseconds, as sent on the wire
+pub fn remaining_millis(deadline: DateTime<Utc>) -> u64 {
+    u64::try_from(remaining(deadline).as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Header name/value pairs that carry the deadline on REST and NATS requests
+pub fn header_pairs(deadline: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    vec![(TIMEOUT_HEADER, remaining_millis(deadline).to_string())]
+}
+
+/// Write the deadline header for an outgoing request
+pub fn inject(deadline: DateTime<Utc>, headers: &mut dyn HeaderLike) -> Result<()> {
+    for (name, value) in header_pairs(deadline) {
+        headers.set(name, &value)?;
+    }
+    Ok(())
+}
+
+/// Read the deadline from `grpc-timeout` or the timeout header, the earlier one winning
+pub fn extract(headers: &dyn HeaderLike) -> Option<DateTime<Utc>> {
+    let grpc = headers
+        .get(GRPC_TIMEOUT_HEADER)
+        .and_then(parse_grpc_timeout)
+        .map(after);
+    let millis = headers
+        .get(TIMEOUT_HEADER)
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(|millis| after(Duration::from_millis(millis)));
+    earliest(grpc, millis)
+}
+
+/// Set the deadline of a received envelope from the time remaining sent by the caller
+///
+/// Envelope bodies never carry a deadline, so the transport's value, measured
+/// on this host's clock, is the only one.
+pub fn merge_into_meta(meta: &mut Meta, incoming: Option<DateTime<Utc>>) {
+    meta.deadline = incoming;
+}
+
+/// Deadline to send on an outgoing request
+///
+/// The earliest of the envelope's own deadline, the deadline of the request
+/// currently being handled (see [`Context::current`]) and `timeout` from now,
+/// where `timeout` is the calling client's configured request timeout.
+pub fn outgoing(
+    deadline: Option<DateTime<Utc>>,
+    timeout: Option<Duration>,
+) -> Option<DateTime<Utc>> {
+    let inherited = Context::current().and_then(|context| context.meta().deadline);
+    earliest(earliest(deadline, inherited), timeout.map(after))
+}
+
+/// Run a future, failing with [`QollectiveError::DeadlineExceeded`] once the deadline passes
+///
+/// An expired deadline fails without polling the future; without a deadline
+/// the future runs to completion.
+pub async fn enforce<F, T>(deadline: Option<DateTime<Utc>>, future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let Some(deadline) = deadline else {
+        return future.await;
+    };
+    check(Some(deadline))?;
+
+    tokio::time::timeout(remaining(deadline), future)
+        .await
+        .unwrap_or_else(|_| Err(exceeded(deadline)))
+}
+
+/// Run a request handler under the request's deadline with its context current
+///
+/// Outbound calls made by the handler see the request metadata through
+/// [`Context::current`] and so inherit the remaining time.
+pub async fn scope<F, T>(meta: &Meta, future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let context = Context::new(meta.clone());
+    enforce(meta.deadline, context.run_with(future)).await
+}
+
+/// Add the deadline header to the headers of an outgoing NATS message
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+pub fn with_nats_header(
+    deadline: Option<DateTime<Utc>>,
+    headers: Option<async_nats::HeaderMap>,
+) -> Option<async_nats::HeaderMap> {
+    let Some(deadline) = deadline else {
+        return headers;
+    };
+    let mut headers = headers.unwrap_or_default();
+    for (name, value) in header_pairs(deadline) {
+        headers.insert(name, value);
+    }
+    Some(headers)
+}
+
+/// Error reported once `deadline` has passed
+pub(crate) fn exceeded(deadline: DateTime<Utc>) -> QollectiveError {
+    QollectiveError::deadline_exceeded(format!(
+        "request deadline {} has passed",
+        deadline.to_rfc3339()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestHeaders(HashMap<String, String>);
+
+    impl HeaderLike for TestHeaders {
+        fn get(&self, name: &str) -> Option<&str> {
+            self.0.get(name).map(|s| s.as_str())
+        }
+
+        fn set(&mut self, name: &str, value: &str) -> Result<()> {
+            self.0.insert(name.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn keys(&self) -> Vec<String> {
+            self.0.keys().cloned().collect()
+        }
+    }
+
+    #[test]
+    fn test_grpc_timeout_format_and_parse() {
+        assert_eq!(format_grpc_timeout(Duration::from_millis(1500)), "1500000u");
+        assert_eq!(format_grpc_timeout(Duration::from_nanos(250)), "250n");
+        assert_eq!(
+            format_grpc_timeout(Duration::from_secs(3600 * 24)),
+            "86400000m"
+        );
+        assert_eq!(
+            format_grpc_timeout(Duration::from_secs(u64::MAX)),
+            "99999999H"
+        );
+
+        assert_eq!(parse_grpc_timeout("100m"), Some(Duration::from_millis(100)));
+        assert_eq!(parse_grpc_timeout("5S"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_grpc_timeout("2H"), Some(Duration::from_secs(7200)));
+        assert_eq!(parse_grpc_timeout("123456789m"), None);
+        assert_eq!(parse_grpc_timeout("10x"), None);
+        assert_eq!(parse_grpc_timeout("m"), None);
+
+        let timeout = Duration::from_millis(4321);
+        assert_eq!(
+            parse_grpc_timeout(&format_grpc_timeout(timeout)),
+            Some(timeout)
+        );
+    }
+
+    #[test]
+    fn test_headers_round_trip_and_earliest_wins_among_headers() {
+        let deadline = after(Duration::from_secs(10));
+        let mut headers = TestHeaders::default();
+        inject(deadline, &mut headers).unwrap();
+
+        let extracted = extract(&headers).unwrap();
+        assert!((extracted - deadline).num_milliseconds().abs() < 100);
+
+        headers.set(GRPC_TIMEOUT_HEADER, "1S").unwrap();
+        let extracted = extract(&headers).unwrap();
+        assert!(remaining(extracted) <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_deadline_is_rebuilt_from_the_time_remaining() {
+        // A sender whose clock runs an hour ahead cannot move the deadline
+        let mut meta = Meta::default();
+        meta.deadline = Some(Utc::now() + chrono::Duration::hours(1));
+        let json = serde_json::to_value(&meta).unwrap();
+        assert!(json.get("deadline").is_none());
+
+        let mut received: Meta = serde_json::from_value(json).unwrap();
+        assert_eq!(received.deadline, None);
+
+        let mut headers = TestHeaders::default();
+        headers.set(TIMEOUT_HEADER, "500").unwrap();
+        merge_into_meta(&mut received, extract(&headers));
+        assert!(remaining(received.deadline.unwrap()) <= Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_enforce_rejects_expired_and_cancels_slow_futures() {
+        let expired = Some(Utc::now() - chrono::Duration::seconds(1));
+        let polled = std::sync::atomic::AtomicBool::new(false);
+        let result = enforce(expired, async {
+            polled.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+        assert!(matches!(result, Err(QollectiveError::DeadlineExceeded(_))));
+        assert!(!polled.load(std::sync::atomic::Ordering::SeqCst));
+
+        let result = enforce(Some(after(Duration::from_millis(20))), async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await;
+        assert!(matches!(result, Err(QollectiveError::DeadlineExceeded(_))));
+
+        assert_eq!(enforce(None, async { Ok(7) }).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_outgoing_inherits_and_shortens_the_current_deadline() {
+        let mut meta = Meta::default();
+        meta.deadline = Some(after(Duration::from_millis(500)));
+        let inbound = meta.deadline;
+
+        let outbound = scope(&meta, async {
+            // The client's own 30s timeout is cut down to the inbound deadline
+            Ok(outgoing(None, Some(Duration::from_secs(30))))
+        })
+        .await
+        .unwrap();
+        assert_eq!(outbound, inbound);
+
+        // Outside a handler the client timeout becomes the deadline
+        let standalone = outgoing(None, Some(Duration::from_secs(1))).unwrap();
+        assert!(remaining(standalone) <= Duration::from_secs(1));
+        assert_eq!(outgoing(None, None), None);
+    }
+}
@@ -0,0 +1,581 @@
+// ABOUTME: Pluggable metadata enrichers filling performance, monitoring and tracing sections
+// ABOUTME: Servers run registered enrichers on every response; ContextPropagation uses the built-in ones
+
+//! Metadata enrichment.
+//!
+//! A [`MetaEnricher`] fills sections of response metadata with data only known
+//! once a handler finished. Servers run the [`MetaEnrichers`] registered with
+//! their `with_enrichers` method on every response, before the meta policy
+//! strips what may not leave the service:
+//!
+//! - [`PerformanceEnricher`]: handler duration, process memory, CPU usage and
+//!   thread count read from `/proc`, and the response size;
+//! - [`MonitoringEnricher`]: host, instance, build version, environment and
+//!   uptime from a [`ServiceIdentity`];
+//! - [`TracingEnricher`]: the IDs of the current span.
+//!
+//! ```rust,ignore
+//! let identity = ServiceIdentity::from_env().with_build_version(env!("CARGO_PKG_VERSION"));
+//! let server = RestServer::new(config)
+//!     .await?
+//!     .with_enrichers(MetaEnrichers::standard(identity));
+//! ```
+//!
+//! The same enrichers back [`ContextPropagation`](super::ContextPropagation),
+//! so `meta.enrich_performance()` in a handler reports real process data.
+
+use super::meta::{Environment, ExtensionsMeta, Meta, MonitoringMeta, PerformanceMeta};
+use super::trace_context;
+use crate::constants::env_vars;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// What is known about a response when it is enriched
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResponseInfo {
+    /// Route, subject, method or tool that produced the response
+    pub operation: String,
+    /// Time the handler took, if measured
+    pub handler_duration: Option<Duration>,
+    /// Size of the JSON-encoded response payload in bytes
+    pub response_size: Option<usize>,
+}
+
+/// Fills metadata sections of a response
+pub trait MetaEnricher: Send + Sync {
+    /// Add what this enricher knows to `meta`
+    fn enrich(&self, meta: &mut Meta, response: &ResponseInfo);
+}
+
+/// Enrichers a server runs on every response, in registration order
+#[derive(Clone, Default)]
+pub struct MetaEnrichers {
+    enrichers: Vec<Arc<dyn MetaEnricher>>,
+}
+
+impl std::fmt::Debug for MetaEnrichers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetaEnrichers")
+            .field("count", &self.enrichers.len())
+            .finish()
+    }
+}
+
+impl MetaEnrichers {
+    /// No enrichers; responses keep the metadata the server built
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Performance, monitoring and tracing enrichers for a service
+    pub fn standard(identity: ServiceIdentity) -> Self {
+        Self::new()
+            .with(PerformanceEnricher::new())
+            .with(MonitoringEnricher::new(identity))
+            .with(TracingEnricher)
+    }
+
+    /// Add an enricher that runs after those already registered
+    pub fn with(mut self, enricher: impl MetaEnricher + 'static) -> Self {
+        self.enrichers.push(Arc::new(enricher));
+        self
+    }
+
+    /// Whether no enricher is registered
+    pub fn is_empty(&self) -> bool {
+        self.enrichers.is_empty()
+    }
+
+    /// Run every enricher on the metadata of a response to `operation`
+    ///
+    /// `started` is when the handler was called. The payload is only encoded
+    /// to measure its size when an enricher is registered.
+    pub fn enrich<P: Serialize + ?Sized>(
+        &self,
+        meta: &mut Meta,
+        operation: &str,
+        started: Instant,
+        payload: &P,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        let response = ResponseInfo {
+            operation: operation.to_string(),
+            handler_duration: Some(started.elapsed()),
+            response_size: serde_json::to_vec(payload).ok().map(|bytes| bytes.len()),
+        };
+        for enricher in &self.enrichers {
+            enricher.enrich(meta, &response);
+        }
+    }
+}
+
+/// Records handler duration, response size and process resource usage
+///
+/// CPU usage is the share of one core the process used since the previous
+/// response, or since the enricher was created. Memory, CPU and thread counts
+/// are read from `/proc` and left unset on other platforms.
+#[derive(Debug)]
+pub struct PerformanceEnricher {
+    last_cpu_sample: Mutex<Option<CpuSample>>,
+}
+
+impl PerformanceEnricher {
+    /// Extension section holding the response size
+    pub const RESPONSE_EXTENSION: &'static str = "response";
+
+    /// Create an enricher, taking the first CPU sample now
+    pub fn new() -> Self {
+        Self {
+            last_cpu_sample: Mutex::new(CpuSample::now()),
+        }
+    }
+
+    fn cpu_usage(&self) -> Option<f64> {
+        let current = CpuSample::now()?;
+        let mut last = self
+            .last_cpu_sample
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let usage = last.as_ref().and_then(|last| current.usage_since(last));
+        *last = Some(current);
+        usage
+    }
+}
+
+impl Default for PerformanceEnricher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetaEnricher for PerformanceEnricher {
+    fn enrich(&self, meta: &mut Meta, response: &ResponseInfo) {
+        let cpu_usage = self.cpu_usage();
+        let status = process::status();
+        let performance = meta.performance.get_or_insert_with(empty_performance_meta);
+
+        if let Some(duration) = response.handler_duration {
+            performance.processing_time_ms = Some(duration.as_millis() as u64);
+        }
+        if let Some(status) = status {
+            performance.memory_allocated = status.resident_bytes.map(|bytes| bytes as i64);
+            performance.memory_peak = status.peak_resident_bytes.map(|bytes| bytes as i64);
+            performance.thread_count = status.threads.map(|threads| threads as i32);
+        }
+        if cpu_usage.is_some() {
+            performance.cpu_usage = cpu_usage;
+        }
+
+        if let Some(size) = response.response_size {
+            meta.extensions
+                .get_or_insert_with(|| ExtensionsMeta {
+                    sections: HashMap::new(),
+                })
+                .sections
+                .insert(
+                    Self::RESPONSE_EXTENSION.to_string(),
+                    serde_json::json!({ "size_bytes": size }),
+                );
+        }
+    }
+}
+
+/// Identity of a running service, reported in monitoring metadata
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServiceIdentity {
+    /// Host the service runs on, reported as `server_id`
+    pub host: Option<String>,
+    /// Instance of the service, e.g. the pod name, reported as `deployment_id`
+    pub instance_id: Option<String>,
+    /// Version of the running build
+    pub build_version: Option<String>,
+    /// Deployment environment, e.g. `production` or `staging`
+    pub environment: Option<String>,
+}
+
+impl ServiceIdentity {
+    /// Read the identity from `HOSTNAME`, `QOLLECTIVE_INSTANCE_ID`,
+    /// `QOLLECTIVE_BUILD_VERSION` and `QOLLECTIVE_ENVIRONMENT`
+    ///
+    /// Without `HOSTNAME` the host name is read from `/proc` where available.
+    pub fn from_env() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+        Self {
+            host: var(env_vars::HOSTNAME).or_else(process::hostname),
+            instance_id: var(env_vars::QOLLECTIVE_INSTANCE_ID),
+            build_version: var(env_vars::QOLLECTIVE_BUILD_VERSION),
+            environment: var(env_vars::QOLLECTIVE_ENVIRONMENT),
+        }
+    }
+
+    /// Set the host name
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Set the instance identifier
+    pub fn with_instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
+    /// Set the build version
+    pub fn with_build_version(mut self, build_version: impl Into<String>) -> Self {
+        self.build_version = Some(build_version.into());
+        self
+    }
+
+    /// Set the deployment environment
+    pub fn with_environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+}
+
+/// Records where the response was produced and how long the service has been up
+#[derive(Debug, Clone)]
+pub struct MonitoringEnricher {
+    identity: ServiceIdentity,
+    environment: Option<Environment>,
+    started: Instant,
+}
+
+impl MonitoringEnricher {
+    /// Create an enricher; uptime is counted from now
+    pub fn new(identity: ServiceIdentity) -> Self {
+        let environment = identity.environment.as_deref().map(parse_environment);
+        Self {
+            identity,
+            environment,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl MetaEnricher for MonitoringEnricher {
+    fn enrich(&self, meta: &mut Meta, _response: &ResponseInfo) {
+        let monitoring = meta.monitoring.get_or_insert_with(empty_monitoring_meta);
+        if self.identity.host.is_some() {
+            monitoring.server_id = self.identity.host.clone();
+        }
+        if self.identity.instance_id.is_some() {
+            monitoring.deployment_id = self.identity.instance_id.clone();
+        }
+        if self.identity.build_version.is_some() {
+            monitoring.build_version = self.identity.build_version.clone();
+        }
+        if self.environment.is_some() {
+            monitoring.environment = self.environment.clone();
+        }
+        monitoring.uptime = Some(self.started.elapsed().as_secs_f64());
+    }
+}
+
+/// Records the IDs of the span current when it runs
+///
+/// Run inside a handler's span, the response carries that span as `span_id`
+/// and the caller's span as `parent_span_id`. The operation name is filled in
+/// when the metadata has none. Without the `tracing` feature, or outside any
+/// span, the request's IDs are kept.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingEnricher;
+
+impl MetaEnricher for TracingEnricher {
+    fn enrich(&self, meta: &mut Meta, response: &ResponseInfo) {
+        let Some(mut tracing) = trace_context::outgoing(meta.tracing.as_ref()) else {
+            return;
+        };
+        if tracing.operation_name.is_none() && !response.operation.is_empty() {
+            tracing.operation_name = Some(response.operation.clone());
+        }
+        meta.tracing = Some(tracing);
+    }
+}
+
+/// Map an environment name to its enumeration value
+fn parse_environment(name: &str) -> Environment {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "development" | "dev" => Environment::Development,
+        "staging" | "stage" => Environment::Staging,
+        "testing" | "test" => Environment::Testing,
+        "production" | "prod" => Environment::Production,
+        "canary" => Environment::Canary,
+        _ => Environment::Unspecified,
+    }
+}
+
+/// CPU time consumed by the process at a point in time
+#[derive(Debug, Clone, Copy)]
+struct CpuSample {
+    taken: Instant,
+    cpu_time: Duration,
+}
+
+impl CpuSample {
+    fn now() -> Option<Self> {
+        Some(Self {
+            taken: Instant::now(),
+            cpu_time: process::cpu_time()?,
+        })
+    }
+
+    /// Share of one core used between `earlier` and this sample
+    fn usage_since(&self, earlier: &CpuSample) -> Option<f64> {
+        let wall = self.taken.checked_duration_since(earlier.taken)?;
+        if wall.is_zero() {
+            return None;
+        }
+        let cpu = self.cpu_time.saturating_sub(earlier.cpu_time);
+        Some(cpu.as_secs_f64() / wall.as_secs_f64())
+    }
+}
+
+fn empty_performance_meta() -> PerformanceMeta {
+    PerformanceMeta {
+        db_query_time: None,
+        db_query_count: None,
+        cache_hit_ratio: None,
+        cache_operations: None,
+        memory_allocated: None,
+        memory_peak: None,
+        cpu_usage: None,
+        network_latency: None,
+        external_calls: Vec::new(),
+        gc_collections: None,
+        gc_time: None,
+        thread_count: None,
+        processing_time_ms: None,
+    }
+}
+
+fn empty_monitoring_meta() -> MonitoringMeta {
+    MonitoringMeta {
+        server_id: None,
+        datacenter: None,
+        build_version: None,
+        deployment_id: None,
+        instance_type: None,
+        load_balancer: None,
+        environment: None,
+        cluster_id: None,
+        namespace: None,
+        health_status: None,
+        uptime: None,
+    }
+}
+
+/// Process statistics read from `/proc`
+mod process {
+    use std::time::Duration;
+
+    /// Memory and thread figures from `/proc/self/status`
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub(super) struct ProcessStatus {
+        pub resident_bytes: Option<u64>,
+        pub peak_resident_bytes: Option<u64>,
+        pub threads: Option<u64>,
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) fn status() -> Option<ProcessStatus> {
+        std::fs::read_to_string("/proc/self/status")
+            .ok()
+            .map(|status| parse_status(&status))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn status() -> Option<ProcessStatus> {
+        None
+    }
+
+    /// CPU time of all threads of the process, from `/proc/self/stat`
+    #[cfg(target_os = "linux")]
+    pub(super) fn cpu_time() -> Option<Duration> {
+        let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+        // SAFETY: sysconf only reads a system constant
+        let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+        parse_cpu_time(&stat, u64::try_from(ticks_per_second).ok()?)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn cpu_time() -> Option<Duration> {
+        None
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) fn hostname() -> Option<String> {
+        std::fs::read_to_string("/proc/sys/kernel/hostname")
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn hostname() -> Option<String> {
+        None
+    }
+
+    /// User plus system time, fields 14 and 15 of a `/proc/<pid>/stat` line, in clock ticks
+    pub(super) fn parse_cpu_time(stat: &str, ticks_per_second: u64) -> Option<Duration> {
+        if ticks_per_second == 0 {
+            return None;
+        }
+        // The command name in field 2 may contain spaces, so count from its closing parenthesis
+        let (_, fields) = stat.rsplit_once(')')?;
+        let mut fields = fields.split_whitespace().skip(11);
+        let user: u64 = fields.next()?.parse().ok()?;
+        let system: u64 = fields.next()?.parse().ok()?;
+        Some(Duration::from_secs_f64(
+            (user + system) as f64 / ticks_per_second as f64,
+        ))
+    }
+
+    pub(super) fn parse_status(status: &str) -> ProcessStatus {
+        let mut parsed = ProcessStatus::default();
+        for line in status.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let mut fields = value.split_whitespace();
+            let number = fields.next().and_then(|number| number.parse::<u64>().ok());
+            // Sizes are reported in kB
+            let bytes = number.map(|number| match fields.next() {
+                Some("kB") => number * 1024,
+                _ => number,
+            });
+            match key {
+                "VmRSS" => parsed.resident_bytes = bytes,
+                "VmHWM" => parsed.peak_resident_bytes = bytes,
+                "Threads" => parsed.threads = number,
+                _ => {}
+            }
+        }
+        parsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envelope::meta::TracingMeta;
+
+    fn empty_tracing_meta() -> TracingMeta {
+        TracingMeta {
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
+            baggage: HashMap::new(),
+            sampling_rate: None,
+            sampled: None,
+            trace_state: None,
+            operation_name: None,
+            span_kind: None,
+            span_status: None,
+            tags: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_status_reads_memory_and_threads() {
+        let status = "Name:\tqollective\nVmHWM:\t   20480 kB\nVmRSS:\t   10240 kB\nThreads:\t7\n";
+
+        let parsed = process::parse_status(status);
+
+        assert_eq!(parsed.resident_bytes, Some(10 * 1024 * 1024));
+        assert_eq!(parsed.peak_resident_bytes, Some(20 * 1024 * 1024));
+        assert_eq!(parsed.threads, Some(7));
+    }
+
+    #[test]
+    fn test_parse_cpu_time_sums_user_and_system_ticks() {
+        let stat = "4242 (warp core) S 1 4242 4242 0 -1 4194560 900 0 0 0 250 130 0 0 20 0 9 0 \
+                    1000 1000000 2500 18446744073709551615";
+
+        let cpu_time = process::parse_cpu_time(stat, 100);
+
+        assert_eq!(cpu_time, Some(Duration::from_millis(3800)));
+        assert_eq!(process::parse_cpu_time(stat, 0), None);
+        assert_eq!(process::parse_cpu_time("4242 (warp", 100), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_cpu_time_counts_every_thread() {
+        let before = process::cpu_time().unwrap();
+
+        std::thread::spawn(|| {
+            let started = Instant::now();
+            while started.elapsed() < Duration::from_millis(300) {
+                std::hint::spin_loop();
+            }
+        })
+        .join()
+        .unwrap();
+
+        // The spinning thread's time counts even though it is not the main thread
+        let spent = process::cpu_time().unwrap().saturating_sub(before);
+        assert!(
+            spent >= Duration::from_millis(150),
+            "only {:?} counted",
+            spent
+        );
+    }
+
+    #[test]
+    fn test_enrichers_fill_performance_monitoring_and_tracing() {
+        let identity = ServiceIdentity::default()
+            .with_host("node-7")
+            .with_instance_id("orders-6f9c")
+            .with_build_version("2.4.1")
+            .with_environment("prod");
+        let mut meta = Meta::for_new_request();
+        meta.tracing = Some(TracingMeta {
+            trace_id: Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string()),
+            ..empty_tracing_meta()
+        });
+
+        MetaEnrichers::standard(identity).enrich(
+            &mut meta,
+            "/orders",
+            Instant::now() - Duration::from_millis(25),
+            &serde_json::json!({ "id": 42 }),
+        );
+
+        let performance = meta.performance.as_ref().unwrap();
+        assert!(performance.processing_time_ms.unwrap() >= 25);
+        #[cfg(target_os = "linux")]
+        {
+            assert!(performance.memory_allocated.unwrap() > 0);
+            assert!(performance.thread_count.unwrap() > 0);
+        }
+        let size = &meta.extensions.as_ref().unwrap().sections
+            [PerformanceEnricher::RESPONSE_EXTENSION]["size_bytes"];
+        assert_eq!(size, 9);
+
+        let monitoring = meta.monitoring.as_ref().unwrap();
+        assert_eq!(monitoring.server_id.as_deref(), Some("node-7"));
+        assert_eq!(monitoring.deployment_id.as_deref(), Some("orders-6f9c"));
+        assert_eq!(monitoring.build_version.as_deref(), Some("2.4.1"));
+        assert_eq!(monitoring.environment, Some(Environment::Production));
+        assert!(monitoring.uptime.is_some());
+
+        let tracing = meta.tracing.as_ref().unwrap();
+        assert_eq!(tracing.operation_name.as_deref(), Some("/orders"));
+    }
+
+    #[test]
+    fn test_no_enrichers_leave_meta_untouched() {
+        let mut meta = Meta::for_new_request();
+        let before = meta.clone();
+
+        MetaEnrichers::new().enrich(&mut meta, "/orders", Instant::now(), &"payload");
+
+        assert_eq!(meta, before);
+    }
+}
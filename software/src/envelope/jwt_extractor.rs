@@ -74,6 +74,7 @@
 //!         Ok(JwtTenantInfo {
 //!             tenant_key: Some("validated-tenant".to_string()),
 //!             on_behalf_of: None,
+//!             subject: None,
 //!         })
 //!     }
 //! }
@@ -122,6 +123,11 @@ use thiserror::Error;
 #[cfg(feature = "tenant-extraction")]
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 
+#[cfg(feature = "tenant-extraction")]
+use crate::tenant::{
+    extraction::ExtractionConfig, JwtParseError, JwtParser, JwtVerificationConfig,
+};
+
 /// Information extracted from JWT tokens for tenant context
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JwtTenantInfo {
@@ -129,6 +135,9 @@ pub struct JwtTenantInfo {
     pub tenant_key: Option<String>,
     /// On-behalf-of information for delegated operations
     pub on_behalf_of: Option<OnBehalfOfInfo>,
+    /// User the token was issued to (`sub` claim)
+    #[serde(default)]
+    pub subject: Option<String>,
 }
 
 /// On-behalf-of information for delegated tenant operations
@@ -207,6 +216,8 @@ pub enum JwtExtractionError {
     InvalidClaimType(String, String),
     #[error("Token processing error: {0}")]
     ProcessingError(String),
+    #[error("Token verification failed: {0}")]
+    VerificationFailed(String),
 }
 
 /// Combined error type for JWT processing operations
@@ -243,7 +254,8 @@ pub trait JwtTokenLocator: Send + Sync {
 /// Trait for extracting tenant information from JWT tokens
 ///
 /// This trait allows users to customize how JWT tokens are parsed and validated.
-/// The framework provides a default parse-only implementation, but users can
+/// The framework provides a default parse-only implementation and a
+/// signature-verifying [`VerifiedJwtTenantExtractor`], but users can
 /// implement their own for:
 /// - JWT signature validation
 /// - Encrypted JWT (JWE) decryption
@@ -279,6 +291,20 @@ impl JwtProcessor {
         JwtProcessorBuilder::new()
     }
 
+    /// Create a processor with the default token locator, verifying tokens when
+    /// the extraction config has `jwt_verification` set and parsing them otherwise
+    #[cfg(feature = "tenant-extraction")]
+    pub fn from_extraction_config(config: &ExtractionConfig) -> Result<Self, JwtExtractionError> {
+        let builder = Self::builder();
+        let builder = match &config.jwt_verification {
+            Some(verification) => {
+                builder.tenant_extractor(VerifiedJwtTenantExtractor::new(verification)?)
+            }
+            None => builder,
+        };
+        Ok(builder.build())
+    }
+
     /// Extract tenant information from an HTTP request
     ///
     /// This method combines token location and tenant extraction in a single call,
@@ -451,34 +477,7 @@ impl JwtTenantExtractor for DefaultJwtTenantExtractor {
         let payload: serde_json::Value = serde_json::from_str(&payload_str)
             .map_err(|e| JwtExtractionError::InvalidJson(e.to_string()))?;
 
-        // Extract tenant key (try both "tenantkey" and "tenant_key")
-        let tenant_key = payload
-            .get("tenantkey")
-            .or_else(|| payload.get("tenant_key"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        // Extract onBehalfOf information
-        let on_behalf_of = if let Some(on_behalf_obj) = payload.get("onBehalfOf") {
-            let tenant = on_behalf_obj
-                .get("tenant")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-
-            let user = on_behalf_obj
-                .get("user")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-
-            Some(OnBehalfOfInfo { tenant, user })
-        } else {
-            None
-        };
-
-        Ok(JwtTenantInfo {
-            tenant_key,
-            on_behalf_of,
-        })
+        Ok(tenant_info_from_payload(&payload))
     }
 }
 
@@ -491,6 +490,77 @@ impl JwtTenantExtractor for DefaultJwtTenantExtractor {
     }
 }
 
+/// Tenant extractor that verifies JWT signatures before reading tenant claims
+///
+/// Reads the same `tenantkey`/`onBehalfOf` claims as [`DefaultJwtTenantExtractor`],
+/// but only after the token signature, algorithm and expiry have been verified
+/// against the configured key source, so tenant isolation cannot be spoofed.
+#[cfg(feature = "tenant-extraction")]
+#[derive(Debug, Clone)]
+pub struct VerifiedJwtTenantExtractor {
+    parser: JwtParser,
+}
+
+#[cfg(feature = "tenant-extraction")]
+impl VerifiedJwtTenantExtractor {
+    /// Create a verifying extractor from a verification config
+    pub fn new(config: &JwtVerificationConfig) -> Result<Self, JwtExtractionError> {
+        let parser = JwtParser::with_verification(config)
+            .map_err(|e| JwtExtractionError::ProcessingError(e.to_string()))?;
+        Ok(Self { parser })
+    }
+}
+
+#[cfg(feature = "tenant-extraction")]
+impl JwtTenantExtractor for VerifiedJwtTenantExtractor {
+    fn extract_tenant_info(&self, token: &str) -> Result<JwtTenantInfo, JwtExtractionError> {
+        let payload = self.parser.parse_payload(token).map_err(|e| match e {
+            JwtParseError::InvalidFormat(msg) => JwtExtractionError::InvalidStructure(msg),
+            JwtParseError::PayloadDecodeError(msg) => JwtExtractionError::InvalidJson(msg),
+            other => JwtExtractionError::VerificationFailed(other.to_string()),
+        })?;
+
+        Ok(tenant_info_from_payload(&payload))
+    }
+}
+
+#[cfg(feature = "tenant-extraction")]
+/// Read the tenant key and onBehalfOf object from a decoded JWT payload
+fn tenant_info_from_payload(payload: &serde_json::Value) -> JwtTenantInfo {
+    // Extract tenant key (try both "tenantkey" and "tenant_key")
+    let tenant_key = payload
+        .get("tenantkey")
+        .or_else(|| payload.get("tenant_key"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    // Extract onBehalfOf information
+    let on_behalf_of = if let Some(on_behalf_obj) = payload.get("onBehalfOf") {
+        let tenant = on_behalf_obj
+            .get("tenant")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        let user = on_behalf_obj
+            .get("user")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        Some(OnBehalfOfInfo { tenant, user })
+    } else {
+        None
+    };
+
+    JwtTenantInfo {
+        tenant_key,
+        on_behalf_of,
+        subject: payload
+            .get("sub")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -636,6 +706,7 @@ mod tests {
             Ok(JwtTenantInfo {
                 tenant_key: Some(self.tenant_key.clone()),
                 on_behalf_of: None,
+                subject: None,
             })
         }
     }
@@ -804,5 +875,56 @@ mod tests {
             let result = extractor.extract_tenant_info(&jwt);
             assert!(matches!(result, Err(JwtExtractionError::InvalidJson(_))));
         }
+
+        #[test]
+        fn test_verified_extractor_rejects_forged_tokens() {
+            use crate::tenant::{JwtKeySource, JwtVerificationConfig};
+
+            let config = ExtractionConfig {
+                jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+                    secret: "starfleet-secret".to_string(),
+                })),
+                ..Default::default()
+            };
+            let processor = JwtProcessor::from_extraction_config(&config).unwrap();
+
+            let claims = serde_json::json!({
+                "tenantkey": "enterprise",
+                "onBehalfOf": {"tenant": "voyager", "user": "janeway"},
+                "exp": jsonwebtoken::get_current_timestamp() + 600,
+            });
+            let sign = |secret: &[u8]| {
+                jsonwebtoken::encode(
+                    &jsonwebtoken::Header::default(),
+                    &claims,
+                    &jsonwebtoken::EncodingKey::from_secret(secret),
+                )
+                .unwrap()
+            };
+
+            let info = processor
+                .extract_from_token(&sign(b"starfleet-secret"))
+                .unwrap();
+            assert_eq!(info.tenant_key, Some("enterprise".to_string()));
+            assert_eq!(
+                info.on_behalf_of,
+                Some(OnBehalfOfInfo {
+                    tenant: Some("voyager".to_string()),
+                    user: Some("janeway".to_string()),
+                })
+            );
+
+            assert!(matches!(
+                processor.extract_from_token(&sign(b"attacker-secret")),
+                Err(JwtExtractionError::VerificationFailed(_))
+            ));
+
+            // Without verification the same forged token is accepted
+            let parse_only =
+                JwtProcessor::from_extraction_config(&ExtractionConfig::default()).unwrap();
+            assert!(parse_only
+                .extract_from_token(&sign(b"attacker-secret"))
+                .is_ok());
+        }
     }
 }
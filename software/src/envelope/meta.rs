@@ -37,6 +37,19 @@ pub struct Meta {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tenant: Option<String>,
 
+    /// Services the request passed through, in hop order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service_chain: Vec<ServiceChainEntry>,
+
+    /// Point in time by which the caller needs the response
+    ///
+    /// Servers reject requests whose deadline has passed and cancel handlers when it
+    /// is reached; outbound calls made while handling a request inherit it. Never
+    /// serialized: transports send the time remaining and the receiver rebuilds the
+    /// deadline on its own clock (see [`deadline`](super::deadline)).
+    #[serde(skip)]
+    pub deadline: Option<DateTime<Utc>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<OnBehalfOfMeta>,
 
@@ -59,6 +72,76 @@ pub struct Meta {
     pub extensions: Option<ExtensionsMeta>,
 }
 
+/// A single service hop recorded in the service chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[cfg_attr(feature = "openapi", schema(
+    title = "Service Chain Entry",
+    description = "A service that processed the request on its way through the mesh",
+))]
+pub struct ServiceChainEntry {
+    /// Name of the service that processed the request
+    #[cfg_attr(feature = "openapi", schema(example = "holodeck-coordinator"))]
+    pub service_name: String,
+
+    /// Version of the service that processed the request
+    #[cfg_attr(feature = "openapi", schema(example = "1.2.0"))]
+    pub service_version: String,
+
+    /// Request identifier as seen by this service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<Uuid>,
+
+    /// When this service started processing the request
+    pub timestamp: DateTime<Utc>,
+
+    /// Processing time for this service in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+}
+
+impl ServiceChainEntry {
+    /// Create a hop for the given service, starting now
+    pub fn new(service_name: impl Into<String>, service_version: impl Into<String>) -> Self {
+        Self {
+            service_name: service_name.into(),
+            service_version: service_version.into(),
+            request_id: None,
+            timestamp: Utc::now(),
+            duration: None,
+        }
+    }
+
+    /// Create a hop for the local service.
+    ///
+    /// The identity is read from `QOLLECTIVE_SERVICE_NAME` and `QOLLECTIVE_SERVICE_VERSION`,
+    /// falling back to the framework defaults.
+    pub fn for_local_service() -> Self {
+        use crate::constants::{env_vars, metadata};
+
+        Self::new(
+            std::env::var(env_vars::QOLLECTIVE_SERVICE_NAME)
+                .unwrap_or_else(|_| metadata::DEFAULT_SERVICE_NAME.to_string()),
+            std::env::var(env_vars::QOLLECTIVE_SERVICE_VERSION)
+                .unwrap_or_else(|_| metadata::QOLLECTIVE_VERSION.to_string()),
+        )
+    }
+
+    /// Set the request identifier seen by this service
+    pub fn with_request_id(mut self, request_id: Option<Uuid>) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// Record the processing time as the time elapsed since this hop started
+    pub fn complete(&mut self) {
+        let elapsed = Utc::now().signed_duration_since(self.timestamp);
+        self.duration = elapsed
+            .num_microseconds()
+            .map(|micros| micros as f64 / 1000.0);
+    }
+}
+
 /// OnBehalfOf metadata for delegation context
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -124,7 +207,7 @@ pub struct SecurityMeta {
     pub auth_method: Option<AuthMethod>,
 
     /// List of permissions granted to the user
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[cfg_attr(feature = "openapi", schema(example = "COMMAND_SHIP,CREW_MANAGEMENT"))]
     pub permissions: Vec<String>,
 
@@ -139,7 +222,7 @@ pub struct SecurityMeta {
     pub user_agent: Option<String>,
 
     /// User roles within the system
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[cfg_attr(feature = "openapi", schema(example = "Captain,Bridge Officer"))]
     pub roles: Vec<String>,
 
@@ -218,7 +301,7 @@ pub struct DebugMeta {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_enabled: Option<bool>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub db_queries: Vec<DbQuery>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,7 +401,7 @@ pub struct PerformanceMeta {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_latency: Option<f64>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub external_calls: Vec<ExternalCall>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -548,6 +631,8 @@ impl MetaBuilder {
                 version: None,
                 duration: None,
                 tenant: None,
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
@@ -578,6 +663,8 @@ impl Default for Meta {
             version: None,
             duration: None,
             tenant: None,
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
@@ -599,6 +686,7 @@ impl Meta {
                 request_id: orig.request_id,
                 tenant: orig.tenant.clone(),
                 version: orig.version.clone(),
+                service_chain: orig.service_chain.clone(),
                 on_behalf_of: orig.on_behalf_of.clone(),
                 security: orig.security.clone(),
 
@@ -607,6 +695,7 @@ impl Meta {
 
                 // Reset response-specific fields
                 duration: None,
+                deadline: None,
                 debug: None,
                 performance: None,
                 monitoring: None,
@@ -623,6 +712,59 @@ impl Meta {
         }
     }
 
+    /// Append the local service to the service chain of an incoming request.
+    ///
+    /// Servers call this before dispatching to a handler so the handler context
+    /// and the response both see the full path the request took.
+    pub fn record_service_hop(&mut self) {
+        self.service_chain
+            .push(ServiceChainEntry::for_local_service().with_request_id(self.request_id));
+    }
+
+    /// Complete the local service hop on response metadata.
+    ///
+    /// Carries over the request's service chain when the handler built fresh
+    /// metadata, then records the processing time of the last hop.
+    pub fn complete_service_hop(&mut self, request_chain: &[ServiceChainEntry]) {
+        if self.service_chain.len() < request_chain.len() {
+            self.service_chain = request_chain.to_vec();
+        }
+        if let Some(hop) = self.service_chain.last_mut() {
+            if hop.duration.is_none() {
+                hop.complete();
+            }
+        }
+    }
+
+    /// Extension section holding the retry attempt of a resent request
+    pub const RETRY_EXTENSION: &'static str = "retry";
+
+    /// Attempt number of a retried request, starting at 2 for the first retry.
+    ///
+    /// Returns `None` for first attempts, which carry no retry section.
+    pub fn retry_attempt(&self) -> Option<u32> {
+        self.extensions
+            .as_ref()?
+            .sections
+            .get(Self::RETRY_EXTENSION)?
+            .get("attempt")?
+            .as_u64()
+            .map(|attempt| attempt as u32)
+    }
+
+    /// Record the attempt number of a retried request in the retry extension section
+    pub fn set_retry_attempt(&mut self, attempt: u32) {
+        self.extensions
+            .get_or_insert_with(|| ExtensionsMeta {
+                sections: HashMap::new(),
+            })
+            .sections
+            .insert(
+                Self::RETRY_EXTENSION.to_string(),
+                serde_json::json!({ "attempt": attempt }),
+            );
+    }
+
     /// Create metadata for new requests (when no original metadata exists)
     pub fn for_new_request() -> Self {
         Self {
@@ -639,3 +781,83 @@ pub trait MetaSection {
     fn is_enabled(&self) -> bool;
     fn as_json(&self) -> Result<serde_json::Value>;
 }
+
+/// Meta policy enforced on envelopes before servers and clients send them
+///
+/// Without the `config` feature there is no policy to configure and envelopes
+/// keep whatever metadata they were built with.
+#[derive(Debug, Clone, Default)]
+pub struct OutgoingMetaPolicy {
+    #[cfg(feature = "config")]
+    policy: Option<std::sync::Arc<crate::config::meta::MetaPolicy>>,
+}
+
+impl OutgoingMetaPolicy {
+    /// Enforce `policy` on outgoing metadata
+    #[cfg(feature = "config")]
+    pub fn new(policy: crate::config::meta::MetaPolicy) -> Self {
+        Self {
+            policy: Some(std::sync::Arc::new(policy)),
+        }
+    }
+
+    /// Enforce the meta settings of the configuration loaded for this environment
+    ///
+    /// Fails if the configuration cannot be loaded. Without the `config`
+    /// feature nothing is filtered.
+    pub fn for_environment() -> Result<Self> {
+        #[cfg(feature = "config")]
+        {
+            let config = crate::config::loader::ConfigLoader::new().load()?;
+            Ok(Self::new(crate::config::meta::MetaPolicy::from_config(
+                &config,
+            )))
+        }
+        #[cfg(not(feature = "config"))]
+        Ok(Self::default())
+    }
+
+    /// The policy of this environment, loaded once per process
+    ///
+    /// Servers and transports start with this policy until one is set
+    /// explicitly. Should the configuration fail to load, the production
+    /// preset applies instead so no more metadata leaks than it allows.
+    pub fn environment() -> Self {
+        #[cfg(feature = "config")]
+        {
+            static ENVIRONMENT: std::sync::OnceLock<OutgoingMetaPolicy> =
+                std::sync::OnceLock::new();
+            ENVIRONMENT
+                .get_or_init(|| {
+                    Self::for_environment().unwrap_or_else(|e| {
+                        tracing::warn!(
+                            "Failed to load the environment's meta policy, applying the production preset: {}",
+                            e
+                        );
+                        Self::new(crate::config::meta::MetaPolicy::for_preset(
+                            &crate::config::ConfigPreset::Production,
+                        ))
+                    })
+                })
+                .clone()
+        }
+        #[cfg(not(feature = "config"))]
+        Self::default()
+    }
+
+    /// Strip whatever the policy does not allow from metadata bound for `route`
+    #[cfg_attr(not(feature = "config"), allow(unused_variables))]
+    pub fn apply(&self, meta: &mut Meta, route: &str) {
+        #[cfg(feature = "config")]
+        if let Some(policy) = &self.policy {
+            policy.apply(meta, route);
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<crate::config::meta::MetaPolicy> for OutgoingMetaPolicy {
+    fn from(policy: crate::config::meta::MetaPolicy) -> Self {
+        Self::new(policy)
+    }
+}
@@ -12,7 +12,7 @@
 //! - Integration with axum middleware and tonic interceptors
 //! - Performance metrics collection and tracing integration
 
-use super::{Context, Meta};
+use super::{deadline, trace_context, Context, Meta};
 use crate::error::Result;
 use chrono::{DateTime, Utc};
 use serde_json::Value;
@@ -86,6 +86,9 @@ impl Default for MiddlewareConfig {
             metadata_headers: vec![
                 "x-request-id".to_string(),
                 "x-correlation-id".to_string(),
+                "traceparent".to_string(),
+                "tracestate".to_string(),
+                "baggage".to_string(),
                 "x-trace-id".to_string(),
                 "x-span-id".to_string(),
                 "x-user-id".to_string(),
@@ -208,26 +211,14 @@ impl ContextMiddleware for EnvelopeMiddleware {
             });
         }
 
-        // Extract tracing metadata
-        let trace_id = headers.get("x-trace-id").map(|s| s.to_string());
-        let span_id = headers.get("x-span-id").map(|s| s.to_string());
-
-        if trace_id.is_some() || span_id.is_some() {
-            meta.tracing = Some(crate::envelope::meta::TracingMeta {
-                trace_id,
-                span_id,
-                parent_span_id: headers.get("x-parent-span-id").map(|s| s.to_string()),
-                baggage: std::collections::HashMap::new(),
-                sampling_rate: None,
-                sampled: None,
-                trace_state: headers.get("tracestate").map(|s| s.to_string()),
-                operation_name: None,
-                span_kind: None,
-                span_status: None,
-                tags: std::collections::HashMap::new(),
-            });
+        // Extract tracing metadata (W3C traceparent/baggage, legacy x-trace-id fallback)
+        if self.config.enable_tracing {
+            meta.tracing = trace_context::extract(headers);
         }
 
+        // Extract the caller's deadline from grpc-timeout or the timeout header
+        meta.deadline = deadline::extract(headers);
+
         // Extract extension headers
         let mut extensions_map = HashMap::<String, serde_json::Value>::new();
         for key in headers.keys() {
@@ -314,16 +305,18 @@ impl ContextMiddleware for EnvelopeMiddleware {
             }
         }
 
-        // Inject tracing metadata
-        if let Some(ref tracing) = meta.tracing {
-            if let Some(ref trace_id) = tracing.trace_id {
-                headers.set("x-trace-id", trace_id)?;
-            }
-            if let Some(ref span_id) = tracing.span_id {
-                headers.set("x-span-id", span_id)?;
+        // Inject tracing metadata, parented to the current span when one is active
+        if self.config.enable_tracing {
+            if let Some(tracing) = trace_context::outgoing(meta.tracing.as_ref()) {
+                trace_context::inject(&tracing, headers)?;
             }
         }
 
+        // Inject the remaining time, capped by the deadline of the request being handled
+        if let Some(deadline) = deadline::outgoing(meta.deadline, None) {
+            deadline::inject(deadline, headers)?;
+        }
+
         // Inject extension headers
         if let Some(ref extensions) = meta.extensions {
             for (key, value) in &extensions.sections {
@@ -497,6 +490,8 @@ pub mod propagation {
         if overlay_meta.tracing.is_some() {
             merged_meta.tracing = overlay_meta.tracing.clone();
         }
+        // Neither context may extend the other's deadline
+        merged_meta.deadline = deadline::earliest(merged_meta.deadline, overlay_meta.deadline);
 
         // Merge extensions
         if let Some(ref overlay_ext) = overlay_meta.extensions {
@@ -526,8 +521,9 @@ pub mod propagation {
 
         // Update tracing information for child
         if let Some(ref mut tracing) = child_meta.tracing {
-            // Generate new span ID (keep same trace ID)
-            tracing.span_id = Some(Uuid::now_v7().to_string());
+            // Generate new span ID (keep same trace ID) and remember the parent
+            tracing.parent_span_id = tracing.span_id.take();
+            tracing.span_id = Some(trace_context::new_span_id());
         }
 
         child
@@ -667,6 +663,37 @@ mod tests {
         assert_eq!(headers.get("x-version").unwrap(), "2.0.0");
     }
 
+    #[test]
+    fn test_w3c_trace_context_round_trip() {
+        let middleware = EnvelopeMiddleware::new();
+        let mut headers = MockHeaders::default();
+
+        headers
+            .set(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .unwrap();
+        headers.set("baggage", "mission=survey").unwrap();
+
+        let context = middleware.extract_context(&headers).unwrap();
+        let tracing = context.meta().tracing.as_ref().unwrap();
+        assert_eq!(
+            tracing.trace_id.as_deref(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+        assert_eq!(tracing.baggage.get("mission").unwrap(), "survey");
+
+        let child = propagation::create_child_context(&context);
+        let mut outgoing = MockHeaders::default();
+        middleware.inject_context(&child, &mut outgoing).unwrap();
+
+        let traceparent = outgoing.get("traceparent").unwrap();
+        assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert!(!traceparent.contains("00f067aa0ba902b7"));
+        assert_eq!(outgoing.get("baggage").unwrap(), "mission=survey");
+    }
+
     #[test]
     fn test_context_processing_incoming() {
         let middleware = EnvelopeMiddleware::new();
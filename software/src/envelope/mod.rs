@@ -8,8 +8,11 @@
 
 pub mod builder;
 pub mod context;
+pub mod deadline;
+pub mod enrichment;
 pub mod meta;
 pub mod middleware;
+pub mod trace_context;
 
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 pub mod nats_codec;
@@ -28,6 +31,7 @@ pub mod unified_tenant_extraction;
 
 pub use builder::{Envelope, EnvelopeBuilder, EnvelopeError};
 pub use context::{Context, ContextBuilder, ContextPropagation};
+pub use enrichment::{MetaEnricher, MetaEnrichers, ServiceIdentity};
 pub use meta::{Meta, MetaBuilder, MetaSection};
 pub use middleware::{
     propagation, ContextMiddleware, EnvelopeMiddleware, HeaderLike, MiddlewareBuilder,
@@ -35,20 +39,22 @@ pub use middleware::{
 };
 #[cfg(feature = "tenant-extraction")]
 pub use tenant_middleware::TenantExtractionMiddleware;
+pub use trace_context::TraceParent;
 
 #[cfg(feature = "tenant-extraction")]
 pub use unified_tenant_extraction::UnifiedTenantExtractor;
 
 // Re-exports for convenience
 pub use meta::{
-    DebugMeta, ExtensionsMeta, MonitoringMeta, OnBehalfOfMeta, PerformanceMeta, SecurityMeta, TracingMeta,
+    DebugMeta, ExtensionsMeta, MonitoringMeta, OnBehalfOfMeta, PerformanceMeta, SecurityMeta,
+    ServiceChainEntry, TracingMeta,
 };
 
 #[cfg(feature = "tenant-extraction")]
 pub use jwt_extractor::{
     DefaultJwtTenantExtractor, DefaultJwtTokenLocator, HttpRequest, JwtExtractionError,
     JwtProcessingError, JwtProcessor, JwtProcessorBuilder, JwtTenantExtractor, JwtTenantInfo,
-    JwtTokenLocator, OnBehalfOfInfo, TokenLocationError,
+    JwtTokenLocator, OnBehalfOfInfo, TokenLocationError, VerifiedJwtTenantExtractor,
 };
 
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
@@ -227,6 +227,8 @@ mod tests {
             version: Some("1.0.0".to_string()),
             duration: Some(100.0), // Duration in milliseconds as f64
             tenant: Some("test-tenant".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: None,
             debug: None,
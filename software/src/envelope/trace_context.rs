@@ -0,0 +1,700 @@
+// ABOUTME: W3C Trace Context and Baggage propagation for envelope tracing metadata
+// ABOUTME: Parses and formats traceparent/tracestate/baggage and links tracing spans across transports
+
+//! W3C Trace Context propagation.
+//!
+//! This module maps [`TracingMeta`] to and from the standard `traceparent`,
+//! `tracestate` and `baggage` headers so that traces survive every hop between
+//! REST, gRPC, NATS and WebSocket services.
+//!
+//! - Incoming headers are read through [`HeaderLike`], preferring `traceparent`
+//!   and falling back to the legacy `x-trace-id`/`x-span-id` pair.
+//! - Outgoing headers carry both the W3C headers and the legacy pair.
+//! - With the `tracing` feature, [`instrument`] runs a handler inside a span whose
+//!   OpenTelemetry parent is the remote span, and [`outgoing`] picks up the
+//!   current span so downstream calls become its children.
+
+use super::meta::{Meta, SpanKind, TracingMeta};
+use super::middleware::HeaderLike;
+use crate::error::Result;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+
+/// W3C `traceparent` header name
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// W3C `tracestate` header name
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+/// W3C `baggage` header name
+pub const BAGGAGE_HEADER: &str = "baggage";
+
+/// Legacy trace ID header, still written for older services
+pub const LEGACY_TRACE_ID_HEADER: &str = "x-trace-id";
+
+/// Legacy span ID header, still written for older services
+pub const LEGACY_SPAN_ID_HEADER: &str = "x-span-id";
+
+/// Legacy parent span ID header
+pub const LEGACY_PARENT_SPAN_ID_HEADER: &str = "x-parent-span-id";
+
+const TRACEPARENT_VERSION: &str = "00";
+const TRACEPARENT_LENGTH: usize = 55;
+const FLAG_SAMPLED: u8 = 0x01;
+
+/// Parsed W3C `traceparent` header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceParent {
+    /// 32 lowercase hex characters identifying the whole trace
+    pub trace_id: String,
+    /// 16 lowercase hex characters identifying the calling span
+    pub parent_id: String,
+    /// Whether the caller recorded the trace
+    pub sampled: bool,
+}
+
+impl TraceParent {
+    /// Create a traceparent, normalizing IDs to lowercase hex
+    ///
+    /// Returns `None` if either ID is not valid W3C hex. UUID-formatted trace
+    /// IDs are accepted with their dashes stripped.
+    pub fn new(trace_id: &str, parent_id: &str, sampled: bool) -> Option<Self> {
+        let trace_id = normalize_id(trace_id, 32)?;
+        let parent_id = normalize_id(parent_id, 16)?;
+        Some(Self {
+            trace_id,
+            parent_id,
+            sampled,
+        })
+    }
+
+    /// Start a new sampled trace with random IDs
+    pub fn generate() -> Self {
+        Self {
+            trace_id: new_trace_id(),
+            parent_id: new_span_id(),
+            sampled: true,
+        }
+    }
+
+    /// Same trace with a fresh span ID, for a span started below this one
+    pub fn child(&self) -> Self {
+        Self {
+            trace_id: self.trace_id.clone(),
+            parent_id: new_span_id(),
+            sampled: self.sampled,
+        }
+    }
+
+    /// Parse a `traceparent` header value
+    ///
+    /// Follows the W3C rules: version `ff` and all-zero IDs are invalid,
+    /// version `00` must be exactly 55 characters, and later versions may
+    /// append further fields which are ignored.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.len() < TRACEPARENT_LENGTH || !value.is_char_boundary(TRACEPARENT_LENGTH) {
+            return None;
+        }
+
+        let (head, rest) = value.split_at(TRACEPARENT_LENGTH);
+        let mut parts = head.split('-');
+        let (version, trace_id, parent_id, flags) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+
+        if version.len() != 2 || !is_lower_hex(version) || version == "ff" {
+            return None;
+        }
+        if version == TRACEPARENT_VERSION && !rest.is_empty() {
+            return None;
+        }
+        if !rest.is_empty() && !rest.starts_with('-') {
+            return None;
+        }
+        if flags.len() != 2 || !is_lower_hex(flags) {
+            return None;
+        }
+        if trace_id.len() != 32 || parent_id.len() != 16 {
+            return None;
+        }
+        if !is_lower_hex(trace_id) || !is_lower_hex(parent_id) {
+            return None;
+        }
+        if is_zero(trace_id) || is_zero(parent_id) {
+            return None;
+        }
+
+        let flags = u8::from_str_radix(flags, 16).ok()?;
+        Some(Self {
+            trace_id: trace_id.to_string(),
+            parent_id: parent_id.to_string(),
+            sampled: flags & FLAG_SAMPLED != 0,
+        })
+    }
+
+    /// Build a traceparent from envelope tracing metadata, if its IDs are W3C compatible
+    pub fn from_tracing_meta(tracing: &TracingMeta) -> Option<Self> {
+        Self::new(
+            tracing.trace_id.as_deref()?,
+            tracing.span_id.as_deref()?,
+            tracing.sampled.unwrap_or(true),
+        )
+    }
+}
+
+impl fmt::Display for TraceParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = if self.sampled { FLAG_SAMPLED } else { 0 };
+        write!(
+            f,
+            "{}-{}-{}-{:02x}",
+            TRACEPARENT_VERSION, self.trace_id, self.parent_id, flags
+        )
+    }
+}
+
+/// Generate a random 32 character trace ID
+pub fn new_trace_id() -> String {
+    loop {
+        let id: u128 = rand::random();
+        if id != 0 {
+            return format!("{:032x}", id);
+        }
+    }
+}
+
+/// Generate a random 16 character span ID
+pub fn new_span_id() -> String {
+    loop {
+        let id: u64 = rand::random();
+        if id != 0 {
+            return format!("{:016x}", id);
+        }
+    }
+}
+
+/// Parse a W3C `baggage` header into key/value pairs
+///
+/// Values are percent-decoded and member properties (`;prop`) are dropped.
+/// Malformed members are skipped rather than failing the whole header.
+pub fn parse_baggage(value: &str) -> HashMap<String, String> {
+    value
+        .split(',')
+        .filter_map(|member| {
+            let pair = member.split(';').next()?;
+            let (key, value) = pair.split_once('=')?;
+            let key = key.trim();
+            if key.is_empty() || !key.bytes().all(is_token_byte) {
+                return None;
+            }
+            let value = urlencoding::decode(value.trim()).ok()?;
+            Some((key.to_string(), value.into_owned()))
+        })
+        .collect()
+}
+
+/// Format key/value pairs as a W3C `baggage` header, percent-encoding the values
+///
+/// Keys that are not valid header tokens are skipped. Members are sorted by
+/// key so the header is stable.
+pub fn format_baggage(baggage: &HashMap<String, String>) -> String {
+    let mut members: Vec<_> = baggage
+        .iter()
+        .filter(|(key, _)| !key.is_empty() && key.bytes().all(is_token_byte))
+        .collect();
+    members.sort_by(|a, b| a.0.cmp(b.0));
+
+    members
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Header name/value pairs that carry the given tracing metadata
+///
+/// Always includes the legacy `x-trace-id`/`x-span-id` pair. `traceparent` is
+/// only emitted when the IDs are W3C compatible.
+pub fn header_pairs(tracing: &TracingMeta) -> Vec<(&'static str, String)> {
+    let mut pairs = Vec::new();
+
+    if let Some(traceparent) = TraceParent::from_tracing_meta(tracing) {
+        pairs.push((TRACEPARENT_HEADER, traceparent.to_string()));
+        if let Some(trace_state) = tracing
+            .trace_state
+            .as_deref()
+            .map(str::trim)
+            .filter(|state| !state.is_empty() && is_header_safe(state))
+        {
+            pairs.push((TRACESTATE_HEADER, trace_state.to_string()));
+        }
+    }
+
+    let baggage = format_baggage(&tracing.baggage);
+    if !baggage.is_empty() {
+        pairs.push((BAGGAGE_HEADER, baggage));
+    }
+
+    if let Some(trace_id) = tracing.trace_id.as_deref().filter(|id| is_header_safe(id)) {
+        pairs.push((LEGACY_TRACE_ID_HEADER, trace_id.to_string()));
+    }
+    if let Some(span_id) = tracing.span_id.as_deref().filter(|id| is_header_safe(id)) {
+        pairs.push((LEGACY_SPAN_ID_HEADER, span_id.to_string()));
+    }
+
+    pairs
+}
+
+/// Write trace context headers for the given tracing metadata
+pub fn inject(tracing: &TracingMeta, headers: &mut dyn HeaderLike) -> Result<()> {
+    for (name, value) in header_pairs(tracing) {
+        headers.set(name, &value)?;
+    }
+    Ok(())
+}
+
+/// Read trace context from headers
+///
+/// A valid `traceparent` wins; otherwise the legacy `x-trace-id`/`x-span-id`
+/// headers are used. `baggage` is read in both cases. The returned `span_id`
+/// is the caller's span, i.e. the parent of any span started for this request.
+pub fn extract(headers: &dyn HeaderLike) -> Option<TracingMeta> {
+    let baggage = headers
+        .get(BAGGAGE_HEADER)
+        .map(parse_baggage)
+        .unwrap_or_default();
+
+    if let Some(traceparent) = headers.get(TRACEPARENT_HEADER).and_then(TraceParent::parse) {
+        let mut tracing = empty_tracing_meta();
+        tracing.trace_id = Some(traceparent.trace_id);
+        tracing.span_id = Some(traceparent.parent_id);
+        tracing.sampled = Some(traceparent.sampled);
+        tracing.trace_state = headers
+            .get(TRACESTATE_HEADER)
+            .map(str::trim)
+            .filter(|state| !state.is_empty())
+            .map(str::to_string);
+        tracing.baggage = baggage;
+        return Some(tracing);
+    }
+
+    let trace_id = headers.get(LEGACY_TRACE_ID_HEADER).map(str::to_string);
+    let span_id = headers.get(LEGACY_SPAN_ID_HEADER).map(str::to_string);
+    if trace_id.is_none() && span_id.is_none() {
+        return None;
+    }
+
+    let mut tracing = empty_tracing_meta();
+    tracing.trace_id = trace_id;
+    tracing.span_id = span_id;
+    tracing.parent_span_id = headers
+        .get(LEGACY_PARENT_SPAN_ID_HEADER)
+        .map(str::to_string);
+    tracing.trace_state = headers.get(TRACESTATE_HEADER).map(str::to_string);
+    tracing.baggage = baggage;
+    Some(tracing)
+}
+
+/// Merge trace context received in transport headers into envelope metadata
+///
+/// Header IDs, sampling and trace state take precedence because they reflect
+/// the immediate caller; baggage entries are combined with header values winning.
+pub fn merge_into_meta(meta: &mut Meta, incoming: TracingMeta) {
+    let tracing = match meta.tracing.as_mut() {
+        Some(tracing) => tracing,
+        None => {
+            meta.tracing = Some(incoming);
+            return;
+        }
+    };
+
+    if incoming.trace_id.is_some() {
+        tracing.trace_id = incoming.trace_id;
+        tracing.span_id = incoming.span_id;
+        tracing.parent_span_id = incoming.parent_span_id;
+    }
+    if incoming.sampled.is_some() {
+        tracing.sampled = incoming.sampled;
+    }
+    if incoming.trace_state.is_some() {
+        tracing.trace_state = incoming.trace_state;
+    }
+    tracing.baggage.extend(incoming.baggage);
+}
+
+/// Tracing metadata to send on an outgoing request
+///
+/// With the `tracing` feature and an OpenTelemetry layer installed, the
+/// current span becomes the parent of the downstream span. Otherwise the
+/// given metadata is forwarded unchanged.
+pub fn outgoing(tracing: Option<&TracingMeta>) -> Option<TracingMeta> {
+    #[cfg(feature = "tracing")]
+    if let Some(current) = otel::current_span_context() {
+        let mut outgoing = tracing.cloned().unwrap_or_else(empty_tracing_meta);
+        if outgoing.span_id.as_deref() != Some(current.parent_id.as_str()) {
+            outgoing.parent_span_id = outgoing.span_id.take();
+        }
+        outgoing.trace_id = Some(current.trace_id);
+        outgoing.span_id = Some(current.parent_id);
+        outgoing.sampled = Some(current.sampled);
+        if current.trace_state.is_some() {
+            outgoing.trace_state = current.trace_state;
+        }
+        return Some(outgoing);
+    }
+
+    tracing.cloned()
+}
+
+/// Run a future inside a span for the given operation
+///
+/// The span's OpenTelemetry parent is the remote span described by `parent`,
+/// so the work shows up in the caller's trace. Without the `tracing` feature
+/// the future is returned unchanged.
+#[cfg(feature = "tracing")]
+pub fn instrument<F: Future>(
+    future: F,
+    operation: &str,
+    kind: SpanKind,
+    parent: Option<&TracingMeta>,
+) -> tracing::instrument::Instrumented<F> {
+    use tracing::Instrument;
+
+    future.instrument(start_span(operation, kind, parent))
+}
+
+/// Run a future inside a span for the given operation
+#[cfg(not(feature = "tracing"))]
+pub fn instrument<F: Future>(
+    future: F,
+    _operation: &str,
+    _kind: SpanKind,
+    _parent: Option<&TracingMeta>,
+) -> F {
+    future
+}
+
+/// Create a span for the given operation, linked to the remote parent if known
+#[cfg(feature = "tracing")]
+pub fn start_span(operation: &str, kind: SpanKind, parent: Option<&TracingMeta>) -> tracing::Span {
+    let trace_id = parent.and_then(|tracing| tracing.trace_id.as_deref());
+    let span = tracing::info_span!(
+        "qollective.envelope",
+        otel.name = operation,
+        otel.kind = span_kind_name(&kind),
+        trace_id = trace_id,
+    );
+
+    if let Some(traceparent) = parent.and_then(TraceParent::from_tracing_meta) {
+        otel::set_remote_parent(
+            &span,
+            &traceparent,
+            parent.and_then(|tracing| tracing.trace_state.as_deref()),
+        );
+    }
+
+    span
+}
+
+#[cfg(feature = "tracing")]
+fn span_kind_name(kind: &SpanKind) -> &'static str {
+    match kind {
+        SpanKind::Server => "server",
+        SpanKind::Client => "client",
+        SpanKind::Producer => "producer",
+        SpanKind::Consumer => "consumer",
+        SpanKind::Internal | SpanKind::Unspecified => "internal",
+    }
+}
+
+/// Bridge to the OpenTelemetry context held by `tracing-opentelemetry`
+#[cfg(feature = "tracing")]
+mod otel {
+    use super::TraceParent;
+    use opentelemetry::trace::{
+        SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+    };
+    use std::str::FromStr;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    /// Span context of the current span as seen by OpenTelemetry
+    pub(super) struct CurrentSpanContext {
+        pub trace_id: String,
+        pub parent_id: String,
+        pub sampled: bool,
+        pub trace_state: Option<String>,
+    }
+
+    pub(super) fn current_span_context() -> Option<CurrentSpanContext> {
+        let context = tracing::Span::current().context();
+        let span = context.span();
+        let span_context = span.span_context();
+        if !span_context.is_valid() {
+            return None;
+        }
+
+        let trace_state = span_context.trace_state().header();
+        Some(CurrentSpanContext {
+            trace_id: span_context.trace_id().to_string(),
+            parent_id: span_context.span_id().to_string(),
+            sampled: span_context.is_sampled(),
+            trace_state: (!trace_state.is_empty()).then_some(trace_state),
+        })
+    }
+
+    pub(super) fn set_remote_parent(
+        span: &tracing::Span,
+        traceparent: &TraceParent,
+        trace_state: Option<&str>,
+    ) {
+        let (Ok(trace_id), Ok(span_id)) = (
+            TraceId::from_hex(&traceparent.trace_id),
+            SpanId::from_hex(&traceparent.parent_id),
+        ) else {
+            return;
+        };
+
+        let flags = if traceparent.sampled {
+            TraceFlags::SAMPLED
+        } else {
+            TraceFlags::default()
+        };
+        let trace_state = trace_state
+            .and_then(|state| TraceState::from_str(state).ok())
+            .unwrap_or_default();
+        let remote = SpanContext::new(trace_id, span_id, flags, true, trace_state);
+
+        // Fails only when no OpenTelemetry layer is installed, in which case
+        // there is nothing to link
+        let _ = span.set_parent(opentelemetry::Context::new().with_remote_span_context(remote));
+    }
+}
+
+/// Header access for NATS message headers
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+impl HeaderLike for async_nats::HeaderMap {
+    fn get(&self, name: &str) -> Option<&str> {
+        async_nats::HeaderMap::get(self, name).map(|value| value.as_str())
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        self.insert(name, value);
+        Ok(())
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.iter().map(|(name, _)| name.to_string()).collect()
+    }
+}
+
+/// NATS headers carrying the trace context of an outgoing envelope
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+pub fn nats_headers(meta: &Meta) -> Option<async_nats::HeaderMap> {
+    let tracing = meta.tracing.as_ref()?;
+    let mut headers = async_nats::HeaderMap::new();
+    for (name, value) in header_pairs(tracing) {
+        headers.insert(name, value);
+    }
+    (!headers.is_empty()).then_some(headers)
+}
+
+fn empty_tracing_meta() -> TracingMeta {
+    TracingMeta {
+        trace_id: None,
+        span_id: None,
+        parent_span_id: None,
+        baggage: HashMap::new(),
+        sampling_rate: None,
+        sampled: None,
+        trace_state: None,
+        operation_name: None,
+        span_kind: None,
+        span_status: None,
+        tags: HashMap::new(),
+    }
+}
+
+fn normalize_id(id: &str, len: usize) -> Option<String> {
+    let id = id.replace('-', "").to_ascii_lowercase();
+    (id.len() == len && is_lower_hex(&id) && !is_zero(&id)).then_some(id)
+}
+
+fn is_lower_hex(value: &str) -> bool {
+    value
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+fn is_zero(value: &str) -> bool {
+    value.bytes().all(|b| b == b'0')
+}
+
+fn is_header_safe(value: &str) -> bool {
+    value.bytes().all(|b| (0x20..0x7f).contains(&b))
+}
+
+/// RFC 7230 token characters, as required for baggage keys
+fn is_token_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[derive(Default)]
+    struct TestHeaders(HashMap<String, String>);
+
+    impl HeaderLike for TestHeaders {
+        fn get(&self, name: &str) -> Option<&str> {
+            self.0.get(name).map(|s| s.as_str())
+        }
+
+        fn set(&mut self, name: &str, value: &str) -> Result<()> {
+            self.0.insert(name.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn keys(&self) -> Vec<String> {
+            self.0.keys().cloned().collect()
+        }
+    }
+
+    #[test]
+    fn test_traceparent_round_trip() {
+        let traceparent = TraceParent::parse(TRACEPARENT).unwrap();
+
+        assert_eq!(traceparent.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(traceparent.parent_id, "00f067aa0ba902b7");
+        assert!(traceparent.sampled);
+        assert_eq!(traceparent.to_string(), TRACEPARENT);
+
+        let child = traceparent.child();
+        assert_eq!(child.trace_id, traceparent.trace_id);
+        assert_ne!(child.parent_id, traceparent.parent_id);
+    }
+
+    #[test]
+    fn test_traceparent_rejects_invalid_values() {
+        for invalid in [
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "not a traceparent",
+        ] {
+            assert!(TraceParent::parse(invalid).is_none(), "{}", invalid);
+        }
+
+        // Future versions may append fields
+        let future =
+            TraceParent::parse("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00-extra")
+                .unwrap();
+        assert!(!future.sampled);
+    }
+
+    #[test]
+    fn test_baggage_round_trip() {
+        let baggage = parse_baggage("userId=alice, region=us%20east;ttl=60,invalid,=empty");
+
+        assert_eq!(baggage.len(), 2);
+        assert_eq!(baggage["userId"], "alice");
+        assert_eq!(baggage["region"], "us east");
+        assert_eq!(format_baggage(&baggage), "region=us%20east,userId=alice");
+    }
+
+    #[test]
+    fn test_extract_prefers_traceparent_over_legacy_headers() {
+        let mut headers = TestHeaders::default();
+        headers.set(TRACEPARENT_HEADER, TRACEPARENT).unwrap();
+        headers.set(TRACESTATE_HEADER, "vendor=value").unwrap();
+        headers.set(BAGGAGE_HEADER, "ship=enterprise").unwrap();
+        headers.set(LEGACY_TRACE_ID_HEADER, "legacy-trace").unwrap();
+
+        let tracing = extract(&headers).unwrap();
+        assert_eq!(
+            tracing.trace_id.as_deref(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+        assert_eq!(tracing.span_id.as_deref(), Some("00f067aa0ba902b7"));
+        assert_eq!(tracing.sampled, Some(true));
+        assert_eq!(tracing.trace_state.as_deref(), Some("vendor=value"));
+        assert_eq!(tracing.baggage["ship"], "enterprise");
+
+        let mut legacy = TestHeaders::default();
+        legacy.set(LEGACY_TRACE_ID_HEADER, "legacy-trace").unwrap();
+        assert_eq!(
+            extract(&legacy).unwrap().trace_id.as_deref(),
+            Some("legacy-trace")
+        );
+        assert!(extract(&TestHeaders::default()).is_none());
+    }
+
+    #[test]
+    fn test_inject_writes_w3c_and_legacy_headers() {
+        let mut tracing = empty_tracing_meta();
+        tracing.trace_id = Some("0190b4a2-7c1e-7d3a-9f00-1234567890ab".to_string());
+        tracing.span_id = Some("00f067aa0ba902b7".to_string());
+        tracing.sampled = Some(false);
+        tracing
+            .baggage
+            .insert("tenant".to_string(), "starfleet".to_string());
+
+        let mut headers = TestHeaders::default();
+        inject(&tracing, &mut headers).unwrap();
+
+        assert_eq!(
+            headers.get(TRACEPARENT_HEADER),
+            Some("00-0190b4a27c1e7d3a9f001234567890ab-00f067aa0ba902b7-00")
+        );
+        assert_eq!(headers.get(BAGGAGE_HEADER), Some("tenant=starfleet"));
+        assert_eq!(
+            headers.get(LEGACY_TRACE_ID_HEADER),
+            Some("0190b4a2-7c1e-7d3a-9f00-1234567890ab")
+        );
+
+        // Non-W3C IDs still travel in the legacy headers
+        tracing.span_id = Some("span-1".to_string());
+        let mut headers = TestHeaders::default();
+        inject(&tracing, &mut headers).unwrap();
+        assert!(headers.get(TRACEPARENT_HEADER).is_none());
+        assert_eq!(headers.get(LEGACY_SPAN_ID_HEADER), Some("span-1"));
+    }
+
+    #[test]
+    fn test_merge_into_meta_prefers_header_ids() {
+        let mut meta = Meta::default();
+        let mut body = empty_tracing_meta();
+        body.trace_id = Some("body-trace".to_string());
+        body.operation_name = Some("scan".to_string());
+        body.baggage.insert("a".to_string(), "1".to_string());
+        meta.tracing = Some(body);
+
+        let mut headers = TestHeaders::default();
+        headers.set(TRACEPARENT_HEADER, TRACEPARENT).unwrap();
+        headers.set(BAGGAGE_HEADER, "b=2").unwrap();
+        merge_into_meta(&mut meta, extract(&headers).unwrap());
+
+        let tracing = meta.tracing.unwrap();
+        assert_eq!(
+            tracing.trace_id.as_deref(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+        assert_eq!(tracing.operation_name.as_deref(), Some("scan"));
+        assert_eq!(tracing.baggage.len(), 2);
+    }
+
+    #[test]
+    fn test_outgoing_without_active_span_forwards_metadata() {
+        let mut tracing = empty_tracing_meta();
+        tracing.trace_id = Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string());
+
+        assert_eq!(outgoing(Some(&tracing)), Some(tracing));
+        assert_eq!(outgoing(None), None);
+    }
+}
@@ -48,6 +48,20 @@ impl UnifiedTenantExtractor {
         }
     }
 
+    /// Create with custom configuration, failing if JWT verification keys cannot be loaded
+    pub fn try_with_config(config: ExtractionConfig) -> Result<Self> {
+        let enabled = config.enabled;
+        Ok(Self {
+            extractor: TenantExtractor::try_with_config(config)?,
+            error_handler: TenantExtractionErrorHandler::new(enabled),
+        })
+    }
+
+    /// Whether JWT tokens are signature-verified before their tenant is trusted
+    pub fn verifies_jwt(&self) -> bool {
+        self.extractor.verifies_jwt()
+    }
+
     /// Enable or disable extraction
     pub fn set_enabled(&mut self, enabled: bool) {
         self.error_handler.set_extraction_enabled(enabled);
@@ -102,6 +116,13 @@ impl UnifiedTenantExtractor {
                 Ok(None) => {
                     // No tenant in JWT, continue to other sources
                 }
+                Err(e) if self.extractor.verifies_jwt() => {
+                    // A token that fails verification must not fall back to other sources
+                    return Err(QollectiveError::security(format!(
+                        "Tenant token rejected: {}",
+                        e
+                    )));
+                }
                 Err(e) => {
                     // Handle JWT extraction error
                     let result = self.error_handler.handle_extraction_error(e, "jwt")?;
@@ -436,6 +457,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verified_extractor_does_not_fall_back_on_forged_jwt() {
+        use crate::tenant::{JwtKeySource, JwtVerificationConfig};
+
+        let config = ExtractionConfig {
+            jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+                secret: "starfleet-secret".to_string(),
+            })),
+            ..Default::default()
+        };
+        let extractor = UnifiedTenantExtractor::try_with_config(config).unwrap();
+        assert!(extractor.verifies_jwt());
+
+        let claims = serde_json::json!({
+            "tenant": "enterprise",
+            "exp": jsonwebtoken::get_current_timestamp() + 600,
+        });
+        let forged = jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(b"attacker-secret"),
+        )
+        .unwrap();
+        let mut headers = TestHeaders::new();
+        headers.insert("authorization", format!("Bearer {}", forged));
+        headers.insert("X-Tenant-ID", "romulan");
+
+        let result = extractor.process_context_with_tenant_extraction(
+            &Context::empty(),
+            &headers,
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(QollectiveError::Security(_))));
+    }
+
     #[test]
     fn test_extraction_disabled() {
         let mut extractor = UnifiedTenantExtractor::new();
@@ -65,6 +65,10 @@ pub enum QollectiveError {
     #[error("remote service error: {0}")]
     Remote(String),
 
+    /// Request deadline exceeded errors
+    #[error("deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+
     /// gRPC-specific errors
     #[error("gRPC error: {0}")]
     Grpc(String),
@@ -202,6 +206,11 @@ impl QollectiveError {
         Self::Remote(msg.into())
     }
 
+    /// Create a new deadline exceeded error
+    pub fn deadline_exceeded(msg: impl Into<String>) -> Self {
+        Self::DeadlineExceeded(msg.into())
+    }
+
     /// Create a new gRPC error
     pub fn grpc(msg: impl Into<String>) -> Self {
         Self::Grpc(msg.into())
@@ -838,6 +847,7 @@ impl EnhancedQollectiveError {
             QollectiveError::TenantExtraction(_) => "TenantExtraction".to_string(),
             QollectiveError::FeatureNotEnabled(_) => "FeatureNotEnabled".to_string(),
             QollectiveError::AgentNotFound(_) => "AgentNotFound".to_string(),
+            QollectiveError::DeadlineExceeded(_) => "DeadlineExceeded".to_string(),
             #[cfg(any(feature = "nats-client", feature = "nats-server"))]
             QollectiveError::NatsConnection(_) => "NatsConnection".to_string(),
             #[cfg(any(feature = "nats-client", feature = "nats-server"))]
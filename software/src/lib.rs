@@ -177,10 +177,13 @@ pub mod prelude {
     };
 
     pub use crate::monitoring::{
-        get_metrics_summary, record_envelope_operation, record_http_request, start_operation_timer,
-        OperationTimer,
+        observe, record_envelope_operation, record_http_request, record_request,
+        start_operation_timer, track_in_flight, InFlightGuard, OperationTimer,
     };
 
+    #[cfg(feature = "metrics")]
+    pub use crate::monitoring::{restrict_tenant_labels, MetricsExporter};
+
     pub use crate::transport::{
         HybridTransportClient, ServerInfo, TransportCapabilities, TransportDetectionConfig,
         TransportMetrics, TransportProtocol, TransportRequirements,
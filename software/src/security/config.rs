@@ -31,6 +31,9 @@ impl SecurityConfig {
                 issuer: None,
                 audience: None,
                 algorithms: vec!["HS256".to_string()],
+                leeway_seconds: timeouts::DEFAULT_JWT_LEEWAY_SECS,
+                jwks_path: None,
+                jwks: None,
             },
             storage: StorageConfig {
                 backend: "memory".to_string(),
@@ -79,6 +82,9 @@ impl SecurityConfig {
                 issuer: None,
                 audience: None,
                 algorithms: vec!["RS256".to_string(), "ES256".to_string()],
+                leeway_seconds: timeouts::DEFAULT_JWT_LEEWAY_SECS,
+                jwks_path: None,
+                jwks: None,
             },
             storage: StorageConfig {
                 backend: "redis".to_string(),
@@ -156,12 +162,25 @@ pub struct JwtValidationConfig {
     pub issuer: Option<String>,
     pub audience: Option<String>,
     pub algorithms: Vec<String>,
+    /// Clock skew tolerated when checking `exp` and `nbf`
+    #[serde(default = "default_jwt_leeway_seconds")]
+    pub leeway_seconds: u64,
+    /// Path to a local JWKS file holding the verification keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks_path: Option<String>,
+    /// In-memory JWKS holding the verification keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks: Option<jsonwebtoken::jwk::JwkSet>,
+}
+
+fn default_jwt_leeway_seconds() -> u64 {
+    timeouts::DEFAULT_JWT_LEEWAY_SECS
 }
 
 /// Storage Configuration
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct StorageConfig {
-    pub backend: String, // "memory", "redis", "database", "vault"
+    pub backend: String, // "memory", "file", "nats", "redis", "database", "vault"
     pub connection_string: Option<String>,
     pub encryption_key: Option<String>,
     pub ttl_seconds: Option<u64>,
@@ -325,6 +344,12 @@ impl SecurityConfigBuilder {
             if let Ok(audience) = env::var("QOLLECTIVE_JWT_AUDIENCE") {
                 jwt_config.audience = Some(audience);
             }
+            if let Ok(leeway) = env::var("QOLLECTIVE_JWT_LEEWAY_SECONDS") {
+                jwt_config.leeway_seconds = leeway.parse().unwrap_or(jwt_config.leeway_seconds);
+            }
+            if let Ok(jwks_path) = env::var("QOLLECTIVE_JWT_JWKS_PATH") {
+                jwt_config.jwks_path = Some(jwks_path);
+            }
         }
 
         // Storage overrides
@@ -433,6 +458,9 @@ mod tests {
                 issuer: Some("https://dev.auth0.com".to_string()),
                 audience: Some("api".to_string()),
                 algorithms: vec!["RS256".to_string()],
+                leeway_seconds: 30,
+                jwks_path: None,
+                jwks: None,
             })
             .build();
 
@@ -1,17 +1,36 @@
 // ABOUTME: Token expiration handling functionality for lifecycle management
 // ABOUTME: Provides expiration detection and refresh scheduling for token security
 
+use crate::constants::timeouts;
+use crate::security::config::ExpirationConfig;
 use crate::security::jwt::Token;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 /// Token Expiration Checker
+#[derive(Debug, Clone)]
 pub struct TokenExpirationChecker {
-    // For now, we'll keep this simple for the RED->GREEN phase
+    refresh_threshold: Duration,
 }
 
 impl TokenExpirationChecker {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            refresh_threshold: Duration::from_secs(timeouts::DEFAULT_JWT_REFRESH_THRESHOLD_SECS),
+        }
+    }
+
+    pub fn from_config(config: &ExpirationConfig) -> Self {
+        Self::new().with_refresh_threshold(Duration::from_secs(config.refresh_threshold_seconds))
+    }
+
+    /// Remaining lifetime below which a token counts as near expiration
+    pub fn with_refresh_threshold(mut self, threshold: Duration) -> Self {
+        self.refresh_threshold = threshold;
+        self
+    }
+
+    pub fn refresh_threshold(&self) -> Duration {
+        self.refresh_threshold
     }
 
     /// Check if a token is expired
@@ -19,14 +38,24 @@ impl TokenExpirationChecker {
         token.is_expired()
     }
 
-    /// Check if a token is near expiration (within 5 minutes)
+    /// Check if a token is near expiration (within the refresh threshold, 5 minutes by default)
     pub fn is_near_expiration(&self, token: &Token) -> bool {
-        let five_minutes = std::time::Duration::from_secs(300);
         match token.expires_at().duration_since(SystemTime::now()) {
-            Ok(remaining) => remaining <= five_minutes,
+            Ok(remaining) => remaining <= self.refresh_threshold,
             Err(_) => true, // Already expired
         }
     }
+
+    /// Remaining lifetime of a token, or `None` once it has expired
+    ///
+    /// Token storage uses this as the TTL so stored tokens are evicted when they expire.
+    pub fn time_to_live(&self, token: &Token) -> Option<Duration> {
+        token
+            .expires_at()
+            .duration_since(SystemTime::now())
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
 }
 
 impl Default for TokenExpirationChecker {
@@ -0,0 +1,343 @@
+// ABOUTME: JSON Web Key Set storage for JWT signature verification keys
+// ABOUTME: Loads keys from JWKS files or memory and resolves decoding keys by key id with rotation support
+
+use crate::constants::timeouts;
+use crate::security::jwt::TokenValidationError;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Where the keys of a `JwksKeyStore` come from
+#[derive(Debug, Clone)]
+enum JwksSource {
+    Memory,
+    File(PathBuf),
+}
+
+/// Key store holding the JSON Web Key Set used to verify token signatures.
+///
+/// Keys are looked up by the `kid` header of the token. File-backed stores are
+/// re-read when an unknown key id is seen, at most once per reload interval, so
+/// a rotated JWKS file is picked up without restarting the service.
+#[derive(Debug)]
+pub struct JwksKeyStore {
+    source: JwksSource,
+    keys: RwLock<JwkSet>,
+    reload_interval: Duration,
+    reloaded_at: Mutex<Option<Instant>>,
+}
+
+impl JwksKeyStore {
+    /// Create an empty in-memory key store
+    pub fn new() -> Self {
+        Self::from_jwk_set(JwkSet { keys: Vec::new() })
+    }
+
+    /// Create an in-memory key store from an existing key set
+    pub fn from_jwk_set(keys: JwkSet) -> Self {
+        Self {
+            source: JwksSource::Memory,
+            keys: RwLock::new(keys),
+            reload_interval: default_reload_interval(),
+            reloaded_at: Mutex::new(None),
+        }
+    }
+
+    /// Create an in-memory key store from a JWKS JSON document
+    pub fn from_json(json: &str) -> Result<Self, TokenValidationError> {
+        Ok(Self::from_jwk_set(parse_jwks(json)?))
+    }
+
+    /// Create a key store backed by a local JWKS file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TokenValidationError> {
+        let path = path.as_ref().to_path_buf();
+        let keys = read_jwks_file(&path)?;
+
+        Ok(Self {
+            source: JwksSource::File(path),
+            keys: RwLock::new(keys),
+            reload_interval: default_reload_interval(),
+            reloaded_at: Mutex::new(None),
+        })
+    }
+
+    /// Minimum time between file reloads triggered by tokens with an unknown key id
+    pub fn with_reload_interval(mut self, interval: Duration) -> Self {
+        self.reload_interval = interval;
+        self
+    }
+
+    /// Re-read the key set from its source. In-memory stores are left unchanged.
+    pub fn reload(&self) -> Result<(), TokenValidationError> {
+        if let JwksSource::File(path) = &self.source {
+            let keys = read_jwks_file(path)?;
+            *self.keys.write().unwrap() = keys;
+        }
+        Ok(())
+    }
+
+    /// Replace the whole key set, e.g. after fetching a rotated JWKS
+    pub fn rotate(&self, keys: JwkSet) {
+        *self.keys.write().unwrap() = keys;
+    }
+
+    /// Add a key, replacing any existing key with the same key id
+    pub fn insert(&self, jwk: Jwk) {
+        let mut keys = self.keys.write().unwrap();
+        if let Some(kid) = jwk.common.key_id.as_deref() {
+            keys.keys
+                .retain(|existing| existing.common.key_id.as_deref() != Some(kid));
+        }
+        keys.keys.push(jwk);
+    }
+
+    /// Remove the key with the given key id, returning whether a key was removed
+    pub fn remove(&self, kid: &str) -> bool {
+        let mut keys = self.keys.write().unwrap();
+        let before = keys.keys.len();
+        keys.keys
+            .retain(|existing| existing.common.key_id.as_deref() != Some(kid));
+        keys.keys.len() != before
+    }
+
+    /// Key ids of all keys currently in the store
+    pub fn key_ids(&self) -> Vec<String> {
+        self.keys
+            .read()
+            .unwrap()
+            .keys
+            .iter()
+            .filter_map(|jwk| jwk.common.key_id.clone())
+            .collect()
+    }
+
+    /// Number of keys currently in the store
+    pub fn len(&self) -> usize {
+        self.keys.read().unwrap().keys.len()
+    }
+
+    /// Whether the store holds no keys
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Resolve the decoding key for a token signed with `algorithm`.
+    ///
+    /// With a key id the matching key is used; without one the store must hold
+    /// exactly one key usable for the algorithm.
+    pub fn decoding_key(
+        &self,
+        kid: Option<&str>,
+        algorithm: Algorithm,
+    ) -> Result<DecodingKey, TokenValidationError> {
+        match self.find_key(kid, algorithm) {
+            // The key may have been rotated in since we last read the file, but reload at
+            // most once per interval so unknown key ids cannot turn into disk reads
+            Err(TokenValidationError::KeyNotFound(_))
+                if matches!(self.source, JwksSource::File(_)) && self.claim_reload() =>
+            {
+                self.reload()?;
+                self.find_key(kid, algorithm)
+            }
+            result => result,
+        }
+    }
+
+    /// Claim a reload unless the last one happened less than the reload interval ago
+    fn claim_reload(&self) -> bool {
+        let mut reloaded_at = self
+            .reloaded_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if reloaded_at.is_some_and(|at| at.elapsed() < self.reload_interval) {
+            return false;
+        }
+        *reloaded_at = Some(Instant::now());
+        true
+    }
+
+    fn find_key(
+        &self,
+        kid: Option<&str>,
+        algorithm: Algorithm,
+    ) -> Result<DecodingKey, TokenValidationError> {
+        let keys = self.keys.read().unwrap();
+
+        let jwk = match kid {
+            Some(kid) => keys
+                .find(kid)
+                .ok_or_else(|| TokenValidationError::KeyNotFound(kid.to_string()))?,
+            None => {
+                let mut candidates = keys
+                    .keys
+                    .iter()
+                    .filter(|jwk| key_supports_algorithm(jwk, algorithm));
+                match (candidates.next(), candidates.next()) {
+                    (Some(jwk), None) => jwk,
+                    (None, _) => {
+                        return Err(TokenValidationError::KeyNotFound(format!(
+                            "no {:?} key available",
+                            algorithm
+                        )))
+                    }
+                    (Some(_), Some(_)) => {
+                        return Err(TokenValidationError::KeyNotFound(
+                            "token has no key id and several keys match".to_string(),
+                        ))
+                    }
+                }
+            }
+        };
+
+        if !key_supports_algorithm(jwk, algorithm) {
+            return Err(TokenValidationError::UnsupportedAlgorithm(format!(
+                "{:?} is not allowed for key {}",
+                algorithm,
+                jwk.common.key_id.as_deref().unwrap_or("<unnamed>")
+            )));
+        }
+
+        DecodingKey::from_jwk(jwk)
+            .map_err(|e| TokenValidationError::ValidationFailed(format!("Invalid JWK: {}", e)))
+    }
+}
+
+impl Default for JwksKeyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn default_reload_interval() -> Duration {
+    Duration::from_secs(timeouts::DEFAULT_JWKS_REFETCH_INTERVAL_SECS)
+}
+
+/// Check that a key's declared algorithm and key type fit the token algorithm
+fn key_supports_algorithm(jwk: &Jwk, algorithm: Algorithm) -> bool {
+    if let Some(key_algorithm) = &jwk.common.key_algorithm {
+        if key_algorithm.to_string() != format!("{:?}", algorithm) {
+            return false;
+        }
+    }
+
+    DecodingKey::from_jwk(jwk)
+        .map(|key| key.family() == algorithm.family())
+        .unwrap_or(false)
+}
+
+fn parse_jwks(json: &str) -> Result<JwkSet, TokenValidationError> {
+    serde_json::from_str(json)
+        .map_err(|e| TokenValidationError::ValidationFailed(format!("Invalid JWKS: {}", e)))
+}
+
+fn read_jwks_file(path: &Path) -> Result<JwkSet, TokenValidationError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        TokenValidationError::ValidationFailed(format!(
+            "Failed to read JWKS file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    parse_jwks(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JWKS: &str = r#"{
+        "keys": [
+            {"kty": "oct", "kid": "hmac-1", "alg": "HS256", "k": "c2VjcmV0LWtleS1vbmU"},
+            {"kty": "EC", "kid": "ec-1", "crv": "P-256",
+             "x": "dhNTbg_YvYN7vms16WZ0IzF2_6-shwA6VljrsqVCgII",
+             "y": "x_5vhMEyeA0uf9SQYZDHAIXnl9gSfDXaV8Al3ofSxlg"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_decoding_key_lookup_by_kid() {
+        let store = JwksKeyStore::from_json(JWKS).unwrap();
+
+        assert_eq!(store.len(), 2);
+        assert!(store.decoding_key(Some("hmac-1"), Algorithm::HS256).is_ok());
+        assert!(store.decoding_key(Some("ec-1"), Algorithm::ES256).is_ok());
+        assert!(matches!(
+            store.decoding_key(Some("ec-1"), Algorithm::RS256),
+            Err(TokenValidationError::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            store.decoding_key(Some("missing"), Algorithm::HS256),
+            Err(TokenValidationError::KeyNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_decoding_key_without_kid_requires_single_candidate() {
+        let store = JwksKeyStore::from_json(JWKS).unwrap();
+
+        assert!(store.decoding_key(None, Algorithm::ES256).is_ok());
+        assert!(store.decoding_key(None, Algorithm::RS256).is_err());
+
+        store.insert(
+            serde_json::from_str(r#"{"kty": "oct", "kid": "hmac-2", "k": "c2VjcmV0LWtleS10d28"}"#)
+                .unwrap(),
+        );
+        assert!(store.decoding_key(None, Algorithm::HS256).is_err());
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let store = JwksKeyStore::from_json(JWKS).unwrap();
+
+        assert!(store.remove("hmac-1"));
+        assert!(!store.remove("hmac-1"));
+        assert_eq!(store.key_ids(), vec!["ec-1".to_string()]);
+
+        store.rotate(JwkSet { keys: Vec::new() });
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_file_backed_store_reloads_unknown_kid() {
+        let path =
+            std::env::temp_dir().join(format!("qollective-jwks-{}.json", uuid::Uuid::now_v7()));
+        std::fs::write(&path, r#"{"keys": []}"#).unwrap();
+
+        let store = JwksKeyStore::from_file(&path).unwrap();
+        assert!(store.is_empty());
+
+        std::fs::write(&path, JWKS).unwrap();
+        assert!(store.decoding_key(Some("hmac-1"), Algorithm::HS256).is_ok());
+        assert_eq!(store.len(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_kids_reload_file_once_per_interval() {
+        let path =
+            std::env::temp_dir().join(format!("qollective-jwks-{}.json", uuid::Uuid::now_v7()));
+        std::fs::write(&path, r#"{"keys": []}"#).unwrap();
+        let store = JwksKeyStore::from_file(&path).unwrap();
+
+        // First unknown kid reloads the (still empty) file
+        assert!(matches!(
+            store.decoding_key(Some("random-1"), Algorithm::HS256),
+            Err(TokenValidationError::KeyNotFound(_))
+        ));
+
+        // Further unknown kids within the interval do not touch the file again
+        std::fs::write(&path, JWKS).unwrap();
+        for kid in ["random-2", "random-3", "hmac-1"] {
+            assert!(matches!(
+                store.decoding_key(Some(kid), Algorithm::HS256),
+                Err(TokenValidationError::KeyNotFound(_))
+            ));
+        }
+        assert!(store.is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
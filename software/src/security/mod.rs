@@ -16,8 +16,10 @@
 pub mod audit;
 pub mod config;
 pub mod expiration;
+pub mod jwks;
 pub mod jwt;
 pub mod oauth;
+pub mod refresh;
 pub mod scopes;
 pub mod storage;
 pub mod transmission;
@@ -32,13 +34,27 @@ pub use config::{
     SecurityConfigBuilder, StorageConfig, TransmissionConfig,
 };
 pub use expiration::TokenExpirationChecker;
+pub use jwks::JwksKeyStore;
 pub use jwt::{
-    DefaultJwtValidator, JwtTokenRefresher, JwtValidator, SimpleJwtValidator, Token,
-    TokenValidationError, ValidatedToken,
+    DefaultJwtValidator, JwtValidator, SimpleJwtValidator, Token, TokenValidationError,
+    ValidatedToken,
+};
+pub use oauth::{
+    BearerIdentity, BearerTokenValidator, OAuth2Config, OAuth2TokenInfo, OAuth2Validator,
+    OidcClaims, OidcConfig, OidcValidator,
+};
+pub use refresh::{
+    CredentialProvider, JwtTokenRefresher, OAuth2CredentialProvider, OAuth2Grant,
+    StaticCredentialProvider, TokenGrant,
 };
-pub use oauth::{OAuth2Config, OAuth2Validator, OidcConfig, OidcValidator};
 pub use scopes::{
     DefaultTokenScopeValidator, RoleBasedScopeValidator, ScopeValidationError, TokenScopeValidator,
 };
-pub use storage::{InMemoryTokenStorage, RedisTokenStorage, SecureTokenStorage, StorageError};
+#[cfg(feature = "nats-client")]
+pub use storage::NatsKvTokenStorage;
+pub use storage::{
+    spawn_eviction_task, token_storage_from_config, AsyncSecureTokenStorage,
+    EncryptedFileTokenStorage, InMemoryTokenStorage, RedisTokenStorage, SecureTokenStorage,
+    StorageError, TokenCipher,
+};
 pub use transmission::SecureTokenTransmitter;
@@ -1,19 +1,53 @@
 // ABOUTME: OAuth 2.0 and OIDC integration support for token propagation security
-// ABOUTME: Provides OAuth2 and OIDC token validation and configuration management
+// ABOUTME: Provides RFC 7662 token introspection and discovery-driven OIDC id_token validation
 
-use crate::security::jwt::TokenValidationError;
+use crate::constants::timeouts;
+use crate::envelope::meta::AuthMethod;
+use crate::security::jwks::JwksKeyStore;
+use crate::security::jwt::{DefaultJwtValidator, TokenValidationError};
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::Algorithm;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 
 /// OAuth2 Configuration
+#[derive(Debug, Clone)]
 pub struct OAuth2Config {
     client_id: String,
     client_secret: String,
     token_endpoint: String,
+    introspection_endpoint: String,
+    timeout: Duration,
 }
 
 impl OAuth2Config {
     pub fn new() -> OAuth2ConfigBuilder {
         OAuth2ConfigBuilder::default()
     }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn client_secret(&self) -> &str {
+        &self.client_secret
+    }
+
+    pub fn token_endpoint(&self) -> &str {
+        &self.token_endpoint
+    }
+
+    pub fn introspection_endpoint(&self) -> &str {
+        &self.introspection_endpoint
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
 }
 
 /// OAuth2 Configuration Builder
@@ -22,6 +56,8 @@ pub struct OAuth2ConfigBuilder {
     client_id: Option<String>,
     client_secret: Option<String>,
     token_endpoint: Option<String>,
+    introspection_endpoint: Option<String>,
+    timeout: Option<Duration>,
 }
 
 impl OAuth2ConfigBuilder {
@@ -40,20 +76,39 @@ impl OAuth2ConfigBuilder {
         self
     }
 
+    /// RFC 7662 introspection endpoint used to validate access tokens
+    pub fn introspection_endpoint(mut self, introspection_endpoint: &str) -> Self {
+        self.introspection_endpoint = Some(introspection_endpoint.to_string());
+        self
+    }
+
+    /// Timeout for requests to the authorization server
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     pub fn build(self) -> OAuth2Config {
         OAuth2Config {
             client_id: self.client_id.unwrap_or_default(),
             client_secret: self.client_secret.unwrap_or_default(),
             token_endpoint: self.token_endpoint.unwrap_or_default(),
+            introspection_endpoint: self.introspection_endpoint.unwrap_or_default(),
+            timeout: self.timeout.unwrap_or(Duration::from_millis(
+                timeouts::DEFAULT_OAUTH_REQUEST_TIMEOUT_MS,
+            )),
         }
     }
 }
 
 /// OAuth2 Token Info
+#[derive(Debug, Clone)]
 pub struct OAuth2TokenInfo {
     scopes: Vec<String>,
     client_id: String,
     expires_in: u64,
+    subject: Option<String>,
+    username: Option<String>,
 }
 
 impl OAuth2TokenInfo {
@@ -62,54 +117,164 @@ impl OAuth2TokenInfo {
             scopes,
             client_id,
             expires_in,
+            subject: None,
+            username: None,
         }
     }
 
     pub fn scopes(&self) -> &[String] {
         &self.scopes
     }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Seconds until the token expires, as reported by the authorization server
+    pub fn expires_in(&self) -> u64 {
+        self.expires_in
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+}
+
+/// RFC 7662 introspection response
+#[derive(Debug, Deserialize)]
+struct IntrospectionResponse {
+    active: bool,
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    username: Option<String>,
+    #[serde(default)]
+    sub: Option<String>,
+    #[serde(default)]
+    exp: Option<u64>,
 }
 
 /// OAuth2 Validator
 pub struct OAuth2Validator {
     config: OAuth2Config,
+    http: reqwest::Client,
 }
 
 impl OAuth2Validator {
-    pub fn new(config: OAuth2Config) -> Self {
-        Self { config }
+    /// Create a validator, failing if its HTTP client cannot be built
+    pub fn new(config: OAuth2Config) -> Result<Self, TokenValidationError> {
+        let http = http_client(config.timeout).map_err(|e| {
+            TokenValidationError::ValidationFailed(format!("Failed to build HTTP client: {}", e))
+        })?;
+        Ok(Self { config, http })
     }
 
-    /// Validate an OAuth2 access token
-    pub fn validate_access_token(
+    /// Introspect an OAuth2 access token (RFC 7662) and return its scopes, subject and expiry
+    pub async fn validate_access_token(
         &self,
         token: &str,
     ) -> Result<OAuth2TokenInfo, TokenValidationError> {
-        // For the initial GREEN phase, simple implementation
-        if token == "valid-oauth2-access-token" {
-            Ok(OAuth2TokenInfo::new(
-                vec!["read".to_string(), "write".to_string()],
-                self.config.client_id.clone(),
-                3600,
-            ))
-        } else {
-            Err(TokenValidationError::ValidationFailed(
-                "Invalid OAuth2 token".to_string(),
-            ))
+        if self.config.introspection_endpoint.is_empty() {
+            return Err(TokenValidationError::ValidationFailed(
+                "No OAuth2 introspection endpoint configured".to_string(),
+            ));
+        }
+
+        let response = self
+            .http
+            .post(&self.config.introspection_endpoint)
+            .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
+            .form(&[("token", token), ("token_type_hint", "access_token")])
+            .send()
+            .await
+            .map_err(|e| {
+                TokenValidationError::ValidationFailed(format!(
+                    "Introspection request failed: {}",
+                    e
+                ))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(TokenValidationError::ValidationFailed(format!(
+                "Introspection endpoint returned {}",
+                response.status()
+            )));
         }
+
+        let introspection: IntrospectionResponse = response.json().await.map_err(|e| {
+            TokenValidationError::ValidationFailed(format!("Invalid introspection response: {}", e))
+        })?;
+
+        if !introspection.active {
+            return Err(TokenValidationError::ValidationFailed(
+                "OAuth2 token is not active".to_string(),
+            ));
+        }
+
+        let now = jsonwebtoken::get_current_timestamp();
+        let expires_in = match introspection.exp {
+            Some(exp) if exp <= now => return Err(TokenValidationError::TokenExpired),
+            Some(exp) => exp - now,
+            None => 0,
+        };
+
+        let scopes = introspection
+            .scope
+            .map(|scope| scope.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let mut info = OAuth2TokenInfo::new(
+            scopes,
+            introspection
+                .client_id
+                .unwrap_or_else(|| self.config.client_id.clone()),
+            expires_in,
+        );
+        info.subject = introspection.sub;
+        info.username = introspection.username;
+        Ok(info)
     }
 }
 
 /// OIDC Configuration
+#[derive(Debug, Clone)]
 pub struct OidcConfig {
     issuer: String,
     client_id: String,
+    discovery_url: Option<String>,
+    leeway_seconds: u64,
+    timeout: Duration,
+    jwks_refetch_interval: Duration,
 }
 
 impl OidcConfig {
     pub fn new() -> OidcConfigBuilder {
         OidcConfigBuilder::default()
     }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Discovery document URL, derived from the issuer unless set explicitly
+    pub fn discovery_url(&self) -> String {
+        self.discovery_url.clone().unwrap_or_else(|| {
+            format!(
+                "{}/.well-known/openid-configuration",
+                self.issuer.trim_end_matches('/')
+            )
+        })
+    }
 }
 
 /// OIDC Configuration Builder
@@ -117,6 +282,10 @@ impl OidcConfig {
 pub struct OidcConfigBuilder {
     issuer: Option<String>,
     client_id: Option<String>,
+    discovery_url: Option<String>,
+    leeway_seconds: Option<u64>,
+    timeout: Option<Duration>,
+    jwks_refetch_interval: Option<Duration>,
 }
 
 impl OidcConfigBuilder {
@@ -130,19 +299,56 @@ impl OidcConfigBuilder {
         self
     }
 
+    /// Override the discovery document URL
+    pub fn discovery_url(mut self, discovery_url: &str) -> Self {
+        self.discovery_url = Some(discovery_url.to_string());
+        self
+    }
+
+    /// Clock skew tolerated when checking `exp` and `nbf`
+    pub fn leeway_seconds(mut self, leeway_seconds: u64) -> Self {
+        self.leeway_seconds = Some(leeway_seconds);
+        self
+    }
+
+    /// Timeout for requests to the identity provider
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Minimum time between JWKS refetches for tokens signed with an unknown key
+    pub fn jwks_refetch_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refetch_interval = Some(interval);
+        self
+    }
+
     pub fn build(self) -> OidcConfig {
         OidcConfig {
             issuer: self.issuer.unwrap_or_default(),
             client_id: self.client_id.unwrap_or_default(),
+            discovery_url: self.discovery_url,
+            leeway_seconds: self
+                .leeway_seconds
+                .unwrap_or(timeouts::DEFAULT_JWT_LEEWAY_SECS),
+            timeout: self.timeout.unwrap_or(Duration::from_millis(
+                timeouts::DEFAULT_OAUTH_REQUEST_TIMEOUT_MS,
+            )),
+            jwks_refetch_interval: self.jwks_refetch_interval.unwrap_or(Duration::from_secs(
+                timeouts::DEFAULT_JWKS_REFETCH_INTERVAL_SECS,
+            )),
         }
     }
 }
 
 /// OIDC Claims
+#[derive(Debug, Clone)]
 pub struct OidcClaims {
     subject: String,
     issuer: String,
     audience: String,
+    nonce: Option<String>,
+    claims: serde_json::Value,
 }
 
 impl OidcClaims {
@@ -151,37 +357,270 @@ impl OidcClaims {
             subject,
             issuer,
             audience,
+            nonce: None,
+            claims: serde_json::Value::Null,
         }
     }
 
     pub fn subject(&self) -> &str {
         &self.subject
     }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn audience(&self) -> &str {
+        &self.audience
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    /// Look up any other claim of the id_token, e.g. `email`
+    pub fn claim(&self, name: &str) -> Option<&serde_json::Value> {
+        self.claims.get(name)
+    }
+}
+
+/// Subset of the OpenID Provider metadata needed for id_token validation
+#[derive(Debug, Clone, Deserialize)]
+struct OidcDiscoveryDocument {
+    issuer: String,
+    jwks_uri: String,
+    #[serde(default)]
+    id_token_signing_alg_values_supported: Vec<String>,
+}
+
+/// Provider metadata and keys resolved from the discovery document
+struct OidcProvider {
+    document: OidcDiscoveryDocument,
+    keys: Arc<JwksKeyStore>,
+    keys_fetched_at: Mutex<Instant>,
+}
+
+impl OidcProvider {
+    /// Claim a JWKS refetch unless the keys were fetched less than `interval` ago
+    ///
+    /// Tokens naming random key ids must not turn into one identity provider
+    /// request each.
+    fn claim_refetch(&self, interval: Duration) -> bool {
+        let mut fetched_at = self
+            .keys_fetched_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if fetched_at.elapsed() < interval {
+            return false;
+        }
+        *fetched_at = Instant::now();
+        true
+    }
 }
 
 /// OIDC Validator
 pub struct OidcValidator {
     config: OidcConfig,
+    http: reqwest::Client,
+    provider: RwLock<Option<Arc<OidcProvider>>>,
 }
 
 impl OidcValidator {
-    pub fn new(config: OidcConfig) -> Self {
-        Self { config }
-    }
-
-    /// Validate an OIDC ID token
-    pub fn validate_id_token(&self, token: &str) -> Result<OidcClaims, TokenValidationError> {
-        // For the initial GREEN phase, simple implementation
-        if token == "valid-oidc-id-token" {
-            Ok(OidcClaims::new(
-                "user123".to_string(),
-                self.config.issuer.clone(),
-                self.config.client_id.clone(),
-            ))
-        } else {
-            Err(TokenValidationError::ValidationFailed(
-                "Invalid OIDC token".to_string(),
-            ))
+    /// Create a validator, failing if its HTTP client cannot be built
+    pub fn new(config: OidcConfig) -> Result<Self, TokenValidationError> {
+        let http = http_client(config.timeout).map_err(|e| {
+            TokenValidationError::ValidationFailed(format!("Failed to build HTTP client: {}", e))
+        })?;
+        Ok(Self {
+            config,
+            http,
+            provider: RwLock::new(None),
+        })
+    }
+
+    /// Verify an OIDC ID token's signature, issuer, audience and expiry and return its claims
+    pub async fn validate_id_token(&self, token: &str) -> Result<OidcClaims, TokenValidationError> {
+        self.validate_id_token_with_nonce(token, None).await
+    }
+
+    /// Validate an OIDC ID token and, when given, that it carries `expected_nonce`
+    pub async fn validate_id_token_with_nonce(
+        &self,
+        token: &str,
+        expected_nonce: Option<&str>,
+    ) -> Result<OidcClaims, TokenValidationError> {
+        let provider = self.provider().await?;
+
+        let claims = match self.verify(&provider, token) {
+            // The provider may have rotated its signing keys, but refetch at most once per
+            // interval so unknown key ids cannot amplify load on the provider
+            Err(TokenValidationError::KeyNotFound(kid))
+                if !provider.claim_refetch(self.config.jwks_refetch_interval) =>
+            {
+                return Err(TokenValidationError::KeyNotFound(kid));
+            }
+            Err(TokenValidationError::KeyNotFound(_)) => {
+                let keys = self.fetch_jwks(&provider.document.jwks_uri).await?;
+                provider.keys.rotate(keys);
+                self.verify(&provider, token)?
+            }
+            result => result?,
+        };
+
+        let nonce = claims
+            .get("nonce")
+            .and_then(|nonce| nonce.as_str())
+            .map(str::to_string);
+        if let Some(expected) = expected_nonce {
+            if nonce.as_deref() != Some(expected) {
+                return Err(TokenValidationError::ValidationFailed(
+                    "id_token nonce does not match".to_string(),
+                ));
+            }
         }
+
+        let subject = claims
+            .get("sub")
+            .and_then(|sub| sub.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let mut oidc_claims = OidcClaims::new(
+            subject,
+            provider.document.issuer.clone(),
+            self.config.client_id.clone(),
+        );
+        oidc_claims.nonce = nonce;
+        oidc_claims.claims = claims;
+        Ok(oidc_claims)
+    }
+
+    /// Drop the cached discovery document and keys so they are fetched again
+    pub async fn refresh(&self) {
+        *self.provider.write().await = None;
     }
+
+    fn verify(
+        &self,
+        provider: &OidcProvider,
+        token: &str,
+    ) -> Result<serde_json::Value, TokenValidationError> {
+        let algorithms = provider
+            .document
+            .id_token_signing_alg_values_supported
+            .iter()
+            .filter_map(|name| Algorithm::from_str(name).ok())
+            .collect::<Vec<_>>();
+
+        DefaultJwtValidator::with_key_store(Arc::clone(&provider.keys))
+            .with_algorithms(if algorithms.is_empty() {
+                // RS256 is the only algorithm every OpenID Provider must support
+                vec![Algorithm::RS256]
+            } else {
+                algorithms
+            })
+            .with_issuer(provider.document.issuer.clone())
+            .with_audience(self.config.client_id.clone())
+            .with_leeway(self.config.leeway_seconds)
+            .verify_claims(token)
+    }
+
+    async fn provider(&self) -> Result<Arc<OidcProvider>, TokenValidationError> {
+        if let Some(provider) = self.provider.read().await.as_ref() {
+            return Ok(Arc::clone(provider));
+        }
+
+        let mut cached = self.provider.write().await;
+        if let Some(provider) = cached.as_ref() {
+            return Ok(Arc::clone(provider));
+        }
+
+        let document: OidcDiscoveryDocument = self.get_json(&self.config.discovery_url()).await?;
+        if document.issuer.trim_end_matches('/') != self.config.issuer.trim_end_matches('/') {
+            return Err(TokenValidationError::InvalidIssuer);
+        }
+
+        let keys = self.fetch_jwks(&document.jwks_uri).await?;
+        let provider = Arc::new(OidcProvider {
+            document,
+            keys: Arc::new(JwksKeyStore::from_jwk_set(keys)),
+            keys_fetched_at: Mutex::new(Instant::now()),
+        });
+        *cached = Some(Arc::clone(&provider));
+        Ok(provider)
+    }
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, TokenValidationError> {
+        self.get_json(jwks_uri).await
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<T, TokenValidationError> {
+        let response = self.http.get(url).send().await.map_err(|e| {
+            TokenValidationError::ValidationFailed(format!("Request to {} failed: {}", url, e))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(TokenValidationError::ValidationFailed(format!(
+                "{} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            TokenValidationError::ValidationFailed(format!("Invalid response from {}: {}", url, e))
+        })
+    }
+}
+
+/// Identity established by a validated bearer token
+#[derive(Debug, Clone, PartialEq)]
+pub struct BearerIdentity {
+    /// Subject the token was issued to, if it names one
+    pub subject: Option<String>,
+    /// Scopes granted to the token
+    pub scopes: Vec<String>,
+    /// How the token was validated
+    pub auth_method: AuthMethod,
+}
+
+/// Validates the bearer tokens servers receive in `Authorization` headers
+///
+/// Servers accept one through `with_token_validator`.
+#[async_trait]
+pub trait BearerTokenValidator: Send + Sync {
+    /// Validate `token` and return the identity it establishes
+    async fn validate_bearer(&self, token: &str) -> Result<BearerIdentity, TokenValidationError>;
+}
+
+#[async_trait]
+impl BearerTokenValidator for OAuth2Validator {
+    async fn validate_bearer(&self, token: &str) -> Result<BearerIdentity, TokenValidationError> {
+        let info = self.validate_access_token(token).await?;
+        Ok(BearerIdentity {
+            subject: info.subject().or(info.username()).map(str::to_string),
+            scopes: info.scopes().to_vec(),
+            auth_method: AuthMethod::OAuth2,
+        })
+    }
+}
+
+#[async_trait]
+impl BearerTokenValidator for OidcValidator {
+    async fn validate_bearer(&self, token: &str) -> Result<BearerIdentity, TokenValidationError> {
+        let claims = self.validate_id_token(token).await?;
+        Ok(BearerIdentity {
+            subject: Some(claims.subject().to_string()).filter(|subject| !subject.is_empty()),
+            scopes: Vec::new(),
+            auth_method: AuthMethod::Oidc,
+        })
+    }
+}
+
+/// HTTP client whose requests give up after `timeout`
+pub(crate) fn http_client(timeout: Duration) -> reqwest::Result<reqwest::Client> {
+    reqwest::Client::builder().timeout(timeout).build()
 }
@@ -0,0 +1,343 @@
+// ABOUTME: OAuth2 token refresh and client-side credential providers for outgoing requests
+// ABOUTME: Performs refresh_token and client_credentials grants and caches access tokens until near expiry
+
+use crate::constants::timeouts;
+use crate::security::expiration::TokenExpirationChecker;
+use crate::security::jwt::{Token, TokenValidationError};
+use crate::security::oauth::{http_client, OAuth2Config};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Mutex;
+
+/// Result of a successful OAuth2 token grant
+#[derive(Debug, Clone)]
+pub struct TokenGrant {
+    /// The issued access token
+    pub token: Token,
+    /// Refresh token returned by the server, if any (may be rotated on every refresh)
+    pub refresh_token: Option<String>,
+}
+
+/// RFC 6749 token endpoint response
+#[derive(Debug, Deserialize)]
+struct TokenEndpointResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+/// RFC 6749 token endpoint error response
+#[derive(Debug, Deserialize)]
+struct TokenEndpointError {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// JWT Token Refresher
+///
+/// Obtains access tokens from an OAuth2 token endpoint using the
+/// `refresh_token` and `client_credentials` grants.
+pub struct JwtTokenRefresher {
+    config: OAuth2Config,
+    http: reqwest::Client,
+}
+
+impl JwtTokenRefresher {
+    /// Create a refresher, failing if its HTTP client cannot be built
+    pub fn new(config: OAuth2Config) -> Result<Self, TokenValidationError> {
+        let http = http_client(config.timeout()).map_err(|e| {
+            TokenValidationError::RefreshFailed(format!("Failed to build HTTP client: {}", e))
+        })?;
+        Ok(Self { config, http })
+    }
+
+    pub fn config(&self) -> &OAuth2Config {
+        &self.config
+    }
+
+    /// Exchange a refresh token for a new access token
+    pub async fn refresh(&self, refresh_token: &str) -> Result<TokenGrant, TokenValidationError> {
+        self.request_grant(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    /// Obtain an access token for the client itself
+    pub async fn client_credentials(
+        &self,
+        scopes: &[String],
+    ) -> Result<TokenGrant, TokenValidationError> {
+        let scope = scopes.join(" ");
+        let mut form = vec![("grant_type", "client_credentials")];
+        if !scope.is_empty() {
+            form.push(("scope", scope.as_str()));
+        }
+        self.request_grant(&form).await
+    }
+
+    async fn request_grant(
+        &self,
+        form: &[(&str, &str)],
+    ) -> Result<TokenGrant, TokenValidationError> {
+        if self.config.token_endpoint().is_empty() {
+            return Err(TokenValidationError::RefreshFailed(
+                "No OAuth2 token endpoint configured".to_string(),
+            ));
+        }
+
+        let response = self
+            .http
+            .post(self.config.token_endpoint())
+            .basic_auth(self.config.client_id(), Some(self.config.client_secret()))
+            .form(form)
+            .send()
+            .await
+            .map_err(|e| {
+                TokenValidationError::RefreshFailed(format!("Token request failed: {}", e))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match response.json::<TokenEndpointError>().await {
+                Ok(error) => match error.error_description {
+                    Some(description) => format!("{}: {}", error.error, description),
+                    None => error.error,
+                },
+                Err(_) => status.to_string(),
+            };
+            return Err(TokenValidationError::RefreshFailed(format!(
+                "Token endpoint rejected grant ({})",
+                reason
+            )));
+        }
+
+        let body: TokenEndpointResponse = response.json().await.map_err(|e| {
+            TokenValidationError::RefreshFailed(format!("Invalid token response: {}", e))
+        })?;
+
+        let expires_in = body
+            .expires_in
+            .unwrap_or(timeouts::DEFAULT_SECURITY_TTL_SECS);
+        let scopes = body
+            .scope
+            .map(|scope| scope.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        Ok(TokenGrant {
+            token: Token::new(
+                body.access_token,
+                self.config.client_id().to_string(),
+                SystemTime::now() + Duration::from_secs(expires_in),
+                scopes,
+            ),
+            refresh_token: body.refresh_token,
+        })
+    }
+}
+
+/// Source of access tokens attached to outgoing client requests
+#[async_trait]
+pub trait CredentialProvider: Send + Sync + std::fmt::Debug {
+    /// Return a currently valid access token, refreshing it if needed
+    async fn access_token(&self) -> Result<String, TokenValidationError>;
+
+    /// Discard the cached access token, e.g. after the server rejected it
+    async fn invalidate(&self);
+}
+
+/// Grant used by `OAuth2CredentialProvider` to obtain tokens
+#[derive(Debug, Clone)]
+pub enum OAuth2Grant {
+    /// Authenticate as the client itself, requesting the given scopes
+    ClientCredentials { scopes: Vec<String> },
+    /// Act for a user through a previously issued refresh token
+    RefreshToken { refresh_token: String },
+}
+
+#[derive(Debug, Default)]
+struct CachedCredentials {
+    token: Option<Token>,
+    refresh_token: Option<String>,
+}
+
+/// Credential provider backed by an OAuth2 token endpoint
+///
+/// Access tokens are cached and refreshed proactively once
+/// `TokenExpirationChecker::is_near_expiration` reports them as close to expiry.
+/// Rotated refresh tokens are kept for the next refresh.
+pub struct OAuth2CredentialProvider {
+    refresher: JwtTokenRefresher,
+    grant: OAuth2Grant,
+    expiration: TokenExpirationChecker,
+    cache: Mutex<CachedCredentials>,
+}
+
+impl OAuth2CredentialProvider {
+    pub fn new(config: OAuth2Config, grant: OAuth2Grant) -> Result<Self, TokenValidationError> {
+        let refresh_token = match &grant {
+            OAuth2Grant::RefreshToken { refresh_token } => Some(refresh_token.clone()),
+            OAuth2Grant::ClientCredentials { .. } => None,
+        };
+
+        Ok(Self {
+            refresher: JwtTokenRefresher::new(config)?,
+            grant,
+            expiration: TokenExpirationChecker::new(),
+            cache: Mutex::new(CachedCredentials {
+                token: None,
+                refresh_token,
+            }),
+        })
+    }
+
+    /// Use a client_credentials grant for the given scopes
+    pub fn client_credentials(
+        config: OAuth2Config,
+        scopes: Vec<String>,
+    ) -> Result<Self, TokenValidationError> {
+        Self::new(config, OAuth2Grant::ClientCredentials { scopes })
+    }
+
+    /// Use a refresh_token grant starting from the given refresh token
+    pub fn refresh_token(
+        config: OAuth2Config,
+        refresh_token: impl Into<String>,
+    ) -> Result<Self, TokenValidationError> {
+        Self::new(
+            config,
+            OAuth2Grant::RefreshToken {
+                refresh_token: refresh_token.into(),
+            },
+        )
+    }
+
+    /// Use a custom expiration checker, e.g. with a different refresh threshold
+    pub fn with_expiration_checker(mut self, expiration: TokenExpirationChecker) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    async fn obtain(&self, cache: &CachedCredentials) -> Result<TokenGrant, TokenValidationError> {
+        if let Some(refresh_token) = &cache.refresh_token {
+            match self.refresher.refresh(refresh_token).await {
+                Ok(grant) => return Ok(grant),
+                // A client can always fall back to authenticating as itself
+                Err(e) if matches!(self.grant, OAuth2Grant::ClientCredentials { .. }) => {
+                    tracing::debug!("Refresh token rejected, requesting new client token: {}", e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        match &self.grant {
+            OAuth2Grant::ClientCredentials { scopes } => {
+                self.refresher.client_credentials(scopes).await
+            }
+            OAuth2Grant::RefreshToken { .. } => Err(TokenValidationError::RefreshFailed(
+                "No refresh token available".to_string(),
+            )),
+        }
+    }
+}
+
+impl std::fmt::Debug for OAuth2CredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2CredentialProvider")
+            .field("token_endpoint", &self.refresher.config().token_endpoint())
+            .field("client_id", &self.refresher.config().client_id())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for OAuth2CredentialProvider {
+    async fn access_token(&self) -> Result<String, TokenValidationError> {
+        let mut cache = self.cache.lock().await;
+
+        if let Some(token) = &cache.token {
+            if !self.expiration.is_near_expiration(token) {
+                return Ok(token.raw.clone());
+            }
+        }
+
+        let grant = self.obtain(&cache).await?;
+        let access_token = grant.token.raw.clone();
+        if grant.refresh_token.is_some() {
+            cache.refresh_token = grant.refresh_token;
+        }
+        cache.token = Some(grant.token);
+        Ok(access_token)
+    }
+
+    async fn invalidate(&self) {
+        self.cache.lock().await.token = None;
+    }
+}
+
+/// Credential provider that always supplies the same token
+#[derive(Clone)]
+pub struct StaticCredentialProvider {
+    token: String,
+}
+
+impl StaticCredentialProvider {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for StaticCredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticCredentialProvider")
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for StaticCredentialProvider {
+    async fn access_token(&self) -> Result<String, TokenValidationError> {
+        Ok(self.token.clone())
+    }
+
+    async fn invalidate(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_static_credential_provider() {
+        let provider = StaticCredentialProvider::new("service-token");
+
+        assert_eq!(provider.access_token().await.unwrap(), "service-token");
+        provider.invalidate().await;
+        assert_eq!(provider.access_token().await.unwrap(), "service-token");
+        assert!(!format!("{:?}", provider).contains("service-token"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_without_token_endpoint_fails() {
+        let refresher =
+            JwtTokenRefresher::new(OAuth2Config::new().client_id("probe").build()).unwrap();
+
+        assert!(matches!(
+            refresher.refresh("refresh-token").await,
+            Err(TokenValidationError::RefreshFailed(_))
+        ));
+        assert!(matches!(
+            refresher.client_credentials(&[]).await,
+            Err(TokenValidationError::RefreshFailed(_))
+        ));
+    }
+}
@@ -1,7 +1,20 @@
-// ABOUTME: Secure token storage trait and implementations for encrypted token persistence
-// ABOUTME: Provides pluggable storage backends (in-memory, Redis, database, etc.)
+// ABOUTME: Secure token storage traits and implementations for encrypted token persistence
+// ABOUTME: Provides pluggable storage backends (in-memory, encrypted file, NATS KV) with TTL eviction
 
+use crate::security::config::StorageConfig;
+use crate::security::expiration::TokenExpirationChecker;
+use crate::security::jwt::Token;
+use ::base64::engine::general_purpose::STANDARD;
+use ::base64::Engine as _;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
 
 /// Storage operation errors
 #[derive(Debug, thiserror::Error)]
@@ -34,6 +47,546 @@ pub trait SecureTokenStorage: Send + Sync {
     fn clear_all(&mut self) -> Result<(), StorageError>;
 }
 
+/// Async trait for secure token storage backends shared across tasks or replicas
+///
+/// Tokens are stored with an optional time-to-live. Expired tokens are never
+/// returned and are removed by `evict_expired`.
+#[async_trait]
+pub trait AsyncSecureTokenStorage: Send + Sync {
+    /// Store a token for a user, expiring after `ttl` (or the backend default when `None`)
+    async fn store_token(
+        &self,
+        user_id: &str,
+        token: &str,
+        ttl: Option<Duration>,
+    ) -> Result<(), StorageError>;
+
+    /// Retrieve a non-expired token for a user
+    async fn get_token(&self, user_id: &str) -> Result<Option<String>, StorageError>;
+
+    /// Remove a token for a user (logout, revocation)
+    async fn remove_token(&self, user_id: &str) -> Result<bool, StorageError>;
+
+    /// Check if a non-expired token exists for a user
+    async fn has_token(&self, user_id: &str) -> Result<bool, StorageError> {
+        Ok(self.get_token(user_id).await?.is_some())
+    }
+
+    /// Clear all tokens (admin operation)
+    async fn clear_all(&self) -> Result<(), StorageError>;
+
+    /// Remove all expired tokens, returning how many were evicted
+    async fn evict_expired(&self) -> Result<usize, StorageError>;
+
+    /// Store a validated token so that it is evicted when the token itself expires
+    async fn store_expiring_token(&self, user_id: &str, token: &Token) -> Result<(), StorageError> {
+        match TokenExpirationChecker::new().time_to_live(token) {
+            Some(ttl) => self.store_token(user_id, &token.raw, Some(ttl)).await,
+            None => Err(StorageError::OperationFailed(format!(
+                "Token for {} has already expired",
+                user_id
+            ))),
+        }
+    }
+}
+
+/// AES-256-GCM cipher used to encrypt tokens at rest
+///
+/// Ciphertexts are base64 encoded as `nonce || ciphertext` with a fresh random
+/// nonce per encryption. The associated data passed to `encrypt` must be passed
+/// again to `decrypt`, which binds a ciphertext to the record it was written for.
+#[derive(Clone)]
+pub struct TokenCipher {
+    cipher: Aes256Gcm,
+}
+
+impl TokenCipher {
+    /// Length of the AES-256 key in bytes
+    pub const KEY_LEN: usize = 32;
+    const NONCE_LEN: usize = 12;
+
+    /// Create a cipher from raw key bytes
+    pub fn new(key: &[u8]) -> Result<Self, StorageError> {
+        if key.len() != Self::KEY_LEN {
+            return Err(StorageError::CryptographyFailed(format!(
+                "Encryption key must be {} bytes, got {}",
+                Self::KEY_LEN,
+                key.len()
+            )));
+        }
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        })
+    }
+
+    /// Create a cipher from a base64 encoded key
+    pub fn from_base64_key(key: &str) -> Result<Self, StorageError> {
+        let key = STANDARD.decode(key.trim()).map_err(|e| {
+            StorageError::CryptographyFailed(format!("Encryption key is not valid base64: {}", e))
+        })?;
+        Self::new(&key)
+    }
+
+    /// Create a cipher from `StorageConfig::encryption_key`
+    /// (populated from `QOLLECTIVE_STORAGE_ENCRYPTION_KEY` by the config builder)
+    pub fn from_config(config: &StorageConfig) -> Result<Self, StorageError> {
+        let key = config.encryption_key.as_deref().ok_or_else(|| {
+            StorageError::CryptographyFailed("No storage encryption key configured".to_string())
+        })?;
+        Self::from_base64_key(key)
+    }
+
+    /// Generate a random base64 encoded key suitable for `from_base64_key`
+    pub fn generate_key() -> String {
+        STANDARD.encode(Aes256Gcm::generate_key(OsRng))
+    }
+
+    /// Encrypt a token authenticated with `aad`, returning base64 encoded `nonce || ciphertext`
+    pub fn encrypt(&self, plaintext: &str, aad: &str) -> Result<String, StorageError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: plaintext.as_bytes(),
+            aad: aad.as_bytes(),
+        };
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, payload)
+            .map_err(|_| StorageError::CryptographyFailed("Encryption failed".to_string()))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(STANDARD.encode(sealed))
+    }
+
+    /// Decrypt a value produced by `encrypt` with the same `aad`
+    pub fn decrypt(&self, sealed: &str, aad: &str) -> Result<String, StorageError> {
+        let sealed = STANDARD
+            .decode(sealed)
+            .map_err(|_| StorageError::CryptographyFailed("Decryption failed".to_string()))?;
+        if sealed.len() < Self::NONCE_LEN {
+            return Err(StorageError::CryptographyFailed(
+                "Ciphertext too short".to_string(),
+            ));
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(Self::NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: aad.as_bytes(),
+        };
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| StorageError::CryptographyFailed("Decryption failed".to_string()))?;
+        String::from_utf8(plaintext).map_err(|e| StorageError::CryptographyFailed(e.to_string()))
+    }
+}
+
+impl std::fmt::Debug for TokenCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenCipher").finish_non_exhaustive()
+    }
+}
+
+/// Encrypted token together with its expiry, as persisted by storage backends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredToken {
+    ciphertext: String,
+    /// Expiry as seconds since the UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<u64>,
+}
+
+impl StoredToken {
+    /// Encrypt `token` with `user_id` as associated data, so the record cannot be
+    /// moved to another user's entry
+    fn seal(
+        cipher: &TokenCipher,
+        user_id: &str,
+        token: &str,
+        ttl: Option<Duration>,
+    ) -> Result<Self, StorageError> {
+        Ok(Self {
+            ciphertext: cipher.encrypt(token, user_id)?,
+            expires_at: ttl.map(|ttl| unix_now() + ttl.as_secs()),
+        })
+    }
+
+    fn open(&self, cipher: &TokenCipher, user_id: &str) -> Result<String, StorageError> {
+        cipher.decrypt(&self.ciphertext, user_id)
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Encrypted file-backed token storage
+///
+/// Tokens are AES-GCM encrypted and persisted as a JSON document. The file is
+/// rewritten atomically on every change, so the store survives restarts.
+pub struct EncryptedFileTokenStorage {
+    path: PathBuf,
+    cipher: TokenCipher,
+    default_ttl: Option<Duration>,
+    entries: RwLock<HashMap<String, StoredToken>>,
+}
+
+impl EncryptedFileTokenStorage {
+    /// Open (or create) an encrypted token file
+    pub fn open(path: impl AsRef<Path>, cipher: TokenCipher) -> Result<Self, StorageError> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(content) if !content.trim().is_empty() => {
+                serde_json::from_str(&content).map_err(|e| {
+                    StorageError::OperationFailed(format!(
+                        "Invalid token file {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?
+            }
+            Ok(_) => HashMap::new(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => {
+                return Err(StorageError::BackendUnavailable(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        Ok(Self {
+            path,
+            cipher,
+            default_ttl: None,
+            entries: RwLock::new(entries),
+        })
+    }
+
+    /// Open the token file configured in `StorageConfig` (path in `connection_string`)
+    pub fn from_config(config: &StorageConfig) -> Result<Self, StorageError> {
+        let path = config.connection_string.as_deref().ok_or_else(|| {
+            StorageError::OperationFailed(
+                "File storage requires a connection_string path".to_string(),
+            )
+        })?;
+        Ok(Self::open(path, TokenCipher::from_config(config)?)?
+            .with_default_ttl(config.ttl_seconds.map(Duration::from_secs)))
+    }
+
+    /// TTL applied to tokens stored without an explicit TTL
+    pub fn with_default_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Path of the backing file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    async fn persist(&self, entries: &HashMap<String, StoredToken>) -> Result<(), StorageError> {
+        let content = serde_json::to_vec_pretty(entries)
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+
+        // Write to a sibling file first so readers never see a partial document
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, content)
+            .await
+            .map_err(|e| StorageError::BackendUnavailable(e.to_string()))?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(|e| StorageError::BackendUnavailable(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl AsyncSecureTokenStorage for EncryptedFileTokenStorage {
+    async fn store_token(
+        &self,
+        user_id: &str,
+        token: &str,
+        ttl: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let stored = StoredToken::seal(&self.cipher, user_id, token, ttl.or(self.default_ttl))?;
+        let mut entries = self.entries.write().await;
+        entries.insert(user_id.to_string(), stored);
+        self.persist(&entries).await
+    }
+
+    async fn get_token(&self, user_id: &str) -> Result<Option<String>, StorageError> {
+        let entries = self.entries.read().await;
+        match entries.get(user_id) {
+            Some(stored) if !stored.is_expired(unix_now()) => {
+                stored.open(&self.cipher, user_id).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn remove_token(&self, user_id: &str) -> Result<bool, StorageError> {
+        let mut entries = self.entries.write().await;
+        let removed = entries.remove(user_id).is_some();
+        if removed {
+            self.persist(&entries).await?;
+        }
+        Ok(removed)
+    }
+
+    async fn clear_all(&self) -> Result<(), StorageError> {
+        let mut entries = self.entries.write().await;
+        entries.clear();
+        self.persist(&entries).await
+    }
+
+    async fn evict_expired(&self) -> Result<usize, StorageError> {
+        let now = unix_now();
+        let mut entries = self.entries.write().await;
+        let before = entries.len();
+        entries.retain(|_, stored| !stored.is_expired(now));
+        let evicted = before - entries.len();
+        if evicted > 0 {
+            self.persist(&entries).await?;
+        }
+        Ok(evicted)
+    }
+}
+
+/// Token storage backed by a NATS JetStream key-value bucket
+///
+/// Tokens are encrypted before they leave the process, so replicas sharing the
+/// bucket must share the encryption key. The bucket's `max_age` bounds how long
+/// any token lives; per-token expiry is enforced on read and by `evict_expired`.
+#[cfg(feature = "nats-client")]
+pub struct NatsKvTokenStorage {
+    store: async_nats::jetstream::kv::Store,
+    cipher: TokenCipher,
+    default_ttl: Option<Duration>,
+}
+
+#[cfg(feature = "nats-client")]
+impl NatsKvTokenStorage {
+    /// Use an existing key-value store
+    pub fn new(store: async_nats::jetstream::kv::Store, cipher: TokenCipher) -> Self {
+        Self {
+            store,
+            cipher,
+            default_ttl: None,
+        }
+    }
+
+    /// Open the bucket, creating it if needed with `max_age` set to `max_ttl`
+    pub async fn connect(
+        client: async_nats::Client,
+        bucket: &str,
+        cipher: TokenCipher,
+        max_ttl: Option<Duration>,
+    ) -> Result<Self, StorageError> {
+        let jetstream = async_nats::jetstream::new(client);
+        let store = match jetstream.get_key_value(bucket).await {
+            Ok(store) => store,
+            Err(e) if !is_missing_bucket(&e) => {
+                return Err(StorageError::BackendUnavailable(e.to_string()))
+            }
+            Err(_) => jetstream
+                .create_key_value(async_nats::jetstream::kv::Config {
+                    bucket: bucket.to_string(),
+                    history: 1,
+                    max_age: max_ttl.unwrap_or_default(),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| StorageError::BackendUnavailable(e.to_string()))?,
+        };
+
+        Ok(Self::new(store, cipher).with_default_ttl(max_ttl))
+    }
+
+    /// Connect using `StorageConfig` (NATS URL in `connection_string`)
+    pub async fn from_config(config: &StorageConfig) -> Result<Self, StorageError> {
+        let url = config.connection_string.as_deref().ok_or_else(|| {
+            StorageError::OperationFailed("NATS storage requires a connection_string".to_string())
+        })?;
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|e| StorageError::BackendUnavailable(e.to_string()))?;
+
+        Self::connect(
+            client,
+            crate::constants::network::DEFAULT_TOKEN_STORAGE_BUCKET,
+            TokenCipher::from_config(config)?,
+            config.ttl_seconds.map(Duration::from_secs),
+        )
+        .await
+    }
+
+    /// TTL applied to tokens stored without an explicit TTL
+    pub fn with_default_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// KV keys only allow a restricted alphabet, so user ids are base64url encoded
+    fn key_for(user_id: &str) -> String {
+        ::base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(user_id)
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<StoredToken>, StorageError> {
+        let value = self
+            .store
+            .get(key)
+            .await
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+        value
+            .map(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| StorageError::OperationFailed(e.to_string()))
+            })
+            .transpose()
+    }
+
+    async fn keys(&self) -> Result<Vec<String>, StorageError> {
+        use futures::TryStreamExt;
+
+        self.store
+            .keys()
+            .await
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))?
+            .try_collect()
+            .await
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))
+    }
+}
+
+/// Whether opening a bucket failed only because it does not exist yet
+#[cfg(feature = "nats-client")]
+fn is_missing_bucket(error: &async_nats::jetstream::context::KeyValueError) -> bool {
+    use async_nats::jetstream::context::{GetStreamError, GetStreamErrorKind, KeyValueErrorKind};
+
+    error.kind() == KeyValueErrorKind::GetBucket
+        && std::error::Error::source(error)
+            .and_then(|source| source.downcast_ref::<GetStreamError>())
+            .is_some_and(|source| {
+                matches!(
+                    source.kind(),
+                    GetStreamErrorKind::JetStream(e)
+                        if e.error_code() == async_nats::jetstream::ErrorCode::STREAM_NOT_FOUND
+                )
+            })
+}
+
+#[cfg(feature = "nats-client")]
+#[async_trait]
+impl AsyncSecureTokenStorage for NatsKvTokenStorage {
+    async fn store_token(
+        &self,
+        user_id: &str,
+        token: &str,
+        ttl: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let stored = StoredToken::seal(&self.cipher, user_id, token, ttl.or(self.default_ttl))?;
+        let value = serde_json::to_vec(&stored)
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+        self.store
+            .put(Self::key_for(user_id), value.into())
+            .await
+            .map(|_| ())
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))
+    }
+
+    async fn get_token(&self, user_id: &str) -> Result<Option<String>, StorageError> {
+        match self.load(&Self::key_for(user_id)).await? {
+            Some(stored) if !stored.is_expired(unix_now()) => {
+                stored.open(&self.cipher, user_id).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn remove_token(&self, user_id: &str) -> Result<bool, StorageError> {
+        let key = Self::key_for(user_id);
+        if self.load(&key).await?.is_none() {
+            return Ok(false);
+        }
+        self.store
+            .purge(key)
+            .await
+            .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+        Ok(true)
+    }
+
+    async fn clear_all(&self) -> Result<(), StorageError> {
+        for key in self.keys().await? {
+            self.store
+                .purge(key)
+                .await
+                .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    async fn evict_expired(&self) -> Result<usize, StorageError> {
+        let now = unix_now();
+        let mut evicted = 0;
+        for key in self.keys().await? {
+            if self
+                .load(&key)
+                .await?
+                .is_some_and(|stored| stored.is_expired(now))
+            {
+                self.store
+                    .purge(key)
+                    .await
+                    .map_err(|e| StorageError::OperationFailed(e.to_string()))?;
+                evicted += 1;
+            }
+        }
+        Ok(evicted)
+    }
+}
+
+/// Create the async token storage backend selected by `StorageConfig::backend`
+///
+/// Supported backends are `"file"` and, with the `nats-client` feature, `"nats"`.
+pub async fn token_storage_from_config(
+    config: &StorageConfig,
+) -> Result<Arc<dyn AsyncSecureTokenStorage>, StorageError> {
+    match config.backend.as_str() {
+        "file" => Ok(Arc::new(EncryptedFileTokenStorage::from_config(config)?)),
+        #[cfg(feature = "nats-client")]
+        "nats" => Ok(Arc::new(NatsKvTokenStorage::from_config(config).await?)),
+        other => Err(StorageError::BackendUnavailable(format!(
+            "Unsupported async token storage backend: {}",
+            other
+        ))),
+    }
+}
+
+/// Spawn a background task that evicts expired tokens every `interval`
+pub fn spawn_eviction_task(
+    storage: Arc<dyn AsyncSecureTokenStorage>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = storage.evict_expired().await {
+                tracing::warn!("Token eviction failed: {}", e);
+            }
+        }
+    })
+}
+
 /// In-Memory Token Storage Implementation
 pub struct InMemoryTokenStorage {
     storage: HashMap<String, String>,
@@ -157,3 +710,204 @@ mod base64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_token_file() -> PathBuf {
+        std::env::temp_dir().join(format!("qollective-tokens-{}.json", uuid::Uuid::now_v7()))
+    }
+
+    #[test]
+    fn test_token_cipher_round_trip() {
+        let cipher = TokenCipher::from_base64_key(&TokenCipher::generate_key()).unwrap();
+
+        let first = cipher.encrypt("access-token", "picard").unwrap();
+        let second = cipher.encrypt("access-token", "picard").unwrap();
+        assert_ne!(first, second, "nonces must differ per encryption");
+        assert_eq!(cipher.decrypt(&first, "picard").unwrap(), "access-token");
+
+        let other = TokenCipher::from_base64_key(&TokenCipher::generate_key()).unwrap();
+        assert!(matches!(
+            other.decrypt(&first, "picard"),
+            Err(StorageError::CryptographyFailed(_))
+        ));
+        assert!(matches!(
+            cipher.decrypt(&first, "riker"),
+            Err(StorageError::CryptographyFailed(_))
+        ));
+        assert!(TokenCipher::new(&[0u8; 16]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_file_storage_persists_ciphertext() {
+        let path = temp_token_file();
+        let key = TokenCipher::generate_key();
+
+        let storage =
+            EncryptedFileTokenStorage::open(&path, TokenCipher::from_base64_key(&key).unwrap())
+                .unwrap();
+        storage.store_token("picard", "engage", None).await.unwrap();
+
+        let on_disk = std::fs::read_to_string(&path).unwrap();
+        assert!(!on_disk.contains("engage"));
+
+        // A new instance with the same key reads the persisted token
+        let reopened =
+            EncryptedFileTokenStorage::open(&path, TokenCipher::from_base64_key(&key).unwrap())
+                .unwrap();
+        assert_eq!(
+            reopened.get_token("picard").await.unwrap(),
+            Some("engage".to_string())
+        );
+        assert!(reopened.remove_token("picard").await.unwrap());
+        assert!(!reopened.has_token("picard").await.unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_token_record_swapped_to_another_user_is_rejected() {
+        // ARRANGE: two users with tokens in the same file
+        let path = temp_token_file();
+        let key = TokenCipher::generate_key();
+        let storage =
+            EncryptedFileTokenStorage::open(&path, TokenCipher::from_base64_key(&key).unwrap())
+                .unwrap();
+        storage.store_token("picard", "captain", None).await.unwrap();
+        storage.store_token("wesley", "ensign", None).await.unwrap();
+
+        // ACT: someone with write access to the file copies picard's record over wesley's
+        let mut records: HashMap<String, serde_json::Value> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let stolen = records["picard"].clone();
+        records.insert("wesley".to_string(), stolen);
+        std::fs::write(&path, serde_json::to_string(&records).unwrap()).unwrap();
+        let reopened =
+            EncryptedFileTokenStorage::open(&path, TokenCipher::from_base64_key(&key).unwrap())
+                .unwrap();
+
+        // ASSERT: the moved record no longer decrypts, the original still does
+        assert!(matches!(
+            reopened.get_token("wesley").await,
+            Err(StorageError::CryptographyFailed(_))
+        ));
+        assert_eq!(
+            reopened.get_token("picard").await.unwrap(),
+            Some("captain".to_string())
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_are_hidden_and_evicted() {
+        let path = temp_token_file();
+        let cipher = TokenCipher::from_base64_key(&TokenCipher::generate_key()).unwrap();
+        let storage = EncryptedFileTokenStorage::open(&path, cipher).unwrap();
+
+        storage
+            .store_token("riker", "stale", Some(Duration::ZERO))
+            .await
+            .unwrap();
+        storage
+            .store_token("data", "fresh", Some(Duration::from_secs(600)))
+            .await
+            .unwrap();
+
+        assert_eq!(storage.get_token("riker").await.unwrap(), None);
+        assert_eq!(storage.evict_expired().await.unwrap(), 1);
+        assert_eq!(storage.evict_expired().await.unwrap(), 0);
+        assert_eq!(
+            storage.get_token("data").await.unwrap(),
+            Some("fresh".to_string())
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_store_expiring_token_uses_token_expiry() {
+        let path = temp_token_file();
+        let cipher = TokenCipher::from_base64_key(&TokenCipher::generate_key()).unwrap();
+        let storage = EncryptedFileTokenStorage::open(&path, cipher).unwrap();
+
+        let valid = Token::new(
+            "valid-token".to_string(),
+            "worf".to_string(),
+            SystemTime::now() + Duration::from_secs(300),
+            vec![],
+        );
+        storage.store_expiring_token("worf", &valid).await.unwrap();
+        let expires_at = storage.entries.read().await["worf"].expires_at.unwrap();
+        assert!(expires_at > unix_now() && expires_at <= unix_now() + 300);
+
+        let expired = Token::new(
+            "expired-token".to_string(),
+            "worf".to_string(),
+            SystemTime::now() - Duration::from_secs(1),
+            vec![],
+        );
+        assert!(storage
+            .store_expiring_token("worf", &expired)
+            .await
+            .is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "nats-client")]
+    #[test]
+    fn test_only_missing_buckets_are_created() {
+        use async_nats::jetstream::context::{
+            GetStreamError, GetStreamErrorKind, KeyValueError, KeyValueErrorKind,
+        };
+
+        let jetstream_error = |err_code: u64| {
+            let error = serde_json::from_value(serde_json::json!({
+                "code": 404,
+                "err_code": err_code,
+                "description": "stream not found",
+            }))
+            .unwrap();
+            KeyValueError::with_source(
+                KeyValueErrorKind::GetBucket,
+                GetStreamError::new(GetStreamErrorKind::JetStream(error)),
+            )
+        };
+
+        assert!(is_missing_bucket(&jetstream_error(10059)));
+        assert!(!is_missing_bucket(&jetstream_error(10039)));
+        assert!(!is_missing_bucket(&KeyValueError::with_source(
+            KeyValueErrorKind::GetBucket,
+            GetStreamError::new(GetStreamErrorKind::Request),
+        )));
+        assert!(!is_missing_bucket(&KeyValueError::new(
+            KeyValueErrorKind::InvalidStoreName
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_token_storage_from_config() {
+        let path = temp_token_file();
+        let config = StorageConfig {
+            backend: "file".to_string(),
+            connection_string: Some(path.to_string_lossy().into_owned()),
+            encryption_key: Some(TokenCipher::generate_key()),
+            ttl_seconds: Some(60),
+        };
+
+        let storage = token_storage_from_config(&config).await.unwrap();
+        storage.store_token("troi", "counsel", None).await.unwrap();
+        assert!(storage.has_token("troi").await.unwrap());
+
+        let missing_key = StorageConfig {
+            encryption_key: None,
+            ..config
+        };
+        assert!(token_storage_from_config(&missing_key).await.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
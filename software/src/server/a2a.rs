@@ -8,19 +8,24 @@
 //! - Capability query processing and agent discovery
 //! - Health monitoring and status management
 //! - Request routing and response handling
+//! - A2A task lifecycle with `tasks/get`, `tasks/cancel` and streamed updates
 //! - Integration with NATS server and transport layer
 
 use crate::{
-    client::a2a::{A2AClient, AgentCard, AgentMetadata, AgentRegistration, AgentRegistry},
+    client::a2a::{
+        A2AClient, A2AMessage, AgentCard, AgentMetadata, AgentRegistration, AgentRegistry,
+        PerformanceMetrics,
+    },
     config::a2a::{A2AServerConfig, HealthConfig, RegistryConfig, RoutingConfig},
     constants::limits,
-    envelope::Context,
+    envelope::{Context, JwtTenantInfo, Meta},
     error::{QollectiveError, Result},
-    server::nats::NatsServer,
+    server::{common::TenantAuthenticator, nats::NatsServer},
     traits::handlers::{ContextDataHandler, DefaultEnvelopeHandler},
     types::a2a::{
-        AgentId, AgentInfo, CapabilityQuery, DeregistrationRequest, HealthStatus, Heartbeat,
-        RoutingDecision, RoutingPreferences,
+        A2ATask, AgentId, AgentInfo, CapabilityQuery, DeregistrationRequest, HealthStatus,
+        Heartbeat, RoutingDecision, RoutingPreferences, TaskArtifact, TaskIdParams, TaskState,
+        TaskStatus, TaskUpdate,
     },
 };
 
@@ -29,7 +34,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     sync::Arc,
-    time::{Duration, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 use tokio::sync::RwLock;
 
@@ -84,11 +89,56 @@ pub struct RegistryStats {
     pub last_cleanup: Option<SystemTime>,
 }
 
+/// Weight of the newest observation in an agent's moving average latency
+const LATENCY_SMOOTHING: f64 = 0.2;
+
 /// Capability router for handling routing decisions
+///
+/// The router keeps the state its load balancing strategies rely on: a
+/// round-robin cursor per set of required capabilities, the number of in-flight
+/// requests per agent and a moving average of each agent's observed latency.
+/// Requests sent to a routed agent should be tracked with
+/// [`begin_request`](Self::begin_request) to keep that state current.
 #[derive(Debug)]
 pub struct CapabilityRouter {
     config: RoutingConfig,
     routing_cache: HashMap<String, Vec<AgentId>>,
+    round_robin_cursors: std::sync::Mutex<HashMap<String, usize>>,
+    in_flight: std::sync::Mutex<HashMap<AgentId, usize>>,
+    observed_latency_ms: std::sync::Mutex<HashMap<AgentId, f64>>,
+    reported_performance: std::sync::Mutex<HashMap<AgentId, PerformanceMetrics>>,
+}
+
+/// A request dispatched to a routed agent
+///
+/// The agent's in-flight count is decremented when the request is dropped.
+#[derive(Debug)]
+pub struct InFlightRequest<'a> {
+    router: &'a CapabilityRouter,
+    agent_id: AgentId,
+    started: Instant,
+}
+
+impl InFlightRequest<'_> {
+    /// Complete the request, recording its latency if it succeeded
+    pub fn finish(self, success: bool) {
+        if success {
+            self.router
+                .record_latency(self.agent_id, self.started.elapsed());
+        }
+    }
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.router.in_flight.lock().unwrap();
+        if let Some(count) = in_flight.get_mut(&self.agent_id) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                in_flight.remove(&self.agent_id);
+            }
+        }
+    }
 }
 
 impl CapabilityRouter {
@@ -96,9 +146,117 @@ impl CapabilityRouter {
         Self {
             config,
             routing_cache: HashMap::new(),
+            round_robin_cursors: std::sync::Mutex::new(HashMap::new()),
+            in_flight: std::sync::Mutex::new(HashMap::new()),
+            observed_latency_ms: std::sync::Mutex::new(HashMap::new()),
+            reported_performance: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
+    /// Track a request to `agent_id` until the returned guard is dropped
+    pub fn begin_request(&self, agent_id: AgentId) -> InFlightRequest<'_> {
+        *self.in_flight.lock().unwrap().entry(agent_id).or_insert(0) += 1;
+        InFlightRequest {
+            router: self,
+            agent_id,
+            started: Instant::now(),
+        }
+    }
+
+    /// Number of requests currently in flight to `agent_id`
+    pub fn in_flight_requests(&self, agent_id: &AgentId) -> usize {
+        self.in_flight
+            .lock()
+            .unwrap()
+            .get(agent_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Fold an observed response time into the agent's moving average latency
+    pub fn record_latency(&self, agent_id: AgentId, latency: Duration) {
+        let sample = latency.as_secs_f64() * 1000.0;
+        self.observed_latency_ms
+            .lock()
+            .unwrap()
+            .entry(agent_id)
+            .and_modify(|average| {
+                *average = LATENCY_SMOOTHING * sample + (1.0 - LATENCY_SMOOTHING) * *average
+            })
+            .or_insert(sample);
+    }
+
+    /// Record the performance metrics an agent reported about itself
+    ///
+    /// Reported response times are used until the router observed its own.
+    pub fn record_performance(&self, agent_id: AgentId, metrics: PerformanceMetrics) {
+        self.reported_performance
+            .lock()
+            .unwrap()
+            .insert(agent_id, metrics);
+    }
+
+    /// Expected response time of an agent in milliseconds
+    ///
+    /// Prefers the router's own observations, then the agent's reported
+    /// average, and falls back to an estimate from the agent's health status.
+    pub fn estimated_latency_ms(&self, agent: &AgentInfo) -> f64 {
+        if let Some(observed) = self.observed_latency_ms.lock().unwrap().get(&agent.id) {
+            return *observed;
+        }
+        let reported = self
+            .reported_performance
+            .lock()
+            .unwrap()
+            .get(&agent.id)
+            .map(|metrics| metrics.average_response_time_ms)
+            .filter(|average| *average > 0.0);
+        reported.unwrap_or(match agent.health_status {
+            HealthStatus::Healthy => 50.0,
+            HealthStatus::Warning => 100.0,
+            HealthStatus::Unhealthy => 200.0,
+            HealthStatus::Unknown => 150.0,
+        })
+    }
+
+    /// Rotate agents by the round-robin cursor of the query's capabilities and advance it
+    fn rotate_round_robin(&self, query: &CapabilityQuery, agents: &mut [AgentInfo]) {
+        if agents.is_empty() {
+            return;
+        }
+        // Registry iteration order is not stable, so rotate a canonical order
+        agents.sort_by_key(|agent| agent.id);
+
+        let mut capabilities = query.required_capabilities.clone();
+        capabilities.sort();
+        let offset = {
+            let mut cursors = self.round_robin_cursors.lock().unwrap();
+            let cursor = cursors.entry(capabilities.join(",")).or_insert(0);
+            let offset = *cursor;
+            *cursor = cursor.wrapping_add(1);
+            offset
+        };
+        agents.rotate_left(offset % agents.len());
+    }
+
+    /// Order agents with a weighted random draw favouring low expected latency
+    fn order_by_latency_weight(&self, agents: Vec<AgentInfo>) -> Vec<AgentInfo> {
+        use rand::Rng;
+
+        let mut rng = rand::rng();
+        let mut weighted: Vec<(f64, AgentInfo)> = agents
+            .into_iter()
+            .map(|agent| {
+                // Efraimidis-Spirakis key ln(u) / w with weight w = 1 / latency,
+                // so agents are drawn in proportion to the inverse of their latency
+                let latency_ms = self.estimated_latency_ms(&agent).max(1.0);
+                (rng.random::<f64>().ln() * latency_ms, agent)
+            })
+            .collect();
+        weighted.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        weighted.into_iter().map(|(_, agent)| agent).collect()
+    }
+
     pub async fn route_capability(
         &self,
         query: &CapabilityQuery,
@@ -116,44 +274,23 @@ impl CapabilityRouter {
         // Filter out excluded agents
         agents.retain(|agent| !preferences.excluded_agents.contains(&agent.id));
 
-        // If preferred agents exist and are available, prioritize them
-        let mut preferred_agents = Vec::new();
-        let mut other_agents = Vec::new();
-
-        for agent in agents {
-            if preferences.preferred_agents.contains(&agent.id) {
-                preferred_agents.push(agent);
-            } else {
-                other_agents.push(agent);
-            }
-        }
-
-        // Combine preferred agents first, then others
-        let mut available_agents = preferred_agents;
-        available_agents.extend(other_agents);
-
-        // Apply max results limit
-        let max_results = query
-            .max_results
-            .unwrap_or(limits::DEFAULT_MAX_AGENTS)
-            .min(available_agents.len());
-
-        // Apply load balancing strategy
-        let selected_agents: Vec<AgentInfo> = match preferences.load_balancing_strategy {
+        // Order candidates by the load balancing strategy
+        let mut available_agents: Vec<AgentInfo> = match preferences.load_balancing_strategy {
             LoadBalancingStrategy::RoundRobin => {
-                // For round robin, take agents in order up to max_results
-                available_agents.into_iter().take(max_results).collect()
+                // Rotate through the agents on every query for the same capabilities
+                self.rotate_round_robin(query, &mut agents);
+                agents
             }
             LoadBalancingStrategy::Random => {
-                // For random, shuffle and take max_results
+                // For random, shuffle the candidates
                 use rand::seq::SliceRandom;
                 let mut rng = rand::rng();
-                available_agents.shuffle(&mut rng);
-                available_agents.into_iter().take(max_results).collect()
+                agents.shuffle(&mut rng);
+                agents
             }
             LoadBalancingStrategy::HealthBased => {
                 // Sort by health status (Healthy first, then Warning, then others)
-                available_agents.sort_by(|a, b| {
+                agents.sort_by(|a, b| {
                     use HealthStatus::*;
                     let health_priority = |status: &HealthStatus| match status {
                         Healthy => 0,
@@ -163,44 +300,51 @@ impl CapabilityRouter {
                     };
                     health_priority(&a.health_status).cmp(&health_priority(&b.health_status))
                 });
-                available_agents.into_iter().take(max_results).collect()
+                agents
             }
             LoadBalancingStrategy::LeastConnections => {
-                // For now, treat as round robin since we don't track connections yet
-                available_agents.into_iter().take(max_results).collect()
+                // Fewest in-flight requests first, rotating between equally loaded agents
+                self.rotate_round_robin(query, &mut agents);
+                let in_flight = self.in_flight.lock().unwrap();
+                agents.sort_by_key(|agent| in_flight.get(&agent.id).copied().unwrap_or(0));
+                drop(in_flight);
+                agents
             }
+            LoadBalancingStrategy::LatencyWeighted => self.order_by_latency_weight(agents),
         };
 
+        // Preferred agents go first, keeping the strategy's order within each group
+        available_agents.sort_by_key(|agent| !preferences.preferred_agents.contains(&agent.id));
+
+        // Apply max results limit; the remaining candidates become fallbacks
+        let max_results = query
+            .max_results
+            .unwrap_or(limits::DEFAULT_MAX_AGENTS)
+            .min(available_agents.len());
+        let fallback_agents = available_agents.split_off(max_results);
+        let selected_agents = available_agents;
+
         // Generate routing reason
         let routing_reason = format!(
-            "Selected {} agents using {:?} strategy, healthy_only: {}, preferred_count: {}",
+            "Selected {} agents using {:?} strategy, healthy_only: {}, preferred_count: {}, fallback_count: {}",
             selected_agents.len(),
             preferences.load_balancing_strategy,
             preferences.require_healthy_agents,
-            preferences.preferred_agents.len()
+            preferences.preferred_agents.len(),
+            fallback_agents.len()
         );
 
-        // Estimate response time based on agent health
+        // Estimate response time from the selected agents' expected latency
         let estimated_response_time_ms = if selected_agents.is_empty() {
             None
         } else {
-            let avg_health_score = selected_agents
+            let total_ms: f64 = selected_agents
                 .iter()
-                .map(|agent| match agent.health_status {
-                    HealthStatus::Healthy => 50,
-                    HealthStatus::Warning => 100,
-                    HealthStatus::Unhealthy => 200,
-                    HealthStatus::Unknown => 150,
-                })
-                .sum::<u64>()
-                / selected_agents.len() as u64;
-
-            Some(avg_health_score)
+                .map(|agent| self.estimated_latency_ms(agent))
+                .sum();
+            Some((total_ms / selected_agents.len() as f64).round() as u64)
         };
 
-        // Set fallback agents (remaining available agents not selected)
-        let fallback_agents = Vec::new(); // For now, keep simple without complex fallback logic
-
         Ok(RoutingDecision {
             selected_agents,
             routing_reason,
@@ -335,6 +479,303 @@ impl AgentHealthMonitor {
     }
 }
 
+// ============================================================================
+// TASK LIFECYCLE
+// ============================================================================
+
+/// Updates buffered per task update subscriber before it starts missing updates
+const TASK_UPDATE_CHANNEL_CAPACITY: usize = 256;
+
+/// How long finished tasks stay available to `tasks/get`
+const FINISHED_TASK_RETENTION: Duration = Duration::from_secs(3600);
+
+/// Store of A2A tasks and the updates they stream to their callers
+///
+/// Every status change and artifact is broadcast to
+/// [`subscribe`](Self::subscribe)rs; the [`A2AServer`] forwards them to the
+/// task's updates subject on NATS. Agents working on a task can
+/// [`watch`](Self::watch) its state to notice cancellation.
+#[derive(Debug)]
+pub struct TaskStore {
+    tasks: RwLock<HashMap<String, TaskEntry>>,
+    updates: tokio::sync::broadcast::Sender<TaskUpdate>,
+}
+
+/// Caller a task belongs to
+///
+/// A task is only visible to callers of the same tenant and user; tasks
+/// submitted without an identity only to anonymous callers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskOwner {
+    pub tenant: Option<String>,
+    pub user_id: Option<String>,
+}
+
+impl TaskOwner {
+    /// Owner identified by a verified tenant token
+    pub fn from_token(info: &JwtTenantInfo) -> Self {
+        Self {
+            tenant: info.tenant_key.clone(),
+            user_id: info.subject.clone(),
+        }
+    }
+
+    /// Owner identified by the tenant and user in the metadata of a request
+    ///
+    /// Only meaningful for metadata a [`TenantAuthenticator`] has verified;
+    /// otherwise both are whatever the caller claimed.
+    pub fn from_meta(meta: &Meta) -> Self {
+        Self {
+            tenant: meta.tenant.clone(),
+            user_id: meta
+                .security
+                .as_ref()
+                .and_then(|security| security.user_id.clone()),
+        }
+    }
+
+    /// Caller of a request whose metadata passed through `authenticator`
+    ///
+    /// Without an active authenticator nothing was verified and the caller is
+    /// anonymous.
+    pub fn authenticated(authenticator: &TenantAuthenticator, meta: Option<&Meta>) -> Self {
+        match meta {
+            Some(meta) if authenticator.is_active() => Self::from_meta(meta),
+            _ => Self::default(),
+        }
+    }
+
+    /// Whether `caller` may see and cancel a task owned by `self`
+    pub fn permits(&self, caller: &TaskOwner) -> bool {
+        self == caller
+    }
+}
+
+/// Stored task together with its owner and the channel publishing its state
+#[derive(Debug)]
+struct TaskEntry {
+    task: A2ATask,
+    owner: TaskOwner,
+    state: tokio::sync::watch::Sender<TaskState>,
+}
+
+impl Default for TaskStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskStore {
+    pub fn new() -> Self {
+        let (updates, _) = tokio::sync::broadcast::channel(TASK_UPDATE_CHANNEL_CAPACITY);
+        Self {
+            tasks: RwLock::new(HashMap::new()),
+            updates,
+        }
+    }
+
+    /// Submit the task an incoming message asks for
+    ///
+    /// A message without a task id starts a new task. A message naming a task
+    /// that awaits input resumes it as working; naming any other existing task
+    /// is rejected. A message naming an unknown task starts it under that id.
+    pub async fn submit(&self, message: &A2AMessage) -> Result<A2ATask> {
+        self.submit_for(message, TaskOwner::default()).await
+    }
+
+    /// Submit a task on behalf of `owner`, see [`submit`](Self::submit)
+    ///
+    /// Only the owner may resume the task later.
+    pub async fn submit_for(&self, message: &A2AMessage, owner: TaskOwner) -> Result<A2ATask> {
+        let task_id = message
+            .task_id
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::now_v7().to_string());
+
+        let mut tasks = self.tasks.write().await;
+        if let Some(entry) = tasks.get_mut(&task_id) {
+            if !entry.owner.permits(&owner) {
+                return Err(QollectiveError::validation(format!(
+                    "Task {} belongs to another caller",
+                    task_id
+                )));
+            }
+            if entry.task.status.state != TaskState::InputRequired {
+                return Err(QollectiveError::validation(format!(
+                    "Task {} is {:?} and does not accept messages",
+                    task_id, entry.task.status.state
+                )));
+            }
+            let status = TaskStatus::new(TaskState::Working, None);
+            self.apply_status(entry, status);
+            return Ok(entry.task.clone());
+        }
+
+        let status = TaskStatus::new(TaskState::Submitted, None);
+        let task = A2ATask {
+            id: task_id.clone(),
+            context_id: message.context_id.clone(),
+            status: status.clone(),
+            history: vec![status.clone()],
+            artifacts: Vec::new(),
+            metadata: HashMap::new(),
+        };
+        let (state, _) = tokio::sync::watch::channel(TaskState::Submitted);
+        tasks.insert(
+            task_id.clone(),
+            TaskEntry {
+                task: task.clone(),
+                owner,
+                state,
+            },
+        );
+        let _ = self.updates.send(TaskUpdate::Status {
+            task_id,
+            status,
+            is_final: false,
+        });
+        Ok(task)
+    }
+
+    /// Get a task by id
+    pub async fn get(&self, task_id: &str) -> Option<A2ATask> {
+        self.tasks
+            .read()
+            .await
+            .get(task_id)
+            .map(|entry| entry.task.clone())
+    }
+
+    /// Get a task by id if `caller` may see it
+    ///
+    /// Tasks owned by another caller are reported as unknown.
+    pub async fn get_as(&self, task_id: &str, caller: &TaskOwner) -> Option<A2ATask> {
+        self.tasks
+            .read()
+            .await
+            .get(task_id)
+            .filter(|entry| entry.owner.permits(caller))
+            .map(|entry| entry.task.clone())
+    }
+
+    /// Move a task to `state`, rejecting transitions out of terminal states
+    pub async fn update_status(
+        &self,
+        task_id: &str,
+        state: TaskState,
+        message: Option<String>,
+    ) -> Result<A2ATask> {
+        let mut tasks = self.tasks.write().await;
+        let entry = tasks
+            .get_mut(task_id)
+            .ok_or_else(|| QollectiveError::validation(format!("Unknown task {}", task_id)))?;
+        if !entry.task.status.state.can_transition_to(state) {
+            return Err(QollectiveError::validation(format!(
+                "Task {} cannot move from {:?} to {:?}",
+                task_id, entry.task.status.state, state
+            )));
+        }
+        self.apply_status(entry, TaskStatus::new(state, message));
+        Ok(entry.task.clone())
+    }
+
+    /// Attach an artifact to a running task
+    pub async fn add_artifact(&self, task_id: &str, artifact: TaskArtifact) -> Result<()> {
+        let mut tasks = self.tasks.write().await;
+        let entry = tasks
+            .get_mut(task_id)
+            .ok_or_else(|| QollectiveError::validation(format!("Unknown task {}", task_id)))?;
+        if entry.task.status.state.is_terminal() {
+            return Err(QollectiveError::validation(format!(
+                "Task {} is {:?} and accepts no artifacts",
+                task_id, entry.task.status.state
+            )));
+        }
+        entry.task.artifacts.push(artifact.clone());
+        let _ = self.updates.send(TaskUpdate::Artifact {
+            task_id: task_id.to_string(),
+            artifact,
+        });
+        Ok(())
+    }
+
+    /// Cancel a task
+    ///
+    /// Returns the task after cancellation, unchanged if it had already
+    /// finished, or `None` if the task is unknown.
+    pub async fn cancel(&self, task_id: &str, reason: Option<String>) -> Option<A2ATask> {
+        let mut tasks = self.tasks.write().await;
+        let entry = tasks.get_mut(task_id)?;
+        Some(self.cancel_entry(entry, reason))
+    }
+
+    /// Cancel a task on behalf of `caller`
+    ///
+    /// Tasks owned by another caller are left running and reported as unknown.
+    pub async fn cancel_as(
+        &self,
+        task_id: &str,
+        caller: &TaskOwner,
+        reason: Option<String>,
+    ) -> Option<A2ATask> {
+        let mut tasks = self.tasks.write().await;
+        let entry = tasks
+            .get_mut(task_id)
+            .filter(|entry| entry.owner.permits(caller))?;
+        Some(self.cancel_entry(entry, reason))
+    }
+
+    /// Cancel a stored task unless it has already finished
+    fn cancel_entry(&self, entry: &mut TaskEntry, reason: Option<String>) -> A2ATask {
+        if !entry.task.status.state.is_terminal() {
+            self.apply_status(entry, TaskStatus::new(TaskState::Canceled, reason));
+        }
+        entry.task.clone()
+    }
+
+    /// Watch the state of a task, e.g. to stop working on it once canceled
+    pub async fn watch(&self, task_id: &str) -> Option<tokio::sync::watch::Receiver<TaskState>> {
+        self.tasks
+            .read()
+            .await
+            .get(task_id)
+            .map(|entry| entry.state.subscribe())
+    }
+
+    /// Receive the status and artifact updates of all tasks
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<TaskUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// Drop finished tasks whose last update is older than `max_age`
+    pub async fn purge_finished(&self, max_age: Duration) -> usize {
+        let now = SystemTime::now();
+        let mut tasks = self.tasks.write().await;
+        let before = tasks.len();
+        tasks.retain(|_, entry| {
+            let status = &entry.task.status;
+            !status.state.is_terminal()
+                || now
+                    .duration_since(status.timestamp)
+                    .map(|age| age < max_age)
+                    .unwrap_or(true)
+        });
+        before - tasks.len()
+    }
+
+    /// Record a new status on a task and notify watchers and subscribers
+    fn apply_status(&self, entry: &mut TaskEntry, status: TaskStatus) {
+        entry.task.status = status.clone();
+        entry.task.history.push(status.clone());
+        entry.state.send_replace(status.state);
+        let _ = self.updates.send(TaskUpdate::Status {
+            task_id: entry.task.id.clone(),
+            is_final: status.state.is_terminal(),
+            status,
+        });
+    }
+}
+
 // ============================================================================
 // SERVER STATE
 // ============================================================================
@@ -461,7 +902,7 @@ impl RegistrationRateLimit {
 /// Agent registration request handler
 #[derive(Debug)]
 struct RegistrationHandler {
-    registry: Arc<RwLock<AgentRegistry>>,
+    registry: Arc<AgentRegistry>,
     client: Arc<A2AClient>,
     config: RegistryConfig,
     rate_limiter: Arc<RwLock<RegistrationRateLimit>>,
@@ -470,7 +911,7 @@ struct RegistrationHandler {
 /// Capability query request handler
 #[derive(Debug)]
 struct QueryHandler {
-    registry: Arc<RwLock<AgentRegistry>>,
+    registry: Arc<AgentRegistry>,
     client: Arc<A2AClient>,
     router: Arc<CapabilityRouter>,
     config: RoutingConfig,
@@ -479,19 +920,35 @@ struct QueryHandler {
 /// Dedicated handler for agent discovery requests that returns Vec<AgentInfo>
 #[derive(Debug)]
 struct AgentDiscoveryHandler {
-    registry: Arc<RwLock<AgentRegistry>>,
+    registry: Arc<AgentRegistry>,
     config: RoutingConfig,
 }
 
 /// Health monitoring request handler
 #[derive(Debug)]
 struct HealthHandler {
-    registry: Arc<RwLock<AgentRegistry>>,
+    registry: Arc<AgentRegistry>,
     client: Arc<A2AClient>,
     health_monitor: Arc<tokio::sync::Mutex<AgentHealthMonitor>>,
     config: HealthConfig,
 }
 
+/// `tasks/get` request handler
+#[derive(Debug)]
+struct TaskQueryHandler {
+    tasks: Arc<TaskStore>,
+    /// Authenticator the NATS server verified the caller's metadata with
+    authenticator: TenantAuthenticator,
+}
+
+/// `tasks/cancel` request handler
+#[derive(Debug)]
+struct TaskCancelHandler {
+    tasks: Arc<TaskStore>,
+    /// Authenticator the NATS server verified the caller's metadata with
+    authenticator: TenantAuthenticator,
+}
+
 // ============================================================================
 // MAIN A2A SERVER
 // ============================================================================
@@ -504,7 +961,7 @@ pub struct A2AServer {
     /// Server configuration
     config: A2AServerConfig,
     /// Agent registry for managing registered agents
-    registry: Arc<RwLock<AgentRegistry>>,
+    registry: Arc<AgentRegistry>,
     /// A2A client for communication
     client: Arc<A2AClient>,
     /// Capability router
@@ -516,6 +973,10 @@ pub struct A2AServer {
     query_handler: Arc<QueryHandler>,
     discovery_handler: Arc<AgentDiscoveryHandler>,
     health_handler: Arc<HealthHandler>,
+    /// A2A tasks and their update stream
+    tasks: Arc<TaskStore>,
+    /// Verifies the tokens of callers asking for tasks
+    tenant_authenticator: TenantAuthenticator,
     /// Server statistics
     server_stats: Arc<RwLock<ServerStats>>,
     /// Request queue
@@ -566,7 +1027,7 @@ pub enum RequestPriority {
 impl RegistrationHandler {
     /// Create a new registration handler
     pub fn new(
-        registry: Arc<RwLock<AgentRegistry>>,
+        registry: Arc<AgentRegistry>,
         client: Arc<A2AClient>,
         config: RegistryConfig,
     ) -> Self {
@@ -586,7 +1047,7 @@ impl RegistrationHandler {
         self.validate_agent_info(&agent_info)?;
 
         // Check registry limits
-        let stats = self.registry.read().await.get_stats().await?;
+        let stats = self.registry.get_stats().await?;
         if stats.total_agents >= self.config.max_agents {
             return Err(QollectiveError::validation(
                 "Registry capacity exceeded".to_string(),
@@ -644,14 +1105,12 @@ impl RegistrationHandler {
         );
 
         // Log registry statistics
-        if let Ok(stats) = self.registry.read().await.get_stats().await {
+        if let Ok(stats) = self.registry.get_stats().await {
             tracing::debug!("Registry stats: {} total agents", stats.total_agents);
         }
 
         // Register agent in registry
         self.registry
-            .write()
-            .await
             .register_agent(agent_info.clone(), metadata.clone())
             .await?;
 
@@ -678,10 +1137,13 @@ impl RegistrationHandler {
 
         // Get agent info before deregistering for the registry event
         let agent_info = {
-            let registry = self.registry.read().await;
-            if let Some(info) = registry.get_agent_by_id(&deregistration.agent_id) {
+            if let Some(info) = self
+                .registry
+                .get_agent_by_id(&deregistration.agent_id)
+                .await
+            {
                 tracing::debug!("Found agent in registry: '{}' (ID: {})", info.name, info.id);
-                info.clone()
+                info
             } else {
                 tracing::warn!(
                     "Agent not found in registry, using placeholder info for ID: {}",
@@ -705,8 +1167,6 @@ impl RegistrationHandler {
             agent_info.id
         );
         self.registry
-            .write()
-            .await
             .deregister_agent(deregistration.agent_id)
             .await?;
 
@@ -823,7 +1283,7 @@ impl ContextDataHandler<AgentRegistration, RegistrationResponse> for Registratio
 impl QueryHandler {
     /// Create a new query handler
     pub fn new(
-        registry: Arc<RwLock<AgentRegistry>>,
+        registry: Arc<AgentRegistry>,
         client: Arc<A2AClient>,
         router: Arc<CapabilityRouter>,
         config: RoutingConfig,
@@ -850,7 +1310,7 @@ impl QueryHandler {
         }
 
         // Find matching agents from local registry
-        let query_result = self.registry.read().await.find_agents(&query).await?;
+        let query_result = self.registry.find_agents(&query).await?;
 
         if query_result.is_empty() {
             return Err(QollectiveError::validation(
@@ -858,6 +1318,18 @@ impl QueryHandler {
             ));
         }
 
+        // Let the router weigh agents by the performance they reported
+        for agent in &query_result {
+            if let Some(metrics) = self
+                .registry
+                .get_agent_metadata(&agent.id)
+                .await
+                .and_then(|metadata| metadata.performance_metrics)
+            {
+                self.router.record_performance(agent.id, metrics);
+            }
+        }
+
         // Route the request
         let routing_preferences = preferences.unwrap_or_default();
         self.router
@@ -867,7 +1339,7 @@ impl QueryHandler {
 
     /// Handle agent discovery request
     pub async fn handle_agent_discovery(&self, query: CapabilityQuery) -> Result<Vec<AgentInfo>> {
-        let result = self.registry.read().await.find_agents(&query).await?;
+        let result = self.registry.find_agents(&query).await?;
 
         // Apply server-side result limits
         let mut agents = result;
@@ -956,7 +1428,7 @@ impl ContextDataHandler<QueryRequest, RoutingDecision> for QueryHandler {
 
 impl AgentDiscoveryHandler {
     /// Create a new agent discovery handler
-    pub fn new(registry: Arc<RwLock<AgentRegistry>>, config: RoutingConfig) -> Self {
+    pub fn new(registry: Arc<AgentRegistry>, config: RoutingConfig) -> Self {
         Self { registry, config }
     }
 }
@@ -969,7 +1441,7 @@ impl ContextDataHandler<CapabilityQuery, Vec<AgentInfo>> for AgentDiscoveryHandl
         query: CapabilityQuery,
     ) -> Result<Vec<AgentInfo>> {
         // Use the registry to find agents matching the query
-        let result = self.registry.read().await.find_agents(&query).await?;
+        let result = self.registry.find_agents(&query).await?;
 
         // Apply server-side result limits
         let mut agents = result;
@@ -984,7 +1456,7 @@ impl ContextDataHandler<CapabilityQuery, Vec<AgentInfo>> for AgentDiscoveryHandl
 impl HealthHandler {
     /// Create a new health handler
     pub fn new(
-        registry: Arc<RwLock<AgentRegistry>>,
+        registry: Arc<AgentRegistry>,
         client: Arc<A2AClient>,
         health_monitor: Arc<tokio::sync::Mutex<AgentHealthMonitor>>,
         config: HealthConfig,
@@ -1011,9 +1483,13 @@ impl HealthHandler {
             return Ok(HealthStatus::Unhealthy);
         }
 
-        // Would implement actual health check logic
-        // For now, return healthy
-        Ok(HealthStatus::Healthy)
+        // Report the health the agent last announced in its heartbeats
+        Ok(self
+            .registry
+            .get_agent_by_id(agent_id)
+            .await
+            .map(|agent| agent.health_status)
+            .unwrap_or(HealthStatus::Unknown))
     }
 
     /// Handle health status update
@@ -1054,6 +1530,40 @@ impl ContextDataHandler<HealthCheckRequest, HealthCheckResponse> for HealthHandl
     }
 }
 
+#[async_trait]
+impl ContextDataHandler<TaskIdParams, Option<A2ATask>> for TaskQueryHandler {
+    async fn handle(
+        &self,
+        context: Option<Context>,
+        params: TaskIdParams,
+    ) -> Result<Option<A2ATask>> {
+        let caller =
+            TaskOwner::authenticated(&self.authenticator, context.as_ref().map(Context::meta));
+        Ok(self.tasks.get_as(&params.task_id, &caller).await)
+    }
+}
+
+#[async_trait]
+impl ContextDataHandler<TaskIdParams, Option<A2ATask>> for TaskCancelHandler {
+    async fn handle(
+        &self,
+        context: Option<Context>,
+        params: TaskIdParams,
+    ) -> Result<Option<A2ATask>> {
+        tracing::info!("Cancel requested for task {}", params.task_id);
+        let caller =
+            TaskOwner::authenticated(&self.authenticator, context.as_ref().map(Context::meta));
+        Ok(self
+            .tasks
+            .cancel_as(
+                &params.task_id,
+                &caller,
+                Some("Canceled by caller".to_string()),
+            )
+            .await)
+    }
+}
+
 impl A2AServer {
     /// Create a new A2A server
     pub async fn new(config: A2AServerConfig) -> Result<Self> {
@@ -1065,11 +1575,12 @@ impl A2AServer {
                 enabled: true, // Enable discovery for A2A server operation
                 ..Default::default()
             },
+            jetstream: crate::config::nats::NatsJetStreamConfig::default(),
         })
         .await?;
 
         // Initialize components with proper config inheritance (CONFIG FIRST PRINCIPLE)
-        let registry = Arc::new(RwLock::new(
+        let registry = Arc::new(
             AgentRegistry::new(
                 config.registry.clone(),
                 crate::config::nats::NatsConfig {
@@ -1080,10 +1591,11 @@ impl A2AServer {
                         enabled: true, // Enable discovery for agent registry operation
                         ..Default::default()
                     },
+                    jetstream: crate::config::nats::NatsJetStreamConfig::default(),
                 },
             )
             .await?,
-        ));
+        );
         // Create A2A client config from server config (CONFIG FIRST PRINCIPLE)
         let client_config = crate::config::a2a::A2AClientConfig {
             client: crate::config::a2a::AgentClientConfig {
@@ -1104,11 +1616,15 @@ impl A2AServer {
             nats_client: config.nats_client.clone(),
             discovery_cache_ttl_ms: 300000, // 5 minutes default
         };
-        let client = Arc::new(A2AClient::new(client_config).await?);
         let router = Arc::new(CapabilityRouter::new(config.routing.clone()));
         let health_monitor = Arc::new(tokio::sync::Mutex::new(AgentHealthMonitor::new(
             config.health.clone(),
         )));
+        let client = Arc::new(
+            A2AClient::new(client_config)
+                .await?
+                .with_load_balancing(router.clone(), health_monitor.clone()),
+        );
 
         // Create request handlers
         let registration_handler = Arc::new(RegistrationHandler::new(
@@ -1144,6 +1660,8 @@ impl A2AServer {
             query_handler,
             discovery_handler,
             health_handler,
+            tasks: Arc::new(TaskStore::new()),
+            tenant_authenticator: TenantAuthenticator::default(),
             server_stats: Arc::new(RwLock::new(ServerStats {
                 uptime: Duration::from_secs(0),
                 total_requests: 0,
@@ -1164,6 +1682,21 @@ impl A2AServer {
         })
     }
 
+    /// Identify task callers by the tenant tokens `config.jwt_verification` verifies
+    ///
+    /// Applies to the NATS server and the [task HTTP router](Self::task_http_router).
+    /// Without verification every caller is anonymous and only sees tasks
+    /// submitted without an owner. Call before [`start`](Self::start).
+    #[cfg(feature = "tenant-extraction")]
+    pub fn with_tenant_extraction_config(
+        mut self,
+        config: &crate::tenant::extraction::ExtractionConfig,
+    ) -> Result<Self> {
+        self.tenant_authenticator = TenantAuthenticator::from_extraction_config(config)?;
+        self.nats_server = self.nats_server.with_tenant_extraction_config(config)?;
+        Ok(self)
+    }
+
     /// Start the A2A server
     pub async fn start(&mut self) -> Result<()> {
         // A2A server connects to existing NATS infrastructure, never starts its own
@@ -1292,6 +1825,34 @@ impl A2AServer {
             )
             .await?;
 
+        // Task lifecycle requests
+        tracing::info!(
+            "Registering handler for subject: {}",
+            crate::constants::subjects::A2A_TASKS_GET
+        );
+        self.nats_server
+            .handle(
+                crate::constants::subjects::A2A_TASKS_GET,
+                DefaultEnvelopeHandler::new(Arc::new(TaskQueryHandler {
+                    tasks: self.tasks.clone(),
+                    authenticator: self.tenant_authenticator.clone(),
+                })),
+            )
+            .await?;
+        tracing::info!(
+            "Registering handler for subject: {}",
+            crate::constants::subjects::A2A_TASKS_CANCEL
+        );
+        self.nats_server
+            .handle(
+                crate::constants::subjects::A2A_TASKS_CANCEL,
+                DefaultEnvelopeHandler::new(Arc::new(TaskCancelHandler {
+                    tasks: self.tasks.clone(),
+                    authenticator: self.tenant_authenticator.clone(),
+                })),
+            )
+            .await?;
+
         tracing::info!("All A2A server NATS handlers registered successfully");
         Ok(())
     }
@@ -1318,34 +1879,65 @@ impl A2AServer {
         tracing::info!("Starting request queue processing task");
         self.start_request_processing_task().await;
 
+        // Start streaming task updates to callers
+        tracing::info!("Starting task update forwarding task");
+        self.start_task_update_task().await;
+
         tracing::info!("All A2A server background tasks started successfully");
         Ok(())
     }
 
     /// Start registry cleanup background task
     async fn start_registry_cleanup_task(&self) {
-        let registry = self.registry.clone();
-        let cleanup_interval = self.config.registry.cleanup_interval;
+        self.registry.start_cleanup_task();
+    }
 
+    /// Start forwarding task updates to their NATS subjects and purging finished tasks
+    async fn start_task_update_task(&self) {
+        let tasks = self.tasks.clone();
+        let purge_interval = self.config.registry.cleanup_interval;
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(cleanup_interval);
+            let mut interval = tokio::time::interval(purge_interval);
             loop {
                 interval.tick().await;
-                // Perform registry cleanup - remove stale agents
-                match registry.write().await.cleanup_stale_agents().await {
-                    Ok(removed_count) => {
-                        if removed_count > 0 {
-                            tracing::info!(
-                                "Registry cleanup completed: removed {} stale agents",
-                                removed_count
-                            );
-                        } else {
-                            tracing::debug!("Registry cleanup completed: no stale agents found");
-                        }
-                    }
-                    Err(e) => {
-                        tracing::error!("Registry cleanup failed: {}", e);
+                let purged = tasks.purge_finished(FINISHED_TASK_RETENTION).await;
+                if purged > 0 {
+                    tracing::debug!("Purged {} finished tasks", purged);
+                }
+            }
+        });
+
+        let Some(nats_client) = self
+            .client
+            .transport()
+            .and_then(|transport| transport.internal_nats_client())
+            .cloned()
+        else {
+            tracing::warn!("NATS client not available, task updates will not be streamed");
+            return;
+        };
+        let mut updates = self.tasks.subscribe();
+        tokio::spawn(async move {
+            loop {
+                let update = match updates.recv().await {
+                    Ok(update) => update,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                        tracing::warn!("Task update forwarding missed {} updates", missed);
+                        continue;
                     }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                };
+                let subject = crate::constants::helpers::a2a_task_updates_subject(update.task_id());
+                let meta = crate::envelope::Meta {
+                    request_id: Some(uuid::Uuid::now_v7()),
+                    timestamp: Some(chrono::Utc::now()),
+                    ..Default::default()
+                };
+                if let Err(e) = nats_client
+                    .publish_envelope(&subject, crate::envelope::Envelope::new(meta, update))
+                    .await
+                {
+                    tracing::warn!("Failed to publish task update to {}: {}", subject, e);
                 }
             }
         });
@@ -1413,12 +2005,29 @@ impl A2AServer {
         });
     }
 
+    /// Task store of this server
+    ///
+    /// Agents submit incoming messages as tasks and report their progress here;
+    /// callers follow it with `tasks/get`, `tasks/cancel` and the task's updates
+    /// subject.
+    pub fn tasks(&self) -> Arc<TaskStore> {
+        self.tasks.clone()
+    }
+
+    /// HTTP router serving the task store to standard A2A clients
+    ///
+    /// See [`a2a_http`](crate::server::a2a_http) for the methods it answers.
+    #[cfg(all(feature = "a2a-standard", feature = "rest-server"))]
+    pub fn task_http_router(&self) -> axum::Router {
+        crate::server::a2a_http::task_router(self.tasks.clone(), self.tenant_authenticator.clone())
+    }
+
     /// Get comprehensive server statistics
     pub async fn get_server_stats(&self) -> Result<ServerStats> {
         let mut stats = self.server_stats.read().await.clone();
 
         // Update registry stats from AgentRegistry
-        stats.registry_stats = self.registry.read().await.get_stats().await?;
+        stats.registry_stats = self.registry.get_stats().await?;
 
         // Update current load (would be calculated based on various factors)
         stats.current_load = self.calculate_current_load().await;
@@ -1519,3 +2128,364 @@ impl Default for ServerStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::a2a::LoadBalancingStrategy;
+
+    fn agent(name: &str) -> AgentInfo {
+        AgentInfo {
+            id: uuid::Uuid::now_v7(),
+            name: name.to_string(),
+            capabilities: vec!["translate".to_string()],
+            health_status: HealthStatus::Healthy,
+            last_heartbeat: SystemTime::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn query(max_results: usize) -> CapabilityQuery {
+        CapabilityQuery {
+            required_capabilities: vec!["translate".to_string()],
+            preferred_capabilities: vec![],
+            exclude_agents: vec![],
+            max_results: Some(max_results),
+        }
+    }
+
+    fn preferences(strategy: LoadBalancingStrategy) -> RoutingPreferences {
+        RoutingPreferences {
+            load_balancing_strategy: strategy,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_robin_rotates_per_capability_set() {
+        let router = CapabilityRouter::new(RoutingConfig::default());
+        let agents = vec![agent("a"), agent("b"), agent("c")];
+        let prefs = preferences(LoadBalancingStrategy::RoundRobin);
+
+        let mut firsts = Vec::new();
+        for _ in 0..3 {
+            let decision = router
+                .route_capability(&query(1), agents.clone(), &prefs)
+                .await
+                .unwrap();
+            firsts.push(decision.selected_agents[0].id);
+        }
+        firsts.sort();
+        firsts.dedup();
+        assert_eq!(firsts.len(), 3);
+
+        // Another capability set starts its own rotation
+        let other = CapabilityQuery {
+            required_capabilities: vec!["summarize".to_string()],
+            ..query(1)
+        };
+        let mut sorted: Vec<AgentId> = agents.iter().map(|agent| agent.id).collect();
+        sorted.sort();
+        let decision = router
+            .route_capability(&other, agents.clone(), &prefs)
+            .await
+            .unwrap();
+        assert_eq!(decision.selected_agents[0].id, sorted[0]);
+    }
+
+    #[tokio::test]
+    async fn test_least_connections_prefers_idle_agents() {
+        let router = CapabilityRouter::new(RoutingConfig::default());
+        let busy = agent("busy");
+        let idle = agent("idle");
+        let prefs = preferences(LoadBalancingStrategy::LeastConnections);
+
+        let first = router.begin_request(busy.id);
+        let _second = router.begin_request(busy.id);
+        assert_eq!(router.in_flight_requests(&busy.id), 2);
+
+        let decision = router
+            .route_capability(&query(1), vec![busy.clone(), idle.clone()], &prefs)
+            .await
+            .unwrap();
+        assert_eq!(decision.selected_agents[0].id, idle.id);
+        assert_eq!(decision.fallback_agents[0].id, busy.id);
+
+        first.finish(true);
+        assert_eq!(router.in_flight_requests(&busy.id), 1);
+    }
+
+    #[tokio::test]
+    async fn test_latency_weighted_favours_fast_agents() {
+        let router = CapabilityRouter::new(RoutingConfig::default());
+        let fast = agent("fast");
+        let slow = agent("slow");
+        router.record_latency(fast.id, Duration::from_millis(10));
+        router.record_performance(
+            slow.id,
+            PerformanceMetrics {
+                cpu_usage: 0.5,
+                memory_usage: 0.5,
+                active_tasks: 1,
+                max_tasks: 10,
+                average_response_time_ms: 1000.0,
+            },
+        );
+        assert_eq!(router.estimated_latency_ms(&fast), 10.0);
+        assert_eq!(router.estimated_latency_ms(&slow), 1000.0);
+
+        let prefs = preferences(LoadBalancingStrategy::LatencyWeighted);
+        let mut fast_selected = 0;
+        for _ in 0..200 {
+            let decision = router
+                .route_capability(&query(1), vec![slow.clone(), fast.clone()], &prefs)
+                .await
+                .unwrap();
+            if decision.selected_agents[0].id == fast.id {
+                fast_selected += 1;
+            }
+        }
+        assert!(
+            fast_selected > 150,
+            "fast agent selected {fast_selected} times"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preferred_agents_first_and_rest_become_fallbacks() {
+        let router = CapabilityRouter::new(RoutingConfig::default());
+        let agents = vec![agent("a"), agent("b"), agent("c")];
+        let prefs = RoutingPreferences {
+            preferred_agents: vec![agents[2].id],
+            ..preferences(LoadBalancingStrategy::HealthBased)
+        };
+
+        let decision = router
+            .route_capability(&query(1), agents.clone(), &prefs)
+            .await
+            .unwrap();
+        assert_eq!(decision.selected_agents.len(), 1);
+        assert_eq!(decision.selected_agents[0].id, agents[2].id);
+        assert_eq!(decision.fallback_agents.len(), 2);
+        assert_eq!(decision.estimated_response_time_ms, Some(50));
+    }
+
+    fn message(task_id: Option<&str>) -> A2AMessage {
+        A2AMessage {
+            role: "user".to_string(),
+            parts: vec![],
+            message_id: uuid::Uuid::now_v7().to_string(),
+            task_id: task_id.map(str::to_string),
+            context_id: Some("conversation".to_string()),
+            kind: "message".to_string(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_lifecycle_streams_updates() {
+        let store = TaskStore::new();
+        let mut updates = store.subscribe();
+
+        let task = store.submit(&message(None)).await.unwrap();
+        assert_eq!(task.status.state, TaskState::Submitted);
+        assert_eq!(task.context_id.as_deref(), Some("conversation"));
+
+        store
+            .update_status(&task.id, TaskState::Working, None)
+            .await
+            .unwrap();
+        store
+            .add_artifact(
+                &task.id,
+                TaskArtifact {
+                    artifact_id: "result".to_string(),
+                    name: Some("answer".to_string()),
+                    data: serde_json::json!({"answer": 42}),
+                    append: false,
+                    last_chunk: true,
+                },
+            )
+            .await
+            .unwrap();
+        let done = store
+            .update_status(&task.id, TaskState::Completed, Some("done".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(done.history.len(), 3);
+        assert_eq!(done.artifacts.len(), 1);
+
+        let mut kinds = Vec::new();
+        while let Ok(update) = updates.try_recv() {
+            assert_eq!(update.task_id(), task.id);
+            kinds.push(match update {
+                TaskUpdate::Status { status, .. } => format!("{:?}", status.state),
+                TaskUpdate::Artifact { .. } => "Artifact".to_string(),
+            });
+        }
+        assert_eq!(kinds, ["Submitted", "Working", "Artifact", "Completed"]);
+
+        // Terminal tasks accept no further changes
+        assert!(store
+            .update_status(&task.id, TaskState::Working, None)
+            .await
+            .is_err());
+        assert!(store.submit(&message(Some(&task.id))).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_task_input_required_resumes_on_message() {
+        let store = TaskStore::new();
+        let task = store.submit(&message(Some("task-1"))).await.unwrap();
+        assert_eq!(task.id, "task-1");
+
+        store
+            .update_status("task-1", TaskState::InputRequired, None)
+            .await
+            .unwrap();
+        let resumed = store.submit(&message(Some("task-1"))).await.unwrap();
+        assert_eq!(resumed.status.state, TaskState::Working);
+
+        assert_eq!(
+            serde_json::to_value(TaskState::InputRequired).unwrap(),
+            "input-required"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_task_cancel_notifies_watchers() {
+        let store = TaskStore::new();
+        let task = store.submit(&message(None)).await.unwrap();
+        let mut state = store.watch(&task.id).await.unwrap();
+
+        let canceled = store
+            .cancel(&task.id, Some("no longer needed".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(canceled.status.state, TaskState::Canceled);
+        assert!(state.has_changed().unwrap());
+        assert_eq!(*state.borrow_and_update(), TaskState::Canceled);
+
+        // Canceling again leaves the finished task unchanged
+        let again = store.cancel(&task.id, None).await.unwrap();
+        assert_eq!(again.history.len(), canceled.history.len());
+        assert!(store.cancel("unknown", None).await.is_none());
+
+        assert_eq!(store.purge_finished(Duration::from_secs(3600)).await, 0);
+        assert_eq!(store.purge_finished(Duration::ZERO).await, 1);
+        assert!(store.get(&task.id).await.is_none());
+    }
+
+    #[cfg(feature = "tenant-extraction")]
+    fn verifying_authenticator() -> TenantAuthenticator {
+        TenantAuthenticator::from_extraction_config(&crate::tenant::extraction::ExtractionConfig {
+            jwt_verification: Some(crate::tenant::JwtVerificationConfig::new(
+                crate::tenant::JwtKeySource::Secret {
+                    secret: "starfleet-secret".to_string(),
+                },
+            )),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[cfg(feature = "tenant-extraction")]
+    #[tokio::test]
+    async fn test_task_cancel_is_limited_to_its_owner() {
+        let tasks = Arc::new(TaskStore::new());
+        let owner_meta = Meta {
+            tenant: Some("enterprise".to_string()),
+            security: Some(crate::envelope::SecurityMeta {
+                user_id: Some("picard".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let owner = TaskOwner::from_meta(&owner_meta);
+        let task = tasks
+            .submit_for(&message(None), owner.clone())
+            .await
+            .unwrap();
+        let cancel = TaskCancelHandler {
+            tasks: tasks.clone(),
+            authenticator: verifying_authenticator(),
+        };
+        let params = || TaskIdParams {
+            task_id: task.id.clone(),
+        };
+
+        // ARRANGE: callers from another tenant, another user, or without identity
+        let intruders = [
+            Some(Meta {
+                tenant: Some("borg".to_string()),
+                ..owner_meta.clone()
+            }),
+            Some(Meta {
+                security: None,
+                ..owner_meta.clone()
+            }),
+            None,
+        ];
+
+        // ACT / ASSERT: none of them can see or cancel the task
+        for meta in intruders {
+            let context = meta.map(Context::new);
+            assert!(cancel.handle(context, params()).await.unwrap().is_none());
+        }
+        let stranger = TaskOwner::default();
+        assert!(tasks.get_as(&task.id, &stranger).await.is_none());
+        assert!(tasks
+            .submit_for(&message(Some(&task.id)), stranger)
+            .await
+            .is_err());
+        assert_eq!(
+            tasks.get(&task.id).await.unwrap().status.state,
+            TaskState::Submitted
+        );
+
+        // The owner can
+        let canceled = cancel
+            .handle(Some(Context::new(owner_meta)), params())
+            .await
+            .unwrap()
+            .expect("the owner should cancel its task");
+        assert_eq!(canceled.status.state, TaskState::Canceled);
+        assert!(tasks.get_as(&task.id, &owner).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unverified_task_callers_are_anonymous() {
+        let tasks = Arc::new(TaskStore::new());
+        let task = tasks
+            .submit_for(
+                &message(None),
+                TaskOwner {
+                    tenant: Some("enterprise".to_string()),
+                    user_id: None,
+                },
+            )
+            .await
+            .unwrap();
+        let query = TaskQueryHandler {
+            tasks: tasks.clone(),
+            authenticator: TenantAuthenticator::default(),
+        };
+
+        // A tenant claimed in unverified metadata does not identify the caller
+        let claimed = Meta {
+            tenant: Some("enterprise".to_string()),
+            ..Default::default()
+        };
+        let found = query
+            .handle(
+                Some(Context::new(claimed)),
+                TaskIdParams {
+                    task_id: task.id.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(found.is_none());
+    }
+}
@@ -0,0 +1,166 @@
+// ABOUTME: Standard A2A JSON-RPC task endpoint served over HTTP from the A2A task store
+// ABOUTME: Answers tasks/get and tasks/cancel and streams tasks/resubscribe as server-sent events
+
+//! HTTP endpoint for A2A tasks.
+//!
+//! Standard A2A clients reach the tasks of an [`A2AServer`](super::A2AServer)
+//! through a single JSON-RPC route:
+//! - `tasks/get` and `tasks/cancel` answer with the task
+//! - `tasks/resubscribe` answers with a `text/event-stream` carrying the
+//!   task's current status followed by every status and artifact update,
+//!   ending after the final update
+//!
+//! Callers are identified by the tenant token in their `Authorization` header,
+//! verified by the [`TenantAuthenticator`]; requests with a token that fails
+//! verification are refused. Tasks owned by another caller are reported as not
+//! found.
+
+use super::a2a::{TaskOwner, TaskStore};
+use super::common::TenantAuthenticator;
+use crate::types::a2a::{standard, A2ATask, TaskUpdate};
+use a2a_rs::application::{parse_request, A2ARequest, JSONRPCError, JSONRPCResponse};
+use a2a_rs::A2AError;
+use axum::extract::State;
+use axum::http::{header::AUTHORIZATION, HeaderMap};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::Value;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Updates buffered per `tasks/resubscribe` stream before the slow client is dropped
+const RESUBSCRIBE_BUFFER: usize = 64;
+
+/// Router serving the JSON-RPC task methods at `/`
+///
+/// Callers are identified by the tokens `authenticator` verifies; while it is
+/// inactive every caller is anonymous.
+pub fn task_router(tasks: Arc<TaskStore>, authenticator: TenantAuthenticator) -> Router {
+    Router::new()
+        .route("/", post(handle_task_request))
+        .with_state((tasks, authenticator))
+}
+
+async fn handle_task_request(
+    State((tasks, authenticator)): State<(Arc<TaskStore>, TenantAuthenticator)>,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
+    let request = match parse_request(&body) {
+        Ok(request) => request,
+        Err(e) => return rpc_error(None, A2AError::InvalidRequest(e.to_string())),
+    };
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let caller = match authenticator.authenticate(authorization) {
+        Ok(info) => info.as_ref().map(TaskOwner::from_token).unwrap_or_default(),
+        Err(e) => {
+            return rpc_error(
+                request.id().cloned(),
+                A2AError::InvalidRequest(e.to_string()),
+            )
+        }
+    };
+
+    match request {
+        A2ARequest::GetTask(request) => {
+            let task = tasks.get_as(&request.params.id, &caller).await;
+            task_response(request.id, &request.params.id, task)
+        }
+        A2ARequest::CancelTask(request) => {
+            let task = tasks
+                .cancel_as(
+                    &request.params.id,
+                    &caller,
+                    Some("Canceled by caller".to_string()),
+                )
+                .await;
+            task_response(request.id, &request.params.id, task)
+        }
+        A2ARequest::TaskResubscription(request) => {
+            // Subscribe before reading the task so no update is missed in between
+            let updates = tasks.subscribe();
+            match tasks.get_as(&request.params.id, &caller).await {
+                Some(task) => resubscribe(request.id, task, updates).into_response(),
+                None => rpc_error(request.id, A2AError::TaskNotFound(request.params.id)),
+            }
+        }
+        other => {
+            let method = other.method().to_string();
+            rpc_error(other.id().cloned(), A2AError::MethodNotFound(method))
+        }
+    }
+}
+
+fn task_response(id: Option<Value>, task_id: &str, task: Option<A2ATask>) -> Response {
+    match task {
+        Some(task) => {
+            let result =
+                serde_json::to_value(standard::task_to_standard(&task)).unwrap_or_default();
+            Json(JSONRPCResponse::success(id, result)).into_response()
+        }
+        None => rpc_error(id, A2AError::TaskNotFound(task_id.to_string())),
+    }
+}
+
+fn rpc_error(id: Option<Value>, error: A2AError) -> Response {
+    Json(JSONRPCResponse::error(id, JSONRPCError::from(error))).into_response()
+}
+
+/// Stream the current status of `task` and then its updates until the final one
+fn resubscribe(
+    id: Option<Value>,
+    task: A2ATask,
+    mut updates: tokio::sync::broadcast::Receiver<TaskUpdate>,
+) -> impl IntoResponse {
+    let (sender, receiver) =
+        tokio::sync::mpsc::channel::<Result<Event, Infallible>>(RESUBSCRIBE_BUFFER);
+    let context_id = task.context_id.clone().unwrap_or_default();
+    let current = TaskUpdate::Status {
+        task_id: task.id.clone(),
+        is_final: task.status.state.is_terminal(),
+        status: task.status,
+    };
+
+    tokio::spawn(async move {
+        let event = |update: &TaskUpdate| {
+            let result = standard::update_to_standard(update, &context_id);
+            Event::default()
+                .json_data(JSONRPCResponse::success(id.clone(), result))
+                .map_err(|e| tracing::warn!("Failed to encode task update event: {}", e))
+        };
+
+        let mut next = Some(current);
+        while let Some(update) = next.take() {
+            if let Ok(event) = event(&update) {
+                if sender.send(Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+            if update.is_final() {
+                return;
+            }
+            while next.is_none() {
+                // Stop once the client disconnects, even if the task never finishes
+                let received = tokio::select! {
+                    received = updates.recv() => received,
+                    _ = sender.closed() => return,
+                };
+                match received {
+                    Ok(update) if update.task_id() == task.id => next = Some(update),
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                        tracing::warn!("Task {} stream missed {} updates", task.id, missed);
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+                }
+            }
+        }
+    });
+
+    Sse::new(ReceiverStream::new(receiver)).keep_alive(KeepAlive::default())
+}
@@ -4,6 +4,14 @@
 //! Common server traits and utilities for protocol abstraction.
 
 use crate::constants::{limits, network};
+use crate::envelope::{HttpRequest, JwtProcessor, JwtTenantInfo, Meta, SecurityMeta};
+use crate::error::{QollectiveError, Result};
+#[cfg(feature = "security")]
+use crate::security::BearerTokenValidator;
+#[cfg(feature = "tenant-extraction")]
+use crate::tenant::extraction::ExtractionConfig;
+#[cfg(feature = "security")]
+use std::sync::Arc;
 
 /// Server configuration
 #[derive(Debug, Clone)]
@@ -60,3 +68,251 @@ impl Default for ServerBuilder {
         Self::new()
     }
 }
+
+/// Verifies the bearer token of incoming requests and supplies the tenant it carries
+///
+/// An inactive authenticator, the default, leaves the tenant callers claim in
+/// their metadata untouched.
+#[derive(Clone, Default)]
+pub struct TenantAuthenticator {
+    processor: Option<JwtProcessor>,
+}
+
+impl std::fmt::Debug for TenantAuthenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantAuthenticator")
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+impl TenantAuthenticator {
+    /// Verify tokens as `config.jwt_verification` describes
+    ///
+    /// Fails if the verification keys cannot be loaded. Without
+    /// `jwt_verification` the authenticator stays inactive, since an unverified
+    /// token proves nothing about its tenant.
+    #[cfg(feature = "tenant-extraction")]
+    pub fn from_extraction_config(config: &ExtractionConfig) -> Result<Self> {
+        if !config.enabled || config.jwt_verification.is_none() {
+            return Ok(Self::default());
+        }
+        let processor = JwtProcessor::from_extraction_config(config)
+            .map_err(|e| QollectiveError::config(format!("JWT verification unavailable: {}", e)))?;
+        Ok(Self {
+            processor: Some(processor),
+        })
+    }
+
+    /// Whether tenants are taken from verified tokens
+    pub fn is_active(&self) -> bool {
+        self.processor.is_some()
+    }
+
+    /// Verify the token in an `Authorization` header value
+    ///
+    /// Returns `None` when inactive or when no header was sent.
+    pub fn authenticate(&self, authorization: Option<&str>) -> Result<Option<JwtTenantInfo>> {
+        let (Some(processor), Some(authorization)) = (&self.processor, authorization) else {
+            return Ok(None);
+        };
+        let request = HttpRequest::new().with_header("authorization", authorization);
+        processor
+            .extract_from_request(&request)
+            .map_err(|e| QollectiveError::security(format!("Tenant token rejected: {}", e)))
+    }
+
+    /// Replace the tenant and user claimed in `meta` with those of the verified token
+    ///
+    /// While active, requests without a token lose the tenant and user id they
+    /// claimed.
+    pub fn apply(&self, authorization: Option<&str>, meta: &mut Meta) -> Result<()> {
+        if self.is_active() {
+            let info = self.authenticate(authorization)?;
+            let (tenant, user_id) =
+                info.map_or((None, None), |info| (info.tenant_key, info.subject));
+            meta.tenant = tenant;
+            match meta.security.as_mut() {
+                Some(security) => security.user_id = user_id,
+                None if user_id.is_some() => {
+                    meta.security = Some(SecurityMeta {
+                        user_id,
+                        ..Default::default()
+                    })
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validates the bearer token of incoming requests and records the identity it establishes
+///
+/// An inactive authenticator, the default, accepts every request.
+#[cfg(feature = "security")]
+#[derive(Clone, Default)]
+pub struct BearerAuthenticator {
+    validator: Option<Arc<dyn BearerTokenValidator>>,
+}
+
+#[cfg(feature = "security")]
+impl std::fmt::Debug for BearerAuthenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerAuthenticator")
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+#[cfg(feature = "security")]
+impl BearerAuthenticator {
+    /// Require every request to carry a bearer token `validator` accepts
+    pub fn new(validator: Arc<dyn BearerTokenValidator>) -> Self {
+        Self {
+            validator: Some(validator),
+        }
+    }
+
+    /// Whether requests must carry a valid bearer token
+    pub fn is_active(&self) -> bool {
+        self.validator.is_some()
+    }
+
+    /// Validate the token in an `Authorization` header value and record its identity in `meta`
+    ///
+    /// While active, requests without a bearer token or with one the validator
+    /// rejects fail with a security error. The token's subject, scopes and
+    /// validation method replace the user id, permissions and auth method
+    /// claimed in `meta`.
+    pub async fn apply(&self, authorization: Option<&str>, meta: &mut Meta) -> Result<()> {
+        let Some(validator) = &self.validator else {
+            return Ok(());
+        };
+        let token = authorization
+            .and_then(|value| value.split_once(' '))
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+            .map(|(_, token)| token.trim())
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| QollectiveError::security("Bearer token required"))?;
+        let identity = validator
+            .validate_bearer(token)
+            .await
+            .map_err(|e| QollectiveError::security(format!("Bearer token rejected: {}", e)))?;
+
+        let security = meta.security.get_or_insert_with(SecurityMeta::default);
+        if identity.subject.is_some() {
+            security.user_id = identity.subject;
+        }
+        security.permissions = identity.scopes;
+        security.auth_method = Some(identity.auth_method);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "tenant-extraction"))]
+mod tests {
+    use super::*;
+    use crate::tenant::{JwtKeySource, JwtVerificationConfig};
+
+    fn verifying_authenticator() -> TenantAuthenticator {
+        TenantAuthenticator::from_extraction_config(&ExtractionConfig {
+            jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+                secret: "starfleet-secret".to_string(),
+            })),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn bearer(secret: &[u8]) -> String {
+        let claims = serde_json::json!({
+            "sub": "picard",
+            "tenantkey": "enterprise",
+            "exp": jsonwebtoken::get_current_timestamp() + 600,
+        });
+        let token = jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(secret),
+        )
+        .unwrap();
+        format!("Bearer {}", token)
+    }
+
+    #[test]
+    fn test_authenticator_replaces_claimed_tenant_with_verified_one() {
+        // ARRANGE
+        let authenticator = verifying_authenticator();
+        let mut meta = Meta {
+            tenant: Some("romulan".to_string()),
+            ..Default::default()
+        };
+
+        // ACT
+        authenticator
+            .apply(Some(&bearer(b"starfleet-secret")), &mut meta)
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(meta.tenant.as_deref(), Some("enterprise"));
+        assert_eq!(
+            meta.security
+                .and_then(|security| security.user_id)
+                .as_deref(),
+            Some("picard")
+        );
+    }
+
+    #[test]
+    fn test_authenticator_rejects_forged_token() {
+        // ARRANGE
+        let authenticator = verifying_authenticator();
+        let mut meta = Meta::default();
+
+        // ACT
+        let result = authenticator.apply(Some(&bearer(b"attacker-secret")), &mut meta);
+
+        // ASSERT
+        assert!(matches!(result, Err(QollectiveError::Security(_))));
+    }
+
+    #[test]
+    fn test_authenticator_drops_claimed_identity_without_token() {
+        // ARRANGE
+        let authenticator = verifying_authenticator();
+        let mut meta = Meta {
+            tenant: Some("romulan".to_string()),
+            security: Some(SecurityMeta {
+                user_id: Some("sela".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // ACT
+        authenticator.apply(None, &mut meta).unwrap();
+
+        // ASSERT
+        assert_eq!(meta.tenant, None);
+        assert_eq!(meta.security.unwrap().user_id, None);
+    }
+
+    #[test]
+    fn test_authenticator_without_verification_is_inactive() {
+        // ARRANGE
+        let authenticator =
+            TenantAuthenticator::from_extraction_config(&ExtractionConfig::default()).unwrap();
+        let mut meta = Meta {
+            tenant: Some("enterprise".to_string()),
+            ..Default::default()
+        };
+
+        // ACT
+        authenticator.apply(None, &mut meta).unwrap();
+
+        // ASSERT
+        assert!(!authenticator.is_active());
+        assert_eq!(meta.tenant.as_deref(), Some("enterprise"));
+    }
+}
@@ -11,26 +11,35 @@
 use {
     crate::constants::env_vars,
     crate::{
-        envelope::{meta::ExtensionsMeta, Envelope},
+        envelope::{
+            deadline,
+            meta::{ExtensionsMeta, OutgoingMetaPolicy, SpanKind},
+            trace_context, Envelope, MetaEnrichers, TracingMeta,
+        },
         error::{QollectiveError, Result},
         generated::qollective::{
             qollective_service_server::{QollectiveService, QollectiveServiceServer},
             Envelope as ProtoEnvelope, HealthCheckRequest, HealthCheckResponse,
         },
         server::common::ServerConfig,
+        server::shutdown::{self, RequestGuard, ShutdownCoordinator},
         traits::handlers::ContextDataHandler,
         traits::receivers::UnifiedEnvelopeReceiver,
+        transport::grpc::{handler_type_key, normalize_grpc_route, EnvelopeStream},
     },
     async_trait::async_trait,
-    futures_util::stream,
+    chrono::{DateTime, Utc},
     serde::{Deserialize, Serialize},
     // tokio_stream::wrappers::ReceiverStream,
     std::{net::SocketAddr, pin::Pin, sync::Arc},
     tokio::sync::{broadcast, RwLock},
     tokio_stream::{Stream, StreamExt},
-    tonic::{transport::Server, Code, Request, Response, Status},
+    tonic::{metadata::MetadataMap, transport::Server, Code, Request, Response, Status},
 };
 
+#[cfg(all(feature = "grpc-server", feature = "security"))]
+use crate::{envelope::Meta, security::BearerTokenValidator, server::common::BearerAuthenticator};
+
 #[cfg(feature = "grpc-server")]
 use {
     tonic::service::Interceptor,
@@ -53,6 +62,9 @@ pub struct GrpcServer {
     reflection_enabled: bool,
     tls_config: Option<TlsConfig>,
     tenant_extraction_enabled: bool,
+    #[cfg(feature = "tenant-extraction")]
+    tenant_extractor: Option<Arc<crate::envelope::UnifiedTenantExtractor>>,
+    shutdown: Option<ShutdownCoordinator>,
 }
 
 /// TLS configuration for gRPC server
@@ -71,6 +83,8 @@ pub struct TlsConfig {
 pub struct EnvelopeInterceptor {
     grpc_middleware: crate::server::middleware::GrpcServerMiddleware,
     tenant_extraction_enabled: bool,
+    #[cfg(feature = "tenant-extraction")]
+    tenant_extractor: Arc<crate::envelope::UnifiedTenantExtractor>,
 }
 
 #[cfg(feature = "grpc-server")]
@@ -81,6 +95,8 @@ impl EnvelopeInterceptor {
             tenant_extraction_enabled: std::env::var(env_vars::QOLLECTIVE_TENANT_EXTRACTION)
                 .map(|v| v.parse().unwrap_or(false))
                 .unwrap_or(false),
+            #[cfg(feature = "tenant-extraction")]
+            tenant_extractor: Arc::new(Self::default_tenant_extractor()),
         }
     }
 
@@ -90,6 +106,8 @@ impl EnvelopeInterceptor {
             tenant_extraction_enabled: std::env::var(env_vars::QOLLECTIVE_TENANT_EXTRACTION)
                 .map(|v| v.parse().unwrap_or(false))
                 .unwrap_or(false),
+            #[cfg(feature = "tenant-extraction")]
+            tenant_extractor: Arc::new(Self::default_tenant_extractor()),
         }
     }
 
@@ -98,6 +116,23 @@ impl EnvelopeInterceptor {
         self.grpc_middleware.set_tenant_extraction_enabled(enabled);
         self
     }
+
+    /// Extract tenants with `extractor` instead of the default unverified one
+    #[cfg(feature = "tenant-extraction")]
+    pub fn with_tenant_extractor(
+        mut self,
+        extractor: Arc<crate::envelope::UnifiedTenantExtractor>,
+    ) -> Self {
+        self.tenant_extractor = extractor;
+        self
+    }
+
+    #[cfg(feature = "tenant-extraction")]
+    fn default_tenant_extractor() -> crate::envelope::UnifiedTenantExtractor {
+        let mut extractor = crate::envelope::UnifiedTenantExtractor::new();
+        extractor.set_enabled(true);
+        extractor
+    }
 }
 
 #[cfg(feature = "grpc-server")]
@@ -110,8 +145,6 @@ impl Default for EnvelopeInterceptor {
 #[cfg(feature = "grpc-server")]
 impl Interceptor for EnvelopeInterceptor {
     fn call(&mut self, mut request: Request<()>) -> std::result::Result<Request<()>, Status> {
-        #[cfg(feature = "tenant-extraction")]
-        use crate::envelope::UnifiedTenantExtractor;
         use crate::envelope::{middleware::ContextMiddleware, Context};
         use crate::server::middleware::utils::extract_context_from_tonic_request;
 
@@ -133,16 +166,18 @@ impl Interceptor for EnvelopeInterceptor {
         // Enhanced tenant extraction if enabled
         #[cfg(feature = "tenant-extraction")]
         if self.tenant_extraction_enabled {
-            let mut tenant_extractor = UnifiedTenantExtractor::new();
-            tenant_extractor.set_enabled(true);
-
             // Process context with advanced tenant extraction using unified extractor
-            if let Ok(enhanced_context) = crate::envelope::unified_tenant_extraction::grpc::process_grpc_context_with_tenant_extraction(
-                &tenant_extractor,
+            match crate::envelope::unified_tenant_extraction::grpc::process_grpc_context_with_tenant_extraction(
+                &self.tenant_extractor,
                 &processed_context,
                 request.metadata(),
             ) {
-                processed_context = enhanced_context;
+                Ok(enhanced_context) => processed_context = enhanced_context,
+                // Verified extraction fails closed instead of serving the call untenanted
+                Err(e) if self.tenant_extractor.verifies_jwt() => {
+                    return Err(Status::unauthenticated(e.to_string()));
+                }
+                Err(_) => {}
             }
         }
 
@@ -198,9 +233,21 @@ impl GrpcServer {
             tenant_extraction_enabled: std::env::var(env_vars::QOLLECTIVE_TENANT_EXTRACTION)
                 .map(|v| v.parse().unwrap_or(false))
                 .unwrap_or(false),
+            #[cfg(feature = "tenant-extraction")]
+            tenant_extractor: None,
+            shutdown: None,
         }
     }
 
+    /// Drain this server with `coordinator`
+    ///
+    /// Once draining starts new calls fail with `UNAVAILABLE`, the listener is
+    /// closed and [`serve`](Self::serve) returns when draining is over.
+    pub fn with_shutdown(mut self, coordinator: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(coordinator);
+        self
+    }
+
     /// Configure TLS for the server (must be called before serve())
     pub fn with_tls(mut self, tls_config: TlsConfig) -> Self {
         self.tls_config = Some(tls_config);
@@ -219,6 +266,21 @@ impl GrpcServer {
         self
     }
 
+    /// Extract tenants as `config` describes, verifying JWTs when it sets `jwt_verification`
+    ///
+    /// Fails if the verification keys cannot be loaded, so a misconfigured
+    /// server never falls back to trusting unverified tokens.
+    #[cfg(feature = "tenant-extraction")]
+    pub fn with_tenant_extraction_config(
+        mut self,
+        config: crate::tenant::extraction::ExtractionConfig,
+    ) -> Result<Self> {
+        self.tenant_extraction_enabled = config.enabled;
+        let extractor = crate::envelope::UnifiedTenantExtractor::try_with_config(config)?;
+        self.tenant_extractor = Some(Arc::new(extractor));
+        Ok(self)
+    }
+
     /// Register a service implementation
     pub async fn register_service(&self, service: QollectiveServiceImpl) -> Result<()> {
         let mut service_lock = self.service.write().await;
@@ -226,6 +288,56 @@ impl GrpcServer {
         Ok(())
     }
 
+    /// Register a server streaming handler on the registered service
+    ///
+    /// The handler is keyed by its request and response types, which is how
+    /// `GrpcClient::send_server_streaming` selects it.
+    pub async fn register_server_streaming_handler<T, R, H>(&self, handler: H) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: ServerStreamingHandler<T, R> + 'static,
+    {
+        self.registered_service()
+            .await?
+            .register_server_streaming_handler(handler_type_key::<T, R>(), handler)
+            .await
+    }
+
+    /// Register a client streaming handler on the registered service
+    pub async fn register_client_streaming_handler<T, R, H>(&self, handler: H) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: ClientStreamingHandler<T, R> + 'static,
+    {
+        self.registered_service()
+            .await?
+            .register_client_streaming_handler(handler_type_key::<T, R>(), handler)
+            .await
+    }
+
+    /// Register a bidirectional streaming handler on the registered service
+    pub async fn register_bidirectional_streaming_handler<T, R, H>(&self, handler: H) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: BidirectionalStreamingHandler<T, R> + 'static,
+    {
+        self.registered_service()
+            .await?
+            .register_bidirectional_streaming_handler(handler_type_key::<T, R>(), handler)
+            .await
+    }
+
+    async fn registered_service(&self) -> Result<Arc<QollectiveServiceImpl>> {
+        self.service
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| QollectiveError::internal("gRPC service not initialized"))
+    }
+
     /// Start the gRPC server following the correct TLS setup order
     pub async fn serve(&self) -> Result<()> {
         let addr: SocketAddr = format!("{}:{}", self.config.bind_address, self.config.port)
@@ -239,7 +351,8 @@ impl GrpcServer {
             .clone();
         drop(service_guard);
 
-        let service_impl = service.as_ref().clone();
+        let mut service_impl = service.as_ref().clone();
+        service_impl.shutdown = self.shutdown.clone();
 
         let mut shutdown_rx = self
             .shutdown_tx
@@ -278,6 +391,11 @@ impl GrpcServer {
         // STEP 5: Add routes/services to the TLS-configured builder with interceptors
         let interceptor =
             EnvelopeInterceptor::new().with_tenant_extraction(self.tenant_extraction_enabled);
+        #[cfg(feature = "tenant-extraction")]
+        let interceptor = match &self.tenant_extractor {
+            Some(extractor) => interceptor.with_tenant_extractor(extractor.clone()),
+            None => interceptor,
+        };
         let router = server_builder.add_service(QollectiveServiceServer::with_interceptor(
             service_impl,
             interceptor,
@@ -304,14 +422,22 @@ impl GrpcServer {
         };
 
         // STEP 6: Build and serve with the configured router
+        let coordinator = self.shutdown.clone();
         let server = router.serve_with_shutdown(addr, async move {
-            let _ = shutdown_rx.recv().await;
+            tokio::select! {
+                _ = shutdown_rx.recv() => {}
+                _ = shutdown::until_draining(coordinator.as_ref()) => {}
+            }
             tracing::info!("Graceful shutdown initiated");
         });
 
-        server
-            .await
-            .map_err(|e| QollectiveError::internal(&format!("gRPC server error: {}", e)))
+        // Connections still open when draining ends are not waited for
+        tokio::select! {
+            result = server => {
+                result.map_err(|e| QollectiveError::internal(format!("gRPC server error: {}", e)))
+            }
+            _ = shutdown::until_stopped(self.shutdown.as_ref()) => Ok(()),
+        }
     }
 
     /// Configure TLS for the server builder (following correct order)
@@ -385,11 +511,7 @@ impl UnifiedEnvelopeReceiver for GrpcServer {
         // Get the service instance and register the handler
         let service_guard = self.service.read().await;
         if let Some(service) = service_guard.as_ref() {
-            let type_key = format!(
-                "{}:{}",
-                std::any::type_name::<T>(),
-                std::any::type_name::<R>()
-            );
+            let type_key = handler_type_key::<T, R>();
             service.register_handler(type_key, handler).await?;
             Ok(())
         } else {
@@ -397,25 +519,75 @@ impl UnifiedEnvelopeReceiver for GrpcServer {
         }
     }
 
-    /// Receive and process envelopes at a specific gRPC method route.
+    /// Receive and process envelopes at a specific route.
     ///
-    /// For gRPC, the route corresponds to the service method name
-    /// (e.g., "/qollective.v1.QollectiveService/UnaryCall").
-    async fn receive_envelope_at<T, R, H>(&mut self, route: &str, _handler: H) -> Result<()>
+    /// Clients select the route through the `x-qollective-route` metadata, either
+    /// explicitly or from the endpoint path (e.g. `grpc://server:50051/orders/create`).
+    /// Calls naming an unregistered route are rejected with `Code::NotFound`; only
+    /// calls without a route go to the handlers registered through `receive_envelope`.
+    async fn receive_envelope_at<T, R, H>(&mut self, route: &str, handler: H) -> Result<()>
     where
         T: for<'de> Deserialize<'de> + Send + 'static,
         R: Serialize + Send + 'static,
         H: ContextDataHandler<T, R> + Send + Sync + 'static,
     {
-        // For now, return an error indicating this needs full implementation
-        // This follows the TDD pattern - make the test fail first, then implement
-        tracing::info!("Attempting to register gRPC handler at route: {}", route);
-        Err(QollectiveError::internal(
-            "gRPC route-based envelope handling not yet fully implemented",
-        ))
+        let service_guard = self.service.read().await;
+        if let Some(service) = service_guard.as_ref() {
+            tracing::info!("Registering gRPC handler at route: {}", route);
+            service.register_route_handler(route, handler).await
+        } else {
+            Err(QollectiveError::internal("gRPC service not initialized"))
+        }
     }
 }
 
+/// Handler for server streaming calls: one request envelope in, a stream of envelopes out.
+///
+/// Useful for pushing progress updates or large result sets in chunks.
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+pub trait ServerStreamingHandler<T, R>: Send + Sync
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+{
+    /// Handle a request envelope and return the stream of response envelopes.
+    async fn handle(&self, request: Envelope<T>) -> Result<EnvelopeStream<R>>;
+}
+
+/// Handler for client streaming calls: a stream of request envelopes in, one envelope out.
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+pub trait ClientStreamingHandler<T, R>: Send + Sync
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+{
+    /// Consume the request envelope stream and return a single response envelope.
+    async fn handle(&self, requests: EnvelopeStream<T>) -> Result<Envelope<R>>;
+}
+
+/// Handler for bidirectional streaming calls: envelope stream in, envelope stream out.
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+pub trait BidirectionalStreamingHandler<T, R>: Send + Sync
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+{
+    /// Map the request envelope stream to a stream of response envelopes.
+    async fn handle(&self, requests: EnvelopeStream<T>) -> Result<EnvelopeStream<R>>;
+}
+
+/// Type-erased handler storage keyed by handler type key
+#[cfg(feature = "grpc-server")]
+type HandlerMap<W> = Arc<RwLock<std::collections::HashMap<String, Arc<W>>>>;
+
+/// Stream of protobuf envelopes returned by the streaming RPCs
+#[cfg(feature = "grpc-server")]
+type ProtoEnvelopeStream =
+    Pin<Box<dyn Stream<Item = std::result::Result<ProtoEnvelope, Status>> + Send>>;
+
 /// Service implementation with proper handler registration and routing
 #[cfg(feature = "grpc-server")]
 #[derive(Clone)]
@@ -423,7 +595,24 @@ pub struct QollectiveServiceImpl {
     /// Storage for registered handlers by type - simplified for now
     has_handlers: Arc<RwLock<bool>>,
     /// Storage for type-erased handlers by type key
-    handlers: Arc<RwLock<std::collections::HashMap<String, Arc<dyn HandlerWrapper>>>>,
+    handlers: HandlerMap<dyn HandlerWrapper>,
+    /// Storage for type-erased handlers by normalized route
+    route_handlers: HandlerMap<dyn HandlerWrapper>,
+    /// Storage for type-erased server streaming handlers by type key
+    server_streaming_handlers: HandlerMap<dyn ServerStreamingHandlerWrapper>,
+    /// Storage for type-erased client streaming handlers by type key
+    client_streaming_handlers: HandlerMap<dyn ClientStreamingHandlerWrapper>,
+    /// Storage for type-erased bidirectional streaming handlers by type key
+    bidirectional_streaming_handlers: HandlerMap<dyn BidirectionalStreamingHandlerWrapper>,
+    /// Meta policy enforced on unary responses
+    meta_policy: OutgoingMetaPolicy,
+    /// Enrichers run on unary responses before the meta policy
+    enrichers: MetaEnrichers,
+    /// Coordinator refusing calls once draining starts, set by the server
+    shutdown: Option<ShutdownCoordinator>,
+    /// Bearer token check every call must pass
+    #[cfg(feature = "security")]
+    bearer_authenticator: BearerAuthenticator,
 }
 
 /// Type-erased wrapper for handlers to enable storage in HashMap
@@ -433,6 +622,7 @@ trait HandlerWrapper: Send + Sync {
     async fn handle_envelope(
         &self,
         envelope: ProtoEnvelope,
+        deadline: Option<DateTime<Utc>>,
     ) -> std::result::Result<ProtoEnvelope, Status>;
 }
 
@@ -445,6 +635,10 @@ where
     H: ContextDataHandler<T, R> + Send + Sync + 'static,
 {
     handler: H,
+    /// Route the handler was registered under, or the unary method path for type-key handlers
+    route: String,
+    meta_policy: OutgoingMetaPolicy,
+    enrichers: MetaEnrichers,
     _phantom: std::marker::PhantomData<fn() -> (T, R)>,
 }
 
@@ -459,6 +653,7 @@ where
     async fn handle_envelope(
         &self,
         proto_envelope: ProtoEnvelope,
+        request_deadline: Option<DateTime<Utc>>,
     ) -> std::result::Result<ProtoEnvelope, Status> {
         // Convert protobuf envelope to Qollective envelope
         let qollective_envelope: Envelope<T> =
@@ -473,23 +668,25 @@ where
             };
 
         // Extract context and data from envelope
-        let (meta, data) = qollective_envelope.extract();
+        let (mut meta, data) = qollective_envelope.extract();
+        meta.record_service_hop();
+        deadline::merge_into_meta(&mut meta, request_deadline);
         let context = Some(crate::envelope::Context::from(meta.clone())); // Proper context conversion
 
-        // Call the registered handler
-        let response_data = match self.handler.handle(context, data).await {
+        // Call the registered handler within the caller's deadline
+        let started = std::time::Instant::now();
+        let response_data = match deadline::scope(&meta, self.handler.handle(context, data)).await {
             Ok(data) => data,
-            Err(e) => {
-                return Err(Status::new(
-                    Code::Internal,
-                    format!("Handler failed: {}", e),
-                ))
-            }
+            Err(e) => return Err(handler_status(e)),
         };
 
         // Create response envelope with properly preserved metadata
         // This follows the same pattern as WebSocket and other transports for consistency
-        let response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+        let mut response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+        response_meta.complete_service_hop(&meta.service_chain);
+        self.enrichers
+            .enrich(&mut response_meta, &self.route, started, &response_data);
+        self.meta_policy.apply(&mut response_meta, &self.route);
         let response_envelope = Envelope::new(response_meta, response_data);
 
         // Convert back to protobuf envelope
@@ -503,15 +700,407 @@ where
     }
 }
 
+/// Type-erased wrapper for server streaming handlers
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+trait ServerStreamingHandlerWrapper: Send + Sync {
+    async fn handle_stream(
+        &self,
+        envelope: ProtoEnvelope,
+        deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelopeStream, Status>;
+}
+
+/// Type-erased wrapper for client streaming handlers
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+trait ClientStreamingHandlerWrapper: Send + Sync {
+    async fn handle_stream(
+        &self,
+        envelopes: tonic::Streaming<ProtoEnvelope>,
+        deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelope, Status>;
+}
+
+/// Type-erased wrapper for bidirectional streaming handlers
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+trait BidirectionalStreamingHandlerWrapper: Send + Sync {
+    async fn handle_stream(
+        &self,
+        envelopes: tonic::Streaming<ProtoEnvelope>,
+        deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelopeStream, Status>;
+}
+
+/// Concrete streaming handler wrapper for specific request/response types
+#[cfg(feature = "grpc-server")]
+struct TypedStreamingHandlerWrapper<T, R, H> {
+    handler: H,
+    responses: StreamResponses,
+    _phantom: std::marker::PhantomData<fn() -> (T, R)>,
+}
+
+#[cfg(feature = "grpc-server")]
+impl<T, R, H> TypedStreamingHandlerWrapper<T, R, H> {
+    fn new(handler: H, responses: StreamResponses) -> Self {
+        Self {
+            handler,
+            responses,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Enrichment and meta policy applied to the responses of a streaming handler
+#[cfg(feature = "grpc-server")]
+#[derive(Clone)]
+struct StreamResponses {
+    /// gRPC method path the handler answers
+    route: &'static str,
+    meta_policy: OutgoingMetaPolicy,
+    enrichers: MetaEnrichers,
+}
+
+#[cfg(feature = "grpc-server")]
+impl StreamResponses {
+    /// Enrich a response and strip what the meta policy does not allow
+    fn finish<R: Serialize>(
+        &self,
+        mut response: Envelope<R>,
+        started: std::time::Instant,
+    ) -> Envelope<R> {
+        self.enrichers
+            .enrich(&mut response.meta, self.route, started, &response.payload);
+        self.meta_policy.apply(&mut response.meta, self.route);
+        response
+    }
+
+    /// Convert a handler's response stream, finishing every response
+    ///
+    /// The stream ends with `DeadlineExceeded` once the call's deadline passes.
+    fn to_protobuf<R>(
+        &self,
+        responses: EnvelopeStream<R>,
+        started: std::time::Instant,
+        deadline: Option<DateTime<Utc>>,
+    ) -> ProtoEnvelopeStream
+    where
+        R: Serialize + Send + 'static,
+    {
+        let finisher = self.clone();
+        let responses: ProtoEnvelopeStream = Box::pin(responses.map(move |item| {
+            item.map(|response| finisher.finish(response, started))
+                .and_then(qollective_to_protobuf_envelope)
+                .map_err(|e| Status::new(Code::Internal, format!("Handler stream failed: {}", e)))
+        }));
+        match deadline {
+            Some(deadline) => until_deadline(responses, deadline),
+            None => responses,
+        }
+    }
+}
+
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+impl<T, R, H> ServerStreamingHandlerWrapper for TypedStreamingHandlerWrapper<T, R, H>
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+    H: ServerStreamingHandler<T, R> + 'static,
+{
+    async fn handle_stream(
+        &self,
+        proto_envelope: ProtoEnvelope,
+        request_deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelopeStream, Status> {
+        let mut envelope: Envelope<T> =
+            protobuf_to_qollective_envelope(proto_envelope).map_err(|e| {
+                Status::new(
+                    Code::InvalidArgument,
+                    format!("Failed to convert envelope: {}", e),
+                )
+            })?;
+        envelope.meta.record_service_hop();
+        deadline::merge_into_meta(&mut envelope.meta, request_deadline);
+        let meta = envelope.meta.clone();
+
+        let started = std::time::Instant::now();
+        let responses = deadline::scope(&meta, self.handler.handle(envelope))
+            .await
+            .map_err(handler_status)?;
+
+        Ok(self
+            .responses
+            .to_protobuf(responses, started, request_deadline))
+    }
+}
+
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+impl<T, R, H> ClientStreamingHandlerWrapper for TypedStreamingHandlerWrapper<T, R, H>
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+    H: ClientStreamingHandler<T, R> + 'static,
+{
+    async fn handle_stream(
+        &self,
+        envelopes: tonic::Streaming<ProtoEnvelope>,
+        request_deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelope, Status> {
+        let started = std::time::Instant::now();
+        let requests = protobuf_stream_to_envelopes(envelopes, request_deadline);
+        let response = deadline::scope(&call_meta(request_deadline), self.handler.handle(requests))
+            .await
+            .map_err(handler_status)?;
+
+        qollective_to_protobuf_envelope(self.responses.finish(response, started))
+            .map_err(|e| Status::new(Code::Internal, format!("Failed to convert response: {}", e)))
+    }
+}
+
+#[cfg(feature = "grpc-server")]
+#[async_trait]
+impl<T, R, H> BidirectionalStreamingHandlerWrapper for TypedStreamingHandlerWrapper<T, R, H>
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+    R: Serialize + Send + 'static,
+    H: BidirectionalStreamingHandler<T, R> + 'static,
+{
+    async fn handle_stream(
+        &self,
+        envelopes: tonic::Streaming<ProtoEnvelope>,
+        request_deadline: Option<DateTime<Utc>>,
+    ) -> std::result::Result<ProtoEnvelopeStream, Status> {
+        let started = std::time::Instant::now();
+        let requests = protobuf_stream_to_envelopes(envelopes, request_deadline);
+        let responses =
+            deadline::scope(&call_meta(request_deadline), self.handler.handle(requests))
+                .await
+                .map_err(handler_status)?;
+
+        Ok(self
+            .responses
+            .to_protobuf(responses, started, request_deadline))
+    }
+}
+
+/// Convert an incoming protobuf envelope stream into typed Qollective envelopes
+///
+/// Every envelope carries the deadline of the call and records this server in
+/// its service chain.
+#[cfg(feature = "grpc-server")]
+fn protobuf_stream_to_envelopes<T>(
+    envelopes: tonic::Streaming<ProtoEnvelope>,
+    request_deadline: Option<DateTime<Utc>>,
+) -> EnvelopeStream<T>
+where
+    T: for<'de> Deserialize<'de> + Send + 'static,
+{
+    Box::pin(envelopes.map(move |item| {
+        item.map_err(|status| {
+            QollectiveError::grpc(format!(
+                "gRPC stream error [{}]: {}",
+                status.code(),
+                status.message()
+            ))
+        })
+        .and_then(protobuf_to_qollective_envelope::<T>)
+        .map(|mut envelope| {
+            envelope.meta.record_service_hop();
+            deadline::merge_into_meta(&mut envelope.meta, request_deadline);
+            envelope
+        })
+    }))
+}
+
+/// Metadata a streaming call's handler runs under, carrying only the call's deadline
+#[cfg(feature = "grpc-server")]
+fn call_meta(request_deadline: Option<DateTime<Utc>>) -> crate::envelope::Meta {
+    let mut meta = crate::envelope::Meta::default();
+    deadline::merge_into_meta(&mut meta, request_deadline);
+    meta
+}
+
+/// End a response stream with `DeadlineExceeded` once `deadline` passes
+#[cfg(feature = "grpc-server")]
+fn until_deadline(responses: ProtoEnvelopeStream, deadline: DateTime<Utc>) -> ProtoEnvelopeStream {
+    let expiry = Box::pin(tokio::time::sleep(deadline::remaining(deadline)));
+    Box::pin(futures_util::stream::unfold(
+        Some((responses, expiry)),
+        move |state| async move {
+            let (mut responses, mut expiry) = state?;
+            tokio::select! {
+                response = responses.next() => {
+                    response.map(|response| (response, Some((responses, expiry))))
+                }
+                _ = &mut expiry => {
+                    let expired = QollectiveError::deadline_exceeded(format!(
+                        "request deadline {} has passed",
+                        deadline.to_rfc3339()
+                    ));
+                    Some((Err(handler_status(expired)), None))
+                }
+            }
+        },
+    ))
+}
+
+/// Trace context the caller sent in the request metadata
+#[cfg(feature = "grpc-server")]
+fn request_tracing<M>(request: &Request<M>) -> Option<TracingMeta> {
+    crate::server::middleware::utils::extract_context_from_tonic_request(request)
+        .and_then(|context| context.meta().tracing.clone())
+}
+
+/// Deadline the caller sent as `grpc-timeout` metadata
+#[cfg(feature = "grpc-server")]
+fn request_deadline<M>(request: &Request<M>) -> Option<DateTime<Utc>> {
+    request
+        .metadata()
+        .get(deadline::GRPC_TIMEOUT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(deadline::parse_grpc_timeout)
+        .map(deadline::after)
+}
+
+/// Reject a streaming call whose deadline has already passed
+#[cfg(feature = "grpc-server")]
+fn check_deadline<M>(request: &Request<M>) -> std::result::Result<(), Status> {
+    deadline::check(request_deadline(request)).map_err(handler_status)
+}
+
+/// Keep a streaming call counted as in flight until its response stream is dropped
+#[cfg(feature = "grpc-server")]
+fn hold_until_done(
+    responses: ProtoEnvelopeStream,
+    in_flight: Option<RequestGuard>,
+) -> ProtoEnvelopeStream {
+    match in_flight {
+        Some(guard) => Box::pin(responses.map(move |response| {
+            let _ = &guard;
+            response
+        })),
+        None => responses,
+    }
+}
+
+/// Status reported for a failed handler, keeping deadline expiry distinguishable
+#[cfg(feature = "grpc-server")]
+fn handler_status(error: QollectiveError) -> Status {
+    match error {
+        QollectiveError::DeadlineExceeded(_) => {
+            Status::new(Code::DeadlineExceeded, error.to_string())
+        }
+        e => Status::new(Code::Internal, format!("Handler failed: {}", e)),
+    }
+}
+
+/// Tenant carried in a protobuf envelope, used to label request metrics
+#[cfg(feature = "grpc-server")]
+fn envelope_tenant(envelope: &ProtoEnvelope) -> Option<String> {
+    envelope.meta.as_ref().and_then(|meta| meta.tenant.clone())
+}
+
+/// Select the handler named by the handler key metadata
+///
+/// Without a handler key the only registered handler is used. An unknown key, or
+/// a call without a key while several handlers are registered, is `Code::NotFound`.
+/// `None` means no handler is registered at all.
+#[cfg(feature = "grpc-server")]
+async fn select_handler<W: ?Sized>(
+    handlers: &HandlerMap<W>,
+    metadata: &MetadataMap,
+) -> std::result::Result<Option<Arc<W>>, Status> {
+    let handlers = handlers.read().await;
+    if handlers.is_empty() {
+        return Ok(None);
+    }
+
+    let requested = metadata
+        .get(crate::constants::metadata::GRPC_HANDLER_KEY_METADATA)
+        .and_then(|value| value.to_str().ok());
+
+    match requested {
+        Some(type_key) => handlers.get(type_key).cloned().map(Some).ok_or_else(|| {
+            Status::not_found(format!("No handler registered for type key: {}", type_key))
+        }),
+        None if handlers.len() == 1 => Ok(handlers.values().next().cloned()),
+        None => Err(Status::not_found(
+            "Several handlers are registered; the call must name one by handler key",
+        )),
+    }
+}
+
 #[cfg(feature = "grpc-server")]
 impl QollectiveServiceImpl {
     pub fn new() -> Self {
         Self {
             has_handlers: Arc::new(RwLock::new(false)),
             handlers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            route_handlers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            server_streaming_handlers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            client_streaming_handlers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            bidirectional_streaming_handlers: Arc::new(RwLock::new(
+                std::collections::HashMap::new(),
+            )),
+            meta_policy: OutgoingMetaPolicy::environment(),
+            enrichers: MetaEnrichers::new(),
+            shutdown: None,
+            #[cfg(feature = "security")]
+            bearer_authenticator: BearerAuthenticator::default(),
         }
     }
 
+    /// Admit a call, failing with `UNAVAILABLE` while the server drains
+    fn admit(&self, operation: &str) -> std::result::Result<Option<RequestGuard>, Status> {
+        self.shutdown
+            .as_ref()
+            .map(|coordinator| coordinator.admit("grpc", operation))
+            .transpose()
+            .map_err(|e| Status::unavailable(e.to_string()))
+    }
+
+    /// Reject a call whose bearer token the configured validator does not accept
+    #[cfg(feature = "security")]
+    async fn authenticate(&self, metadata: &MetadataMap) -> std::result::Result<(), Status> {
+        let authorization = metadata
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+        self.bearer_authenticator
+            .apply(authorization, &mut Meta::default())
+            .await
+            .map_err(|e| Status::unauthenticated(e.to_string()))
+    }
+
+    /// Enforce `policy` on the metadata of every response, streamed ones included
+    ///
+    /// Applies to handlers registered after this call.
+    #[cfg(feature = "config")]
+    pub fn with_meta_policy(mut self, policy: impl Into<OutgoingMetaPolicy>) -> Self {
+        self.meta_policy = policy.into();
+        self
+    }
+
+    /// Run `enrichers` on the metadata of every response, before the meta policy
+    ///
+    /// Applies to handlers registered after this call.
+    pub fn with_enrichers(mut self, enrichers: MetaEnrichers) -> Self {
+        self.enrichers = enrichers;
+        self
+    }
+
+    /// Require every call to carry a bearer token `validator` accepts
+    ///
+    /// Calls without one, or with a token the validator rejects, fail with
+    /// `UNAUTHENTICATED` before reaching a handler.
+    #[cfg(feature = "security")]
+    pub fn with_token_validator(mut self, validator: impl BearerTokenValidator + 'static) -> Self {
+        self.bearer_authenticator = BearerAuthenticator::new(Arc::new(validator));
+        self
+    }
+
     /// Register a handler for a specific type combination
     pub async fn register_handler<T, R, H>(&self, type_key: String, handler: H) -> Result<()>
     where
@@ -522,6 +1111,9 @@ impl QollectiveServiceImpl {
         // Create a typed handler wrapper
         let wrapper = TypedHandlerWrapper {
             handler,
+            route: crate::constants::endpoints::GRPC_UNARY_METHOD.to_string(),
+            meta_policy: self.meta_policy.clone(),
+            enrichers: self.enrichers.clone(),
             _phantom: std::marker::PhantomData,
         };
 
@@ -536,6 +1128,99 @@ impl QollectiveServiceImpl {
         Ok(())
     }
 
+    /// Register a handler for envelopes addressed to a specific route
+    pub async fn register_route_handler<T, R, H>(&self, route: &str, handler: H) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: ContextDataHandler<T, R> + Send + Sync + 'static,
+    {
+        let route = normalize_grpc_route(route);
+        let wrapper = TypedHandlerWrapper {
+            handler,
+            route: route.clone(),
+            meta_policy: self.meta_policy.clone(),
+            enrichers: self.enrichers.clone(),
+            _phantom: std::marker::PhantomData,
+        };
+
+        let mut route_handlers = self.route_handlers.write().await;
+        route_handlers.insert(route, Arc::new(wrapper));
+
+        let mut has_handlers = self.has_handlers.write().await;
+        *has_handlers = true;
+
+        Ok(())
+    }
+
+    fn stream_responses(&self, route: &'static str) -> StreamResponses {
+        StreamResponses {
+            route,
+            meta_policy: self.meta_policy.clone(),
+            enrichers: self.enrichers.clone(),
+        }
+    }
+
+    /// Register a server streaming handler for a specific type combination
+    pub async fn register_server_streaming_handler<T, R, H>(
+        &self,
+        type_key: String,
+        handler: H,
+    ) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: ServerStreamingHandler<T, R> + 'static,
+    {
+        let wrapper = TypedStreamingHandlerWrapper::<T, R, H>::new(
+            handler,
+            self.stream_responses(crate::constants::endpoints::GRPC_SERVER_STREAMING_METHOD),
+        );
+        let mut handlers = self.server_streaming_handlers.write().await;
+        handlers.insert(type_key, Arc::new(wrapper));
+        Ok(())
+    }
+
+    /// Register a client streaming handler for a specific type combination
+    pub async fn register_client_streaming_handler<T, R, H>(
+        &self,
+        type_key: String,
+        handler: H,
+    ) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: ClientStreamingHandler<T, R> + 'static,
+    {
+        let wrapper = TypedStreamingHandlerWrapper::<T, R, H>::new(
+            handler,
+            self.stream_responses(crate::constants::endpoints::GRPC_CLIENT_STREAMING_METHOD),
+        );
+        let mut handlers = self.client_streaming_handlers.write().await;
+        handlers.insert(type_key, Arc::new(wrapper));
+        Ok(())
+    }
+
+    /// Register a bidirectional streaming handler for a specific type combination
+    pub async fn register_bidirectional_streaming_handler<T, R, H>(
+        &self,
+        type_key: String,
+        handler: H,
+    ) -> Result<()>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+        R: Serialize + Send + 'static,
+        H: BidirectionalStreamingHandler<T, R> + 'static,
+    {
+        let wrapper = TypedStreamingHandlerWrapper::<T, R, H>::new(
+            handler,
+            self.stream_responses(crate::constants::endpoints::GRPC_BIDIRECTIONAL_STREAMING_METHOD),
+        );
+        let mut handlers = self.bidirectional_streaming_handlers.write().await;
+        handlers.insert(type_key, Arc::new(wrapper));
+        Ok(())
+    }
+
     /// Check if any handlers are registered (for testing)
     pub async fn has_registered_handlers(&self) -> bool {
         let has_handlers = self.has_handlers.read().await;
@@ -565,6 +1250,11 @@ where
     meta.version = Some(proto_meta.version);
     meta.duration = proto_meta.duration;
     meta.tenant = proto_meta.tenant;
+    meta.service_chain =
+        crate::transport::grpc::service_chain_from_proto(proto_meta.service_chain);
+    meta.tracing = proto_meta
+        .tracing
+        .map(crate::transport::grpc::trace_context_from_proto);
 
     // Extract data from response
     let response = proto_envelope
@@ -628,8 +1318,8 @@ where
             .unwrap_or_else(|| uuid::Uuid::now_v7().to_string()),
         version: meta.version.unwrap_or_else(|| "1.0.0".to_string()),
         duration: meta.duration,                      // Keep as Option<f64>
-        tenant: meta.tenant,                         // Tenant is now directly on Meta
-        service_chain: Vec::new(),                    // Empty for now
+        tenant: meta.tenant,                          // Tenant is now directly on Meta
+        service_chain: crate::transport::grpc::service_chain_to_proto(&meta.service_chain),
         security: None,                               // Security meta without tenant
         debug: None,                                  // Simplified for now
         performance: None,                            // Simplified for now
@@ -654,61 +1344,105 @@ impl QollectiveService for QollectiveServiceImpl {
         &self,
         request: Request<ProtoEnvelope>,
     ) -> std::result::Result<Response<ProtoEnvelope>, Status> {
-        let envelope = request.into_inner();
-
-        // Check if we have any registered handlers
-        let has_handlers = self.has_handlers.read().await;
-
-        if *has_handlers {
-            // Try to find a handler for common test types
-            // In a real implementation, type information would be extracted from the envelope
-            let test_type_keys = vec![
-                "transport_dual_transport_grpc_tests::TestRequest:transport_dual_transport_grpc_tests::TestResponse".to_string(),
-            ];
-
-            let handlers = self.handlers.read().await;
-
-            // Try to find a matching handler
-            for type_key in &test_type_keys {
-                if let Some(handler) = handlers.get(type_key) {
-                    // Found a handler, use it to process the envelope
-                    match handler.handle_envelope(envelope).await {
-                        Ok(response) => return Ok(Response::new(response)),
-                        Err(status) => return Err(status),
-                    }
-                }
-            }
-
-            // If no specific handler found, try the first available handler
-            if let Some((_, handler)) = handlers.iter().next() {
-                match handler.handle_envelope(envelope).await {
-                    Ok(response) => return Ok(Response::new(response)),
-                    Err(status) => return Err(status),
-                }
-            }
+        #[cfg(feature = "security")]
+        self.authenticate(request.metadata()).await?;
+        let parent = request_tracing(&request);
+        let request_deadline = request_deadline(&request);
+
+        // Route-addressed calls only reach the handler registered at that route
+        if let Some(route) = request
+            .metadata()
+            .get(crate::constants::metadata::GRPC_ROUTE_METADATA)
+            .and_then(|value| value.to_str().ok())
+            .map(normalize_grpc_route)
+        {
+            let handler = self.route_handlers.read().await.get(&route).cloned();
+            let Some(handler) = handler else {
+                return Err(Status::not_found(format!(
+                    "No handler registered for route: {}",
+                    route
+                )));
+            };
+            let _in_flight = self.admit(&route)?;
+            let envelope = request.into_inner();
+            let tenant = envelope_tenant(&envelope);
+            return crate::monitoring::observe(
+                "grpc",
+                &route,
+                tenant.as_deref(),
+                trace_context::instrument(
+                    handler.handle_envelope(envelope, request_deadline),
+                    &route,
+                    SpanKind::Server,
+                    parent.as_ref(),
+                ),
+            )
+            .await
+            .map(Response::new);
+        }
 
-            // No handlers available despite flag being set, fall back to echo
-            Ok(Response::new(envelope))
-        } else {
+        let _in_flight = self.admit("unary_call")?;
+        let handler = select_handler(&self.handlers, request.metadata()).await?;
+        let envelope = request.into_inner();
+        let tenant = envelope_tenant(&envelope);
+
+        match handler {
+            // Dispatch to the handler named in the metadata, or the only registered one
+            Some(handler) => crate::monitoring::observe(
+                "grpc",
+                "unary_call",
+                tenant.as_deref(),
+                trace_context::instrument(
+                    handler.handle_envelope(envelope, request_deadline),
+                    "unary_call",
+                    SpanKind::Server,
+                    parent.as_ref(),
+                ),
+            )
+            .await
+            .map(Response::new),
             // No handlers registered, fall back to echo
-            Ok(Response::new(envelope))
+            None => Ok(Response::new(envelope)),
         }
     }
 
-    type ServerStreamingStream =
-        Pin<Box<dyn Stream<Item = std::result::Result<ProtoEnvelope, Status>> + Send>>;
+    type ServerStreamingStream = ProtoEnvelopeStream;
 
     /// Handle server streaming requests
     async fn server_streaming(
         &self,
         request: Request<ProtoEnvelope>,
     ) -> std::result::Result<Response<Self::ServerStreamingStream>, Status> {
-        let envelope = request.into_inner();
-
-        // Create a simple stream that echoes the request multiple times
-        let stream = stream::iter(vec![Ok(envelope.clone()), Ok(envelope)]);
+        let handler = select_handler(&self.server_streaming_handlers, request.metadata())
+            .await?
+            .ok_or_else(|| {
+                Status::new(
+                    Code::Unimplemented,
+                    "No server streaming handler registered",
+                )
+            })?;
+        check_deadline(&request)?;
+        #[cfg(feature = "security")]
+        self.authenticate(request.metadata()).await?;
+        let in_flight = self.admit("server_streaming")?;
 
-        Ok(Response::new(Box::pin(stream)))
+        let parent = request_tracing(&request);
+        let deadline = request_deadline(&request);
+        let envelope = request.into_inner();
+        let tenant = envelope_tenant(&envelope);
+        let responses = crate::monitoring::observe(
+            "grpc",
+            "server_streaming",
+            tenant.as_deref(),
+            trace_context::instrument(
+                handler.handle_stream(envelope, deadline),
+                "server_streaming",
+                SpanKind::Server,
+                parent.as_ref(),
+            ),
+        )
+        .await?;
+        Ok(Response::new(hold_until_done(responses, in_flight)))
     }
 
     /// Handle client streaming requests
@@ -716,38 +1450,71 @@ impl QollectiveService for QollectiveServiceImpl {
         &self,
         request: Request<tonic::Streaming<ProtoEnvelope>>,
     ) -> std::result::Result<Response<ProtoEnvelope>, Status> {
-        let mut stream = request.into_inner();
-
-        // Collect all messages and return the last one
-        let mut last_envelope = None;
-        while let Some(result) = stream.next().await {
-            match result {
-                Ok(envelope) => last_envelope = Some(envelope),
-                Err(status) => return Err(status),
-            }
-        }
-
-        if let Some(envelope) = last_envelope {
-            Ok(Response::new(envelope))
-        } else {
-            Err(Status::new(Code::InvalidArgument, "No envelopes received"))
-        }
+        let handler = select_handler(&self.client_streaming_handlers, request.metadata())
+            .await?
+            .ok_or_else(|| {
+                Status::new(
+                    Code::Unimplemented,
+                    "No client streaming handler registered",
+                )
+            })?;
+        check_deadline(&request)?;
+        #[cfg(feature = "security")]
+        self.authenticate(request.metadata()).await?;
+        let _in_flight = self.admit("client_streaming")?;
+
+        let parent = request_tracing(&request);
+        let deadline = request_deadline(&request);
+        let response = crate::monitoring::observe(
+            "grpc",
+            "client_streaming",
+            None,
+            trace_context::instrument(
+                handler.handle_stream(request.into_inner(), deadline),
+                "client_streaming",
+                SpanKind::Server,
+                parent.as_ref(),
+            ),
+        )
+        .await?;
+        Ok(Response::new(response))
     }
 
-    type BidirectionalStreamingStream =
-        Pin<Box<dyn Stream<Item = std::result::Result<ProtoEnvelope, Status>> + Send>>;
+    type BidirectionalStreamingStream = ProtoEnvelopeStream;
 
     /// Handle bidirectional streaming requests
     async fn bidirectional_streaming(
         &self,
         request: Request<tonic::Streaming<ProtoEnvelope>>,
     ) -> std::result::Result<Response<Self::BidirectionalStreamingStream>, Status> {
-        let stream = request.into_inner();
-
-        // Echo each received envelope
-        let response_stream = stream.map(|result| result);
-
-        Ok(Response::new(Box::pin(response_stream)))
+        let handler = select_handler(&self.bidirectional_streaming_handlers, request.metadata())
+            .await?
+            .ok_or_else(|| {
+                Status::new(
+                    Code::Unimplemented,
+                    "No bidirectional streaming handler registered",
+                )
+            })?;
+        check_deadline(&request)?;
+        #[cfg(feature = "security")]
+        self.authenticate(request.metadata()).await?;
+        let in_flight = self.admit("bidirectional_streaming")?;
+
+        let parent = request_tracing(&request);
+        let deadline = request_deadline(&request);
+        let responses = crate::monitoring::observe(
+            "grpc",
+            "bidirectional_streaming",
+            None,
+            trace_context::instrument(
+                handler.handle_stream(request.into_inner(), deadline),
+                "bidirectional_streaming",
+                SpanKind::Server,
+                parent.as_ref(),
+            ),
+        )
+        .await?;
+        Ok(Response::new(hold_until_done(responses, in_flight)))
     }
 
     /// Handle health check requests
@@ -809,6 +1576,8 @@ mod tests {
                 version: Some("1.0.0".to_string()),
                 duration: None,
                 tenant: None,
+                service_chain: Vec::new(),
+                deadline: None,
                 on_behalf_of: None,
                 security: None,
                 debug: None,
@@ -1186,13 +1955,245 @@ mod tests {
         let mut server = GrpcServer::new(config);
         let handler = TestHandler::new();
 
-        // ACT: Try to register handler at specific route
-        let result = server
+        // ACT: Register handler at specific route before and after registering a service
+        let without_service = server
+            .receive_envelope_at("/grpc/service/method", handler.clone())
+            .await;
+        server
+            .register_service(QollectiveServiceImpl::new())
+            .await
+            .unwrap();
+        let with_service = server
             .receive_envelope_at("/grpc/service/method", handler)
             .await;
 
-        // ASSERT: Should support route-based handling (will fail until implemented)
-        assert!(result.is_err()); // Expect failure until implementation is complete
+        // ASSERT: Route registration requires an initialized service
+        assert!(without_service.is_err());
+        assert!(with_service.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_qollective_service_impl_dispatches_by_route() {
+        // ARRANGE: Service with a handler registered at a route
+        let service = QollectiveServiceImpl::new();
+        service
+            .register_route_handler("orders/create", TestHandler::new())
+            .await
+            .unwrap();
+        let envelope = Envelope::new(
+            crate::envelope::Meta::default(),
+            TestRequest {
+                message: "route".to_string(),
+            },
+        );
+        let mut request = Request::new(qollective_to_protobuf_envelope(envelope).unwrap());
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_ROUTE_METADATA,
+            "/orders/create".parse().unwrap(),
+        );
+
+        // ACT: Call the unary method addressed to the route
+        let response = service.unary_call(request).await.unwrap();
+
+        // ASSERT: The route handler processed the request
+        let response: Envelope<TestResponse> =
+            protobuf_to_qollective_envelope(response.into_inner()).unwrap();
+        assert_eq!(response.payload.result, "Processed: route");
+    }
+
+    #[tokio::test]
+    async fn test_qollective_service_impl_unknown_route_is_not_found() {
+        // ARRANGE: Service with route handlers only
+        let service = QollectiveServiceImpl::new();
+        service
+            .register_route_handler("orders/create", TestHandler::new())
+            .await
+            .unwrap();
+        let mut request = Request::new(create_test_proto_envelope());
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_ROUTE_METADATA,
+            "/orders/unknown".parse().unwrap(),
+        );
+
+        // ACT: Call the unary method addressed to an unregistered route
+        let status = service.unary_call(request).await.unwrap_err();
+
+        // ASSERT: Unknown routes map to NotFound
+        assert_eq!(status.code(), Code::NotFound);
+        assert!(status.message().contains("/orders/unknown"));
+    }
+
+    #[tokio::test]
+    async fn test_qollective_service_impl_unknown_route_skips_type_keyed_handlers() {
+        // ARRANGE: Service with a type-keyed handler but no route handlers
+        let service = QollectiveServiceImpl::new();
+        service
+            .register_handler(
+                handler_type_key::<TestRequest, TestResponse>(),
+                TestHandler::new(),
+            )
+            .await
+            .unwrap();
+        let envelope = Envelope::new(
+            crate::envelope::Meta::default(),
+            TestRequest {
+                message: "typed".to_string(),
+            },
+        );
+        let mut request = Request::new(qollective_to_protobuf_envelope(envelope).unwrap());
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_ROUTE_METADATA,
+            "/OrderService/Create".parse().unwrap(),
+        );
+
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_HANDLER_KEY_METADATA,
+            handler_type_key::<TestRequest, TestResponse>()
+                .parse()
+                .unwrap(),
+        );
+
+        // ACT: Call the unary method addressed to a route nobody registered
+        let status = service.unary_call(request).await.unwrap_err();
+
+        // ASSERT: The route is unknown even though a type-keyed handler matches
+        assert_eq!(status.code(), Code::NotFound);
+        assert!(status.message().contains("/OrderService/Create"));
+    }
+
+    #[tokio::test]
+    async fn test_qollective_service_impl_unknown_handler_key_is_not_found() {
+        // ARRANGE: Service with one type-keyed handler
+        let service = QollectiveServiceImpl::new();
+        service
+            .register_handler(
+                handler_type_key::<TestRequest, TestResponse>(),
+                TestHandler::new(),
+            )
+            .await
+            .unwrap();
+        let mut request = Request::new(create_test_proto_envelope());
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_HANDLER_KEY_METADATA,
+            "unknown:key".parse().unwrap(),
+        );
+
+        // ACT: Call the unary method naming an unregistered handler key
+        let status = service.unary_call(request).await.unwrap_err();
+
+        // ASSERT: The registered handler is not picked in its place
+        assert_eq!(status.code(), Code::NotFound);
+        assert!(status.message().contains("unknown:key"));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn test_stream_responses_enrich_then_apply_meta_policy() {
+        use crate::config::{meta::MetaPolicy, ConfigPreset};
+        use crate::envelope::enrichment::PerformanceEnricher;
+        use crate::envelope::meta::SecurityMeta;
+
+        // ARRANGE: Production policy and a performance enricher on a server streaming route
+        let responses = StreamResponses {
+            route: crate::constants::endpoints::GRPC_SERVER_STREAMING_METHOD,
+            meta_policy: OutgoingMetaPolicy::new(MetaPolicy::for_preset(&ConfigPreset::Production)),
+            enrichers: MetaEnrichers::new().with(PerformanceEnricher::new()),
+        };
+        let mut meta = Meta::default();
+        meta.security = Some(SecurityMeta {
+            user_id: Some("riker".to_string()),
+            ip_address: Some("10.0.0.7".to_string()),
+            ..Default::default()
+        });
+        let response = Envelope::new(
+            meta,
+            TestResponse {
+                result: "away team report".to_string(),
+            },
+        );
+
+        // ACT: Finish a streamed response
+        let response = responses.finish(response, std::time::Instant::now());
+
+        // ASSERT: The response is enriched and the policy strips the address
+        assert!(response.meta.performance.is_some());
+        let security = response.meta.security.unwrap();
+        assert_eq!(security.user_id.as_deref(), Some("riker"));
+        assert!(security.ip_address.is_none());
+    }
+
+    /// Server streaming handler that emits one response per second forever
+    struct SlowStreamHandler;
+
+    #[async_trait::async_trait]
+    impl ServerStreamingHandler<TestRequest, TestResponse> for SlowStreamHandler {
+        async fn handle(
+            &self,
+            request: Envelope<TestRequest>,
+        ) -> crate::error::Result<EnvelopeStream<TestResponse>> {
+            // The handler runs with the call's deadline in scope
+            assert!(crate::envelope::Context::current()
+                .and_then(|context| context.meta().deadline)
+                .is_some());
+            assert!(request.meta.deadline.is_some());
+            let ticks = tokio_stream::wrappers::IntervalStream::new(tokio::time::interval(
+                std::time::Duration::from_secs(1),
+            ));
+            Ok(Box::pin(ticks.map(|_| {
+                Ok(Envelope::new(
+                    Meta::default(),
+                    TestResponse {
+                        result: "still scanning".to_string(),
+                    },
+                ))
+            })))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_streaming_ends_when_deadline_passes() {
+        // ARRANGE: Endless stream called with a 200ms deadline
+        let service = QollectiveServiceImpl::new();
+        let type_key = handler_type_key::<TestRequest, TestResponse>();
+        service
+            .register_server_streaming_handler(type_key.clone(), SlowStreamHandler)
+            .await
+            .unwrap();
+        let envelope = Envelope::new(
+            Meta::default(),
+            TestRequest {
+                message: "long range scan".to_string(),
+            },
+        );
+        let mut request = Request::new(qollective_to_protobuf_envelope(envelope).unwrap());
+        request.metadata_mut().insert(
+            crate::constants::metadata::GRPC_HANDLER_KEY_METADATA,
+            type_key.parse().unwrap(),
+        );
+        request
+            .metadata_mut()
+            .insert(deadline::GRPC_TIMEOUT_HEADER, "200m".parse().unwrap());
+
+        // ACT: Drain the response stream
+        let responses = service
+            .server_streaming(request)
+            .await
+            .unwrap()
+            .into_inner();
+        let items: Vec<_> = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            responses.collect::<Vec<_>>(),
+        )
+        .await
+        .expect("stream should end at the deadline");
+
+        // ASSERT: The first response arrives, then the stream fails with DeadlineExceeded
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_ok());
+        assert_eq!(
+            items[1].as_ref().unwrap_err().code(),
+            Code::DeadlineExceeded
+        );
     }
 
     /// Test 5: GrpcServer should preserve gRPC-specific features in unified pattern
@@ -1314,6 +2315,7 @@ mod tests {
             QollectiveError::Security(_) => Code::Unauthenticated,
             QollectiveError::External(_) => Code::Unavailable,
             QollectiveError::Remote(_) => Code::Unknown,
+            QollectiveError::DeadlineExceeded(_) => Code::DeadlineExceeded,
             QollectiveError::Grpc(_) => Code::Internal,
             QollectiveError::Envelope(_) => Code::InvalidArgument,
             QollectiveError::TenantExtraction(_) => Code::Unauthenticated,
@@ -1503,6 +2505,86 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_envelope_interceptor_rejects_forged_tenant_jwt() {
+            use crate::tenant::{
+                extraction::ExtractionConfig, JwtKeySource, JwtVerificationConfig,
+            };
+
+            // ARRANGE: Interceptor verifying tokens signed with the server secret
+            let config = ExtractionConfig {
+                jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+                    secret: "starfleet-secret".to_string(),
+                })),
+                ..Default::default()
+            };
+            let extractor =
+                crate::envelope::UnifiedTenantExtractor::try_with_config(config).unwrap();
+            let mut interceptor = EnvelopeInterceptor::new()
+                .with_tenant_extraction(true)
+                .with_tenant_extractor(Arc::new(extractor));
+
+            let claims = serde_json::json!({
+                "sub": "picard",
+                "tenant": "enterprise",
+                "exp": jsonwebtoken::get_current_timestamp() + 600,
+            });
+            let request_with = |secret: &[u8]| {
+                let token = jsonwebtoken::encode(
+                    &jsonwebtoken::Header::default(),
+                    &claims,
+                    &jsonwebtoken::EncodingKey::from_secret(secret),
+                )
+                .unwrap();
+                let mut request = Request::new(());
+                request.metadata_mut().insert(
+                    "authorization",
+                    MetadataValue::try_from(format!("Bearer {}", token)).unwrap(),
+                );
+                request.metadata_mut().insert(
+                    MetadataKey::from_static("x-tenant-id"),
+                    MetadataValue::try_from("romulan").unwrap(),
+                );
+                request
+            };
+
+            // ACT & ASSERT: The verified tenant wins over the header
+            let accepted = interceptor.call(request_with(b"starfleet-secret")).unwrap();
+            let context = accepted
+                .extensions()
+                .get::<crate::envelope::Context>()
+                .unwrap();
+            assert_eq!(context.meta().tenant.as_deref(), Some("enterprise"));
+
+            // ACT & ASSERT: A forged token is refused instead of falling back to the header
+            let rejected = interceptor
+                .call(request_with(b"attacker-secret"))
+                .unwrap_err();
+            assert_eq!(rejected.code(), Code::Unauthenticated);
+        }
+
+        #[test]
+        fn test_grpc_server_tenant_extraction_config_fails_closed() {
+            use crate::tenant::{
+                extraction::ExtractionConfig, JwtKeySource, JwtVerificationConfig,
+            };
+
+            // ARRANGE: Verification keys that cannot be loaded
+            let config = ExtractionConfig {
+                jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::PemFile {
+                    path: "/nonexistent/qollective/jwt.pem".to_string(),
+                })),
+                ..Default::default()
+            };
+
+            // ACT
+            let result =
+                GrpcServer::new(create_test_config()).with_tenant_extraction_config(config);
+
+            // ASSERT: The server refuses to start without its verification keys
+            assert!(result.is_err());
+        }
+
         #[test]
         fn test_grpc_server_integrates_tenant_extraction_in_serve_method() {
             // ARRANGE: Create server with tenant extraction enabled
@@ -10,9 +10,13 @@
 //! - Provides tool execution, resource access, and prompt handling
 
 use crate::config::mcp::McpServerRegistryConfig;
-use crate::envelope::Envelope;
+use crate::envelope::deadline;
+use crate::envelope::meta::OutgoingMetaPolicy;
+use crate::envelope::{Envelope, MetaEnrichers};
 use crate::error::{QollectiveError, Result};
 use crate::server::common::ServerConfig;
+use crate::server::mcp_resources::ResourceProvider;
+use crate::server::shutdown::ShutdownCoordinator;
 use crate::traits::catalog::{RegisteredResource, RegisteredTool, ServerCapability, ServerCatalog};
 use crate::traits::handlers::ContextDataHandler;
 use crate::traits::receivers::UnifiedEnvelopeReceiver;
@@ -20,13 +24,15 @@ use crate::traits::senders::UnifiedSender;
 use crate::types::mcp::{McpData, McpDiscoveryData, McpServerInfo, ServerMetadata};
 use async_trait::async_trait;
 use rmcp::model::{
-    CallToolRequest, CallToolResult, ClientCapabilities, Content, Implementation,
-    InitializeRequest, InitializeResult, RawContent, ServerCapabilities, Tool,
+    CallToolRequest, CallToolResult, ClientCapabilities, Content, GetPromptResult, Implementation,
+    InitializeRequest, InitializeResult, PromptMessage, PromptMessageRole, RawContent,
+    ReadResourceResult, ServerCapabilities, ServerNotification, Tool,
 };
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use std::collections::HashMap;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 // ============================================================================
@@ -373,6 +379,19 @@ pub struct McpResource {
     pub mime_type: Option<String>,
 }
 
+/// MCP resource template for parameterized resources
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceTemplate {
+    /// RFC 6570 URI template, e.g. `weather://{city}/current`
+    pub uri_template: String,
+    /// Template name
+    pub name: String,
+    /// Template description
+    pub description: Option<String>,
+    /// MIME type of the resources matching the template
+    pub mime_type: Option<String>,
+}
+
 /// MCP prompt definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpPrompt {
@@ -380,7 +399,7 @@ pub struct McpPrompt {
     pub name: String,
     /// Prompt description
     pub description: Option<String>,
-    /// Prompt template
+    /// Prompt template with `{argument}` placeholders (`{{` and `}}` for literal braces)
     pub template: String,
     /// Required arguments
     pub arguments: Vec<McpPromptArgument>,
@@ -397,6 +416,72 @@ pub struct McpPromptArgument {
     pub required: bool,
 }
 
+impl McpPrompt {
+    /// Render the prompt for a prompts/get request.
+    ///
+    /// Fails when a required argument is missing or the template references an
+    /// argument that is neither declared nor supplied. Optional arguments that are
+    /// not supplied render as empty text.
+    pub fn render(&self, arguments: &HashMap<String, Value>) -> Result<GetPromptResult> {
+        let missing: Vec<&str> = self
+            .arguments
+            .iter()
+            .filter(|arg| arg.required && arguments.get(&arg.name).is_none_or(Value::is_null))
+            .map(|arg| arg.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(QollectiveError::mcp_protocol(format!(
+                "Missing required argument(s) for prompt '{}': {}",
+                self.name,
+                missing.join(", ")
+            )));
+        }
+
+        let text = self.render_template(arguments)?;
+
+        Ok(GetPromptResult {
+            description: self.description.clone(),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+
+    fn render_template(&self, arguments: &HashMap<String, Value>) -> Result<String> {
+        let mut rendered = String::with_capacity(self.template.len());
+        let mut chars = self.template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    rendered.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    rendered.push('}');
+                }
+                '{' => {
+                    let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    match arguments.get(&name) {
+                        Some(Value::String(value)) => rendered.push_str(value),
+                        Some(Value::Null) | None
+                            if self.arguments.iter().any(|arg| arg.name == name) => {}
+                        Some(value) => rendered.push_str(&value.to_string()),
+                        None => {
+                            return Err(QollectiveError::mcp_protocol(format!(
+                                "Prompt '{}' references unknown argument '{}'",
+                                self.name, name
+                            )));
+                        }
+                    }
+                }
+                c => rendered.push(c),
+            }
+        }
+
+        Ok(rendered)
+    }
+}
+
 impl Default for McpServerConfig {
     fn default() -> Self {
         Self {
@@ -427,6 +512,16 @@ pub struct McpServer {
     registry: Arc<McpServerRegistry>,
     /// Current session state
     sessions: Arc<tokio::sync::RwLock<HashMap<String, McpSession>>>,
+    /// Providers serving resource content, queried in registration order
+    resource_providers: Vec<Arc<dyn ResourceProvider>>,
+    /// Meta policy enforced on envelope responses, keyed by tool name
+    meta_policy: OutgoingMetaPolicy,
+    /// Enrichers run on envelope responses before the meta policy
+    enrichers: MetaEnrichers,
+    /// Coordinator refusing envelope requests once draining starts
+    shutdown: Option<ShutdownCoordinator>,
+    /// Session id of the rmcp connection served by this instance
+    connection_id: String,
 }
 
 /// MCP session state
@@ -440,6 +535,35 @@ struct McpSession {
     client_info: Option<Implementation>,
     /// Is initialized
     is_initialized: bool,
+    /// Resource URIs the client subscribed to via resources/subscribe
+    resource_subscriptions: HashSet<String>,
+    /// Channel delivering server notifications to the client connection
+    notifier: Option<mpsc::UnboundedSender<ServerNotification>>,
+}
+
+impl McpSession {
+    fn new(session_id: String) -> Self {
+        Self {
+            session_id,
+            client_capabilities: None,
+            client_info: None,
+            is_initialized: false,
+            resource_subscriptions: HashSet::new(),
+            notifier: None,
+        }
+    }
+
+    /// Deliver a notification, dropping the channel once the client has gone away
+    fn notify(&mut self, notification: ServerNotification) -> bool {
+        let Some(notifier) = &self.notifier else {
+            return false;
+        };
+        if notifier.send(notification).is_err() {
+            self.notifier = None;
+            return false;
+        }
+        true
+    }
 }
 
 impl McpServer {
@@ -465,9 +589,37 @@ impl McpServer {
             transport,
             registry,
             sessions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            resource_providers: Vec::new(),
+            meta_policy: OutgoingMetaPolicy::environment(),
+            enrichers: MetaEnrichers::new(),
+            shutdown: None,
+            connection_id: Uuid::now_v7().to_string(),
         })
     }
 
+    /// Enforce `policy` on the metadata of every envelope response
+    ///
+    /// Tool calls resolve route overrides by tool name, discovery queries by query type.
+    pub fn with_meta_policy(mut self, policy: impl Into<OutgoingMetaPolicy>) -> Self {
+        self.meta_policy = policy.into();
+        self
+    }
+
+    /// Run `enrichers` on the metadata of every envelope response, before the meta policy
+    pub fn with_enrichers(mut self, enrichers: MetaEnrichers) -> Self {
+        self.enrichers = enrichers;
+        self
+    }
+
+    /// Drain envelope requests with `coordinator`
+    ///
+    /// Once draining starts new envelope requests are refused while running
+    /// tool calls may finish within the grace period.
+    pub fn with_shutdown(mut self, coordinator: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(coordinator);
+        self
+    }
+
     /// Initialize the server and register tools (async initialization)
     pub async fn initialize(&mut self) -> Result<()> {
         // Use the Implementation name as the server_id
@@ -493,18 +645,15 @@ impl McpServer {
         session_id: String,
         request: InitializeRequest,
     ) -> Result<InitializeResult> {
-        // Create or update session
+        // Create or update session, keeping subscriptions of a re-initializing client
         {
             let mut sessions = self.sessions.write().await;
-            sessions.insert(
-                session_id.clone(),
-                McpSession {
-                    session_id: session_id.clone(),
-                    client_capabilities: Some(request.params.capabilities),
-                    client_info: Some(request.params.client_info),
-                    is_initialized: true,
-                },
-            );
+            let session = sessions
+                .entry(session_id.clone())
+                .or_insert_with(|| McpSession::new(session_id.clone()));
+            session.client_capabilities = Some(request.params.capabilities);
+            session.client_info = Some(request.params.client_info);
+            session.is_initialized = true;
         }
 
         // Return server capabilities
@@ -514,14 +663,14 @@ impl McpServer {
                 experimental: None,
                 logging: None,
                 prompts: Some(rmcp::model::PromptsCapability {
-                    list_changed: Some(false),
+                    list_changed: Some(true),
                 }),
                 resources: Some(rmcp::model::ResourcesCapability {
-                    subscribe: Some(false),
-                    list_changed: Some(false),
+                    subscribe: Some(true),
+                    list_changed: Some(true),
                 }),
                 tools: Some(rmcp::model::ToolsCapability {
-                    list_changed: Some(false),
+                    list_changed: Some(true),
                 }),
                 completions: Some(serde_json::Map::new()),
             },
@@ -702,29 +851,47 @@ impl McpServer {
 
     /// Handle MCP resources/list request
     pub async fn handle_resources_list(&self, _session_id: String) -> Result<Vec<McpResource>> {
-        Ok(self.config.resources.clone())
+        let mut resources = self.config.resources.clone();
+        for provider in &self.resource_providers {
+            resources.extend(provider.list_resources().await?);
+        }
+        Ok(resources)
     }
 
-    /// Handle MCP resources/read request
-    pub async fn handle_resource_read(&self, _session_id: String, uri: String) -> Result<Value> {
-        // Find resource
-        let resource = self
-            .config
-            .resources
+    /// Handle MCP resources/templates/list request
+    pub async fn handle_resource_templates_list(
+        &self,
+        _session_id: String,
+    ) -> Result<Vec<McpResourceTemplate>> {
+        Ok(self
+            .resource_providers
             .iter()
-            .find(|r| r.uri == uri)
-            .ok_or_else(|| {
-                QollectiveError::mcp_tool_execution(format!("Resource not found: {}", uri))
-            })?;
+            .flat_map(|provider| provider.resource_templates())
+            .collect())
+    }
 
-        // Return resource content (simplified implementation)
-        Ok(json!({
-            "uri": resource.uri,
-            "name": resource.name,
-            "description": resource.description,
-            "content": "Resource content placeholder",
-            "mime_type": resource.mime_type
-        }))
+    /// Handle MCP resources/read request
+    pub async fn handle_resource_read(
+        &self,
+        _session_id: String,
+        uri: String,
+    ) -> Result<ReadResourceResult> {
+        for provider in &self.resource_providers {
+            if let Some(contents) = provider.read_resource(&uri).await? {
+                return Ok(ReadResourceResult { contents });
+            }
+        }
+
+        if self.config.resources.iter().any(|r| r.uri == uri) {
+            return Err(QollectiveError::mcp_tool_execution(format!(
+                "No content provider registered for resource: {}",
+                uri
+            )));
+        }
+        Err(QollectiveError::mcp_tool_execution(format!(
+            "Resource not found: {}",
+            uri
+        )))
     }
 
     /// Handle MCP prompts/list request
@@ -738,7 +905,7 @@ impl McpServer {
         _session_id: String,
         name: String,
         arguments: HashMap<String, Value>,
-    ) -> Result<Value> {
+    ) -> Result<GetPromptResult> {
         // Find prompt
         let prompt = self
             .config
@@ -749,20 +916,7 @@ impl McpServer {
                 QollectiveError::mcp_tool_execution(format!("Prompt not found: {}", name))
             })?;
 
-        // Process prompt template with arguments (simplified implementation)
-        let mut processed_template = prompt.template.clone();
-        for (key, value) in &arguments {
-            let placeholder = format!("{{{}}}", key);
-            let value_str = value.as_str().unwrap_or("");
-            processed_template = processed_template.replace(&placeholder, value_str);
-        }
-
-        Ok(json!({
-            "name": prompt.name,
-            "description": prompt.description,
-            "processed_template": processed_template,
-            "arguments": arguments
-        }))
+        prompt.render(&arguments)
     }
 
     /// Handle Qollective envelope-wrapped MCP request
@@ -770,19 +924,41 @@ impl McpServer {
         &self,
         envelope: Envelope<McpData>,
     ) -> Result<Envelope<McpData>> {
-        let (meta, data) = envelope.extract();
-
-        // Generate session ID from envelope metadata or create new one
-        let session_id = meta.request_id.clone().unwrap_or_else(|| Uuid::now_v7());
+        let (mut meta, data) = envelope.extract();
+        meta.record_service_hop();
+
+        // Envelopes share a session only when the caller names one in its security meta;
+        // otherwise the session lives for this request alone
+        let named_session = meta
+            .security
+            .as_ref()
+            .and_then(|security| security.session_id.clone());
+        let session_id = named_session
+            .clone()
+            .unwrap_or_else(|| Uuid::now_v7().to_string());
+        let route = match (&data.tool_call, &data.discovery_data) {
+            (Some(tool_call), _) => tool_call.params.name.to_string(),
+            (None, Some(discovery)) => discovery.query_type.clone(),
+            (None, None) => String::new(),
+        };
+        let _in_flight = self
+            .shutdown
+            .as_ref()
+            .map(|coordinator| coordinator.admit("mcp", &route))
+            .transpose()?;
+        let started = std::time::Instant::now();
 
         let response_data = if let Some(tool_call) = data.tool_call {
             // Ensure session exists for envelope-based tool calls
-            self.ensure_session_exists(session_id.to_string()).await?;
+            self.ensure_session_exists(session_id.clone()).await?;
 
-            // Handle tool call
-            let tool_result = self
-                .handle_tool_call(session_id.to_string(), tool_call)
-                .await?;
+            // Handle tool call within the caller's deadline
+            let tool_result =
+                deadline::scope(&meta, self.handle_tool_call(session_id.clone(), tool_call)).await;
+            if named_session.is_none() {
+                self.remove_session(&session_id).await?;
+            }
+            let tool_result = tool_result?;
             McpData {
                 tool_call: None,
                 tool_response: Some(tool_result),
@@ -793,7 +969,7 @@ impl McpServer {
             // Handle discovery request
             let response = match discovery_data.query_type.as_str() {
                 "list_tools" => {
-                    let tools = self.handle_tools_list(session_id.to_string()).await?;
+                    let tools = self.handle_tools_list(session_id).await?;
                     McpDiscoveryData {
                         query_type: "list_tools_response".to_string(),
                         tools: Some(tools),
@@ -822,10 +998,22 @@ impl McpServer {
 
         // Create response metadata using the proper preservation utility
         // This follows the same pattern as WebSocket and gRPC servers for consistency
-        let response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+        let mut response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+        response_meta.complete_service_hop(&meta.service_chain);
+        self.enrichers
+            .enrich(&mut response_meta, &route, started, &response_data);
+        self.meta_policy.apply(&mut response_meta, &route);
         Ok(Envelope::new(response_meta, response_data))
     }
 
+    /// Session id used for the rmcp connection served by this instance
+    ///
+    /// Clones share tools, resources and sessions but get their own connection id,
+    /// so one clone should be served per connection.
+    pub fn connection_id(&self) -> &str {
+        &self.connection_id
+    }
+
     /// Get server configuration
     pub fn get_config(&self) -> &McpServerConfig {
         &self.config
@@ -847,6 +1035,11 @@ impl McpServer {
         // For now, log that the tool was added to config
         tracing::info!("Tool '{}' added to server configuration", tool.name);
 
+        self.broadcast(ServerNotification::ToolListChangedNotification(
+            Default::default(),
+        ))
+        .await;
+
         Ok(())
     }
 
@@ -886,15 +1079,140 @@ impl McpServer {
     /// Add resource to server
     pub async fn add_resource(&mut self, resource: McpResource) -> Result<()> {
         self.config.resources.push(resource);
+        self.broadcast(ServerNotification::ResourceListChangedNotification(
+            Default::default(),
+        ))
+        .await;
+        Ok(())
+    }
+
+    /// Add a provider serving resource content
+    pub async fn add_resource_provider(
+        &mut self,
+        provider: Arc<dyn ResourceProvider>,
+    ) -> Result<()> {
+        self.resource_providers.push(provider);
+        self.broadcast(ServerNotification::ResourceListChangedNotification(
+            Default::default(),
+        ))
+        .await;
         Ok(())
     }
 
     /// Add prompt to server
     pub async fn add_prompt(&mut self, prompt: McpPrompt) -> Result<()> {
         self.config.prompts.push(prompt);
+        self.broadcast(ServerNotification::PromptListChangedNotification(
+            Default::default(),
+        ))
+        .await;
         Ok(())
     }
 
+    /// Open the notification channel of a session.
+    ///
+    /// The connection serving the session forwards everything received here to the
+    /// client. Opening the channel again replaces the previous one.
+    pub async fn subscribe_notifications(
+        &self,
+        session_id: &str,
+    ) -> Result<mpsc::UnboundedReceiver<ServerNotification>> {
+        let mut sessions = self.sessions.write().await;
+        let session = sessions.get_mut(session_id).ok_or_else(|| {
+            QollectiveError::mcp_protocol(format!("Session not found: {}", session_id))
+        })?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        session.notifier = Some(sender);
+        Ok(receiver)
+    }
+
+    /// Handle MCP resources/subscribe request
+    pub async fn handle_resource_subscribe(&self, session_id: String, uri: String) -> Result<()> {
+        if !self.resource_exists(&uri).await? {
+            return Err(QollectiveError::mcp_tool_execution(format!(
+                "Resource not found: {}",
+                uri
+            )));
+        }
+
+        let mut sessions = self.sessions.write().await;
+        let session = sessions.get_mut(&session_id).ok_or_else(|| {
+            QollectiveError::mcp_protocol(format!("Session not found: {}", session_id))
+        })?;
+        session.resource_subscriptions.insert(uri);
+        Ok(())
+    }
+
+    /// Handle MCP resources/unsubscribe request
+    pub async fn handle_resource_unsubscribe(&self, session_id: String, uri: String) -> Result<()> {
+        let mut sessions = self.sessions.write().await;
+        if let Some(session) = sessions.get_mut(&session_id) {
+            session.resource_subscriptions.remove(&uri);
+        }
+        Ok(())
+    }
+
+    /// Send notifications/resources/updated to every session subscribed to `uri`.
+    ///
+    /// Returns the number of sessions notified.
+    pub async fn notify_resource_updated(&self, uri: &str) -> usize {
+        let notification = ServerNotification::ResourceUpdatedNotification(
+            rmcp::model::ResourceUpdatedNotification::new(
+                rmcp::model::ResourceUpdatedNotificationParam {
+                    uri: uri.to_string(),
+                },
+            ),
+        );
+
+        let mut sessions = self.sessions.write().await;
+        let mut notified = 0;
+        for session in sessions.values_mut() {
+            if session.resource_subscriptions.contains(uri) && session.notify(notification.clone())
+            {
+                notified += 1;
+            }
+        }
+        notified
+    }
+
+    /// Send notifications/tools/list_changed to every connected session
+    pub async fn notify_tools_list_changed(&self) -> usize {
+        self.broadcast(ServerNotification::ToolListChangedNotification(
+            Default::default(),
+        ))
+        .await
+    }
+
+    /// Send a notification to every session with an open notification channel
+    async fn broadcast(&self, notification: ServerNotification) -> usize {
+        let mut sessions = self.sessions.write().await;
+        let mut notified = 0;
+        for session in sessions.values_mut() {
+            if session.notify(notification.clone()) {
+                notified += 1;
+            }
+        }
+        notified
+    }
+
+    /// Whether `uri` names a listed resource or matches a resource template
+    async fn resource_exists(&self, uri: &str) -> Result<bool> {
+        let resources = self.handle_resources_list(String::new()).await?;
+        if resources.iter().any(|r| r.uri == uri) {
+            return Ok(true);
+        }
+
+        Ok(self
+            .resource_providers
+            .iter()
+            .flat_map(|provider| provider.resource_templates())
+            .filter_map(|template| {
+                crate::server::mcp_resources::UriTemplate::parse(&template.uri_template).ok()
+            })
+            .any(|template| template.matches(uri).is_some()))
+    }
+
     /// Get active sessions count
     pub async fn get_active_sessions_count(&self) -> usize {
         let sessions = self.sessions.read().await;
@@ -920,15 +1238,10 @@ impl McpServer {
         let mut sessions = self.sessions.write().await;
         // Double-check pattern to avoid race condition
         if !sessions.contains_key(&session_id) {
-            sessions.insert(
-                session_id.clone(),
-                McpSession {
-                    session_id: session_id.clone(),
-                    client_capabilities: None, // Will be set when client initializes properly
-                    client_info: None,         // Will be set when client initializes properly
-                    is_initialized: true,      // Auto-initialize for envelope-based requests
-                },
-            );
+            // Client capabilities and info are set when the client initializes properly
+            let mut session = McpSession::new(session_id.clone());
+            session.is_initialized = true; // Auto-initialize for envelope-based requests
+            sessions.insert(session_id.clone(), session);
         }
         Ok(())
     }
@@ -941,6 +1254,12 @@ impl Clone for McpServer {
             transport: Arc::clone(&self.transport),
             registry: Arc::clone(&self.registry),
             sessions: Arc::clone(&self.sessions),
+            resource_providers: self.resource_providers.clone(),
+            meta_policy: self.meta_policy.clone(),
+            enrichers: self.enrichers.clone(),
+            shutdown: self.shutdown.clone(),
+            // Each clone serves its own rmcp connection
+            connection_id: Uuid::now_v7().to_string(),
         }
     }
 }
@@ -986,7 +1305,7 @@ impl rmcp::ServerHandler for McpServer {
             protocol_version: rmcp::model::ProtocolVersion::default(),
             capabilities: rmcp::model::ServerCapabilities {
                 tools: Some(rmcp::model::ToolsCapability { list_changed: Some(true) }),
-                resources: Some(rmcp::model::ResourcesCapability { list_changed: Some(true), subscribe: Some(false) }),
+                resources: Some(rmcp::model::ResourcesCapability { list_changed: Some(true), subscribe: Some(true) }),
                 prompts: Some(rmcp::model::PromptsCapability { list_changed: Some(true) }),
                 logging: None,
                 completions: None,
@@ -1001,6 +1320,70 @@ impl rmcp::ServerHandler for McpServer {
             )),
         }
     }
+
+    async fn initialize(
+        &self,
+        request: rmcp::model::InitializeRequestParam,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> std::result::Result<InitializeResult, rmcp::ErrorData> {
+        if context.peer.peer_info().is_none() {
+            context.peer.set_peer_info(request.clone());
+        }
+
+        self.handle_initialize(self.connection_id.clone(), InitializeRequest::new(request))
+            .await
+            .map_err(|e| rmcp::ErrorData::internal_error(e.to_string(), None))?;
+        let notifications = self
+            .subscribe_notifications(&self.connection_id)
+            .await
+            .map_err(|e| rmcp::ErrorData::internal_error(e.to_string(), None))?;
+        self.forward_notifications(notifications, context.peer);
+
+        Ok(self.get_info())
+    }
+
+    async fn subscribe(
+        &self,
+        request: rmcp::model::SubscribeRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> std::result::Result<(), rmcp::ErrorData> {
+        self.handle_resource_subscribe(self.connection_id.clone(), request.uri)
+            .await
+            .map_err(|e| rmcp::ErrorData::resource_not_found(e.to_string(), None))
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: rmcp::model::UnsubscribeRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> std::result::Result<(), rmcp::ErrorData> {
+        self.handle_resource_unsubscribe(self.connection_id.clone(), request.uri)
+            .await
+            .map_err(|e| rmcp::ErrorData::internal_error(e.to_string(), None))
+    }
+}
+
+#[cfg(feature = "mcp-server")]
+impl McpServer {
+    /// Push the session's notifications to the connected client through its rmcp peer
+    ///
+    /// The session is dropped once the client connection has closed.
+    fn forward_notifications(
+        &self,
+        mut notifications: mpsc::UnboundedReceiver<ServerNotification>,
+        peer: rmcp::Peer<rmcp::RoleServer>,
+    ) {
+        let sessions = Arc::clone(&self.sessions);
+        let session_id = self.connection_id.clone();
+        tokio::spawn(async move {
+            while let Some(notification) = notifications.recv().await {
+                if peer.send_notification(notification).await.is_err() {
+                    sessions.write().await.remove(&session_id);
+                    break;
+                }
+            }
+        });
+    }
 }
 
 #[async_trait]
@@ -1293,6 +1676,7 @@ mod tests {
     use crate::traits::receivers::UnifiedEnvelopeReceiver;
     use async_trait::async_trait;
     use serde::{Deserialize, Serialize};
+    use serde_json::json;
     use std::sync::Arc;
     use tokio::sync::Mutex;
 
@@ -1519,6 +1903,9 @@ mod tests {
                 assert!(text_content.text.contains("not found"));
             }
         }
+
+        // Without a named session the request leaves no session behind
+        assert_eq!(server.get_active_sessions_count().await, 0);
     }
 
     #[tokio::test]
@@ -1541,6 +1928,7 @@ mod tests {
             annotations: None,
             icons: None,
             title: None,
+            meta: None,
         };
 
         config.tools.push(tool);
@@ -1681,4 +2069,299 @@ mod tests {
             cloned_server.get_config().server_info.name
         );
     }
+
+    fn briefing_prompt() -> McpPrompt {
+        McpPrompt {
+            name: "briefing".to_string(),
+            description: Some("Mission briefing".to_string()),
+            template: "Brief {officer} on sector {sector}{note} using {{codes}}".to_string(),
+            arguments: vec![
+                McpPromptArgument {
+                    name: "officer".to_string(),
+                    description: None,
+                    required: true,
+                },
+                McpPromptArgument {
+                    name: "sector".to_string(),
+                    description: None,
+                    required: true,
+                },
+                McpPromptArgument {
+                    name: "note".to_string(),
+                    description: None,
+                    required: false,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prompt_get_renders_messages() {
+        let mut server = create_test_server();
+        server.add_prompt(briefing_prompt()).await.unwrap();
+
+        let arguments = HashMap::from([
+            ("officer".to_string(), json!("Worf")),
+            ("sector".to_string(), json!(42)),
+        ]);
+        let result = server
+            .handle_prompt_get("session_1".to_string(), "briefing".to_string(), arguments)
+            .await
+            .unwrap();
+
+        assert_eq!(result.description.as_deref(), Some("Mission briefing"));
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].role, PromptMessageRole::User);
+        assert_eq!(
+            result.messages[0].content,
+            rmcp::model::PromptMessageContent::Text {
+                text: "Brief Worf on sector 42 using {codes}".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prompt_get_requires_arguments() {
+        let mut server = create_test_server();
+        server.add_prompt(briefing_prompt()).await.unwrap();
+
+        let arguments = HashMap::from([("officer".to_string(), json!("Worf"))]);
+        let error = server
+            .handle_prompt_get("session_1".to_string(), "briefing".to_string(), arguments)
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("sector"));
+    }
+
+    #[tokio::test]
+    async fn test_resource_read_uses_providers() {
+        use crate::server::mcp_resources::{StaticResource, TemplateResourceProvider};
+        use rmcp::model::ResourceContents;
+
+        let mut server = create_test_server();
+        let manifest = McpResource {
+            uri: "ship://manifest".to_string(),
+            name: "manifest".to_string(),
+            description: None,
+            mime_type: Some("text/plain".to_string()),
+        };
+        server
+            .add_resource_provider(Arc::new(StaticResource::text(manifest, "NCC-1701-D")))
+            .await
+            .unwrap();
+        let deck_template = McpResourceTemplate {
+            uri_template: "ship://decks/{deck}".to_string(),
+            name: "deck".to_string(),
+            description: None,
+            mime_type: Some("text/plain".to_string()),
+        };
+        let decks = TemplateResourceProvider::new(deck_template, |uri, variables| async move {
+            Ok(vec![ResourceContents::text(
+                format!("Deck {}", variables["deck"]),
+                uri,
+            )])
+        })
+        .unwrap();
+        server.add_resource_provider(Arc::new(decks)).await.unwrap();
+
+        let resources = server.handle_resources_list("s".to_string()).await.unwrap();
+        assert_eq!(resources.len(), 1);
+        let templates = server
+            .handle_resource_templates_list("s".to_string())
+            .await
+            .unwrap();
+        assert_eq!(templates[0].uri_template, "ship://decks/{deck}");
+
+        let manifest = server
+            .handle_resource_read("s".to_string(), "ship://manifest".to_string())
+            .await
+            .unwrap();
+        assert!(matches!(
+            &manifest.contents[0],
+            ResourceContents::TextResourceContents { text, .. } if text == "NCC-1701-D"
+        ));
+
+        let deck = server
+            .handle_resource_read("s".to_string(), "ship://decks/10".to_string())
+            .await
+            .unwrap();
+        assert!(matches!(
+            &deck.contents[0],
+            ResourceContents::TextResourceContents { text, .. } if text == "Deck 10"
+        ));
+
+        assert!(server
+            .handle_resource_read("s".to_string(), "ship://unknown".to_string())
+            .await
+            .is_err());
+    }
+
+    async fn initialized_session(server: &McpServer, session_id: &str) {
+        server
+            .ensure_session_exists(session_id.to_string())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resource_subscription_notifications() {
+        use crate::server::mcp_resources::StaticResource;
+
+        let mut server = create_test_server();
+        let status = McpResource {
+            uri: "ship://status".to_string(),
+            name: "status".to_string(),
+            description: None,
+            mime_type: None,
+        };
+        server
+            .add_resource_provider(Arc::new(StaticResource::text(status, "green")))
+            .await
+            .unwrap();
+
+        initialized_session(&server, "bridge").await;
+        initialized_session(&server, "engineering").await;
+        let mut bridge = server.subscribe_notifications("bridge").await.unwrap();
+        let mut engineering = server.subscribe_notifications("engineering").await.unwrap();
+
+        server
+            .handle_resource_subscribe("bridge".to_string(), "ship://status".to_string())
+            .await
+            .unwrap();
+        assert!(server
+            .handle_resource_subscribe("bridge".to_string(), "ship://unknown".to_string())
+            .await
+            .is_err());
+
+        assert_eq!(server.notify_resource_updated("ship://status").await, 1);
+        match bridge.try_recv().unwrap() {
+            ServerNotification::ResourceUpdatedNotification(notification) => {
+                assert_eq!(notification.params.uri, "ship://status");
+            }
+            other => panic!("unexpected notification: {:?}", other),
+        }
+        assert!(engineering.try_recv().is_err());
+
+        server
+            .handle_resource_unsubscribe("bridge".to_string(), "ship://status".to_string())
+            .await
+            .unwrap();
+        assert_eq!(server.notify_resource_updated("ship://status").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_changed_notifications() {
+        let mut server = create_test_server();
+        initialized_session(&server, "bridge").await;
+        let mut bridge = server.subscribe_notifications("bridge").await.unwrap();
+
+        let tool: Tool = serde_json::from_value(json!({
+            "name": "scan",
+            "description": "Run a sensor sweep",
+            "inputSchema": {"type": "object"}
+        }))
+        .unwrap();
+        server.add_tool(tool).await.unwrap();
+        assert!(matches!(
+            bridge.try_recv().unwrap(),
+            ServerNotification::ToolListChangedNotification(_)
+        ));
+
+        server
+            .add_prompt(McpPrompt {
+                name: "greeting".to_string(),
+                description: None,
+                template: "Hello".to_string(),
+                arguments: vec![],
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            bridge.try_recv().unwrap(),
+            ServerNotification::PromptListChangedNotification(_)
+        ));
+
+        // A closed connection stops receiving notifications
+        drop(bridge);
+        assert_eq!(server.notify_tools_list_changed().await, 0);
+        assert!(server.subscribe_notifications("missing").await.is_err());
+    }
+
+    #[cfg(feature = "mcp-server")]
+    #[tokio::test]
+    async fn test_rmcp_client_receives_subscribed_resource_updates() {
+        use crate::server::mcp_resources::StaticResource;
+        use rmcp::model::{ResourceUpdatedNotificationParam, SubscribeRequestParam};
+        use rmcp::service::NotificationContext;
+        use rmcp::{RoleClient, ServiceExt};
+
+        /// Client forwarding the URIs of updated resources to the test
+        struct UpdateListener(mpsc::UnboundedSender<String>);
+
+        impl rmcp::ClientHandler for UpdateListener {
+            async fn on_resource_updated(
+                &self,
+                params: ResourceUpdatedNotificationParam,
+                _context: NotificationContext<RoleClient>,
+            ) {
+                let _ = self.0.send(params.uri);
+            }
+        }
+
+        // ARRANGE: a server with one resource serving an rmcp client over a duplex pipe
+        let mut server = create_test_server();
+        let status = McpResource {
+            uri: "ship://status".to_string(),
+            name: "status".to_string(),
+            description: None,
+            mime_type: None,
+        };
+        server
+            .add_resource_provider(Arc::new(StaticResource::text(status, "green")))
+            .await
+            .unwrap();
+        let (server_io, client_io) = tokio::io::duplex(4096);
+        let connection = server.clone();
+        assert_ne!(connection.connection_id(), server.connection_id());
+        tokio::spawn(async move {
+            if let Ok(running) = connection.serve(server_io).await {
+                let _ = running.waiting().await;
+            }
+        });
+        let (updates_tx, mut updates) = mpsc::unbounded_channel();
+        let client = UpdateListener(updates_tx).serve(client_io).await.unwrap();
+
+        // ACT: subscribe through the protocol and publish an update
+        client
+            .subscribe(SubscribeRequestParam {
+                uri: "ship://status".to_string(),
+            })
+            .await
+            .unwrap();
+        let unknown = client
+            .subscribe(SubscribeRequestParam {
+                uri: "ship://unknown".to_string(),
+            })
+            .await;
+        let notified = server.notify_resource_updated("ship://status").await;
+
+        // ASSERT: the notification reaches the client through its rmcp peer
+        assert!(unknown.is_err());
+        assert_eq!(notified, 1);
+        let uri = tokio::time::timeout(std::time::Duration::from_secs(2), updates.recv())
+            .await
+            .expect("resource update should be pushed to the client")
+            .unwrap();
+        assert_eq!(uri, "ship://status");
+
+        client
+            .unsubscribe(rmcp::model::UnsubscribeRequestParam {
+                uri: "ship://status".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(server.notify_resource_updated("ship://status").await, 0);
+        client.cancel().await.unwrap();
+    }
 }
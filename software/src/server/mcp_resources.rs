@@ -0,0 +1,603 @@
+// ABOUTME: Resource providers backing MCP resources/list and resources/read with real content
+// ABOUTME: Offers static text/blob resources, file-system directories and closure-backed URI templates
+
+//! MCP resource providers.
+//!
+//! `McpServer` serves resource content through registered `ResourceProvider`s:
+//! - `StaticResource` for fixed text or binary content
+//! - `DirectoryResourceProvider` for files below a directory on disk
+//! - `TemplateResourceProvider` for content computed by a closure from a URI template
+
+use crate::error::{QollectiveError, Result};
+use crate::server::mcp::{McpResource, McpResourceTemplate};
+use async_trait::async_trait;
+use base64::prelude::*;
+use rmcp::model::ResourceContents;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Component, Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Source of MCP resource content
+#[async_trait]
+pub trait ResourceProvider: Send + Sync {
+    /// Concrete resources served by this provider
+    async fn list_resources(&self) -> Result<Vec<McpResource>>;
+
+    /// URI templates for parameterized resources served by this provider
+    fn resource_templates(&self) -> Vec<McpResourceTemplate> {
+        Vec::new()
+    }
+
+    /// Read the resource at `uri`, returning `None` when this provider does not serve it
+    async fn read_resource(&self, uri: &str) -> Result<Option<Vec<ResourceContents>>>;
+}
+
+/// Build text resource contents
+pub fn text_contents(
+    uri: impl Into<String>,
+    mime_type: Option<String>,
+    text: impl Into<String>,
+) -> ResourceContents {
+    ResourceContents::TextResourceContents {
+        uri: uri.into(),
+        mime_type,
+        text: text.into(),
+        meta: None,
+    }
+}
+
+/// Build binary resource contents, base64-encoding the data
+pub fn blob_contents(
+    uri: impl Into<String>,
+    mime_type: Option<String>,
+    data: &[u8],
+) -> ResourceContents {
+    ResourceContents::BlobResourceContents {
+        uri: uri.into(),
+        mime_type,
+        blob: BASE64_STANDARD.encode(data),
+        meta: None,
+    }
+}
+
+#[derive(Debug, Clone)]
+enum StaticContent {
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// Resource with fixed content
+#[derive(Debug, Clone)]
+pub struct StaticResource {
+    resource: McpResource,
+    content: StaticContent,
+}
+
+impl StaticResource {
+    /// Serve `text` as the content of `resource`
+    pub fn text(resource: McpResource, text: impl Into<String>) -> Self {
+        Self {
+            resource,
+            content: StaticContent::Text(text.into()),
+        }
+    }
+
+    /// Serve `data` as the base64-encoded content of `resource`
+    pub fn blob(resource: McpResource, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            resource,
+            content: StaticContent::Blob(data.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl ResourceProvider for StaticResource {
+    async fn list_resources(&self) -> Result<Vec<McpResource>> {
+        Ok(vec![self.resource.clone()])
+    }
+
+    async fn read_resource(&self, uri: &str) -> Result<Option<Vec<ResourceContents>>> {
+        if uri != self.resource.uri {
+            return Ok(None);
+        }
+
+        let mime_type = self.resource.mime_type.clone();
+        let contents = match &self.content {
+            StaticContent::Text(text) => text_contents(uri, mime_type, text.as_str()),
+            StaticContent::Blob(data) => blob_contents(uri, mime_type, data),
+        };
+        Ok(Some(vec![contents]))
+    }
+}
+
+/// Serves the files below a directory, addressed as `<uri_prefix><relative path>`.
+///
+/// UTF-8 files are returned as text, everything else as base64 blobs. Paths that
+/// would leave the directory (`..` segments or symlinks) are rejected.
+#[derive(Debug, Clone)]
+pub struct DirectoryResourceProvider {
+    root: PathBuf,
+    uri_prefix: String,
+    recursive: bool,
+}
+
+impl DirectoryResourceProvider {
+    /// Serve the files of `root` under URIs starting with `uri_prefix`, e.g. `file:///docs/`
+    pub fn new(root: impl AsRef<Path>, uri_prefix: impl Into<String>) -> Result<Self> {
+        let root = root.as_ref().canonicalize().map_err(|e| {
+            QollectiveError::config(format!(
+                "Invalid resource directory {}: {}",
+                root.as_ref().display(),
+                e
+            ))
+        })?;
+        if !root.is_dir() {
+            return Err(QollectiveError::config(format!(
+                "Resource path {} is not a directory",
+                root.display()
+            )));
+        }
+
+        let mut uri_prefix = uri_prefix.into();
+        if !uri_prefix.ends_with('/') {
+            uri_prefix.push('/');
+        }
+
+        Ok(Self {
+            root,
+            uri_prefix,
+            recursive: true,
+        })
+    }
+
+    /// Only list files directly inside the directory
+    pub fn non_recursive(mut self) -> Self {
+        self.recursive = false;
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn uri_prefix(&self) -> &str {
+        &self.uri_prefix
+    }
+
+    fn collect_files(&self, dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                if self.recursive {
+                    self.collect_files(&path, files)?;
+                }
+            } else if path.is_file() {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    fn uri_for(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let segments: Vec<_> = relative
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<_>>()?;
+        Some(format!("{}{}", self.uri_prefix, segments.join("/")))
+    }
+
+    /// Map a URI to a file below the root, or `None` if it is outside this provider
+    fn path_for(&self, uri: &str) -> Result<Option<PathBuf>> {
+        let Some(relative) = uri.strip_prefix(&self.uri_prefix) else {
+            return Ok(None);
+        };
+
+        let relative = Path::new(relative);
+        if relative.as_os_str().is_empty()
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(QollectiveError::validation(format!(
+                "Invalid resource path in URI: {}",
+                uri
+            )));
+        }
+
+        let path = match self.root.join(relative).canonicalize() {
+            Ok(path) => path,
+            Err(_) => return Ok(None),
+        };
+        if !path.starts_with(&self.root) || !path.is_file() {
+            return Ok(None);
+        }
+        Ok(Some(path))
+    }
+}
+
+#[async_trait]
+impl ResourceProvider for DirectoryResourceProvider {
+    async fn list_resources(&self) -> Result<Vec<McpResource>> {
+        let provider = self.clone();
+        let mut files = tokio::task::spawn_blocking(move || {
+            let mut files = Vec::new();
+            provider
+                .collect_files(&provider.root, &mut files)
+                .map(|_| files)
+        })
+        .await
+        .map_err(|e| QollectiveError::internal(format!("Directory listing failed: {}", e)))?
+        .map_err(|e| {
+            QollectiveError::internal(format!(
+                "Failed to list resource directory {}: {}",
+                self.root.display(),
+                e
+            ))
+        })?;
+        files.sort();
+
+        Ok(files
+            .iter()
+            .filter_map(|path| {
+                Some(McpResource {
+                    uri: self.uri_for(path)?,
+                    name: path.file_name()?.to_string_lossy().into_owned(),
+                    description: None,
+                    mime_type: mime_type_for(path).map(str::to_string),
+                })
+            })
+            .collect())
+    }
+
+    async fn read_resource(&self, uri: &str) -> Result<Option<Vec<ResourceContents>>> {
+        let Some(path) = self.path_for(uri)? else {
+            return Ok(None);
+        };
+
+        let data = tokio::fs::read(&path).await.map_err(|e| {
+            QollectiveError::internal(format!("Failed to read resource {}: {}", uri, e))
+        })?;
+        let mime_type = mime_type_for(&path);
+
+        let contents = match String::from_utf8(data) {
+            Ok(text) => text_contents(
+                uri,
+                Some(mime_type.unwrap_or("text/plain").to_string()),
+                text,
+            ),
+            Err(e) => blob_contents(
+                uri,
+                Some(mime_type.unwrap_or("application/octet-stream").to_string()),
+                e.as_bytes(),
+            ),
+        };
+        Ok(Some(vec![contents]))
+    }
+}
+
+/// MIME type for well-known file extensions
+fn mime_type_for(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let mime_type = match extension.as_str() {
+        "txt" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "rs" => "text/x-rust",
+        "js" => "text/javascript",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "yaml" | "yml" => "application/yaml",
+        "toml" => "application/toml",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        _ => return None,
+    };
+    Some(mime_type)
+}
+
+/// Level 1 RFC 6570 URI template such as `weather://{city}/current`.
+///
+/// Each `{name}` expression matches one non-empty URI segment (no `/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UriTemplate {
+    template: String,
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Literal(String),
+    Variable(String),
+}
+
+impl UriTemplate {
+    pub fn parse(template: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                parts.push(TemplatePart::Literal(rest[..start].to_string()));
+            }
+            let end = rest[start..].find('}').ok_or_else(|| {
+                QollectiveError::validation(format!(
+                    "Unclosed expression in URI template: {}",
+                    template
+                ))
+            })? + start;
+
+            let name = &rest[start + 1..end];
+            if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return Err(QollectiveError::validation(format!(
+                    "Invalid variable '{}' in URI template: {}",
+                    name, template
+                )));
+            }
+            if matches!(parts.last(), Some(TemplatePart::Variable(_))) {
+                return Err(QollectiveError::validation(format!(
+                    "Adjacent variables in URI template are ambiguous: {}",
+                    template
+                )));
+            }
+            parts.push(TemplatePart::Variable(name.to_string()));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_string()));
+        }
+
+        Ok(Self {
+            template: template.to_string(),
+            parts,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Names of the template variables in order of appearance
+    pub fn variables(&self) -> Vec<&str> {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                TemplatePart::Variable(name) => Some(name.as_str()),
+                TemplatePart::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Match a URI against the template, returning the variable values on success
+    pub fn matches(&self, uri: &str) -> Option<HashMap<String, String>> {
+        let mut values = HashMap::new();
+        let mut rest = uri;
+
+        for (index, part) in self.parts.iter().enumerate() {
+            match part {
+                TemplatePart::Literal(literal) => rest = rest.strip_prefix(literal.as_str())?,
+                TemplatePart::Variable(name) => {
+                    let end = match self.parts.get(index + 1) {
+                        Some(TemplatePart::Literal(next)) => rest.find(next.as_str())?,
+                        _ => rest.len(),
+                    };
+                    let value = &rest[..end];
+                    if value.is_empty() || value.contains('/') {
+                        return None;
+                    }
+                    values.insert(name.clone(), value.to_string());
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        rest.is_empty().then_some(values)
+    }
+}
+
+type ResourceReadFuture = Pin<Box<dyn Future<Output = Result<Vec<ResourceContents>>> + Send>>;
+type ResourceReader =
+    Arc<dyn Fn(String, HashMap<String, String>) -> ResourceReadFuture + Send + Sync>;
+
+/// Resource whose content is produced by a closure for every URI matching a template.
+///
+/// The closure receives the requested URI and the template variable values. A
+/// template without variables is also listed as a concrete resource.
+#[derive(Clone)]
+pub struct TemplateResourceProvider {
+    template: McpResourceTemplate,
+    uri_template: UriTemplate,
+    reader: ResourceReader,
+}
+
+impl TemplateResourceProvider {
+    pub fn new<F, Fut>(template: McpResourceTemplate, reader: F) -> Result<Self>
+    where
+        F: Fn(String, HashMap<String, String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<ResourceContents>>> + Send + 'static,
+    {
+        let uri_template = UriTemplate::parse(&template.uri_template)?;
+        Ok(Self {
+            template,
+            uri_template,
+            reader: Arc::new(move |uri, variables| Box::pin(reader(uri, variables))),
+        })
+    }
+}
+
+impl std::fmt::Debug for TemplateResourceProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemplateResourceProvider")
+            .field("template", &self.template)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl ResourceProvider for TemplateResourceProvider {
+    async fn list_resources(&self) -> Result<Vec<McpResource>> {
+        if !self.uri_template.variables().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![McpResource {
+            uri: self.template.uri_template.clone(),
+            name: self.template.name.clone(),
+            description: self.template.description.clone(),
+            mime_type: self.template.mime_type.clone(),
+        }])
+    }
+
+    fn resource_templates(&self) -> Vec<McpResourceTemplate> {
+        if self.uri_template.variables().is_empty() {
+            return Vec::new();
+        }
+        vec![self.template.clone()]
+    }
+
+    async fn read_resource(&self, uri: &str) -> Result<Option<Vec<ResourceContents>>> {
+        let Some(variables) = self.uri_template.matches(uri) else {
+            return Ok(None);
+        };
+        (self.reader)(uri.to_string(), variables).await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(uri: &str) -> McpResource {
+        McpResource {
+            uri: uri.to_string(),
+            name: "logo".to_string(),
+            description: None,
+            mime_type: Some("image/png".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_uri_template_matching() {
+        let template = UriTemplate::parse("weather://{city}/forecast/{day}").unwrap();
+
+        assert_eq!(template.variables(), vec!["city", "day"]);
+        let values = template
+            .matches("weather://berlin/forecast/monday")
+            .unwrap();
+        assert_eq!(values["city"], "berlin");
+        assert_eq!(values["day"], "monday");
+
+        assert!(template.matches("weather://berlin/forecast/").is_none());
+        assert!(template.matches("weather://a/b/forecast/monday").is_none());
+        assert!(template
+            .matches("weather://berlin/history/monday")
+            .is_none());
+
+        assert!(UriTemplate::parse("weather://{city").is_err());
+        assert!(UriTemplate::parse("weather://{city}{day}").is_err());
+        assert!(UriTemplate::parse("weather://{}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_static_blob_resource() {
+        let provider = StaticResource::blob(resource("assets://logo.png"), vec![0x89, 0x50, 0x4e]);
+
+        assert_eq!(provider.list_resources().await.unwrap().len(), 1);
+        assert!(provider
+            .read_resource("assets://other.png")
+            .await
+            .unwrap()
+            .is_none());
+
+        let contents = provider
+            .read_resource("assets://logo.png")
+            .await
+            .unwrap()
+            .unwrap();
+        match &contents[0] {
+            ResourceContents::BlobResourceContents {
+                blob, mime_type, ..
+            } => {
+                assert_eq!(
+                    BASE64_STANDARD.decode(blob).unwrap(),
+                    vec![0x89, 0x50, 0x4e]
+                );
+                assert_eq!(mime_type.as_deref(), Some("image/png"));
+            }
+            other => panic!("expected blob contents, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_directory_provider_lists_and_reads_files() {
+        let root = std::env::temp_dir().join(format!("qollective-mcp-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(root.join("guides")).unwrap();
+        std::fs::write(root.join("readme.md"), "# Bridge").unwrap();
+        std::fs::write(root.join("guides/warp.txt"), "engage").unwrap();
+        std::fs::write(root.join("core.bin"), [0xff, 0xfe, 0x00]).unwrap();
+
+        let provider = DirectoryResourceProvider::new(&root, "docs://ship").unwrap();
+
+        let uris: Vec<_> = provider
+            .list_resources()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|resource| resource.uri)
+            .collect();
+        assert_eq!(
+            uris,
+            vec![
+                "docs://ship/core.bin",
+                "docs://ship/guides/warp.txt",
+                "docs://ship/readme.md"
+            ]
+        );
+
+        let contents = provider
+            .read_resource("docs://ship/readme.md")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            &contents[0],
+            ResourceContents::TextResourceContents { text, mime_type, .. }
+                if text == "# Bridge" && mime_type.as_deref() == Some("text/markdown")
+        ));
+
+        let contents = provider
+            .read_resource("docs://ship/core.bin")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            &contents[0],
+            ResourceContents::BlobResourceContents { .. }
+        ));
+
+        assert!(provider
+            .read_resource("docs://ship/missing.md")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(provider
+            .read_resource("other://readme.md")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(provider
+            .read_resource("docs://ship/../secret")
+            .await
+            .is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -46,15 +46,18 @@ impl RestServerMiddleware {
         self.envelope_middleware.extract_context(&header_adapter)
     }
 
-    /// Record HTTP request metrics
+    /// Record HTTP request metrics under the route template the request matched
     pub fn record_http_metrics(
         &self,
         method: &str,
-        path: &str,
+        matched: Option<&axum::extract::MatchedPath>,
         status: u16,
         duration: std::time::Duration,
     ) {
-        crate::monitoring::record_http_request(method, path, status, duration);
+        let route = matched.map_or(crate::constants::metrics::UNMATCHED_ROUTE, |matched| {
+            matched.as_str()
+        });
+        crate::monitoring::record_http_request(method, route, status, duration);
     }
 
     /// Record envelope operation metrics
@@ -161,7 +164,7 @@ where
         Box::pin(async move {
             let start_time = std::time::Instant::now();
             let method = request.method().to_string();
-            let path = request.uri().path().to_string();
+            let route = matched_route(&request);
 
             // Extract context from request headers
             let header_adapter = AxumHeaderAdapter::from_headers(request.headers());
@@ -182,22 +185,35 @@ where
             // Record metrics
             let duration = start_time.elapsed();
             let status = response.status().as_u16();
-            crate::monitoring::record_http_request(&method, &path, status, duration);
+            crate::monitoring::record_http_request(&method, &route, status, duration);
 
             Ok(response)
         })
     }
 }
 
+/// Route template the router matched for `request`, used to label its metrics
+///
+/// Requests that matched no route share the `unmatched` label, so request
+/// paths never become label values.
+#[cfg(feature = "rest-server")]
+pub(crate) fn matched_route<B>(request: &axum::http::Request<B>) -> String {
+    request
+        .extensions()
+        .get::<axum::extract::MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| crate::constants::metrics::UNMATCHED_ROUTE.to_string())
+}
+
 /// Read-only adapter for axum HeaderMap
 #[cfg(feature = "rest-server")]
-struct AxumHeaderAdapter<'a> {
+pub(crate) struct AxumHeaderAdapter<'a> {
     headers: &'a axum::http::HeaderMap,
 }
 
 #[cfg(feature = "rest-server")]
 impl<'a> AxumHeaderAdapter<'a> {
-    fn from_headers(headers: &'a axum::http::HeaderMap) -> Self {
+    pub(crate) fn from_headers(headers: &'a axum::http::HeaderMap) -> Self {
         Self { headers }
     }
 }
@@ -620,6 +636,38 @@ mod tests {
         assert_eq!(headers.get("x-version").unwrap().to_str().unwrap(), "3.0.0");
     }
 
+    #[cfg(all(feature = "rest-server", feature = "metrics"))]
+    #[test]
+    fn test_http_metrics_never_label_raw_paths() {
+        use tower::{Layer, ServiceExt};
+
+        let middleware = RestServerMiddleware::new();
+        let router = axum::Router::new().route("/crew/{id}", axum::routing::get(|| async { "" }));
+        let service = middleware.axum_layer().layer(router);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let output = crate::monitoring::render_with(|| {
+            runtime.block_on(async {
+                let request = axum::http::Request::get("/crew/riker-7")
+                    .body(axum::body::Body::empty())
+                    .unwrap();
+                service.oneshot(request).await.unwrap();
+            });
+            middleware.record_http_metrics("GET", None, 404, std::time::Duration::from_millis(3));
+        });
+
+        // Outside the router no route was matched, so both land on the shared label
+        assert!(output.contains(
+            r#"qollective_requests_total{transport="rest",route="GET unmatched",status="200",tenant="none"} 1"#
+        ));
+        assert!(output.contains(
+            r#"qollective_requests_total{transport="rest",route="GET unmatched",status="404",tenant="none"} 1"#
+        ));
+        assert!(!output.contains("riker-7"));
+    }
+
     #[cfg(feature = "grpc-server")]
     #[test]
     fn test_grpc_server_middleware_creation() {
@@ -18,22 +18,41 @@ pub mod nats;
 #[cfg(feature = "a2a-server")]
 pub mod a2a;
 
+#[cfg(all(
+    feature = "a2a-server",
+    feature = "a2a-standard",
+    feature = "rest-server"
+))]
+pub mod a2a_http;
+
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
 pub mod mcp;
 
+#[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
+pub mod mcp_resources;
+
 #[cfg(feature = "mcp-jsonrpc")]
 pub mod mcp_jsonrpc;
 
 #[cfg(feature = "websocket-server")]
 pub mod websocket;
 
+#[cfg(feature = "websocket-server")]
+pub mod websocket_connections;
+
 // Common server traits and utilities
 pub mod common;
 
 // Middleware utilities for context propagation
 pub mod middleware;
 
-pub use common::{ServerBuilder, ServerConfig};
+// Graceful drain shared by all servers
+pub mod shutdown;
+
+#[cfg(feature = "security")]
+pub use common::BearerAuthenticator;
+pub use common::{ServerBuilder, ServerConfig, TenantAuthenticator};
+pub use shutdown::{DrainReport, ShutdownConfig, ShutdownCoordinator};
 
 #[cfg(feature = "rest-server")]
 pub use rest::{
@@ -50,10 +69,21 @@ pub use crate::traits::handlers::EnvelopeHandler;
 pub use a2a::A2AServer;
 
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
-pub use mcp::{McpPrompt, McpPromptArgument, McpResource, McpServer, McpServerConfig};
+pub use mcp::{
+    McpPrompt, McpPromptArgument, McpResource, McpResourceTemplate, McpServer, McpServerConfig,
+};
+
+#[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
+pub use mcp_resources::{
+    DirectoryResourceProvider, ResourceProvider, StaticResource, TemplateResourceProvider,
+    UriTemplate,
+};
 
 #[cfg(feature = "mcp-jsonrpc")]
 pub use mcp_jsonrpc::{McpJsonRpcServer, McpJsonRpcServerConfig};
 
 #[cfg(feature = "websocket-server")]
 pub use websocket::{WebSocketServer, WebSocketServerConfig};
+
+#[cfg(feature = "websocket-server")]
+pub use websocket_connections::{ConnectionId, ConnectionInfo, PushOutcome, WebSocketConnections};
@@ -11,9 +11,17 @@
 use crate::{
     config::tls::TlsConfig,
     constants::{http::{envelope_headers, envelope_query_params}, metadata::PROTOCOL_EXTENSION_KEY},
-    envelope::{Context, Envelope, EnvelopeError, Meta},
+    envelope::{
+        deadline,
+        meta::{OutgoingMetaPolicy, SpanKind},
+        trace_context, Context, Envelope, EnvelopeError, Meta, MetaEnrichers,
+    },
     error::{QollectiveError, Result},
-    server::common::ServerConfig,
+    server::{
+        common::{ServerConfig, TenantAuthenticator},
+        middleware::{matched_route, AxumHeaderAdapter},
+        shutdown::{self, ShutdownCoordinator},
+    },
     traits::{handlers::ContextDataHandler, receivers::UnifiedEnvelopeReceiver},
 };
 
@@ -414,6 +422,15 @@ pub fn extract_metadata_from_http(
         }
     }
 
+    // W3C trace context headers describe the immediate caller
+    if let Some(tracing) = trace_context::extract(&AxumHeaderAdapter::from_headers(headers)) {
+        trace_context::merge_into_meta(&mut meta, tracing);
+    }
+
+    // Deadline headers carry the time the caller has left
+    let incoming_deadline = deadline::extract(&AxumHeaderAdapter::from_headers(headers));
+    deadline::merge_into_meta(&mut meta, incoming_deadline);
+
     // Set timestamp if not already set
     if meta.timestamp.is_none() {
         meta.timestamp = Some(chrono::Utc::now());
@@ -462,6 +479,16 @@ pub fn inject_metadata_into_headers(
         )?;
     }
 
+    // Inject service chain as complex metadata
+    if let Some(encoded_meta) = encode_service_chain(&meta.service_chain, config)? {
+        inject_header(
+            &mut headers,
+            envelope_headers::QOLLECTIVE_META,
+            &encoded_meta,
+            config,
+        )?;
+    }
+
     // Inject timestamp (no encoding needed)
     if let Some(timestamp) = &meta.timestamp {
         inject_header(
@@ -525,6 +552,26 @@ fn inject_header(
     Ok(())
 }
 
+/// Encode the most recent hops of the service chain that fit in one header
+///
+/// The envelope body always carries the full chain, so a long chain is cut
+/// from the oldest hop instead of failing the response. `None` when the chain
+/// is empty or not even the last hop fits.
+#[cfg(feature = "rest-server")]
+fn encode_service_chain(
+    chain: &[crate::envelope::ServiceChainEntry],
+    config: &MetadataHandlingConfig,
+) -> Result<Option<String>> {
+    for start in 0..chain.len() {
+        let complex_meta = serde_json::json!({ "service_chain": &chain[start..] });
+        let encoded_meta = encode_metadata_value(&complex_meta.to_string(), &config.encoding)?;
+        if encoded_meta.len() <= config.max_header_size {
+            return Ok(Some(encoded_meta));
+        }
+    }
+    Ok(None)
+}
+
 #[cfg(feature = "rest-server")]
 fn encode_metadata_value(value: &str, encoding: &MetadataEncoding) -> Result<String> {
     match encoding {
@@ -573,6 +620,11 @@ fn merge_metadata(meta: &mut Meta, additional: HashMap<String, serde_json::Value
                     meta.version = Some(version);
                 }
             }
+            "service_chain" => {
+                if let Ok(service_chain) = serde_json::from_value(value) {
+                    meta.service_chain = service_chain;
+                }
+            }
             _ => {
                 // Store in custom fields if available in Meta
                 // For now, we skip unknown fields
@@ -679,6 +731,9 @@ pub fn create_basic_axum_router(routes: &[String], config: &RestServerConfig) ->
         router = router.route("/health", get(health_check_handler));
     }
 
+    // Record latency, status and in-flight metrics for every matched route
+    router = router.route_layer(axum::middleware::from_fn(record_request_metrics));
+
     // Add tracing layer for debugging
     router = router.layer(TraceLayer::new_for_http());
 
@@ -692,6 +747,57 @@ pub fn create_basic_axum_router(routes: &[String], config: &RestServerConfig) ->
     Ok(router)
 }
 
+/// Record request metrics labelled by matched route, status code and tenant
+///
+/// The tenant header is not authenticated here; `monitoring::record_request`
+/// bounds which values become labels.
+#[cfg(feature = "rest-server")]
+async fn record_request_metrics(
+    request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let route = format!("{} {}", request.method(), matched_route(&request));
+    let tenant = extract_metadata_from_http(
+        request.headers(),
+        &HashMap::new(),
+        &MetadataHandlingConfig::default(),
+    )
+    .ok()
+    .and_then(|meta| meta.tenant);
+
+    let _in_flight = crate::monitoring::track_in_flight("rest", &route);
+    let timer = crate::monitoring::start_operation_timer();
+    let response = next.run(request).await;
+    crate::monitoring::record_request(
+        "rest",
+        &route,
+        response.status().as_str(),
+        tenant.as_deref(),
+        timer.elapsed(),
+    );
+    response
+}
+
+/// Refuse requests once draining has started and count admitted ones as in flight
+#[cfg(feature = "rest-server")]
+async fn admit_request(
+    axum::extract::State(coordinator): axum::extract::State<ShutdownCoordinator>,
+    request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let route = format!("{} {}", request.method(), matched_route(&request));
+
+    match coordinator.admit("rest", &route) {
+        Ok(_in_flight) => next.run(request).await,
+        Err(e) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(axum::http::header::CONNECTION, "close")],
+            e.to_string(),
+        )
+            .into_response(),
+    }
+}
+
 /// Helper function to inject protocol metadata into envelope extensions
 #[cfg(feature = "rest-server")]
 fn inject_protocol_metadata_into_meta(
@@ -783,8 +889,8 @@ async fn route_request_with_registry(
             .await
             {
                 Ok((response_data, response_meta)) => {
-                    // Create proper envelope response using builder pattern
-                    let envelope = create_response_envelope(response_data, response_meta.clone());
+                    // The handler already built the response metadata, enriched and filtered
+                    let envelope = Envelope::new(response_meta.clone(), response_data);
 
                     // Create response headers with metadata
                     match inject_metadata_into_headers(&response_meta, &metadata_config) {
@@ -839,6 +945,28 @@ async fn route_request_with_registry(
                             http_status_code: Some(413),
                         };
                         return create_error_envelope_response(error, None);
+                    } else if matches!(e, QollectiveError::Security(_)) {
+                        let error = QollectiveError::auth_error(
+                            error_message,
+                            Some(serde_json::json!({"operation": "tenant_authentication"})),
+                        );
+                        return create_error_envelope_response(error, None);
+                    } else if matches!(e, QollectiveError::DeadlineExceeded(_)) {
+                        let error = EnvelopeError {
+                            code: "DEADLINE_EXCEEDED".to_string(),
+                            message: error_message,
+                            details: Some(serde_json::json!({"operation": "handler_execution"})),
+                            trace: None,
+                            #[cfg(any(
+                                feature = "rest-server",
+                                feature = "rest-client",
+                                feature = "websocket-server",
+                                feature = "websocket-client",
+                                feature = "a2a"
+                            ))]
+                            http_status_code: Some(504),
+                        };
+                        return create_error_envelope_response(error, None);
                     } else {
                         let error = EnvelopeError {
                             code: "HANDLER_ERROR".to_string(),
@@ -1102,6 +1230,14 @@ pub struct RestServer {
     handlers: HashMap<String, HandlerInfo>, // Route -> Handler info mapping
     listener: Option<TcpListener>,
     shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    shutdown: Option<ShutdownCoordinator>,
+    meta_policy: OutgoingMetaPolicy,
+    enrichers: MetaEnrichers,
+    tenant_authenticator: TenantAuthenticator,
+    #[cfg(feature = "security")]
+    bearer_authenticator: crate::server::common::BearerAuthenticator,
+    #[cfg(feature = "metrics")]
+    metrics_endpoint: Option<(String, crate::monitoring::MetricsExporter)>,
 }
 
 #[cfg(feature = "rest-server")]
@@ -1148,9 +1284,73 @@ impl RestServer {
             handlers: HashMap::new(),
             listener: None,
             shutdown_tx: None,
+            shutdown: None,
+            meta_policy: OutgoingMetaPolicy::environment(),
+            enrichers: MetaEnrichers::new(),
+            tenant_authenticator: TenantAuthenticator::default(),
+            #[cfg(feature = "security")]
+            bearer_authenticator: Default::default(),
+            #[cfg(feature = "metrics")]
+            metrics_endpoint: None,
         })
     }
 
+    /// Enforce `policy` on the metadata of every response
+    ///
+    /// Applies to handlers registered after this call.
+    pub fn with_meta_policy(mut self, policy: impl Into<OutgoingMetaPolicy>) -> Self {
+        self.meta_policy = policy.into();
+        self
+    }
+
+    /// Run `enrichers` on the metadata of every response, before the meta policy
+    ///
+    /// Applies to handlers registered after this call.
+    pub fn with_enrichers(mut self, enrichers: MetaEnrichers) -> Self {
+        self.enrichers = enrichers;
+        self
+    }
+
+    /// Take request tenants from bearer tokens verified as `config` describes
+    ///
+    /// Requests with a token that fails verification are answered with 401.
+    /// Fails if the verification keys cannot be loaded. Applies to handlers
+    /// registered after this call.
+    #[cfg(feature = "tenant-extraction")]
+    pub fn with_tenant_extraction_config(
+        mut self,
+        config: &crate::tenant::extraction::ExtractionConfig,
+    ) -> Result<Self> {
+        self.tenant_authenticator = TenantAuthenticator::from_extraction_config(config)?;
+        Ok(self)
+    }
+
+    /// Require every request to carry a bearer token `validator` accepts
+    ///
+    /// Requests without one, or with a token the validator rejects, are
+    /// answered with 401. The token's subject and scopes become the user id
+    /// and permissions in the handler's context. Applies to handlers
+    /// registered after this call.
+    #[cfg(feature = "security")]
+    pub fn with_token_validator(
+        mut self,
+        validator: impl crate::security::BearerTokenValidator + 'static,
+    ) -> Self {
+        self.bearer_authenticator =
+            crate::server::common::BearerAuthenticator::new(Arc::new(validator));
+        self
+    }
+
+    /// Drain this server with `coordinator`
+    ///
+    /// Once draining starts the server stops accepting connections and answers
+    /// new requests with 503, while requests already running may finish within
+    /// the grace period.
+    pub fn with_shutdown(mut self, coordinator: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(coordinator);
+        self
+    }
+
     /// Start the REST server
     ///
     /// Binds to the configured address and port, then starts serving HTTP requests.
@@ -1164,6 +1364,33 @@ impl RestServer {
         // Create basic Axum router
         let app = create_basic_axum_router(&self.routes, &self.config)?;
 
+        // Serve the Prometheus exposition alongside the envelope routes
+        #[cfg(feature = "metrics")]
+        let app = match self.metrics_endpoint.clone() {
+            Some((path, exporter)) => app.route(
+                &path,
+                get(move || async move {
+                    (
+                        [(
+                            axum::http::header::CONTENT_TYPE,
+                            "text/plain; version=0.0.4",
+                        )],
+                        exporter.render(),
+                    )
+                }),
+            ),
+            None => app,
+        };
+
+        // Refuse new requests once draining starts and track the running ones
+        let app = match self.shutdown.clone() {
+            Some(coordinator) => app.route_layer(axum::middleware::from_fn_with_state(
+                coordinator,
+                admit_request,
+            )),
+            None => app,
+        };
+
         // Create shutdown channel
         let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
         self.shutdown_tx = Some(shutdown_tx);
@@ -1181,8 +1408,18 @@ impl RestServer {
                     QollectiveError::transport(format!("Invalid bind address {}: {}", bind_addr, e))
                 })?;
 
-                let server =
-                    axum_server::bind_rustls(addr, rustls_config).serve(app.into_make_service());
+                let handle = axum_server::Handle::new();
+                let server = axum_server::bind_rustls(addr, rustls_config)
+                    .handle(handle.clone())
+                    .serve(app.into_make_service());
+
+                // Stop accepting connections once draining starts, letting open ones finish
+                if let Some(coordinator) = self.shutdown.clone() {
+                    tokio::spawn(async move {
+                        coordinator.draining().await;
+                        handle.graceful_shutdown(Some(coordinator.config().grace_period));
+                    });
+                }
 
                 // Handle graceful shutdown
                 tokio::select! {
@@ -1216,12 +1453,22 @@ impl RestServer {
             let listener = self.listener.take().unwrap();
 
             // Start the server with graceful shutdown
-            let server = axum::serve(listener, app).with_graceful_shutdown(async {
-                shutdown_rx.await.ok();
+            let coordinator = self.shutdown.clone();
+            let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+                tokio::select! {
+                    _ = shutdown_rx => {}
+                    _ = shutdown::until_draining(coordinator.as_ref()) => {}
+                }
             });
 
-            if let Err(e) = server.await {
-                return Err(QollectiveError::transport(format!("Server error: {}", e)));
+            // Connections still open when draining ends are not waited for
+            tokio::select! {
+                result = server => {
+                    if let Err(e) = result {
+                        return Err(QollectiveError::transport(format!("Server error: {}", e)));
+                    }
+                }
+                _ = shutdown::until_stopped(self.shutdown.as_ref()) => {}
             }
         }
 
@@ -1241,6 +1488,30 @@ impl RestServer {
         &self.config
     }
 
+    /// Serve the exporter's metrics in Prometheus text format at `path`
+    ///
+    /// Use [`crate::constants::metrics::DEFAULT_METRICS_PATH`] for the conventional `/metrics`.
+    #[cfg(feature = "metrics")]
+    pub fn mount_metrics(
+        &mut self,
+        path: &str,
+        exporter: crate::monitoring::MetricsExporter,
+    ) -> Result<()> {
+        if !path.starts_with('/') {
+            return Err(QollectiveError::config("Metrics path must start with '/'"));
+        }
+
+        if self.routes.iter().any(|route| route == path) {
+            return Err(QollectiveError::config(format!(
+                "Metrics path '{}' is already registered as an envelope route",
+                path
+            )));
+        }
+
+        self.metrics_endpoint = Some((path.to_string(), exporter));
+        Ok(())
+    }
+
     /// Register a route with handler info
     ///
     /// This is an internal method used by the UnifiedEnvelopeReceiver implementation.
@@ -1424,21 +1695,42 @@ impl UnifiedEnvelopeReceiver for RestServer {
     {
         // Create a shared handler that can be moved into the closure
         let handler = Arc::new(handler);
+        let operation = route.to_string();
+        let meta_policy = self.meta_policy.clone();
+        let enrichers = self.enrichers.clone();
+        let tenant_authenticator = self.tenant_authenticator.clone();
+        #[cfg(feature = "security")]
+        let bearer_authenticator = self.bearer_authenticator.clone();
 
         // Create a type-erased closure that wraps the specific handler
         let erased_handler: ErasedHandler =
             Box::new(move |headers, query_params, body, metadata_config, protocol_metadata| {
                 let handler = handler.clone();
+                let operation = operation.clone();
+                let meta_policy = meta_policy.clone();
+                let enrichers = enrichers.clone();
+                let tenant_authenticator = tenant_authenticator.clone();
+                #[cfg(feature = "security")]
+                let bearer_authenticator = bearer_authenticator.clone();
                 Box::pin(async move {
                     // Extract metadata from headers and query parameters
                     let mut meta =
                         extract_metadata_from_http(&headers, &query_params, &metadata_config)?;
+                    let authorization = headers
+                        .get(axum::http::header::AUTHORIZATION)
+                        .and_then(|value| value.to_str().ok());
+                    tenant_authenticator.apply(authorization, &mut meta)?;
+                    #[cfg(feature = "security")]
+                    bearer_authenticator.apply(authorization, &mut meta).await?;
 
                     // Inject protocol metadata into extensions if available
                     if let Some(protocol_meta) = protocol_metadata {
                         inject_protocol_metadata_into_meta(&mut meta, protocol_meta)?;
                     }
 
+                    // Record this server in the service chain before handing off
+                    meta.record_service_hop();
+
                     // Create context from metadata (now includes protocol info)
                     let context = Some(Context::new(meta.clone()));
 
@@ -1460,8 +1752,19 @@ impl UnifiedEnvelopeReceiver for RestServer {
                         })?
                     };
 
-                    // Call the actual handler
-                    let response_data = handler.handle(context, data).await?;
+                    // Call the actual handler inside a span linked to the caller's trace,
+                    // cancelling it once the caller's deadline has passed
+                    let started = std::time::Instant::now();
+                    let response_data = deadline::scope(
+                        &meta,
+                        trace_context::instrument(
+                            handler.handle(context, data),
+                            &operation,
+                            SpanKind::Server,
+                            meta.tracing.as_ref(),
+                        ),
+                    )
+                    .await?;
 
                     // Serialize response
                     let response_value = serde_json::to_value(&response_data).map_err(|e| {
@@ -1473,7 +1776,10 @@ impl UnifiedEnvelopeReceiver for RestServer {
 
                     // Create response metadata using the proper preservation utility
                     // This ensures consistent metadata handling across all transports
-                    let response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+                    let mut response_meta = crate::envelope::Meta::preserve_for_response(Some(&meta));
+                    response_meta.complete_service_hop(&meta.service_chain);
+                    enrichers.enrich(&mut response_meta, &operation, started, &response_value);
+                    meta_policy.apply(&mut response_meta, &operation);
 
                     Ok((response_value, response_meta))
                 })
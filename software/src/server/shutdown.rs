@@ -0,0 +1,508 @@
+// ABOUTME: Shared shutdown coordinator that drains in-flight requests across all servers
+// ABOUTME: Stops intake, waits for running handlers up to a grace period and reports what was cut off
+
+//! Graceful shutdown for servers.
+//!
+//! One [`ShutdownCoordinator`] is shared by every server of a process. When
+//! [`shutdown`](ShutdownCoordinator::shutdown) is called (or SIGTERM arrives,
+//! see [`shutdown_on_signal`](ShutdownCoordinator::shutdown_on_signal)) it:
+//!
+//! 1. switches to [`ShutdownPhase::Draining`]: servers stop accepting
+//!    connections, REST and gRPC reject new requests as unavailable, NATS
+//!    drains its subscriptions and WebSocket connections are sent a close frame;
+//! 2. runs the hooks registered with [`on_stop`](ShutdownCoordinator::on_stop);
+//! 3. waits for requests admitted before draining started to finish, for at
+//!    most the configured grace period;
+//! 4. runs the hooks registered with [`on_stopped`](ShutdownCoordinator::on_stopped),
+//!    e.g. closing the NATS connection;
+//! 5. returns a [`DrainReport`] naming the requests that were still running.
+//!
+//! ```rust,ignore
+//! let shutdown = ShutdownCoordinator::with_grace_period(Duration::from_secs(20));
+//! let server = RestServer::new(config).await?.with_shutdown(shutdown.clone());
+//!
+//! let report = shutdown.shutdown_on_signal().await;
+//! if !report.is_clean() {
+//!     tracing::warn!("Cut off {} requests", report.cut_off.len());
+//! }
+//! ```
+
+use crate::constants::timeouts;
+use crate::error::{QollectiveError, Result};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, Notify, OnceCell};
+
+/// Shutdown settings shared by the servers of a process
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShutdownConfig {
+    /// How long in-flight requests may keep running once draining starts
+    pub grace_period: Duration,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(timeouts::DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT_SECS),
+        }
+    }
+}
+
+/// Lifecycle phase of a [`ShutdownCoordinator`], in the order phases are entered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShutdownPhase {
+    /// Serving requests
+    Running,
+    /// New work is refused while in-flight requests finish
+    Draining,
+    /// Draining is over; servers should stop
+    Stopped,
+}
+
+/// A request that has been admitted and not yet finished
+#[derive(Debug, Clone, PartialEq)]
+pub struct InFlightRequest {
+    /// Server that accepted the request, e.g. `rest` or `nats`
+    pub server: String,
+    /// Route, subject or method being handled
+    pub operation: String,
+    /// How long the request had been running when the snapshot was taken
+    pub elapsed: Duration,
+}
+
+/// Outcome of a drain
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrainReport {
+    /// Requests that finished after draining started
+    pub completed: usize,
+    /// Requests still running when the grace period ran out
+    pub cut_off: Vec<InFlightRequest>,
+    /// Hooks that failed, with their error
+    pub failed_hooks: Vec<(String, String)>,
+    /// Time from the start of draining until the report
+    pub elapsed: Duration,
+}
+
+impl DrainReport {
+    /// Whether every request finished and every hook succeeded
+    pub fn is_clean(&self) -> bool {
+        self.cut_off.is_empty() && self.failed_hooks.is_empty()
+    }
+}
+
+type ShutdownHook = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send>;
+
+struct TrackedRequest {
+    server: String,
+    operation: String,
+    started: Instant,
+}
+
+struct Inner {
+    config: ShutdownConfig,
+    phase: watch::Sender<ShutdownPhase>,
+    next_id: AtomicU64,
+    finished: AtomicU64,
+    in_flight: Mutex<HashMap<u64, TrackedRequest>>,
+    idle: Notify,
+    stop_hooks: Mutex<Vec<(String, ShutdownHook)>>,
+    stopped_hooks: Mutex<Vec<(String, ShutdownHook)>>,
+    report: OnceCell<DrainReport>,
+}
+
+/// Coordinates the graceful shutdown of all servers of a process
+///
+/// Cheap to clone; every clone controls the same shutdown.
+#[derive(Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+impl std::fmt::Debug for ShutdownCoordinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShutdownCoordinator")
+            .field("config", &self.inner.config)
+            .field("phase", &self.phase())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new(ShutdownConfig::default())
+    }
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator in the running phase
+    pub fn new(config: ShutdownConfig) -> Self {
+        let (phase, _) = watch::channel(ShutdownPhase::Running);
+        Self {
+            inner: Arc::new(Inner {
+                config,
+                phase,
+                next_id: AtomicU64::new(0),
+                finished: AtomicU64::new(0),
+                in_flight: Mutex::new(HashMap::new()),
+                idle: Notify::new(),
+                stop_hooks: Mutex::new(Vec::new()),
+                stopped_hooks: Mutex::new(Vec::new()),
+                report: OnceCell::new(),
+            }),
+        }
+    }
+
+    /// Create a coordinator allowing in-flight requests `grace_period` to finish
+    pub fn with_grace_period(grace_period: Duration) -> Self {
+        Self::new(ShutdownConfig { grace_period })
+    }
+
+    /// Shutdown settings
+    pub fn config(&self) -> &ShutdownConfig {
+        &self.inner.config
+    }
+
+    /// Current lifecycle phase
+    pub fn phase(&self) -> ShutdownPhase {
+        *self.inner.phase.borrow()
+    }
+
+    /// Whether draining has started
+    pub fn is_draining(&self) -> bool {
+        self.phase() != ShutdownPhase::Running
+    }
+
+    /// Resolves once draining starts; servers stop accepting new work then
+    pub fn draining(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.reached(ShutdownPhase::Draining)
+    }
+
+    /// Resolves once draining is over, whether or not every request finished
+    pub fn stopped(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.reached(ShutdownPhase::Stopped)
+    }
+
+    /// Admit a new request, refusing it once draining has started
+    ///
+    /// The request counts as in flight until the returned guard is dropped.
+    pub fn admit(&self, server: &str, operation: &str) -> Result<RequestGuard> {
+        let mut in_flight = self.lock_in_flight();
+        if self.is_draining() {
+            return Err(QollectiveError::connection(format!(
+                "{} server is shutting down",
+                server
+            )));
+        }
+        Ok(self.insert(&mut in_flight, server, operation))
+    }
+
+    /// Track a request that was already accepted, even while draining
+    ///
+    /// Used for work that cannot be refused any more, such as messages a NATS
+    /// subscription delivered before it was drained.
+    pub fn track(&self, server: &str, operation: &str) -> RequestGuard {
+        let mut in_flight = self.lock_in_flight();
+        self.insert(&mut in_flight, server, operation)
+    }
+
+    /// Snapshot of the requests currently in flight
+    pub fn in_flight(&self) -> Vec<InFlightRequest> {
+        self.lock_in_flight()
+            .values()
+            .map(|request| InFlightRequest {
+                server: request.server.clone(),
+                operation: request.operation.clone(),
+                elapsed: request.started.elapsed(),
+            })
+            .collect()
+    }
+
+    /// Run `hook` as soon as draining starts, e.g. to unsubscribe from subjects
+    pub fn on_stop<F, Fut>(&self, name: &str, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        push_hook(&self.inner.stop_hooks, name, hook);
+    }
+
+    /// Run `hook` once in-flight requests finished or were cut off, e.g. to close connections
+    pub fn on_stopped<F, Fut>(&self, name: &str, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        push_hook(&self.inner.stopped_hooks, name, hook);
+    }
+
+    /// Drain all servers and report what was cut off
+    ///
+    /// Calling this again, or concurrently, returns the report of the first call.
+    pub async fn shutdown(&self) -> DrainReport {
+        self.inner.report.get_or_init(|| self.drain()).await.clone()
+    }
+
+    /// Wait for SIGTERM or Ctrl-C, then [`shutdown`](Self::shutdown)
+    pub async fn shutdown_on_signal(&self) -> DrainReport {
+        termination_signal().await;
+        tracing::info!("Termination signal received, draining servers");
+        self.shutdown().await
+    }
+
+    async fn drain(&self) -> DrainReport {
+        let started = Instant::now();
+        let finished_before = {
+            let in_flight = self.lock_in_flight();
+            self.inner.phase.send_replace(ShutdownPhase::Draining);
+            tracing::info!(
+                "Draining {} in-flight requests (grace period {:?})",
+                in_flight.len(),
+                self.inner.config.grace_period
+            );
+            self.inner.finished.load(Ordering::SeqCst)
+        };
+
+        let mut failed_hooks = run_hooks(&self.inner.stop_hooks).await;
+
+        let idle = tokio::time::timeout(self.inner.config.grace_period, self.until_idle())
+            .await
+            .is_ok();
+        let cut_off = if idle { Vec::new() } else { self.in_flight() };
+        for request in &cut_off {
+            tracing::warn!(
+                "Cut off {} request {} after {:?}",
+                request.server,
+                request.operation,
+                request.elapsed
+            );
+        }
+
+        failed_hooks.extend(run_hooks(&self.inner.stopped_hooks).await);
+        self.inner.phase.send_replace(ShutdownPhase::Stopped);
+
+        let report = DrainReport {
+            completed: (self.inner.finished.load(Ordering::SeqCst) - finished_before) as usize,
+            cut_off,
+            failed_hooks,
+            elapsed: started.elapsed(),
+        };
+        tracing::info!(
+            "Drain finished in {:?}: {} requests completed, {} cut off",
+            report.elapsed,
+            report.completed,
+            report.cut_off.len()
+        );
+        report
+    }
+
+    async fn until_idle(&self) {
+        loop {
+            let idle = self.inner.idle.notified();
+            tokio::pin!(idle);
+            idle.as_mut().enable();
+            if self.lock_in_flight().is_empty() {
+                return;
+            }
+            idle.await;
+        }
+    }
+
+    fn reached(&self, phase: ShutdownPhase) -> impl Future<Output = ()> + Send + 'static {
+        let mut current = self.inner.phase.subscribe();
+        async move {
+            let _ = current.wait_for(|current| *current >= phase).await;
+        }
+    }
+
+    fn insert(
+        &self,
+        in_flight: &mut HashMap<u64, TrackedRequest>,
+        server: &str,
+        operation: &str,
+    ) -> RequestGuard {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        in_flight.insert(
+            id,
+            TrackedRequest {
+                server: server.to_string(),
+                operation: operation.to_string(),
+                started: Instant::now(),
+            },
+        );
+        RequestGuard {
+            inner: Arc::clone(&self.inner),
+            id,
+        }
+    }
+
+    fn lock_in_flight(&self) -> std::sync::MutexGuard<'_, HashMap<u64, TrackedRequest>> {
+        lock(&self.inner.in_flight)
+    }
+}
+
+/// Keeps a request counted as in flight until dropped
+#[must_use = "the request stops counting as in flight when the guard is dropped"]
+pub struct RequestGuard {
+    inner: Arc<Inner>,
+    id: u64,
+}
+
+impl std::fmt::Debug for RequestGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestGuard")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for RequestGuard {
+    fn drop(&mut self) {
+        let mut in_flight = lock(&self.inner.in_flight);
+        if in_flight.remove(&self.id).is_some() {
+            self.inner.finished.fetch_add(1, Ordering::SeqCst);
+        }
+        if in_flight.is_empty() {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+/// Resolves once draining starts, or never without a coordinator
+pub(crate) async fn until_draining(coordinator: Option<&ShutdownCoordinator>) {
+    match coordinator {
+        Some(coordinator) => coordinator.draining().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Resolves once draining is over, or never without a coordinator
+pub(crate) async fn until_stopped(coordinator: Option<&ShutdownCoordinator>) {
+    match coordinator {
+        Some(coordinator) => coordinator.stopped().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Wait for SIGTERM or Ctrl-C
+///
+/// SIGTERM is what Kubernetes sends a pod before killing it.
+pub async fn termination_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = terminate.recv() => {}
+                    _ = tokio::signal::ctrl_c() => {}
+                }
+                return;
+            }
+            Err(e) => tracing::warn!("Failed to listen for SIGTERM: {}", e),
+        }
+    }
+
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        tracing::warn!("Failed to listen for Ctrl-C: {}", e);
+        std::future::pending::<()>().await;
+    }
+}
+
+fn push_hook<F, Fut>(hooks: &Mutex<Vec<(String, ShutdownHook)>>, name: &str, hook: F)
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    let hook: ShutdownHook = Box::new(move || Box::pin(hook()));
+    lock(hooks).push((name.to_string(), hook));
+}
+
+async fn run_hooks(hooks: &Mutex<Vec<(String, ShutdownHook)>>) -> Vec<(String, String)> {
+    let hooks = std::mem::take(&mut *lock(hooks));
+    let mut failed = Vec::new();
+    for (name, hook) in hooks {
+        if let Err(e) = hook().await {
+            tracing::warn!("Shutdown hook {} failed: {}", name, e);
+            failed.push((name, e.to_string()));
+        }
+    }
+    failed
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_admitted_requests_and_refuses_new_ones() {
+        let coordinator = ShutdownCoordinator::with_grace_period(Duration::from_secs(5));
+        let guard = coordinator.admit("rest", "POST /orders").unwrap();
+
+        let draining = coordinator.clone();
+        let shutdown = tokio::spawn(async move { draining.shutdown().await });
+        coordinator.draining().await;
+
+        assert!(coordinator.admit("rest", "POST /orders").is_err());
+        assert_eq!(coordinator.phase(), ShutdownPhase::Draining);
+
+        // Accepted work can still be tracked while draining
+        drop(coordinator.track("nats", "orders.create"));
+        drop(guard);
+
+        let report = shutdown.await.unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.completed, 2);
+        assert_eq!(coordinator.phase(), ShutdownPhase::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_drain_reports_requests_cut_off_after_grace_period() {
+        let coordinator = ShutdownCoordinator::with_grace_period(Duration::from_millis(50));
+        let _stuck = coordinator.admit("grpc", "/orders/create").unwrap();
+        drop(coordinator.admit("websocket", "/ws"));
+
+        let report = coordinator.shutdown().await;
+        assert_eq!(report.completed, 0);
+        assert_eq!(report.cut_off.len(), 1);
+        assert_eq!(report.cut_off[0].server, "grpc");
+        assert_eq!(report.cut_off[0].operation, "/orders/create");
+        assert!(report.elapsed >= Duration::from_millis(50));
+
+        // Later calls return the same report
+        assert_eq!(coordinator.shutdown().await, report);
+    }
+
+    #[tokio::test]
+    async fn test_hooks_run_around_the_wait_and_failures_are_reported() {
+        let coordinator = ShutdownCoordinator::with_grace_period(Duration::from_secs(1));
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let stop_order = Arc::clone(&order);
+        coordinator.on_stop("unsubscribe", move || async move {
+            lock(&stop_order).push("stop");
+            Ok(())
+        });
+        let stopped_order = Arc::clone(&order);
+        coordinator.on_stopped("close", move || async move {
+            lock(&stopped_order).push("stopped");
+            Err(QollectiveError::connection("already closed"))
+        });
+
+        let report = coordinator.shutdown().await;
+        assert_eq!(*lock(&order), vec!["stop", "stopped"]);
+        assert_eq!(report.failed_hooks.len(), 1);
+        assert_eq!(report.failed_hooks[0].0, "close");
+        assert!(!report.is_clean());
+    }
+}
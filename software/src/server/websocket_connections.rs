@@ -0,0 +1,452 @@
+// ABOUTME: Connection registry for the WebSocket server with rooms and server-initiated push
+// ABOUTME: Tracks tenant/user identity per connection and fans envelopes out through bounded send queues
+
+//! WebSocket connection registry.
+//!
+//! Every accepted connection is registered under a [`ConnectionId`] together
+//! with the tenant and user of the token verified when it was opened; that
+//! identity is bound once and envelopes sent later cannot change it. Connections can
+//! join named rooms, either through a `join` frame from the client or on the
+//! server side via [`WebSocketConnections::join`]. The registry is also the
+//! push handle: envelopes can be sent to one connection, a room, every
+//! connection of a tenant, or everyone.
+//!
+//! Each connection owns a bounded send queue. Pushes never wait on a slow
+//! client: when its queue is full the push is dropped for that connection and
+//! reported in the returned [`PushOutcome`].
+
+use crate::client::websocket::{WebSocketFrame, WebSocketMessageType};
+use crate::constants::metadata::WEBSOCKET_CONNECTION_ID_EXTENSION_KEY;
+use crate::envelope::{Context, Envelope};
+use crate::error::{QollectiveError, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::sync::RwLock;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use uuid::Uuid;
+
+/// Identifier assigned to a WebSocket connection when it is accepted
+pub type ConnectionId = Uuid;
+
+/// Snapshot of a registered connection
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectionInfo {
+    pub id: ConnectionId,
+    /// Request path of the WebSocket handshake
+    pub path: String,
+    /// Tenant of the token verified at upgrade
+    pub tenant: Option<String>,
+    /// User the token verified at upgrade was issued to
+    pub user_id: Option<String>,
+    /// Rooms the connection has joined
+    pub rooms: HashSet<String>,
+    pub connected_at: DateTime<Utc>,
+}
+
+/// Result of pushing an envelope to a group of connections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PushOutcome {
+    /// Connections the envelope was queued for
+    pub delivered: usize,
+    /// Connections skipped because their send queue was full or already closed
+    pub dropped: usize,
+}
+
+/// Tenant and user a connection authenticated as when it was opened
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ConnectionIdentity {
+    pub(crate) tenant: Option<String>,
+    pub(crate) user_id: Option<String>,
+}
+
+struct ConnectionEntry {
+    info: ConnectionInfo,
+    sender: mpsc::Sender<Message>,
+}
+
+#[derive(Default)]
+struct Registry {
+    connections: HashMap<ConnectionId, ConnectionEntry>,
+    rooms: HashMap<String, HashSet<ConnectionId>>,
+}
+
+/// Shared registry of live WebSocket connections, used to push envelopes to clients
+#[derive(Clone, Default)]
+pub struct WebSocketConnections {
+    registry: Arc<RwLock<Registry>>,
+}
+
+impl std::fmt::Debug for WebSocketConnections {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketConnections")
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebSocketConnections {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an accepted connection whose frames are written from `sender`
+    pub(crate) async fn register(
+        &self,
+        path: &str,
+        identity: ConnectionIdentity,
+        sender: mpsc::Sender<Message>,
+    ) -> ConnectionId {
+        let id = Uuid::now_v7();
+        let info = ConnectionInfo {
+            id,
+            path: path.to_string(),
+            tenant: identity.tenant,
+            user_id: identity.user_id,
+            rooms: HashSet::new(),
+            connected_at: Utc::now(),
+        };
+        self.registry
+            .write()
+            .await
+            .connections
+            .insert(id, ConnectionEntry { info, sender });
+        id
+    }
+
+    /// Remove a closed connection and its room memberships
+    pub(crate) async fn unregister(&self, id: ConnectionId) {
+        let mut registry = self.registry.write().await;
+        if let Some(entry) = registry.connections.remove(&id) {
+            for room in &entry.info.rooms {
+                remove_member(&mut registry.rooms, room, id);
+            }
+        }
+    }
+
+    /// Add a connection to a room
+    pub async fn join(&self, id: ConnectionId, room: &str) -> Result<()> {
+        let mut registry = self.registry.write().await;
+        let entry = registry
+            .connections
+            .get_mut(&id)
+            .ok_or_else(|| unknown_connection(id))?;
+        entry.info.rooms.insert(room.to_string());
+        registry
+            .rooms
+            .entry(room.to_string())
+            .or_default()
+            .insert(id);
+        Ok(())
+    }
+
+    /// Remove a connection from a room
+    pub async fn leave(&self, id: ConnectionId, room: &str) {
+        let mut registry = self.registry.write().await;
+        if let Some(entry) = registry.connections.get_mut(&id) {
+            entry.info.rooms.remove(room);
+        }
+        remove_member(&mut registry.rooms, room, id);
+    }
+
+    /// Snapshot of one connection
+    pub async fn connection(&self, id: ConnectionId) -> Option<ConnectionInfo> {
+        let registry = self.registry.read().await;
+        registry
+            .connections
+            .get(&id)
+            .map(|entry| entry.info.clone())
+    }
+
+    /// Snapshots of all live connections
+    pub async fn connections(&self) -> Vec<ConnectionInfo> {
+        let registry = self.registry.read().await;
+        registry
+            .connections
+            .values()
+            .map(|entry| entry.info.clone())
+            .collect()
+    }
+
+    /// Number of live connections
+    pub async fn connection_count(&self) -> usize {
+        self.registry.read().await.connections.len()
+    }
+
+    /// Connections currently in a room
+    pub async fn room_members(&self, room: &str) -> Vec<ConnectionId> {
+        let registry = self.registry.read().await;
+        registry
+            .rooms
+            .get(room)
+            .map(|members| members.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Push an envelope to a single connection
+    ///
+    /// Fails if the connection is unknown or its send queue is full.
+    pub async fn push_to_connection<T>(&self, id: ConnectionId, envelope: Envelope<T>) -> Result<()>
+    where
+        T: Serialize,
+    {
+        let message = push_message(envelope)?;
+        let registry = self.registry.read().await;
+        let entry = registry
+            .connections
+            .get(&id)
+            .ok_or_else(|| unknown_connection(id))?;
+        entry.sender.try_send(message).map_err(|e| match e {
+            TrySendError::Full(_) => QollectiveError::transport(format!(
+                "Send queue of WebSocket connection {} is full",
+                id
+            )),
+            TrySendError::Closed(_) => unknown_connection(id),
+        })
+    }
+
+    /// Push an envelope to every connection in a room
+    pub async fn push_to_room<T>(&self, room: &str, envelope: Envelope<T>) -> Result<PushOutcome>
+    where
+        T: Serialize,
+    {
+        let message = push_message(envelope)?;
+        let registry = self.registry.read().await;
+        let members = registry.rooms.get(room);
+        Ok(fan_out(
+            &message,
+            registry
+                .connections
+                .values()
+                .filter(|entry| members.is_some_and(|members| members.contains(&entry.info.id))),
+        ))
+    }
+
+    /// Push an envelope to every connection of a tenant
+    pub async fn push_to_tenant<T>(
+        &self,
+        tenant: &str,
+        envelope: Envelope<T>,
+    ) -> Result<PushOutcome>
+    where
+        T: Serialize,
+    {
+        let message = push_message(envelope)?;
+        let registry = self.registry.read().await;
+        Ok(fan_out(
+            &message,
+            registry
+                .connections
+                .values()
+                .filter(|entry| entry.info.tenant.as_deref() == Some(tenant)),
+        ))
+    }
+
+    /// Push an envelope to every live connection
+    pub async fn broadcast<T>(&self, envelope: Envelope<T>) -> Result<PushOutcome>
+    where
+        T: Serialize,
+    {
+        let message = push_message(envelope)?;
+        let registry = self.registry.read().await;
+        Ok(fan_out(&message, registry.connections.values()))
+    }
+}
+
+/// Connection a handler's request arrived on, if it came over the WebSocket server
+pub fn connection_id(context: &Context) -> Option<ConnectionId> {
+    context
+        .meta()
+        .extensions
+        .as_ref()?
+        .sections
+        .get(WEBSOCKET_CONNECTION_ID_EXTENSION_KEY)?
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+/// Tag an incoming envelope's metadata with the connection it arrived on
+pub(crate) fn stamp_connection_id(envelope: &mut Value, id: ConnectionId) {
+    let Some(meta) = envelope.get_mut("meta").and_then(Value::as_object_mut) else {
+        return;
+    };
+    let extensions = meta
+        .entry("extensions")
+        .or_insert_with(|| Value::Object(Default::default()));
+    if extensions.is_null() {
+        *extensions = Value::Object(Default::default());
+    }
+    if let Some(extensions) = extensions.as_object_mut() {
+        extensions.insert(
+            WEBSOCKET_CONNECTION_ID_EXTENSION_KEY.to_string(),
+            Value::String(id.to_string()),
+        );
+    }
+}
+
+/// Serialize an envelope once as an uncorrelated push frame
+fn push_message<T: Serialize>(envelope: Envelope<T>) -> Result<Message> {
+    let payload = serde_json::to_value(&envelope).map_err(|e| {
+        QollectiveError::serialization(format!("Failed to serialize pushed envelope: {}", e))
+    })?;
+    let frame = WebSocketFrame {
+        id: None,
+        timeout_ms: None,
+        message: WebSocketMessageType::Envelope { payload },
+    };
+    let text = serde_json::to_string(&frame).map_err(|e| {
+        QollectiveError::serialization(format!("Failed to serialize push frame: {}", e))
+    })?;
+    Ok(Message::Text(text.into()))
+}
+
+fn fan_out<'a>(
+    message: &Message,
+    targets: impl Iterator<Item = &'a ConnectionEntry>,
+) -> PushOutcome {
+    let mut outcome = PushOutcome::default();
+    for entry in targets {
+        match entry.sender.try_send(message.clone()) {
+            Ok(()) => outcome.delivered += 1,
+            Err(e) => {
+                if matches!(e, TrySendError::Full(_)) {
+                    tracing::warn!(
+                        "Dropping push for slow WebSocket connection {}: send queue full",
+                        entry.info.id
+                    );
+                }
+                outcome.dropped += 1;
+            }
+        }
+    }
+    outcome
+}
+
+fn remove_member(rooms: &mut HashMap<String, HashSet<ConnectionId>>, room: &str, id: ConnectionId) {
+    if let Some(members) = rooms.get_mut(room) {
+        members.remove(&id);
+        if members.is_empty() {
+            rooms.remove(room);
+        }
+    }
+}
+
+fn unknown_connection(id: ConnectionId) -> QollectiveError {
+    QollectiveError::connection(format!("WebSocket connection {} is not connected", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envelope::Meta;
+    use serde_json::json;
+
+    fn push(alert: &str) -> Envelope<Value> {
+        Envelope::new(Meta::default(), json!({ "alert": alert }))
+    }
+
+    fn pushed_alert(message: Message) -> Value {
+        let frame: WebSocketFrame = serde_json::from_str(message.to_text().unwrap()).unwrap();
+        assert!(frame.id.is_none());
+        match frame.message {
+            WebSocketMessageType::Envelope { payload } => payload["payload"]["alert"].clone(),
+            other => panic!("expected envelope push, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rooms_and_tenants_select_push_targets() {
+        let connections = WebSocketConnections::new();
+        let (bridge_tx, mut bridge_rx) = mpsc::channel(8);
+        let (engineering_tx, mut engineering_rx) = mpsc::channel(8);
+        let starfleet = ConnectionIdentity {
+            tenant: Some("starfleet".to_string()),
+            user_id: Some("picard".to_string()),
+        };
+        let bridge = connections.register("/", starfleet, bridge_tx).await;
+        let engineering = connections
+            .register("/", ConnectionIdentity::default(), engineering_tx)
+            .await;
+        let bridge_info = connections.connection(bridge).await.unwrap();
+        assert_eq!(bridge_info.user_id.as_deref(), Some("picard"));
+        connections.join(bridge, "bridge").await.unwrap();
+        assert_eq!(connections.room_members("bridge").await, vec![bridge]);
+
+        let outcome = connections
+            .push_to_room("bridge", push("red"))
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome,
+            PushOutcome {
+                delivered: 1,
+                dropped: 0
+            }
+        );
+        assert_eq!(pushed_alert(bridge_rx.recv().await.unwrap()), "red");
+
+        let outcome = connections
+            .push_to_tenant("starfleet", push("yellow"))
+            .await
+            .unwrap();
+        assert_eq!(outcome.delivered, 1);
+        assert_eq!(pushed_alert(bridge_rx.recv().await.unwrap()), "yellow");
+
+        assert_eq!(
+            connections.broadcast(push("all")).await.unwrap().delivered,
+            2
+        );
+        assert_eq!(pushed_alert(engineering_rx.recv().await.unwrap()), "all");
+
+        connections.unregister(bridge).await;
+        assert!(connections.room_members("bridge").await.is_empty());
+        assert!(connections
+            .push_to_connection(bridge, push("gone"))
+            .await
+            .is_err());
+        assert_eq!(connections.connection_count().await, 1);
+        assert!(connections.connection(engineering).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_full_send_queue_drops_pushes_instead_of_blocking() {
+        let connections = WebSocketConnections::new();
+        let (sender, _receiver) = mpsc::channel(1);
+        let id = connections
+            .register("/", ConnectionIdentity::default(), sender)
+            .await;
+
+        connections
+            .push_to_connection(id, push("first"))
+            .await
+            .unwrap();
+        let error = connections
+            .push_to_connection(id, push("second"))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("full"));
+
+        let outcome = connections.broadcast(push("third")).await.unwrap();
+        assert_eq!(
+            outcome,
+            PushOutcome {
+                delivered: 0,
+                dropped: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_connection_id_round_trips_through_envelope_meta() {
+        let id = Uuid::now_v7();
+        let mut envelope = json!({"meta": {"tenant": "starfleet"}, "payload": {}});
+        stamp_connection_id(&mut envelope, id);
+
+        let meta: Meta = serde_json::from_value(envelope["meta"].clone()).unwrap();
+        assert_eq!(connection_id(&Context::new(meta)), Some(id));
+    }
+}
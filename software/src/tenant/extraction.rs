@@ -8,7 +8,7 @@
 //! and query parameters. JWT tokens have the highest priority, followed by headers,
 //! payload, and query parameters.
 
-use super::{ExtractionPriority, JwtParseError, JwtParser};
+use super::{ExtractionPriority, JwtParseError, JwtParser, JwtVerificationConfig};
 use crate::envelope::meta::OnBehalfOfMeta;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -138,6 +138,9 @@ pub struct ExtractionConfig {
     pub tenant_query_params: Vec<String>,
     /// Authorization header patterns (e.g., "Bearer", "JWT")
     pub auth_header_patterns: Vec<String>,
+    /// Signature verification for JWT tokens; `None` keeps parse-only extraction
+    #[serde(default)]
+    pub jwt_verification: Option<JwtVerificationConfig>,
 }
 
 impl Default for ExtractionConfig {
@@ -164,6 +167,7 @@ impl Default for ExtractionConfig {
                 "org_id".to_string(),
             ],
             auth_header_patterns: vec!["Bearer".to_string(), "JWT".to_string()],
+            jwt_verification: None,
         }
     }
 }
@@ -173,30 +177,56 @@ impl Default for ExtractionConfig {
 pub struct TenantExtractor {
     config: ExtractionConfig,
     jwt_parser: JwtParser,
+    /// Set when verification was configured but its keys could not be loaded
+    jwt_unavailable: Option<String>,
 }
 
 impl TenantExtractor {
     /// Create a new tenant extractor with default configuration
     pub fn new() -> Self {
-        let config = ExtractionConfig::default();
-        let jwt_parser = if config.jwt_debug_logging {
-            JwtParser::with_debug_logging()
-        } else {
-            JwtParser::new()
-        };
-
-        Self { config, jwt_parser }
+        Self::with_config(ExtractionConfig::default())
     }
 
     /// Create a new tenant extractor with custom configuration
+    ///
+    /// If the configured JWT verification keys cannot be loaded, JWT extraction
+    /// is disabled rather than falling back to unverified parsing. Use
+    /// [`TenantExtractor::try_with_config`] to surface the error instead.
     pub fn with_config(config: ExtractionConfig) -> Self {
-        let jwt_parser = if config.jwt_debug_logging {
-            JwtParser::with_debug_logging()
-        } else {
-            JwtParser::new()
-        };
+        match Self::try_with_config(config.clone()) {
+            Ok(extractor) => extractor,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!("JWT verification unavailable, ignoring JWT tokens: {}", e);
+
+                Self {
+                    jwt_parser: JwtParser::new().debug(config.jwt_debug_logging),
+                    jwt_unavailable: Some(e.to_string()),
+                    config,
+                }
+            }
+        }
+    }
+
+    /// Create a new tenant extractor, failing if JWT verification keys cannot be loaded
+    pub fn try_with_config(config: ExtractionConfig) -> Result<Self, ExtractionError> {
+        let jwt_parser = match &config.jwt_verification {
+            Some(verification) => JwtParser::with_verification(verification)
+                .map_err(|e| ExtractionError::ConfigError(e.to_string()))?,
+            None => JwtParser::new(),
+        }
+        .debug(config.jwt_debug_logging);
+
+        Ok(Self {
+            config,
+            jwt_parser,
+            jwt_unavailable: None,
+        })
+    }
 
-        Self { config, jwt_parser }
+    /// Whether JWT tokens are signature-verified before their claims are trusted
+    pub fn verifies_jwt(&self) -> bool {
+        self.config.jwt_verification.is_some()
     }
 
     /// Enable or disable tenant extraction
@@ -210,6 +240,10 @@ impl TenantExtractor {
     }
 
     /// Extract tenant information from authorization header (JWT token)
+    ///
+    /// In parse-only mode unparseable tokens yield `Ok(None)`. With JWT
+    /// verification enabled, a token that fails verification is an error so a
+    /// forged token can never contribute tenant information.
     pub fn extract_from_jwt(
         &self,
         auth_header: &str,
@@ -218,6 +252,10 @@ impl TenantExtractor {
             return Err(ExtractionError::ExtractionDisabled);
         }
 
+        if let Some(reason) = &self.jwt_unavailable {
+            return Err(ExtractionError::ConfigError(reason.clone()));
+        }
+
         // Try to extract JWT token from authorization header
         let token = self.extract_jwt_token(auth_header)?;
 
@@ -266,6 +304,12 @@ impl TenantExtractor {
                     None
                 })
             }
+            Err(e) if self.verifies_jwt() => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("JWT verification failed, rejecting token: {}", e);
+
+                Err(ExtractionError::JwtError(e))
+            }
             Err(e) => {
                 // Log JWT parsing failures but don't fail the extraction
                 #[cfg(feature = "tracing")]
@@ -413,8 +457,14 @@ impl TenantExtractor {
 
         // Try JWT extraction first (highest priority)
         if let Some(auth_header) = auth_header {
-            if let Ok(Some(tenant_info)) = self.extract_from_jwt(auth_header) {
-                results.push(tenant_info);
+            match self.extract_from_jwt(auth_header) {
+                Ok(Some(tenant_info)) => results.push(tenant_info),
+                // A rejected token, or verification that is configured but unavailable,
+                // must not be masked by lower priority sources
+                Err(e @ (ExtractionError::JwtError(_) | ExtractionError::ConfigError(_))) => {
+                    return Err(e)
+                }
+                _ => {}
             }
         }
 
@@ -670,4 +720,85 @@ mod tests {
         let result = extractor.extract_from_headers(&HashMap::new());
         assert!(matches!(result, Err(ExtractionError::ExtractionDisabled)));
     }
+
+    #[test]
+    fn test_verified_extraction_rejects_forged_jwt() {
+        use crate::tenant::{JwtKeySource, JwtVerificationConfig};
+
+        let config = ExtractionConfig {
+            jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+                secret: "starfleet-secret".to_string(),
+            })),
+            ..Default::default()
+        };
+        let extractor = TenantExtractor::try_with_config(config).unwrap();
+        assert!(extractor.verifies_jwt());
+
+        let claims = serde_json::json!({
+            "sub": "picard",
+            "tenant": "enterprise",
+            "exp": jsonwebtoken::get_current_timestamp() + 600,
+        });
+        let sign = |secret: &[u8]| {
+            jsonwebtoken::encode(
+                &jsonwebtoken::Header::default(),
+                &claims,
+                &jsonwebtoken::EncodingKey::from_secret(secret),
+            )
+            .unwrap()
+        };
+
+        let valid = extractor
+            .extract_from_jwt(&format!("Bearer {}", sign(b"starfleet-secret")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(valid.tenant_id, Some("enterprise".to_string()));
+
+        let forged = format!("Bearer {}", sign(b"attacker-secret"));
+        assert!(matches!(
+            extractor.extract_from_jwt(&forged),
+            Err(ExtractionError::JwtError(_))
+        ));
+
+        // A forged token must not be masked by a lower priority header
+        let mut headers = HashMap::new();
+        headers.insert("X-Tenant-ID".to_string(), "romulan".to_string());
+        assert!(extractor
+            .extract_with_priority(Some(&forged), Some(&headers), None, None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_unloadable_verification_keys_fail_closed() {
+        use crate::tenant::{JwtKeySource, JwtVerificationConfig};
+
+        let config = ExtractionConfig {
+            jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::PemFile {
+                path: "/nonexistent/qollective/key.pem".to_string(),
+            })),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TenantExtractor::try_with_config(config.clone()),
+            Err(ExtractionError::ConfigError(_))
+        ));
+
+        let extractor = TenantExtractor::with_config(config);
+        assert!(extractor
+            .extract_from_jwt("Bearer header.payload.signature")
+            .is_err());
+
+        // A presented token must not fall through to unverified header sources
+        let mut headers = HashMap::new();
+        headers.insert("X-Tenant-ID".to_string(), "romulan".to_string());
+        assert!(matches!(
+            extractor.extract_with_priority(
+                Some("Bearer header.payload.signature"),
+                Some(&headers),
+                None,
+                None
+            ),
+            Err(ExtractionError::ConfigError(_))
+        ));
+    }
 }
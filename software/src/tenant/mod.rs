@@ -1,12 +1,14 @@
 // ABOUTME: Tenant extraction and JWT parsing functionality for multi-tenant support
-// ABOUTME: Provides parse-only JWT processing and tenant context extraction from multiple sources
+// ABOUTME: Provides parse-only or verified JWT processing and tenant context extraction from multiple sources
 
 //! Tenant extraction module for multi-tenant support.
 //!
 //! This module provides functionality for extracting tenant information from various sources
-//! including JWT tokens, HTTP headers, request payloads, and query parameters. The module
-//! implements parse-only JWT processing without signature validation, leaving authentication
-//! and authorization concerns to upstream or downstream services.
+//! including JWT tokens, HTTP headers, request payloads, and query parameters. By default the
+//! module implements parse-only JWT processing without signature validation, leaving
+//! authentication and authorization concerns to upstream or downstream services. Setting
+//! `ExtractionConfig::jwt_verification` makes the parser verify signatures and expiry so
+//! tenant isolation cannot be spoofed with a forged token.
 
 pub mod error_handler;
 pub mod extraction;
@@ -16,7 +18,7 @@ pub use error_handler::{
     create_error_handler_from_env, ErrorStrategy, TenantExtractionErrorHandler,
 };
 pub use extraction::{ExtractionError, ExtractionSource, TenantExtractor, TenantInfo};
-pub use jwt::{JwtClaims, JwtParseError, JwtParser};
+pub use jwt::{JwtClaims, JwtKeySource, JwtParseError, JwtParser, JwtVerificationConfig};
 
 /// Priority order for tenant extraction sources
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
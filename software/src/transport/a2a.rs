@@ -43,7 +43,7 @@ use crate::config::a2a::A2AClientConfig;
 use crate::envelope::{Envelope, Meta};
 use crate::error::{QollectiveError, Result};
 use crate::traits::senders::{UnifiedEnvelopeSender, UnifiedSender};
-use crate::types::a2a::AgentInfo;
+use crate::types::a2a::{A2ATask, AgentInfo, TaskUpdate};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -52,9 +52,15 @@ use tokio::sync::RwLock;
 
 // Use a2a-rs standard types and client
 #[cfg(feature = "a2a-standard")]
-use a2a_rs::{HttpClient, Message, Task};
+use crate::types::a2a::standard;
+#[cfg(feature = "a2a-standard")]
+use a2a_rs::application::{
+    A2ARequest, CancelTaskRequest, GetTaskRequest, JSONRPCResponse, TaskResubscriptionRequest,
+};
 #[cfg(feature = "a2a-standard")]
 use a2a_rs::services::client::AsyncA2AClient;
+#[cfg(feature = "a2a-standard")]
+use a2a_rs::{HttpClient, Message, Task, TaskIdParams, TaskQueryParams};
 
 /// A2A transport client using a2a-rs standard implementation
 pub struct InternalA2AClient {
@@ -63,6 +69,9 @@ pub struct InternalA2AClient {
     /// Standard a2a-rs HTTP client
     #[cfg(feature = "a2a-standard")]
     a2a_client: Option<HttpClient>,
+    /// HTTP client for the JSON-RPC task methods
+    #[cfg(feature = "a2a-standard")]
+    task_http: reqwest::Client,
     /// Agent registry for discovered agents
     agent_registry: Arc<RwLock<HashMap<String, AgentInfo>>>,
     /// Local agent identifier
@@ -94,6 +103,8 @@ impl InternalA2AClient {
             config,
             #[cfg(feature = "a2a-standard")]
             a2a_client,
+            #[cfg(feature = "a2a-standard")]
+            task_http: reqwest::Client::new(),
             agent_registry: Arc::new(RwLock::new(HashMap::new())),
             local_agent_id,
             is_connected: true,
@@ -106,6 +117,8 @@ impl InternalA2AClient {
             config: A2AClientConfig::default(),
             #[cfg(feature = "a2a-standard")]
             a2a_client: None,
+            #[cfg(feature = "a2a-standard")]
+            task_http: reqwest::Client::new(),
             agent_registry: Arc::new(RwLock::new(HashMap::new())),
             local_agent_id: "mock-agent".to_string(),
             is_connected: true, // Set to true for testing so mock transport can handle requests
@@ -118,6 +131,8 @@ impl InternalA2AClient {
             config: A2AClientConfig::default(),
             #[cfg(feature = "a2a-standard")]
             a2a_client: None,
+            #[cfg(feature = "a2a-standard")]
+            task_http: reqwest::Client::new(),
             agent_registry: Arc::new(RwLock::new(HashMap::new())),
             local_agent_id: "mock-agent".to_string(),
             is_connected: false, // Disconnected for error testing
@@ -208,6 +223,152 @@ impl InternalA2AClient {
         self.a2a_task_to_envelope(task, envelope.meta.clone())
     }
 
+    /// Get an A2A task from the configured HTTP endpoint (`tasks/get`)
+    ///
+    /// `authorization` is sent as the `Authorization` header, e.g. a bearer
+    /// tenant token; tasks of other callers are reported as not found.
+    #[cfg(feature = "a2a-standard")]
+    pub async fn get_task(&self, task_id: &str, authorization: Option<&str>) -> Result<A2ATask> {
+        let request = A2ARequest::GetTask(GetTaskRequest::new(TaskQueryParams {
+            id: task_id.to_string(),
+            history_length: None,
+            metadata: None,
+        }));
+        let response = self.send_task_request(&request, authorization).await?;
+        task_result("tasks/get", response.json().await)
+    }
+
+    #[cfg(not(feature = "a2a-standard"))]
+    pub async fn get_task(&self, _task_id: &str, _authorization: Option<&str>) -> Result<A2ATask> {
+        Err(QollectiveError::feature_not_enabled(
+            "A2A HTTP task queries require the a2a-standard feature",
+        ))
+    }
+
+    /// Cancel an A2A task at the configured HTTP endpoint (`tasks/cancel`)
+    ///
+    /// Only tasks of the caller `authorization` identifies can be canceled.
+    #[cfg(feature = "a2a-standard")]
+    pub async fn cancel_task(&self, task_id: &str, authorization: Option<&str>) -> Result<A2ATask> {
+        let request = A2ARequest::CancelTask(CancelTaskRequest::new(TaskIdParams {
+            id: task_id.to_string(),
+            metadata: None,
+        }));
+        let response = self.send_task_request(&request, authorization).await?;
+        task_result("tasks/cancel", response.json().await)
+    }
+
+    #[cfg(not(feature = "a2a-standard"))]
+    pub async fn cancel_task(
+        &self,
+        _task_id: &str,
+        _authorization: Option<&str>,
+    ) -> Result<A2ATask> {
+        Err(QollectiveError::feature_not_enabled(
+            "A2A HTTP task cancellation requires the a2a-standard feature",
+        ))
+    }
+
+    /// Stream the status and artifact updates of an A2A task over HTTP
+    ///
+    /// Resubscribes to the task with `tasks/resubscribe` and forwards the
+    /// server-sent events as they arrive, starting with the task's current
+    /// status. The stream ends after the final update or when the connection
+    /// fails.
+    #[cfg(feature = "a2a-standard")]
+    pub async fn watch_task(
+        &self,
+        task_id: &str,
+        authorization: Option<&str>,
+    ) -> Result<tokio::sync::mpsc::Receiver<Result<TaskUpdate>>> {
+        let request =
+            A2ARequest::TaskResubscription(TaskResubscriptionRequest::new(TaskQueryParams {
+                id: task_id.to_string(),
+                history_length: None,
+                metadata: None,
+            }));
+        let mut response = self.send_task_request(&request, authorization).await?;
+        let is_stream = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"));
+        if !is_stream {
+            // Errors such as an unknown task come back as a plain JSON-RPC response
+            task_result("tasks/resubscribe", response.json().await)?;
+            return Err(QollectiveError::transport(
+                "A2A tasks/resubscribe did not return an event stream".to_string(),
+            ));
+        }
+
+        let (sender, receiver) = tokio::sync::mpsc::channel(32);
+        tokio::spawn(async move {
+            let mut events = task_event_stream::EventBuffer::default();
+            loop {
+                let chunk = match response.chunk().await {
+                    Ok(Some(chunk)) => chunk,
+                    Ok(None) => return,
+                    Err(e) => {
+                        let _ = sender
+                            .send(Err(QollectiveError::transport(format!(
+                                "A2A task stream failed: {}",
+                                e
+                            ))))
+                            .await;
+                        return;
+                    }
+                };
+                for event in events.push(&chunk) {
+                    let update = task_event_stream::update_from_event(&event);
+                    let is_final = update.as_ref().is_ok_and(TaskUpdate::is_final);
+                    if sender.send(update).await.is_err() || is_final {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Ok(receiver)
+    }
+
+    #[cfg(not(feature = "a2a-standard"))]
+    pub async fn watch_task(
+        &self,
+        _task_id: &str,
+        _authorization: Option<&str>,
+    ) -> Result<tokio::sync::mpsc::Receiver<Result<TaskUpdate>>> {
+        Err(QollectiveError::feature_not_enabled(
+            "A2A HTTP task updates require the a2a-standard feature",
+        ))
+    }
+
+    /// Post a JSON-RPC task request to the configured endpoint with `authorization`
+    #[cfg(feature = "a2a-standard")]
+    async fn send_task_request(
+        &self,
+        request: &A2ARequest,
+        authorization: Option<&str>,
+    ) -> Result<reqwest::Response> {
+        let endpoint = self.config.client.endpoint.as_ref().ok_or_else(|| {
+            QollectiveError::transport("A2A standard client not configured".to_string())
+        })?;
+        let mut http_request = self.task_http.post(endpoint).json(request);
+        if let Some(authorization) = authorization {
+            http_request = http_request.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+        let response = http_request.send().await.map_err(|e| {
+            QollectiveError::transport(format!("A2A {} failed: {}", request.method(), e))
+        })?;
+        if !response.status().is_success() {
+            return Err(QollectiveError::transport(format!(
+                "A2A {} failed with status {}",
+                request.method(),
+                response.status()
+            )));
+        }
+        Ok(response)
+    }
+
     /// Get agent information by ID
     pub async fn get_agent_info(&self, agent_id: &str) -> Option<AgentInfo> {
         let registry = self.agent_registry.read().await;
@@ -233,6 +394,83 @@ impl InternalA2AClient {
     }
 }
 
+/// Task carried by a `tasks/get` or `tasks/cancel` JSON-RPC response
+#[cfg(feature = "a2a-standard")]
+fn task_result(
+    method: &str,
+    response: std::result::Result<JSONRPCResponse, reqwest::Error>,
+) -> Result<A2ATask> {
+    let response = response
+        .map_err(|e| QollectiveError::transport(format!("A2A {} failed: {}", method, e)))?;
+    match (response.result, response.error) {
+        (Some(result), _) => serde_json::from_value::<Task>(result)
+            .map(standard::task_from_standard)
+            .map_err(|e| QollectiveError::deserialization(format!("A2A {} result: {}", method, e))),
+        (None, Some(error)) => Err(QollectiveError::transport(format!(
+            "A2A {} failed: {} ({})",
+            method, error.message, error.code
+        ))),
+        (None, None) => Err(QollectiveError::transport(format!(
+            "A2A {} returned an empty response",
+            method
+        ))),
+    }
+}
+
+/// Parsing of the server-sent events of a `tasks/resubscribe` stream
+#[cfg(feature = "a2a-standard")]
+mod task_event_stream {
+    use super::standard;
+    use crate::error::{QollectiveError, Result};
+    use crate::types::a2a::TaskUpdate;
+    use a2a_rs::application::JSONRPCResponse;
+
+    /// Collects stream chunks into complete events
+    #[derive(Default)]
+    pub(super) struct EventBuffer {
+        pending: String,
+    }
+
+    impl EventBuffer {
+        /// Add a chunk and return the data of every event it completes
+        pub(super) fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+            self.pending
+                .push_str(&String::from_utf8_lossy(chunk).replace("\r\n", "\n"));
+            let mut events = Vec::new();
+            while let Some(end) = self.pending.find("\n\n") {
+                let block: String = self.pending.drain(..end + 2).collect();
+                let data: Vec<&str> = block
+                    .lines()
+                    .filter_map(|line| line.strip_prefix("data:"))
+                    .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                    .collect();
+                if !data.is_empty() {
+                    events.push(data.join("\n"));
+                }
+            }
+            events
+        }
+    }
+
+    /// Task update carried by one event of the stream
+    pub(super) fn update_from_event(data: &str) -> Result<TaskUpdate> {
+        let response: JSONRPCResponse = serde_json::from_str(data)
+            .map_err(|e| QollectiveError::deserialization(format!("A2A task event: {}", e)))?;
+        if let Some(error) = response.error {
+            return Err(QollectiveError::transport(format!(
+                "A2A task stream failed: {} ({})",
+                error.message, error.code
+            )));
+        }
+        response
+            .result
+            .and_then(standard::update_from_standard)
+            .ok_or_else(|| {
+                QollectiveError::deserialization(format!("A2A task event is no update: {}", data))
+            })
+    }
+}
+
 impl std::fmt::Debug for InternalA2AClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InternalA2AClient")
@@ -0,0 +1,484 @@
+// ABOUTME: Client-side circuit breakers keyed per endpoint with failure-rate windows and cool-downs
+// ABOUTME: Tracks closed/open/half-open state, publishes it as metrics and exposes it for inspection
+
+//! Client-side circuit breakers.
+//!
+//! A [`CircuitBreakerRegistry`] holds one circuit per key, typically an
+//! endpoint and the transport used to reach it. Each circuit moves between
+//! three states:
+//!
+//! - **Closed**: requests flow and their outcomes are recorded in a sliding
+//!   window. Once the window holds at least `minimum_requests` outcomes and the
+//!   failure rate reaches `failure_rate_threshold`, the circuit opens.
+//! - **Open**: requests are rejected until `cool_down` has elapsed.
+//! - **Half-open**: up to `half_open_max_requests` probes are let through. A
+//!   successful probe closes the circuit, a failed one opens it again.
+//!
+//! Only failures that [`is_retryable`](super::retry::is_retryable) classifies as
+//! transient count against a circuit; an endpoint rejecting a request it
+//! received is still available. State changes are published through
+//! [`crate::monitoring::record_circuit_state`].
+
+use crate::constants::{circuit_breaker, timeouts};
+use crate::error::QollectiveError;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// State of a circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests are rejected until the cool-down elapses
+    Open,
+    /// A limited number of probe requests are let through
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// Label used for this state in metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Thresholds shared by all circuits of a registry
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Failure rate within the window that opens a circuit, between 0 and 1
+    pub failure_rate_threshold: f64,
+    /// Outcomes the window must hold before the failure rate is evaluated
+    pub minimum_requests: u32,
+    /// Sliding window over which outcomes are kept
+    pub window: Duration,
+    /// Time an open circuit rejects requests before letting probes through
+    pub cool_down: Duration,
+    /// Probe requests let through while half-open
+    pub half_open_max_requests: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_rate_threshold: circuit_breaker::DEFAULT_FAILURE_RATE_THRESHOLD,
+            minimum_requests: circuit_breaker::DEFAULT_FAILURE_THRESHOLD,
+            window: Duration::from_secs(circuit_breaker::DEFAULT_FAILURE_WINDOW_SECS),
+            cool_down: timeouts::DEFAULT_CIRCUIT_BREAKER_RECOVERY,
+            half_open_max_requests: circuit_breaker::DEFAULT_HALF_OPEN_MAX_REQUESTS,
+        }
+    }
+}
+
+/// Point-in-time view of one circuit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CircuitSnapshot {
+    /// Key of the circuit
+    pub key: String,
+    /// Current state
+    pub state: CircuitState,
+    /// Failed requests within the window
+    pub failures: u32,
+    /// Successful requests within the window
+    pub successes: u32,
+    /// Failure rate within the window, 0 when the window is empty
+    pub failure_rate: f64,
+    /// Time left before an open circuit lets probes through
+    pub retry_after: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    outcomes: VecDeque<(Instant, bool)>,
+    opened_at: Option<Instant>,
+    half_open_requests: u32,
+}
+
+impl Circuit {
+    fn new() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            outcomes: VecDeque::new(),
+            opened_at: None,
+            half_open_requests: 0,
+        }
+    }
+
+    fn prune(&mut self, config: &CircuitBreakerConfig, now: Instant) {
+        while let Some((at, _)) = self.outcomes.front() {
+            if now.duration_since(*at) < config.window {
+                break;
+            }
+            self.outcomes.pop_front();
+        }
+    }
+
+    fn counts(&self) -> (u32, u32) {
+        let failures = self.outcomes.iter().filter(|(_, ok)| !ok).count() as u32;
+        (failures, self.outcomes.len() as u32 - failures)
+    }
+
+    fn cool_down_remaining(&self, config: &CircuitBreakerConfig, now: Instant) -> Duration {
+        self.opened_at
+            .map(|opened_at| {
+                config
+                    .cool_down
+                    .saturating_sub(now.duration_since(opened_at))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Decide whether a request may proceed, returning the new state on a transition
+    fn admit(
+        &mut self,
+        config: &CircuitBreakerConfig,
+        now: Instant,
+    ) -> (bool, Option<CircuitState>) {
+        let mut transition = None;
+        if self.state == CircuitState::Open && self.cool_down_remaining(config, now).is_zero() {
+            self.state = CircuitState::HalfOpen;
+            self.half_open_requests = 0;
+            transition = Some(CircuitState::HalfOpen);
+        }
+
+        let allowed = match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                if self.half_open_requests < config.half_open_max_requests {
+                    self.half_open_requests += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+        (allowed, transition)
+    }
+
+    /// Record an outcome, returning the new state on a transition
+    fn record(
+        &mut self,
+        config: &CircuitBreakerConfig,
+        success: bool,
+        now: Instant,
+    ) -> Option<CircuitState> {
+        match self.state {
+            CircuitState::HalfOpen if success => {
+                self.state = CircuitState::Closed;
+                self.outcomes.clear();
+                self.opened_at = None;
+                Some(CircuitState::Closed)
+            }
+            CircuitState::HalfOpen => {
+                self.open(now);
+                Some(CircuitState::Open)
+            }
+            // Outcomes of requests admitted before the circuit opened
+            CircuitState::Open => None,
+            CircuitState::Closed => {
+                self.outcomes.push_back((now, success));
+                self.prune(config, now);
+
+                let (failures, _) = self.counts();
+                let total = self.outcomes.len() as u32;
+                if !success
+                    && total >= config.minimum_requests.max(1)
+                    && failures as f64 / total as f64 >= config.failure_rate_threshold
+                {
+                    self.open(now);
+                    Some(CircuitState::Open)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn open(&mut self, now: Instant) {
+        self.state = CircuitState::Open;
+        self.opened_at = Some(now);
+        self.half_open_requests = 0;
+    }
+
+    fn snapshot(
+        &mut self,
+        key: &str,
+        config: &CircuitBreakerConfig,
+        now: Instant,
+    ) -> CircuitSnapshot {
+        self.prune(config, now);
+        let (failures, successes) = self.counts();
+        let total = failures + successes;
+        CircuitSnapshot {
+            key: key.to_string(),
+            state: self.state,
+            failures,
+            successes,
+            failure_rate: if total == 0 {
+                0.0
+            } else {
+                failures as f64 / total as f64
+            },
+            retry_after: (self.state == CircuitState::Open)
+                .then(|| self.cool_down_remaining(config, now)),
+        }
+    }
+}
+
+/// Circuits keyed by endpoint, shared by all clones of a client
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerRegistry {
+    config: CircuitBreakerConfig,
+    circuits: Arc<Mutex<HashMap<String, Circuit>>>,
+}
+
+impl Default for CircuitBreakerRegistry {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+impl CircuitBreakerRegistry {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Thresholds applied to every circuit
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Whether a request to `key` may proceed
+    ///
+    /// Rejected requests are counted as metrics. While half-open, an allowed
+    /// request is a probe whose outcome must be recorded.
+    pub fn allow(&self, key: &str) -> bool {
+        let (allowed, transition) = {
+            let mut circuits = self.circuits.lock().unwrap();
+            circuits
+                .entry(key.to_string())
+                .or_insert_with(Circuit::new)
+                .admit(&self.config, Instant::now())
+        };
+
+        if let Some(state) = transition {
+            self.publish(key, state);
+        }
+        if !allowed {
+            crate::monitoring::record_circuit_rejection(key);
+        }
+        allowed
+    }
+
+    /// Record a successful request to `key`
+    pub fn record_success(&self, key: &str) {
+        self.record(key, true);
+    }
+
+    /// Record a failed request to `key`
+    pub fn record_failure(&self, key: &str) {
+        self.record(key, false);
+    }
+
+    /// Record the outcome of a request to `key`
+    ///
+    /// Only transient failures count against the circuit; other errors mean
+    /// the endpoint was reached.
+    pub fn record_result<T>(&self, key: &str, result: &Result<T, QollectiveError>) {
+        let failed = matches!(result, Err(error) if super::retry::is_retryable(error));
+        self.record(key, !failed);
+    }
+
+    fn record(&self, key: &str, success: bool) {
+        let transition = {
+            let mut circuits = self.circuits.lock().unwrap();
+            circuits
+                .entry(key.to_string())
+                .or_insert_with(Circuit::new)
+                .record(&self.config, success, Instant::now())
+        };
+
+        if let Some(state) = transition {
+            self.publish(key, state);
+        }
+    }
+
+    /// Current state of the circuit for `key`, closed when unknown
+    pub fn state(&self, key: &str) -> CircuitState {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|circuit| circuit.state)
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    /// Snapshot of the circuit for `key`, if any request was made to it
+    pub fn snapshot(&self, key: &str) -> Option<CircuitSnapshot> {
+        let now = Instant::now();
+        self.circuits
+            .lock()
+            .unwrap()
+            .get_mut(key)
+            .map(|circuit| circuit.snapshot(key, &self.config, now))
+    }
+
+    /// Snapshots of all circuits, sorted by key
+    pub fn snapshots(&self) -> Vec<CircuitSnapshot> {
+        let now = Instant::now();
+        let mut snapshots: Vec<_> = self
+            .circuits
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .map(|(key, circuit)| circuit.snapshot(key, &self.config, now))
+            .collect();
+        snapshots.sort_by(|a, b| a.key.cmp(&b.key));
+        snapshots
+    }
+
+    /// Close the circuit for `key` and forget its outcomes
+    pub fn reset(&self, key: &str) {
+        let removed = self.circuits.lock().unwrap().remove(key);
+        if removed.is_some_and(|circuit| circuit.state != CircuitState::Closed) {
+            self.publish(key, CircuitState::Closed);
+        }
+    }
+
+    fn publish(&self, key: &str, state: CircuitState) {
+        match state {
+            CircuitState::Open => tracing::warn!("Circuit {} opened", key),
+            CircuitState::HalfOpen => tracing::info!("Circuit {} half-open, probing", key),
+            CircuitState::Closed => tracing::info!("Circuit {} closed", key),
+        }
+        crate::monitoring::record_circuit_state(key, state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_rate_threshold: 0.5,
+            minimum_requests: 4,
+            window: Duration::from_secs(60),
+            cool_down: Duration::from_secs(30),
+            half_open_max_requests: 1,
+        }
+    }
+
+    #[test]
+    fn test_circuit_opens_at_failure_rate_after_minimum_requests() {
+        let config = config();
+        let mut circuit = Circuit::new();
+        let now = Instant::now();
+
+        assert_eq!(circuit.record(&config, false, now), None);
+        assert_eq!(circuit.record(&config, false, now), None);
+        assert_eq!(circuit.record(&config, true, now), None);
+        assert_eq!(circuit.state, CircuitState::Closed);
+
+        assert_eq!(
+            circuit.record(&config, false, now),
+            Some(CircuitState::Open)
+        );
+        assert_eq!(circuit.admit(&config, now), (false, None));
+    }
+
+    #[test]
+    fn test_outcomes_outside_window_are_forgotten() {
+        let config = config();
+        let mut circuit = Circuit::new();
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            circuit.record(&config, false, start);
+        }
+        let later = start + Duration::from_secs(61);
+        assert_eq!(circuit.record(&config, false, later), None);
+        assert_eq!(circuit.snapshot("a", &config, later).failures, 1);
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_or_reopens_circuit() {
+        let config = config();
+        let mut circuit = Circuit::new();
+        let now = Instant::now();
+        for _ in 0..4 {
+            circuit.record(&config, false, now);
+        }
+        assert_eq!(circuit.state, CircuitState::Open);
+
+        // One probe after the cool-down, further requests wait for its outcome
+        let after_cool_down = now + Duration::from_secs(30);
+        assert_eq!(
+            circuit.admit(&config, after_cool_down),
+            (true, Some(CircuitState::HalfOpen))
+        );
+        assert_eq!(circuit.admit(&config, after_cool_down), (false, None));
+        assert_eq!(
+            circuit.record(&config, false, after_cool_down),
+            Some(CircuitState::Open)
+        );
+        assert_eq!(
+            circuit.snapshot("a", &config, after_cool_down).retry_after,
+            Some(Duration::from_secs(30))
+        );
+
+        let after_second_cool_down = after_cool_down + Duration::from_secs(30);
+        assert!(circuit.admit(&config, after_second_cool_down).0);
+        assert_eq!(
+            circuit.record(&config, true, after_second_cool_down),
+            Some(CircuitState::Closed)
+        );
+        assert_eq!(
+            circuit
+                .snapshot("a", &config, after_second_cool_down)
+                .failures,
+            0
+        );
+    }
+
+    #[test]
+    fn test_registry_counts_only_transient_failures() {
+        let registry = CircuitBreakerRegistry::new(CircuitBreakerConfig {
+            minimum_requests: 2,
+            ..config()
+        });
+
+        for _ in 0..3 {
+            assert!(registry.allow("rest"));
+            registry.record_result::<()>("rest", &Err(QollectiveError::validation("bad input")));
+        }
+        assert_eq!(registry.state("rest"), CircuitState::Closed);
+
+        for _ in 0..3 {
+            registry.record_result::<()>("rest", &Err(QollectiveError::connection("refused")));
+        }
+        assert_eq!(registry.state("rest"), CircuitState::Open);
+        assert!(!registry.allow("rest"));
+        assert!(registry.allow("other"));
+
+        let snapshots = registry.snapshots();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].key, "rest");
+        assert!(snapshots[1].retry_after.is_some());
+
+        registry.reset("rest");
+        assert_eq!(registry.state("rest"), CircuitState::Closed);
+        assert!(registry.allow("rest"));
+    }
+}
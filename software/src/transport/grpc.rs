@@ -14,7 +14,7 @@
 //! - Support for gRPC-specific features (streaming, interceptors)
 //! - Error status code handling
 
-use crate::envelope::Envelope;
+use crate::envelope::{deadline, trace_context, Envelope};
 use crate::error::{QollectiveError, Result};
 use crate::traits::senders::UnifiedEnvelopeSender;
 use async_trait::async_trait;
@@ -28,6 +28,7 @@ use {
         qollective_service_client::QollectiveServiceClient, Envelope as ProtoEnvelope,
         Meta as ProtoMeta,
     },
+    crate::transport::retry::RetryPolicy,
     std::sync::Arc,
     tokio::sync::Mutex,
     tonic::{
@@ -37,6 +38,148 @@ use {
     },
 };
 
+/// Stream of envelopes exchanged over the gRPC streaming RPCs.
+///
+/// Used both by client-side streaming APIs and by server-side streaming handlers.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub type EnvelopeStream<T> =
+    std::pin::Pin<Box<dyn futures_util::Stream<Item = Result<Envelope<T>>> + Send>>;
+
+/// Build the handler type key for a request/response type combination.
+///
+/// Servers register handlers under this key and clients send it in the
+/// `x-qollective-handler-key` metadata so the server can dispatch to the matching handler.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub fn handler_type_key<T, R>() -> String {
+    format!(
+        "{}:{}",
+        std::any::type_name::<T>(),
+        std::any::type_name::<R>()
+    )
+}
+
+/// Normalize a route so that "orders/create" and "/orders/create" dispatch identically.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub fn normalize_grpc_route(route: &str) -> String {
+    format!("/{}", route.trim().trim_start_matches('/'))
+}
+
+/// Extract the route from a gRPC endpoint such as `grpc://server:50051/orders/create`.
+///
+/// Returns `None` when the endpoint carries no path, i.e. the call should be
+/// dispatched by handler type key instead of by route.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub fn grpc_route_from_endpoint(endpoint: &str) -> Option<String> {
+    let path = match endpoint.split_once("://") {
+        Some((_scheme, rest)) => rest.find('/').map(|index| &rest[index..])?,
+        None => endpoint,
+    };
+
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if path.trim_matches('/').is_empty() {
+        None
+    } else {
+        Some(normalize_grpc_route(path))
+    }
+}
+
+/// Map a failed call's status to an error, keeping deadline expiry distinguishable
+///
+/// Tonic cancels calls locally once the `grpc-timeout` elapses, so a failure
+/// after the request deadline has passed counts as expiry too. Transient codes
+/// become connection errors, which retry policies and circuit breakers count.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+fn call_error(
+    context: &str,
+    status: tonic::Status,
+    request_deadline: Option<chrono::DateTime<chrono::Utc>>,
+) -> QollectiveError {
+    let message = format!("{}: {}", context, status);
+    if status.code() == tonic::Code::DeadlineExceeded || deadline::check(request_deadline).is_err()
+    {
+        QollectiveError::deadline_exceeded(message)
+    } else if super::retry::is_retryable_grpc_code(status.code()) {
+        // Classified here from the code, so retries and circuit breakers see it
+        QollectiveError::connection(message)
+    } else {
+        QollectiveError::transport(message)
+    }
+}
+
+/// Convert the envelope service chain to its protobuf representation
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub(crate) fn service_chain_to_proto(
+    chain: &[crate::envelope::ServiceChainEntry],
+) -> Vec<crate::generated::qollective::ServiceChainEntry> {
+    chain
+        .iter()
+        .map(|entry| crate::generated::qollective::ServiceChainEntry {
+            service_name: entry.service_name.clone(),
+            service_version: entry.service_version.clone(),
+            request_id: entry
+                .request_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            timestamp: entry.timestamp.to_rfc3339(),
+            duration: entry.duration,
+        })
+        .collect()
+}
+
+/// Convert protobuf trace context into envelope tracing metadata, keeping the W3C fields
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub(crate) fn trace_context_from_proto(
+    tracing: crate::generated::qollective::TracingMeta,
+) -> crate::envelope::TracingMeta {
+    crate::envelope::TracingMeta {
+        trace_id: tracing.trace_id,
+        span_id: tracing.span_id,
+        parent_span_id: tracing.parent_span_id,
+        baggage: tracing.baggage,
+        sampling_rate: tracing.sampling_rate,
+        sampled: tracing.sampled,
+        trace_state: tracing.trace_state,
+        operation_name: tracing.operation_name,
+        span_kind: None,
+        span_status: None,
+        tags: std::collections::HashMap::new(),
+    }
+}
+
+/// Convert a protobuf service chain back to envelope service chain entries
+///
+/// The chain is diagnostic, so a hop with a malformed timestamp is dropped
+/// with a warning rather than failing the whole message.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub(crate) fn service_chain_from_proto(
+    chain: Vec<crate::generated::qollective::ServiceChainEntry>,
+) -> Vec<crate::envelope::ServiceChainEntry> {
+    chain
+        .into_iter()
+        .filter_map(|entry| {
+            let timestamp = match chrono::DateTime::parse_from_rfc3339(&entry.timestamp) {
+                Ok(timestamp) => timestamp.with_timezone(&chrono::Utc),
+                Err(e) => {
+                    tracing::warn!(
+                        "Dropping service chain hop {} with invalid timestamp: {}",
+                        entry.service_name,
+                        e
+                    );
+                    return None;
+                }
+            };
+
+            Some(crate::envelope::ServiceChainEntry {
+                service_name: entry.service_name,
+                service_version: entry.service_version,
+                request_id: uuid::Uuid::parse_str(&entry.request_id).ok(),
+                timestamp,
+                duration: entry.duration,
+            })
+        })
+        .collect()
+}
+
 /// gRPC transport for envelope communication.
 ///
 /// This transport implements the `UnifiedEnvelopeSender` trait to enable communication
@@ -84,6 +227,10 @@ pub struct GrpcTransport {
     grpc_client: Arc<Mutex<QollectiveServiceClient<Channel>>>,
     /// Default timeout for gRPC operations
     request_timeout: Duration,
+    /// Retry policy applied to every envelope
+    retry_policy: RetryPolicy,
+    /// Whether the endpoint path is sent as the dispatch route
+    route_dispatch: bool,
     /// gRPC client configuration
     #[allow(dead_code)] // Stored for debugging and future configuration access
     config: GrpcClientConfig,
@@ -97,6 +244,9 @@ pub struct InternalGrpcClient {
     client: Arc<Mutex<QollectiveServiceClient<Channel>>>,
     #[allow(dead_code)] // Stored for debugging and future configuration access
     config: GrpcClientConfig,
+    /// Provider of access tokens sent in the JWT metadata key
+    #[cfg(feature = "security")]
+    credentials: Option<Arc<dyn crate::security::CredentialProvider>>,
 }
 
 #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
@@ -447,9 +597,23 @@ impl InternalGrpcClient {
         Ok(Self {
             client: Arc::new(Mutex::new(client)),
             config,
+            #[cfg(feature = "security")]
+            credentials: None,
         })
     }
 
+    /// Attach access tokens from `provider` to every call.
+    ///
+    /// Unary calls rejected with `Code::Unauthenticated` are retried once with a fresh token.
+    #[cfg(feature = "security")]
+    pub fn with_credential_provider(
+        mut self,
+        provider: Arc<dyn crate::security::CredentialProvider>,
+    ) -> Self {
+        self.credentials = Some(provider);
+        self
+    }
+
     /// Configure TLS settings for the endpoint using unified TLS config
     async fn configure_tls(
         endpoint: tonic::transport::Endpoint,
@@ -497,23 +661,75 @@ impl InternalGrpcClient {
 
     /// Send a unary request (copied from original GrpcClient implementation)
     pub async fn send_envelope<Req, Res>(&self, request: Envelope<Req>) -> Result<Envelope<Res>>
+    where
+        Req: Serialize,
+        Res: for<'de> Deserialize<'de>,
+    {
+        self.send_unary(request, None).await
+    }
+
+    /// Send a unary request to the handler registered at `route` on the server.
+    ///
+    /// The server answers with `Code::NotFound` when no handler is registered at the route.
+    pub async fn send_envelope_to<Req, Res>(
+        &self,
+        route: &str,
+        request: Envelope<Req>,
+    ) -> Result<Envelope<Res>>
+    where
+        Req: Serialize,
+        Res: for<'de> Deserialize<'de>,
+    {
+        self.send_unary(request, Some(route)).await
+    }
+
+    async fn send_unary<Req, Res>(
+        &self,
+        mut request: Envelope<Req>,
+        route: Option<&str>,
+    ) -> Result<Envelope<Res>>
     where
         Req: Serialize,
         Res: for<'de> Deserialize<'de>,
     {
         // Step 1: Convert Qollective envelope to protobuf envelope
+        request.meta.tracing = trace_context::outgoing(request.meta.tracing.as_ref());
+        let tracing = request.meta.tracing.clone();
+        let deadline = deadline::outgoing(request.meta.deadline, Some(self.request_timeout()));
         let proto_envelope = self.envelope_to_protobuf(request)?;
+        let mut can_reauthenticate = true;
 
-        // Step 2: Create gRPC request with metadata
-        let grpc_request = Request::new(proto_envelope);
+        let response = loop {
+            deadline::check(deadline)?;
 
-        // Step 3: Send gRPC request using the underlying client
-        let response = {
-            let mut client = self.client.lock().await;
-            client
-                .unary_call(grpc_request)
-                .await
-                .map_err(|e| QollectiveError::transport(format!("gRPC call failed: {}", e)))?
+            // Step 2: Create gRPC request with metadata
+            let mut grpc_request = Request::new(proto_envelope.clone());
+            Self::insert_handler_key::<Req, Res, _>(&mut grpc_request);
+            if let Some(route) = route {
+                Self::insert_route(route, &mut grpc_request)?;
+            }
+            Self::insert_trace_context(tracing.as_ref(), &mut grpc_request)?;
+            Self::insert_deadline(deadline, &mut grpc_request)?;
+            let authenticated = self.insert_credentials(&mut grpc_request).await?;
+
+            // Step 3: Send gRPC request using the underlying client
+            let result = {
+                let mut client = self.client.lock().await;
+                client.unary_call(grpc_request).await
+            };
+
+            match result {
+                Err(status)
+                    if authenticated
+                        && can_reauthenticate
+                        && status.code() == tonic::Code::Unauthenticated =>
+                {
+                    // Token was rejected - retry once with a freshly obtained one
+                    can_reauthenticate = false;
+                    self.invalidate_credentials().await;
+                }
+                result => break result.map_err(|e| call_error("gRPC call failed", e, deadline))?,
+            }
         };
 
         // Step 4: Extract protobuf envelope from response
@@ -539,21 +755,291 @@ impl InternalGrpcClient {
         Ok(response.into_inner())
     }
 
-    /// Send server streaming request (placeholder for delegation pattern)
+    /// Send a server streaming request (single envelope -> stream of envelopes)
     pub async fn send_server_streaming<Req, Res>(
         &self,
-        _request: Envelope<Req>,
-    ) -> Result<Box<dyn futures_util::Stream<Item = Result<Envelope<Res>>> + Send + Unpin>>
+        request: Envelope<Req>,
+    ) -> Result<EnvelopeStream<Res>>
     where
         Req: Serialize,
+        Res: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        let mut request = request;
+        request.meta.tracing = trace_context::outgoing(request.meta.tracing.as_ref());
+        let tracing = request.meta.tracing.clone();
+        let deadline = deadline::outgoing(request.meta.deadline, Some(self.request_timeout()));
+        deadline::check(deadline)?;
+        let proto_envelope = self.envelope_to_protobuf(request)?;
+
+        let mut grpc_request = Request::new(proto_envelope);
+        Self::insert_handler_key::<Req, Res, _>(&mut grpc_request);
+        Self::insert_trace_context(tracing.as_ref(), &mut grpc_request)?;
+        Self::insert_deadline(deadline, &mut grpc_request)?;
+        self.insert_credentials(&mut grpc_request).await?;
+
+        let response = {
+            let mut client = self.client.lock().await;
+            client
+                .server_streaming(grpc_request)
+                .await
+                .map_err(|e| call_error("gRPC server streaming call failed", e, deadline))?
+        };
+
+        Ok(self.inbound_stream(response.into_inner(), deadline))
+    }
+
+    /// Send a client streaming request (stream of envelopes -> single envelope)
+    ///
+    /// The call shares one deadline: the earliest of the first request's
+    /// deadline, the deadline inherited from the request being handled and the
+    /// configured request timeout.
+    pub async fn send_client_streaming<Req, Res, S>(&self, requests: S) -> Result<Envelope<Res>>
+    where
+        Req: Serialize + Send + 'static,
         Res: for<'de> Deserialize<'de>,
+        S: futures_util::Stream<Item = Envelope<Req>> + Send + 'static,
     {
-        // For now, return error indicating transport delegation pattern
-        Err(QollectiveError::transport(
-            "InternalGrpcClient streaming not yet implemented - transport delegation pattern",
+        let conversion_error = Arc::new(std::sync::Mutex::new(None));
+        let (requests, deadline) = self.stream_deadline(requests).await;
+        deadline::check(deadline)?;
+
+        let mut grpc_request =
+            Request::new(self.outbound_stream(requests, conversion_error.clone()));
+        Self::insert_handler_key::<Req, Res, _>(&mut grpc_request);
+        Self::insert_trace_context(trace_context::outgoing(None).as_ref(), &mut grpc_request)?;
+        Self::insert_deadline(deadline, &mut grpc_request)?;
+        self.insert_credentials(&mut grpc_request).await?;
+
+        let response = {
+            let mut client = self.client.lock().await;
+            client.client_streaming(grpc_request).await
+        };
+
+        // A request envelope that could not be converted ends the stream early; report it
+        // in preference to whatever the server made of the truncated stream.
+        if let Some(error) = conversion_error.lock().unwrap().take() {
+            return Err(error);
+        }
+
+        let response =
+            response.map_err(|e| call_error("gRPC client streaming call failed", e, deadline))?;
+
+        self.protobuf_to_envelope::<Res>(response.into_inner())
+    }
+
+    /// Send a bidirectional streaming request (stream of envelopes -> stream of envelopes)
+    ///
+    /// The call's deadline is chosen as for [`send_client_streaming`](Self::send_client_streaming).
+    pub async fn send_bidirectional_streaming<Req, Res, S>(
+        &self,
+        requests: S,
+    ) -> Result<EnvelopeStream<Res>>
+    where
+        Req: Serialize + Send + 'static,
+        Res: for<'de> Deserialize<'de> + Send + 'static,
+        S: futures_util::Stream<Item = Envelope<Req>> + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        let conversion_error = Arc::new(std::sync::Mutex::new(None));
+        let (requests, deadline) = self.stream_deadline(requests).await;
+        deadline::check(deadline)?;
+
+        let mut grpc_request =
+            Request::new(self.outbound_stream(requests, conversion_error.clone()));
+        Self::insert_handler_key::<Req, Res, _>(&mut grpc_request);
+        Self::insert_trace_context(trace_context::outgoing(None).as_ref(), &mut grpc_request)?;
+        Self::insert_deadline(deadline, &mut grpc_request)?;
+        self.insert_credentials(&mut grpc_request).await?;
+
+        let response = {
+            let mut client = self.client.lock().await;
+            client
+                .bidirectional_streaming(grpc_request)
+                .await
+                .map_err(|e| call_error("gRPC bidirectional streaming call failed", e, deadline))?
+        };
+
+        // Surface a request conversion failure as the final item of the response stream
+        let trailing_error =
+            futures_util::stream::once(async move { conversion_error.lock().unwrap().take() })
+                .filter_map(|error| async move { error.map(Err) });
+
+        Ok(Box::pin(
+            self.inbound_stream(response.into_inner(), deadline)
+                .chain(trailing_error),
         ))
     }
 
+    /// Deadline of a streaming call, read from the first request before it is sent
+    ///
+    /// Returns the request stream with the first request put back in front.
+    async fn stream_deadline<Req, S>(
+        &self,
+        requests: S,
+    ) -> (
+        impl futures_util::Stream<Item = Envelope<Req>> + Send + 'static,
+        Option<chrono::DateTime<chrono::Utc>>,
+    )
+    where
+        Req: Send + 'static,
+        S: futures_util::Stream<Item = Envelope<Req>> + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        let mut requests = Box::pin(requests);
+        let first = requests.next().await;
+        let first_deadline = first.as_ref().and_then(|envelope| envelope.meta.deadline);
+        let deadline = deadline::outgoing(first_deadline, Some(self.request_timeout()));
+        (futures_util::stream::iter(first).chain(requests), deadline)
+    }
+
+    /// Attach the handler type key so the server can dispatch to the matching handler
+    fn insert_handler_key<Req, Res, M>(request: &mut Request<M>) {
+        if let Ok(value) = handler_type_key::<Req, Res>().parse() {
+            request
+                .metadata_mut()
+                .insert(crate::constants::metadata::GRPC_HANDLER_KEY_METADATA, value);
+        }
+    }
+
+    /// Attach the route so the server can dispatch to the handler registered at it
+    fn insert_route<M>(route: &str, request: &mut Request<M>) -> Result<()> {
+        let value = normalize_grpc_route(route)
+            .parse()
+            .map_err(|_| QollectiveError::transport(format!("Invalid gRPC route: {}", route)))?;
+        request
+            .metadata_mut()
+            .insert(crate::constants::metadata::GRPC_ROUTE_METADATA, value);
+        Ok(())
+    }
+
+    /// Attach W3C trace context metadata for the outgoing call
+    fn insert_trace_context<M>(
+        tracing: Option<&crate::envelope::TracingMeta>,
+        request: &mut Request<M>,
+    ) -> Result<()> {
+        let Some(tracing) = tracing else {
+            return Ok(());
+        };
+
+        for (name, value) in trace_context::header_pairs(tracing) {
+            let value = value.parse().map_err(|_| {
+                QollectiveError::transport(format!("Invalid {} for gRPC metadata", name))
+            })?;
+            request.metadata_mut().insert(name, value);
+        }
+        Ok(())
+    }
+
+    /// Attach the time left until the deadline as `grpc-timeout`
+    fn insert_deadline<M>(
+        deadline: Option<chrono::DateTime<chrono::Utc>>,
+        request: &mut Request<M>,
+    ) -> Result<()> {
+        let Some(deadline) = deadline else {
+            return Ok(());
+        };
+
+        let value = deadline::format_grpc_timeout(deadline::remaining(deadline))
+            .parse()
+            .map_err(|_| QollectiveError::transport("Invalid grpc-timeout for gRPC metadata"))?;
+        request
+            .metadata_mut()
+            .insert(deadline::GRPC_TIMEOUT_HEADER, value);
+        Ok(())
+    }
+
+    /// Time a call may take when the caller sets no earlier deadline
+    fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.timeout_ms)
+    }
+
+    /// Attach an access token from the credential provider, returning whether one was attached
+    #[cfg(feature = "security")]
+    async fn insert_credentials<M>(&self, request: &mut Request<M>) -> Result<bool> {
+        let Some(provider) = &self.credentials else {
+            return Ok(false);
+        };
+
+        let token = provider.access_token().await.map_err(|e| {
+            QollectiveError::security(format!("Failed to obtain access token: {}", e))
+        })?;
+        let jwt_config = &self.config.jwt_config;
+        let key = tonic::metadata::MetadataKey::from_bytes(jwt_config.header_name.as_bytes())
+            .map_err(|_| {
+                QollectiveError::transport(format!(
+                    "Invalid JWT metadata key: {}",
+                    jwt_config.header_name
+                ))
+            })?;
+        let value = format!("{}{}", jwt_config.header_prefix, token)
+            .parse()
+            .map_err(|_| QollectiveError::transport("Invalid JWT metadata value"))?;
+        request.metadata_mut().insert(key, value);
+        Ok(true)
+    }
+
+    #[cfg(not(feature = "security"))]
+    async fn insert_credentials<M>(&self, _request: &mut Request<M>) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Drop the cached access token after the server rejected it
+    async fn invalidate_credentials(&self) {
+        #[cfg(feature = "security")]
+        if let Some(provider) = &self.credentials {
+            provider.invalidate().await;
+        }
+    }
+
+    /// Convert outgoing envelopes to protobuf, stopping at the first conversion failure
+    fn outbound_stream<Req, S>(
+        &self,
+        requests: S,
+        conversion_error: Arc<std::sync::Mutex<Option<QollectiveError>>>,
+    ) -> impl futures_util::Stream<Item = ProtoEnvelope> + Send + 'static
+    where
+        Req: Serialize + Send + 'static,
+        S: futures_util::Stream<Item = Envelope<Req>> + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        let client = self.clone();
+        requests
+            .map(move |envelope| client.envelope_to_protobuf(envelope))
+            .take_while(move |converted| {
+                let keep_going = match converted {
+                    Ok(_) => true,
+                    Err(e) => {
+                        tracing::warn!("Stopping gRPC request stream: {}", e);
+                        *conversion_error.lock().unwrap() = Some(e.clone());
+                        false
+                    }
+                };
+                futures_util::future::ready(keep_going)
+            })
+            .filter_map(|converted| futures_util::future::ready(converted.ok()))
+    }
+
+    /// Convert an incoming protobuf response stream to an envelope stream
+    fn inbound_stream<Res>(
+        &self,
+        responses: tonic::Streaming<ProtoEnvelope>,
+        request_deadline: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> EnvelopeStream<Res>
+    where
+        Res: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        let client = self.clone();
+        Box::pin(responses.map(move |item| {
+            item.map_err(|status| call_error("gRPC stream error", status, request_deadline))
+                .and_then(|proto_envelope| client.protobuf_to_envelope::<Res>(proto_envelope))
+        }))
+    }
+
     /// Convert Qollective envelope to protobuf envelope.
     fn envelope_to_protobuf<T: Serialize>(&self, envelope: Envelope<T>) -> Result<ProtoEnvelope> {
         use crate::generated::qollective::envelope::Response as ProtoResponse;
@@ -650,7 +1136,7 @@ impl InternalGrpcClient {
             version,
             duration: meta.duration,
             tenant: meta.tenant.clone(),
-            service_chain: Vec::new(), // Service chain conversion not implemented yet
+            service_chain: service_chain_to_proto(&meta.service_chain),
             security: if meta.security.is_some() {
                 Some(crate::generated::qollective::SecurityMeta {
                     user_id: meta.security.as_ref().and_then(|s| s.user_id.clone()),
@@ -702,6 +1188,8 @@ impl InternalGrpcClient {
             version: Some(proto_meta.version),
             duration: proto_meta.duration,
             tenant: proto_meta.tenant.clone(),
+            service_chain: service_chain_from_proto(proto_meta.service_chain),
+            deadline: None,
             on_behalf_of: match proto_meta.on_behalf_of {
                 Some(obo) => Some(self.convert_on_behalf_of_from_proto(obo)?),
                 None => None,
@@ -927,14 +1415,14 @@ impl InternalGrpcClient {
         crate::generated::qollective::TracingMeta {
             trace_id: tracing.trace_id.clone(),
             span_id: tracing.span_id.clone(),
-            parent_span_id: None, // Not tracked in Qollective TracingMeta
-            baggage: std::collections::HashMap::new(), // Not tracked in Qollective TracingMeta
-            sampling_rate: None,  // Not tracked in Qollective TracingMeta
-            sampled: None,        // Not tracked in Qollective TracingMeta
-            trace_state: None,    // Not tracked in Qollective TracingMeta
-            operation_name: None, // Not tracked in Qollective TracingMeta
-            span_kind: None,      // Not tracked in Qollective TracingMeta
-            span_status: None,    // Not tracked in Qollective TracingMeta
+            parent_span_id: tracing.parent_span_id.clone(),
+            baggage: tracing.baggage.clone(),
+            sampling_rate: tracing.sampling_rate,
+            sampled: tracing.sampled,
+            trace_state: tracing.trace_state.clone(),
+            operation_name: tracing.operation_name.clone(),
+            span_kind: None,   // Not tracked in Qollective TracingMeta
+            span_status: None, // Not tracked in Qollective TracingMeta
             tags: std::collections::HashMap::new(), // Not tracked in Qollective TracingMeta
         }
     }
@@ -1037,6 +1525,8 @@ impl GrpcTransport {
         Ok(Self {
             grpc_client: Arc::new(Mutex::new(grpc_client)),
             request_timeout: Duration::from_secs(30), // Default 30 second timeout
+            retry_policy: RetryPolicy::for_transport(config.retry_attempts),
+            route_dispatch: false,
             config,
         })
     }
@@ -1061,6 +1551,8 @@ impl GrpcTransport {
         Self {
             grpc_client: Arc::new(Mutex::new(grpc_client)),
             request_timeout: Duration::from_millis(config.timeout_ms),
+            retry_policy: RetryPolicy::for_transport(config.retry_attempts),
+            route_dispatch: false,
             config,
         }
     }
@@ -1079,6 +1571,22 @@ impl GrpcTransport {
         self
     }
 
+    /// Replace the retry policy applied to every envelope.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Dispatch by route, sending the endpoint's `Service/Method` path as the route.
+    ///
+    /// Off by default: calls are dispatched by handler type key, and with route
+    /// dispatch on the server answers `Code::NotFound` when no handler is registered
+    /// at the route.
+    pub fn with_route_dispatch(mut self, enabled: bool) -> Self {
+        self.route_dispatch = enabled;
+        self
+    }
+
     /// Extract gRPC service and method from endpoint URL.
     ///
     /// Converts endpoint formats like:
@@ -1257,7 +1765,7 @@ impl GrpcTransport {
             version,
             duration: meta.duration,
             tenant: meta.tenant.clone(),
-            service_chain: Vec::new(), // Service chain conversion not implemented yet
+            service_chain: service_chain_to_proto(&meta.service_chain),
             security: if meta.security.is_some() {
                 Some(crate::generated::qollective::SecurityMeta {
                     user_id: meta.security.as_ref().and_then(|s| s.user_id.clone()),
@@ -1311,6 +1819,8 @@ impl GrpcTransport {
             version: Some(proto_meta.version),
             duration: proto_meta.duration,
             tenant: proto_meta.tenant.clone(),
+            service_chain: service_chain_from_proto(proto_meta.service_chain),
+            deadline: None,
             on_behalf_of: match proto_meta.on_behalf_of {
                 Some(obo) => Some(self.convert_on_behalf_of_from_proto(obo)?),
                 None => None,
@@ -1534,14 +2044,14 @@ impl GrpcTransport {
         crate::generated::qollective::TracingMeta {
             trace_id: tracing.trace_id.clone(),
             span_id: tracing.span_id.clone(),
-            parent_span_id: None, // Not tracked in Qollective TracingMeta
-            baggage: std::collections::HashMap::new(), // Not tracked in Qollective TracingMeta
-            sampling_rate: None,  // Not tracked in Qollective TracingMeta
-            sampled: None,        // Not tracked in Qollective TracingMeta
-            trace_state: None,    // Not tracked in Qollective TracingMeta
-            operation_name: None, // Not tracked in Qollective TracingMeta
-            span_kind: None,      // Not tracked in Qollective TracingMeta
-            span_status: None,    // Not tracked in Qollective TracingMeta
+            parent_span_id: tracing.parent_span_id.clone(),
+            baggage: tracing.baggage.clone(),
+            sampling_rate: tracing.sampling_rate,
+            sampled: tracing.sampled,
+            trace_state: tracing.trace_state.clone(),
+            operation_name: tracing.operation_name.clone(),
+            span_kind: None,   // Not tracked in Qollective TracingMeta
+            span_status: None, // Not tracked in Qollective TracingMeta
             tags: std::collections::HashMap::new(), // Not tracked in Qollective TracingMeta
         }
     }
@@ -1740,45 +2250,39 @@ impl GrpcTransport {
             }
         }
 
+        // W3C trace context, parented to the current span when one is active
+        if let Some(tracing) = trace_context::outgoing(envelope_meta.tracing.as_ref()) {
+            for (name, value) in trace_context::header_pairs(&tracing) {
+                metadata.insert(
+                    name,
+                    value.parse().map_err(|_| {
+                        QollectiveError::transport(format!("Invalid {} for gRPC metadata", name))
+                    })?,
+                );
+            }
+        }
+
         Ok(metadata)
     }
 }
 
 #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
-#[async_trait]
-impl<T, R> UnifiedEnvelopeSender<T, R> for GrpcTransport
-where
-    T: Serialize + Send + Clone + 'static,
-    R: for<'de> Deserialize<'de> + Send + 'static,
-{
-    /// Send an envelope to the specified gRPC endpoint.
-    ///
-    /// This method implements complete gRPC communication with envelope wrapping:
-    /// 1. Extracts the gRPC service and method from the endpoint URL
-    /// 2. Converts the Qollective envelope to protobuf format
-    /// 3. Maps envelope metadata to gRPC headers
-    /// 4. Uses gRPC unary call for synchronous communication
-    /// 5. Converts the response protobuf back to Qollective envelope
-    ///
-    /// # Arguments
-    ///
-    /// * `endpoint` - gRPC endpoint URL (e.g., "grpc://localhost:50051/MyService/MyMethod")
-    /// * `envelope` - The request envelope containing metadata and data
-    ///
-    /// # Returns
-    ///
-    /// Returns the deserialized response envelope.
-    ///
-    /// # Errors
-    ///
-    /// This method will return an error if:
-    /// - The endpoint URL is malformed
-    /// - Envelope ↔ protobuf conversion fails
-    /// - gRPC call fails (timeout, service unavailable, etc.)
-    /// - Response envelope conversion fails
-    async fn send_envelope(&self, endpoint: &str, envelope: Envelope<T>) -> Result<Envelope<R>> {
-        // Extract service and method from endpoint
-        let (_service_name, _method_name) = self.extract_service_method_from_endpoint(endpoint)?;
+impl GrpcTransport {
+    /// Send an envelope once, without retrying
+    async fn send_once<T, R>(&self, endpoint: &str, envelope: Envelope<T>) -> Result<Envelope<R>>
+    where
+        T: Serialize + Send + 'static,
+        R: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        // Extract service and method from endpoint; together they form the route
+        let (service_name, method_name) = self.extract_service_method_from_endpoint(endpoint)?;
+
+        // Wait no longer than the caller has left
+        deadline::check(envelope.meta.deadline)?;
+        let request_deadline = envelope.meta.deadline;
+        let timeout = request_deadline
+            .map(deadline::remaining)
+            .unwrap_or(self.request_timeout);
 
         // Extract context from envelope metadata before converting to protobuf
         let context = crate::envelope::Context::new(envelope.meta.clone());
@@ -1792,13 +2296,20 @@ where
         // Inject envelope context into gRPC metadata
         let grpc_middleware = crate::client::middleware::GrpcClientMiddleware::new();
         grpc_middleware.inject_into_tonic_metadata(&context, request.metadata_mut())?;
+        InternalGrpcClient::insert_handler_key::<T, R, _>(&mut request);
+        if self.route_dispatch {
+            InternalGrpcClient::insert_route(
+                &format!("{}/{}", service_name, method_name),
+                &mut request,
+            )?;
+        }
+        InternalGrpcClient::insert_deadline(request_deadline, &mut request)?;
 
         // Send gRPC request and wait for response
         let mut client = self.grpc_client.lock().await;
 
         // Use a timeout for the gRPC call
-        let response_result =
-            tokio::time::timeout(self.request_timeout, client.unary_call(request)).await;
+        let response_result = tokio::time::timeout(timeout, client.unary_call(request)).await;
 
         match response_result {
             Ok(response_result) => {
@@ -1824,20 +2335,76 @@ where
 
                         Ok(envelope)
                     }
-                    Err(status) => Err(QollectiveError::transport(format!(
-                        "gRPC call failed: {}",
-                        status
-                    ))),
+                    Err(status) => Err(call_error("gRPC call failed", status, request_deadline)),
                 }
             }
-            Err(_) => Err(QollectiveError::transport(format!(
-                "gRPC call to {} timed out after {:?}",
-                endpoint, self.request_timeout
-            ))),
+            // A timeout taken from the deadline expires like any other deadline
+            Err(_) => Err(match request_deadline {
+                Some(request_deadline) => deadline::exceeded(request_deadline),
+                None => QollectiveError::transport(format!(
+                    "gRPC call to {} timed out after {:?}",
+                    endpoint, timeout
+                )),
+            }),
         }
     }
 }
 
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+#[async_trait]
+impl<T, R> UnifiedEnvelopeSender<T, R> for GrpcTransport
+where
+    T: Serialize + Send + Clone + 'static,
+    R: for<'de> Deserialize<'de> + Send + 'static,
+{
+    /// Send an envelope to the specified gRPC endpoint.
+    ///
+    /// This method implements complete gRPC communication with envelope wrapping:
+    /// 1. Extracts the gRPC service and method from the endpoint URL
+    /// 2. Converts the Qollective envelope to protobuf format
+    /// 3. Maps envelope metadata to gRPC headers
+    /// 4. Uses gRPC unary call for synchronous communication
+    /// 5. Converts the response protobuf back to Qollective envelope
+    ///
+    /// Transient failures are retried according to the transport's retry policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - gRPC endpoint URL (e.g., "grpc://localhost:50051/MyService/MyMethod")
+    /// * `envelope` - The request envelope containing metadata and data
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized response envelope.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The endpoint URL is malformed
+    /// - Envelope ↔ protobuf conversion fails
+    /// - gRPC call fails (timeout, service unavailable, etc.)
+    /// - Response envelope conversion fails
+    async fn send_envelope(
+        &self,
+        endpoint: &str,
+        mut envelope: Envelope<T>,
+    ) -> Result<Envelope<R>> {
+        // Fix the deadline once so all attempts share the caller's time budget
+        envelope.meta.deadline =
+            deadline::outgoing(envelope.meta.deadline, Some(self.request_timeout));
+
+        self.retry_policy
+            .retry(move |attempt| {
+                let mut envelope = envelope.clone();
+                if attempt > 1 {
+                    envelope.meta.set_retry_attempt(attempt);
+                }
+                self.send_once(endpoint, envelope)
+            })
+            .await
+    }
+}
+
 // Non-feature version for compilation when gRPC features are disabled
 #[cfg(not(any(feature = "grpc-client", feature = "grpc-server")))]
 #[derive(Debug, Clone)]
@@ -1858,6 +2425,14 @@ impl GrpcTransport {
     pub fn with_timeout(self, _timeout: Duration) -> Self {
         self
     }
+
+    pub fn with_retry_policy(self, _retry_policy: crate::transport::retry::RetryPolicy) -> Self {
+        self
+    }
+
+    pub fn with_route_dispatch(self, _enabled: bool) -> Self {
+        self
+    }
 }
 
 #[cfg(not(any(feature = "grpc-client", feature = "grpc-server")))]
@@ -1891,6 +2466,41 @@ mod tests {
         status: u32,
     }
 
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    #[test]
+    fn test_call_error_classifies_status_codes() {
+        use crate::transport::circuit_breaker::{
+            CircuitBreakerConfig, CircuitBreakerRegistry, CircuitState,
+        };
+        use crate::transport::retry::is_retryable;
+
+        let error = |status| call_error("gRPC call failed", status, None);
+
+        for status in [
+            tonic::Status::unavailable("warp core offline"),
+            tonic::Status::resource_exhausted("shields at capacity"),
+            tonic::Status::aborted("transporter lock lost"),
+        ] {
+            assert!(is_retryable(&error(status)));
+        }
+        assert!(!is_retryable(&error(tonic::Status::invalid_argument(
+            "unknown stardate"
+        ))));
+        assert!(matches!(
+            error(tonic::Status::deadline_exceeded("too slow")),
+            QollectiveError::DeadlineExceeded(_)
+        ));
+
+        // Transient failures count against the endpoint's circuit
+        let breakers = CircuitBreakerRegistry::new(CircuitBreakerConfig {
+            minimum_requests: 1,
+            ..Default::default()
+        });
+        let result: Result<()> = Err(error(tonic::Status::unavailable("down")));
+        breakers.record_result("grpc://enterprise:50051", &result);
+        assert_eq!(breakers.state("grpc://enterprise:50051"), CircuitState::Open);
+    }
+
     #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
     #[test]
     fn test_extract_service_method_from_endpoint() {
@@ -1987,6 +2597,59 @@ mod tests {
             .is_err());
     }
 
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    #[test]
+    fn test_grpc_route_from_endpoint() {
+        assert_eq!(
+            grpc_route_from_endpoint("grpc://localhost:50051/orders/create"),
+            Some("/orders/create".to_string())
+        );
+        assert_eq!(
+            grpc_route_from_endpoint("http://server:50051/orders?trace=1"),
+            Some("/orders".to_string())
+        );
+        assert_eq!(
+            grpc_route_from_endpoint("orders/create"),
+            Some("/orders/create".to_string())
+        );
+        assert_eq!(grpc_route_from_endpoint("grpc://localhost:50051"), None);
+        assert_eq!(grpc_route_from_endpoint("grpc://localhost:50051/"), None);
+        assert_eq!(normalize_grpc_route("/orders/create"), "/orders/create");
+    }
+
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    #[test]
+    fn test_service_chain_proto_round_trip() {
+        let mut gateway = crate::envelope::ServiceChainEntry::new("gateway", "1.0.0")
+            .with_request_id(Some(uuid::Uuid::now_v7()));
+        gateway.duration = Some(12.5);
+        let backend = crate::envelope::ServiceChainEntry::new("backend", "2.1.0");
+        let chain = vec![gateway, backend];
+
+        let proto_chain = service_chain_to_proto(&chain);
+        assert_eq!(proto_chain.len(), 2);
+        assert_eq!(proto_chain[0].service_name, "gateway");
+        assert_eq!(proto_chain[1].request_id, "");
+
+        let restored = service_chain_from_proto(proto_chain);
+        assert_eq!(restored, chain);
+    }
+
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    #[test]
+    fn test_service_chain_from_proto_skips_malformed_hops() {
+        let chain = vec![
+            crate::envelope::ServiceChainEntry::new("gateway", "1.0.0"),
+            crate::envelope::ServiceChainEntry::new("backend", "2.1.0"),
+        ];
+        let mut proto_chain = service_chain_to_proto(&chain);
+        proto_chain[0].timestamp = "stardate 41153.7".to_string();
+
+        let restored = service_chain_from_proto(proto_chain);
+
+        assert_eq!(restored, vec![chain[1].clone()]);
+    }
+
     #[test]
     fn test_grpc_transport_creation_without_features() {
         // Test that transport can be created even when gRPC features are disabled
@@ -2023,6 +2686,8 @@ mod tests {
             version: Some("1.0.0".to_string()),
             duration: Some(1500.0),
             tenant: Some("test-tenant".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: Some(crate::envelope::SecurityMeta {
                 user_id: Some("test-user".to_string()),
@@ -2124,6 +2789,8 @@ mod tests {
             version: Some("1.0.0".to_string()),
             duration: Some(1500.0),
             tenant: Some("test-tenant".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: None,
             security: Some(crate::envelope::SecurityMeta {
                 user_id: Some("test-user".to_string()),
@@ -2217,6 +2884,8 @@ mod tests {
             version: Some("2.1.0".to_string()),
             duration: Some(2500.75),
             tenant: Some("test-tenant-123".to_string()),
+            service_chain: Vec::new(),
+            deadline: None,
             on_behalf_of: Some(OnBehalfOfMeta {
                 original_user: "delegated-user-456".to_string(),
                 delegating_user: "admin-789".to_string(),
@@ -2553,6 +3222,8 @@ mod tests {
                             version: Some(proto_meta.version).filter(|v| !v.is_empty()),
                             duration: proto_meta.duration,
                             tenant,
+                            service_chain: Vec::new(),
+                            deadline: None,
                             on_behalf_of: None, // Simplified for this test
                             security,
                             debug: None,       // Simplified for this test
@@ -2560,7 +3231,7 @@ mod tests {
                             monitoring: None,  // Simplified for this test
                             tracing: None,     // Simplified for this test
                             extensions: None,  // Simplified for this test
-                                        })
+                        })
                     }
                 }
 
@@ -2663,6 +3334,8 @@ mod tests {
         let transport = GrpcTransport {
             grpc_client: Arc::new(Mutex::new(grpc_client)),
             request_timeout: Duration::from_secs(30),
+            retry_policy: RetryPolicy::none(),
+            route_dispatch: false,
             config,
         };
 
@@ -322,6 +322,7 @@ pub mod utils {
             QollectiveError::Internal(msg) => (-32603, format!("Internal error: {}", msg)),
             QollectiveError::External(msg) => (-32000, format!("External service error: {}", msg)),
             QollectiveError::Remote(msg) => (-32000, format!("Remote service error: {}", msg)),
+            QollectiveError::DeadlineExceeded(msg) => (-32000, format!("Deadline exceeded: {}", msg)),
             QollectiveError::Envelope(msg) => (-32600, format!("Envelope error: {}", msg)),
             QollectiveError::AgentNotFound(msg) => (-32000, format!("Agent not found: {}", msg)),
             QollectiveError::ProtocolAdapter(msg) => (-32000, format!("Protocol adapter error: {}", msg)),
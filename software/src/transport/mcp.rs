@@ -34,6 +34,7 @@ use tokio::sync::RwLock;
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
 use {
     crate::traits::senders::UnifiedEnvelopeSender,
+    crate::transport::retry::RetryPolicy,
     async_trait::async_trait,
     serde::{Deserialize, Serialize},
     std::time::Duration,
@@ -481,6 +482,8 @@ pub struct InternalMcpClient {
     connection_pool: Arc<RwLock<HashMap<String, Arc<RunningService<rmcp::service::RoleClient, ()>>>>>,
     /// Client handler for rmcp protocol
     client_handler: (),
+    /// Retry policy for rmcp requests
+    retry_policy: RetryPolicy,
 }
 
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
@@ -488,6 +491,7 @@ impl InternalMcpClient {
     /// Create a new internal MCP client
     pub fn new(config: McpTransportConfig) -> Self {
         Self {
+            retry_policy: RetryPolicy::for_transport(config.retry_attempts),
             config,
             connection_pool: Arc::new(RwLock::new(HashMap::new())),
             client_handler: (),
@@ -730,24 +734,9 @@ impl InternalMcpClient {
         let client = self.get_rmcp_client(endpoint).await?;
 
         // Execute request with retries
-        let mut last_error = None;
-        for attempt in 0..=self.config.retry_attempts {
-            match self.execute_rmcp_request(&client, &request).await {
-                Ok(response) => return Ok(response),
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt < self.config.retry_attempts {
-                        // Exponential backoff for retries
-                        let delay = Duration::from_millis(100 * (1 << attempt));
-                        tokio::time::sleep(delay).await;
-                    }
-                }
-            }
-        }
-
-        Err(last_error.unwrap_or_else(|| {
-            QollectiveError::transport("MCP request failed after all retries".to_string())
-        }))
+        self.retry_policy
+            .retry(|_| self.execute_rmcp_request(&client, &request))
+            .await
     }
 
     /// Execute MCP request using rmcp client
@@ -21,6 +21,9 @@ use url;
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 pub mod nats;
 
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+pub mod nats_jetstream;
+
 #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
 pub mod mcp;
 
@@ -36,10 +39,19 @@ pub mod rest;
 #[cfg(feature = "websocket-client")]
 pub mod websocket;
 
+#[cfg(feature = "websocket-client")]
+pub mod websocket_connection;
+
 
 #[cfg(any(feature = "jsonrpc-client", feature = "jsonrpc-server"))]
 pub mod jsonrpc;
 
+// Retry policies shared by all transports
+pub mod retry;
+
+// Client-side circuit breakers shared by all transports
+pub mod circuit_breaker;
+
 /// Hybrid transport client providing universal communication capabilities
 #[derive(Debug, Clone)]
 pub struct HybridTransportClient {
@@ -59,6 +71,10 @@ pub struct HybridTransportClient {
     #[cfg(feature = "grpc-client")]
     internal_grpc_client: Option<Arc<crate::transport::grpc::InternalGrpcClient>>,
 
+    /// Whether gRPC endpoint paths are sent as the dispatch route
+    #[cfg(feature = "grpc-client")]
+    grpc_route_dispatch: bool,
+
     #[cfg(feature = "rest-client")]
     rest_client: Option<Arc<crate::transport::rest::InternalRestClient>>,
 
@@ -71,6 +87,12 @@ pub struct HybridTransportClient {
     #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
     a2a_transport: Option<Arc<crate::transport::a2a::InternalA2AClient>>,
 
+    /// Retry policy applied to every envelope, whichever transport carries it
+    retry_policy: retry::RetryPolicy,
+    /// Circuit breakers per endpoint and transport, consulted by the fallback chain
+    circuit_breakers: circuit_breaker::CircuitBreakerRegistry,
+    /// Meta policy enforced on every envelope before it is sent
+    meta_policy: crate::envelope::meta::OutgoingMetaPolicy,
 }
 
 /// Universal transport protocol enumeration
@@ -240,6 +262,9 @@ impl HybridTransportClient {
             #[cfg(feature = "grpc-client")]
             internal_grpc_client: None,
 
+            #[cfg(feature = "grpc-client")]
+            grpc_route_dispatch: false,
+
             #[cfg(feature = "rest-client")]
             rest_client: None,
 
@@ -252,6 +277,9 @@ impl HybridTransportClient {
             #[cfg(any(feature = "a2a-client", feature = "a2a-server"))]
             a2a_transport: None,
 
+            retry_policy: retry::RetryPolicy::none(),
+            circuit_breakers: circuit_breaker::CircuitBreakerRegistry::default(),
+            meta_policy: crate::envelope::meta::OutgoingMetaPolicy::environment(),
         }
     }
 
@@ -264,7 +292,8 @@ impl HybridTransportClient {
         transport_config: crate::config::transport::TransportConfig,
     ) -> crate::error::Result<Self> {
         let detection_config = transport_config.to_detection_config();
-        let mut client = Self::new(detection_config);
+        let mut client = Self::new(detection_config)
+            .with_retry_policy((&transport_config.global.retry_config).into());
 
         // Auto-inject transport clients based on feature gates and config presence
 
@@ -273,8 +302,11 @@ impl HybridTransportClient {
             if let Some(_rest_client_config) = &rest_config.client {
                 // Convert preset config to actual REST client config
                 let rest_client_config = crate::client::rest::RestClientConfig::default();
+                // Retries are applied once by the hybrid client for every transport
                 let rest_client = Arc::new(
-                    crate::transport::rest::InternalRestClient::new(rest_client_config).await?,
+                    crate::transport::rest::InternalRestClient::new(rest_client_config)
+                        .await?
+                        .with_retry_policy(retry::RetryPolicy::none()),
                 );
                 client.rest_client = Some(rest_client);
             }
@@ -310,10 +342,13 @@ impl HybridTransportClient {
                 max_message_size: websocket_config.max_message_size,
                 subprotocols: websocket_config.subprotocols.clone(),
                 enable_compression: websocket_config.enable_compression,
+                ..Default::default()
             };
-            let websocket_client = Arc::new(crate::transport::websocket::WebSocketTransport::new(
-                websocket_transport_config,
-            ));
+            // Retries are applied once by the hybrid client for every transport
+            let websocket_client = Arc::new(
+                crate::transport::websocket::WebSocketTransport::new(websocket_transport_config)
+                    .with_retry_policy(retry::RetryPolicy::none()),
+            );
             client.websocket_transport = Some(websocket_client);
         }
 
@@ -364,6 +399,62 @@ impl HybridTransportClient {
         Ok(client)
     }
 
+    /// Builder method to set the retry policy applied to every envelope
+    pub fn with_retry_policy(mut self, retry_policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Get the retry policy applied to every envelope
+    pub fn retry_policy(&self) -> &retry::RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Builder method to set the thresholds of the per-endpoint circuit breakers
+    pub fn with_circuit_breaker_config(
+        mut self,
+        config: circuit_breaker::CircuitBreakerConfig,
+    ) -> Self {
+        self.circuit_breakers = circuit_breaker::CircuitBreakerRegistry::new(config);
+        self
+    }
+
+    /// Get the circuit breakers consulted by `send_with_fallback`
+    pub fn circuit_breakers(&self) -> &circuit_breaker::CircuitBreakerRegistry {
+        &self.circuit_breakers
+    }
+
+    /// Get the state of the circuit for an endpoint reached over a transport
+    pub fn circuit_state(
+        &self,
+        endpoint: &str,
+        transport: &TransportProtocol,
+    ) -> circuit_breaker::CircuitState {
+        self.circuit_breakers
+            .state(&Self::circuit_key(endpoint, transport))
+    }
+
+    /// Key of the circuit for an endpoint reached over a transport
+    pub fn circuit_key(endpoint: &str, transport: &TransportProtocol) -> String {
+        format!("{:?} {}", transport, endpoint)
+    }
+
+    /// Builder method to set the meta policy enforced on every outgoing envelope
+    #[cfg(feature = "config")]
+    pub fn with_meta_policy(
+        mut self,
+        policy: impl Into<crate::envelope::meta::OutgoingMetaPolicy>,
+    ) -> Self {
+        self.meta_policy = policy.into();
+        self
+    }
+
+    /// Strip whatever the meta policy does not allow from an envelope bound for `route`
+    pub fn apply_meta_policy<T>(&self, mut envelope: Envelope<T>, route: &str) -> Envelope<T> {
+        self.meta_policy.apply(&mut envelope.meta, route);
+        envelope
+    }
+
     /// Builder method to inject NATS client for dual transport support
     #[cfg(any(feature = "nats-client", feature = "nats-server"))]
     pub fn with_internal_nats_client(
@@ -390,6 +481,17 @@ impl HybridTransportClient {
         self
     }
 
+    /// Builder method to dispatch gRPC envelopes by the endpoint's path.
+    ///
+    /// Off by default, like `GrpcTransport::with_route_dispatch`: envelopes reach the
+    /// server's type-keyed handlers, and with route dispatch on the server answers
+    /// `Code::NotFound` when no handler is registered at the route.
+    #[cfg(feature = "grpc-client")]
+    pub fn with_grpc_route_dispatch(mut self, enabled: bool) -> Self {
+        self.grpc_route_dispatch = enabled;
+        self
+    }
+
     /// Get reference to internal gRPC client for delegation
     #[cfg(feature = "grpc-client")]
     pub fn internal_grpc_client(&self) -> Option<&Arc<crate::transport::grpc::InternalGrpcClient>> {
@@ -989,10 +1091,21 @@ impl HybridTransportClient {
         let mut last_error = None;
 
         for transport in fallback_chain {
-            match self
+            // Skip transports whose circuit for this endpoint is open
+            let circuit = Self::circuit_key(endpoint, &transport);
+            if !self.circuit_breakers.allow(&circuit) {
+                last_error = Some(QollectiveError::transport(format!(
+                    "Circuit open for {:?} at {}",
+                    transport, endpoint
+                )));
+                continue;
+            }
+
+            let result = self
                 .try_send_with_transport(&transport, endpoint, &payload)
-                .await
-            {
+                .await;
+            self.circuit_breakers.record_result(&circuit, &result);
+            match result {
                 Ok(response) => return Ok(response),
                 Err(e) => last_error = Some(e),
             }
@@ -1435,8 +1548,33 @@ where
     /// Send an envelope through the hybrid transport system
     ///
     /// This method leverages the existing capability detection and transport selection
-    /// logic to route envelopes through the optimal transport protocol.
+    /// logic to route envelopes through the optimal transport protocol, retrying
+    /// transient failures according to the client's retry policy.
     async fn send_envelope(&self, endpoint: &str, envelope: Envelope<T>) -> Result<Envelope<R>> {
+        let envelope = self.apply_meta_policy(envelope, endpoint);
+        if self.retry_policy.max_retries == 0 {
+            return self.dispatch_envelope(endpoint, envelope).await;
+        }
+
+        self.retry_policy
+            .retry_envelope(envelope, |envelope| {
+                self.dispatch_envelope(endpoint, envelope)
+            })
+            .await
+    }
+}
+
+impl HybridTransportClient {
+    /// Route a single envelope through the optimal transport protocol
+    async fn dispatch_envelope<T, R>(
+        &self,
+        endpoint: &str,
+        envelope: Envelope<T>,
+    ) -> Result<Envelope<R>>
+    where
+        T: Serialize + Send + Sync + 'static,
+        R: for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
         // Parse endpoint to extract NATS subject (requires url dependency)
         #[cfg(any(feature = "nats-client", feature = "nats-server", feature = "mcp-client", feature = "mcp-server"))]
         let url = url::Url::parse(endpoint)
@@ -1513,7 +1651,13 @@ where
             #[cfg(feature = "grpc-client")]
             TransportProtocol::QollectiveGrpc | TransportProtocol::NativeGrpc => {
                 if let Some(ref grpc_client) = self.internal_grpc_client {
-                    grpc_client.send_envelope(envelope).await
+                    // With route dispatch on, endpoint paths address routes like REST and NATS subjects
+                    let route = grpc::grpc_route_from_endpoint(endpoint)
+                        .filter(|_| self.grpc_route_dispatch);
+                    match route {
+                        Some(route) => grpc_client.send_envelope_to(&route, envelope).await,
+                        None => grpc_client.send_envelope(envelope).await,
+                    }
                 } else {
                     Err(QollectiveError::transport(
                         "gRPC client not available - ensure TransportConfig includes grpc client config"
@@ -1821,6 +1965,21 @@ mod tests {
         assert!(!client.available_transports.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_retry_policy_from_global_config() {
+        let client = HybridTransportClient::new(TransportDetectionConfig::default());
+        assert_eq!(client.retry_policy().max_retries, 0);
+
+        let mut transport_config = create_test_transport_config_with_no_protocols();
+        transport_config.global.retry_config.max_attempts = 5;
+        let client = HybridTransportClient::from_config(transport_config)
+            .await
+            .expect("Should create client without protocols");
+
+        assert_eq!(client.retry_policy().max_retries, 4);
+        assert!(client.retry_policy().budget().is_some());
+    }
+
     #[tokio::test]
     async fn test_capability_detection_caching() {
         let config = TransportDetectionConfig::default();
@@ -1865,6 +2024,37 @@ mod tests {
         assert!(!chain.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_send_with_fallback_skips_open_circuits() {
+        let client = HybridTransportClient::new(TransportDetectionConfig::default())
+            .with_circuit_breaker_config(circuit_breaker::CircuitBreakerConfig {
+                minimum_requests: 1,
+                ..Default::default()
+            });
+        let endpoint = "https://example.com";
+        let requirements = TransportRequirements::default();
+
+        let chain = client
+            .get_fallback_chain(endpoint, &requirements)
+            .await
+            .unwrap();
+        for transport in &chain {
+            let key = HybridTransportClient::circuit_key(endpoint, transport);
+            client.circuit_breakers().record_failure(&key);
+            assert_eq!(
+                client.circuit_state(endpoint, transport),
+                circuit_breaker::CircuitState::Open
+            );
+        }
+
+        let result: Result<TestResponse> = client
+            .send_with_fallback(endpoint, "ping".to_string(), &requirements)
+            .await;
+        let error = result.expect_err("All circuits are open");
+        assert!(error.to_string().contains("Circuit open"), "{}", error);
+        assert_eq!(client.circuit_breakers().snapshots().len(), chain.len());
+    }
+
     // TDD: Failing tests for UnifiedEnvelopeSender<T, R> trait implementation
 
     #[tokio::test]
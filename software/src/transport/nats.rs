@@ -25,7 +25,11 @@ use std::time::Duration;
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 use crate::config::nats::NatsConfig;
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
-use crate::envelope::{Envelope, NatsEnvelopeCodec};
+use crate::envelope::{deadline, trace_context, Envelope, NatsEnvelopeCodec};
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+use crate::transport::nats_jetstream::{self, DurablePublishAck};
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+use crate::transport::retry::{Jitter, RetryBudget, RetryPolicy};
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 use std::sync::Arc;
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
@@ -144,12 +148,34 @@ struct ClientState {
     event_sender: Option<mpsc::UnboundedSender<ConnectionEvent>>,
 }
 
+#[cfg(any(feature = "nats-client", feature = "nats-server"))]
+impl ClientState {
+    /// Move to a new connection state and publish it with the connection counters as metrics
+    fn set_connection_state(&mut self, new_state: ConnectionState) {
+        let is_circuit = |state: &ConnectionState| {
+            matches!(
+                state,
+                ConnectionState::CircuitOpen | ConnectionState::CircuitHalfOpen
+            )
+        };
+        if new_state != self.connection_state
+            && (is_circuit(&new_state) || is_circuit(&self.connection_state))
+        {
+            self.metrics.circuit_breaker_state_changes += 1;
+        }
+
+        self.connection_state = new_state;
+        crate::monitoring::record_nats_connection(&self.connection_state, &self.metrics);
+    }
+}
+
 /// Internal NATS client with robust connection management (copied from original NatsClient)
 /// This handles all the complex connection logic, circuit breaker, mTLS, etc.
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
 #[derive(Clone)]
 pub struct InternalNatsClient {
     connection: async_nats::Client,
+    jetstream: async_nats::jetstream::Context,
     config: NatsConfig,
     state: Arc<RwLock<ClientState>>,
 }
@@ -161,6 +187,11 @@ impl InternalNatsClient {
     pub fn client(&self) -> &async_nats::Client {
         &self.connection
     }
+
+    /// Get access to the JetStream context used for durable publishing
+    pub fn jetstream(&self) -> &async_nats::jetstream::Context {
+        &self.jetstream
+    }
 }
 
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
@@ -275,7 +306,7 @@ impl InternalNatsClient {
                     async move {
                         let mut state_guard = state.write().await;
                         state_guard.metrics.failed_connections += 1;
-                        state_guard.connection_state = ConnectionState::Disconnected;
+                        state_guard.set_connection_state(ConnectionState::Disconnected);
                         let _ = state_guard.circuit_breaker.record_failure();
                     }
                 });
@@ -289,13 +320,20 @@ impl InternalNatsClient {
         {
             let mut state_guard = state.write().await;
             state_guard.metrics.successful_connections += 1;
-            state_guard.connection_state = ConnectionState::Connected;
+            state_guard.set_connection_state(ConnectionState::Connected);
             state_guard.connection_start_time = Some(Instant::now());
             state_guard.circuit_breaker.record_success();
         }
 
+        let jetstream = async_nats::jetstream::ContextBuilder::new()
+            .ack_timeout(Duration::from_millis(
+                config.jetstream.publish_ack_timeout_ms,
+            ))
+            .build(connection.clone());
+
         Ok(Self {
             connection,
+            jetstream,
             config,
             state,
         })
@@ -305,7 +343,7 @@ impl InternalNatsClient {
     pub async fn send_envelope<T, R>(
         &self,
         subject: &str,
-        envelope: Envelope<T>,
+        mut envelope: Envelope<T>,
     ) -> Result<Envelope<R>>
     where
         T: serde::Serialize,
@@ -314,15 +352,32 @@ impl InternalNatsClient {
         // Check circuit breaker before making request
         self.can_make_request().await?;
 
-        // Encode envelope to bytes
+        // Wait no longer than the caller has left, and tell the responder how long that is
+        envelope.meta.deadline =
+            deadline::outgoing(envelope.meta.deadline, self.connection.timeout());
+        deadline::check(envelope.meta.deadline)?;
+
+        // Encode envelope to bytes, carrying the trace context and deadline in headers as well
+        envelope.meta.tracing = trace_context::outgoing(envelope.meta.tracing.as_ref());
+        let headers = deadline::with_nats_header(
+            envelope.meta.deadline,
+            trace_context::nats_headers(&envelope.meta),
+        );
         let encoded_data = NatsEnvelopeCodec::encode(&envelope).map_err(|e| {
             QollectiveError::nats_message(format!("Failed to encode envelope: {}", e))
         })?;
 
         // Send request and wait for response
+        let mut request = async_nats::Request::new().payload(encoded_data.into());
+        if let Some(headers) = headers {
+            request = request.headers(headers);
+        }
+        if let Some(deadline) = envelope.meta.deadline {
+            request = request.timeout(Some(deadline::remaining(deadline)));
+        }
         let response_result = self
             .connection
-            .request(subject.to_string(), encoded_data.into())
+            .send_request(subject.to_string(), request)
             .await;
 
         match response_result {
@@ -346,7 +401,7 @@ impl InternalNatsClient {
                 {
                     let mut state_guard = self.state.write().await;
                     let new_state = state_guard.circuit_breaker.record_failure();
-                    state_guard.connection_state = new_state.clone();
+                    state_guard.set_connection_state(new_state.clone());
 
                     // Send circuit breaker event if state changed
                     if let Some(ref sender) = state_guard.event_sender {
@@ -401,7 +456,7 @@ impl InternalNatsClient {
                         {
                             let mut state_guard = self.state.write().await;
                             let new_state = state_guard.circuit_breaker.record_failure();
-                            state_guard.connection_state = new_state.clone();
+                            state_guard.set_connection_state(new_state.clone());
 
                             // Send circuit breaker event if state changed
                             if let Some(ref sender) = state_guard.event_sender {
@@ -418,10 +473,7 @@ impl InternalNatsClient {
                             }
                         }
 
-                        Err(QollectiveError::transport(format!(
-                            "Raw NATS request failed: {}",
-                            e
-                        )))
+                        Err(QollectiveError::from(e))
                     }
                 }
             }
@@ -430,10 +482,10 @@ impl InternalNatsClient {
                 {
                     let mut state_guard = self.state.write().await;
                     let new_state = state_guard.circuit_breaker.record_failure();
-                    state_guard.connection_state = new_state;
+                    state_guard.set_connection_state(new_state);
                 }
 
-                Err(QollectiveError::transport(format!(
+                Err(QollectiveError::nats_timeout(format!(
                     "Raw NATS request to {} timed out after {:?}",
                     subject, timeout
                 )))
@@ -442,23 +494,33 @@ impl InternalNatsClient {
     }
 
     /// Publish an envelope to a NATS subject (fire-and-forget)
-    pub async fn publish_envelope<T>(&self, subject: &str, envelope: Envelope<T>) -> Result<()>
+    pub async fn publish_envelope<T>(&self, subject: &str, mut envelope: Envelope<T>) -> Result<()>
     where
         T: serde::Serialize,
     {
         // Check circuit breaker before making request
         self.can_make_request().await?;
 
-        // Encode envelope to bytes
+        // Encode envelope to bytes, carrying the trace context in headers as well
+        envelope.meta.tracing = trace_context::outgoing(envelope.meta.tracing.as_ref());
+        let headers = trace_context::nats_headers(&envelope.meta);
         let encoded_data = NatsEnvelopeCodec::encode(&envelope).map_err(|e| {
             QollectiveError::nats_message(format!("Failed to encode envelope: {}", e))
         })?;
 
         // Publish to NATS (fire-and-forget)
-        let publish_result = self
-            .connection
-            .publish(subject.to_string(), encoded_data.into())
-            .await;
+        let publish_result = match headers {
+            Some(headers) => {
+                self.connection
+                    .publish_with_headers(subject.to_string(), headers, encoded_data.into())
+                    .await
+            }
+            None => {
+                self.connection
+                    .publish(subject.to_string(), encoded_data.into())
+                    .await
+            }
+        };
 
         match publish_result {
             Ok(()) => {
@@ -474,7 +536,7 @@ impl InternalNatsClient {
                 {
                     let mut state_guard = self.state.write().await;
                     let new_state = state_guard.circuit_breaker.record_failure();
-                    state_guard.connection_state = new_state.clone();
+                    state_guard.set_connection_state(new_state.clone());
 
                     // Send circuit breaker event if state changed
                     if let Some(ref sender) = state_guard.event_sender {
@@ -495,6 +557,62 @@ impl InternalNatsClient {
         }
     }
 
+    /// Publish an envelope to a JetStream stream and wait for the stream to store it
+    ///
+    /// The envelope's request id is sent as `Nats-Msg-Id`, so republishing the
+    /// same envelope within the stream's duplicate window is stored only once.
+    pub async fn publish_durable<T>(
+        &self,
+        subject: &str,
+        mut envelope: Envelope<T>,
+    ) -> Result<DurablePublishAck>
+    where
+        T: serde::Serialize,
+    {
+        // Check circuit breaker before making request
+        self.can_make_request().await?;
+
+        envelope.meta.tracing = trace_context::outgoing(envelope.meta.tracing.as_ref());
+        let mut headers = trace_context::nats_headers(&envelope.meta).unwrap_or_default();
+        if let Some(request_id) = envelope.meta.request_id {
+            headers.insert(async_nats::header::NATS_MESSAGE_ID, request_id.to_string());
+        }
+        let encoded_data = NatsEnvelopeCodec::encode(&envelope).map_err(|e| {
+            QollectiveError::nats_message(format!("Failed to encode envelope: {}", e))
+        })?;
+
+        // Both the publish and the stream's acknowledgement count towards the circuit breaker
+        let publish_result = match self
+            .jetstream
+            .publish_with_headers(subject.to_string(), headers, encoded_data.into())
+            .await
+        {
+            Ok(ack) => ack.await.map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        let mut state_guard = self.state.write().await;
+        match publish_result {
+            Ok(ack) => {
+                state_guard.circuit_breaker.record_success();
+                Ok(ack.into())
+            }
+            Err(e) => {
+                let new_state = state_guard.circuit_breaker.record_failure();
+                state_guard.set_connection_state(new_state);
+                Err(QollectiveError::nats_message(format!(
+                    "Durable publish to {} failed: {}",
+                    subject, e
+                )))
+            }
+        }
+    }
+
+    /// Create or update the JetStream streams defined in the configuration
+    pub async fn ensure_streams(&self) -> Result<()> {
+        nats_jetstream::ensure_streams(&self.jetstream, &self.config.jetstream).await
+    }
+
     /// Publish raw bytes to a NATS subject (fire-and-forget)
     pub async fn publish_raw(&self, subject: &str, payload: &[u8]) -> Result<()> {
         // Check circuit breaker before making request
@@ -520,7 +638,7 @@ impl InternalNatsClient {
                 {
                     let mut state_guard = self.state.write().await;
                     let new_state = state_guard.circuit_breaker.record_failure();
-                    state_guard.connection_state = new_state.clone();
+                    state_guard.set_connection_state(new_state.clone());
 
                     // Send circuit breaker event if state changed
                     if let Some(ref sender) = state_guard.event_sender {
@@ -577,7 +695,7 @@ impl InternalNatsClient {
                 {
                     let mut state_guard = self.state.write().await;
                     let new_state = state_guard.circuit_breaker.record_failure();
-                    state_guard.connection_state = new_state.clone();
+                    state_guard.set_connection_state(new_state.clone());
 
                     // Send circuit breaker event if state changed
                     if let Some(ref sender) = state_guard.event_sender {
@@ -656,6 +774,8 @@ pub struct NatsTransport {
     nats_client: InternalNatsClient,
     /// Default timeout for request/reply operations
     request_timeout: Duration,
+    /// Retry policy applied to every request
+    retry_policy: RetryPolicy,
 }
 
 #[cfg(any(feature = "nats-client", feature = "nats-server"))]
@@ -689,10 +809,7 @@ impl NatsTransport {
         // Create underlying internal NATS client
         let nats_client = InternalNatsClient::new(nats_config).await?;
 
-        Ok(Self {
-            nats_client,
-            request_timeout: Duration::from_secs(30), // Default 30 second timeout
-        })
+        Ok(Self::from_internal_nats_client(nats_client))
     }
 
     /// Create a new NATS transport with unified TLS configuration
@@ -734,10 +851,7 @@ impl NatsTransport {
         // Create underlying internal NATS client
         let nats_client = InternalNatsClient::new(nats_config).await?;
 
-        Ok(Self {
-            nats_client,
-            request_timeout: Duration::from_secs(30), // Default 30 second timeout
-        })
+        Ok(Self::from_internal_nats_client(nats_client))
     }
 
     /// Create a pure NATS transport from an existing InternalNatsClient.
@@ -753,9 +867,18 @@ impl NatsTransport {
     ///
     /// Returns a configured `NatsTransport` using the provided client.
     pub fn from_internal_nats_client(nats_client: InternalNatsClient) -> Self {
+        let behavior = &nats_client.config.client;
+        let retry_policy = RetryPolicy::exponential(
+            behavior.retry_attempts,
+            Duration::from_millis(behavior.retry_delay_ms),
+        )
+        .with_jitter(Jitter::Equal)
+        .with_budget(RetryBudget::with_defaults());
+
         Self {
             nats_client,
             request_timeout: Duration::from_secs(30),
+            retry_policy,
         }
     }
 
@@ -773,6 +896,12 @@ impl NatsTransport {
         self
     }
 
+    /// Replace the retry policy applied to every request.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Extract NATS subject from endpoint URL.
     ///
     /// Converts endpoint formats like:
@@ -865,8 +994,11 @@ where
         // Send raw payload to NATS subject and wait for response
         // Use the underlying NATS client's request/reply functionality
         let response_bytes = self
-            .nats_client
-            .request_raw(&subject, &payload_bytes, self.request_timeout)
+            .retry_policy
+            .retry(|_| {
+                self.nats_client
+                    .request_raw(&subject, &payload_bytes, self.request_timeout)
+            })
             .await?;
 
         // Deserialize response from JSON for NATS transport
@@ -908,6 +1040,10 @@ impl NatsTransport {
     pub fn with_timeout(self, _timeout: Duration) -> Self {
         self
     }
+
+    pub fn with_retry_policy(self, _retry_policy: crate::transport::retry::RetryPolicy) -> Self {
+        self
+    }
 }
 
 #[cfg(not(any(feature = "nats-client", feature = "nats-server")))]
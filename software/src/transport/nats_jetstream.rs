@@ -0,0 +1,355 @@
+// ABOUTME: JetStream helpers shared by NatsClient and NatsServer for durable envelope messaging
+// ABOUTME: Maps stream/consumer definitions onto async-nats and settles deliveries with ack, nak or dead-letter
+
+//! JetStream support for durable envelope messaging.
+//!
+//! Streams and durable consumers are declared in
+//! [`NatsJetStreamConfig`](crate::config::nats::NatsJetStreamConfig). This module
+//! translates those definitions into async-nats configurations, opens consumers
+//! as a single delivery stream regardless of pull or push mode, and decides how
+//! each delivery is settled once its handler finished:
+//!
+//! - success acknowledges the message,
+//! - failure naks it so the server redelivers it (optionally after a delay),
+//! - failure on the last allowed delivery publishes the message to the consumer's
+//!   dead-letter subject and terminates further redelivery.
+
+use crate::config::nats::{
+    JetStreamConsumerConfig, JetStreamDelivery, JetStreamRetention, JetStreamStorage,
+    JetStreamStreamConfig, NatsJetStreamConfig,
+};
+use crate::constants::metadata;
+use crate::error::{QollectiveError, Result};
+use async_nats::header::NATS_MESSAGE_ID;
+use async_nats::jetstream::{self, consumer, stream, AckKind};
+use async_nats::HeaderMap;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Stream acknowledgement for an envelope published with JetStream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurablePublishAck {
+    /// Stream that stored the envelope
+    pub stream: String,
+    /// Sequence number of the envelope within the stream
+    pub sequence: u64,
+    /// Whether the stream discarded the envelope as a duplicate request id
+    pub duplicate: bool,
+}
+
+impl From<jetstream::publish::PublishAck> for DurablePublishAck {
+    fn from(ack: jetstream::publish::PublishAck) -> Self {
+        Self {
+            stream: ack.stream,
+            sequence: ack.sequence,
+            duplicate: ack.duplicate,
+        }
+    }
+}
+
+/// Deliveries of a durable consumer, independent of pull or push mode
+pub type DeliveryStream = Pin<Box<dyn Stream<Item = Result<jetstream::Message>> + Send>>;
+
+/// How a delivery was settled with the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Settlement {
+    /// The handler succeeded and the message was acknowledged
+    Acked,
+    /// The handler failed and the message will be redelivered
+    Redelivered,
+    /// The handler failed on the last delivery and the message was dead-lettered
+    DeadLettered,
+    /// The handler failed on the last delivery and no dead-letter subject is configured
+    Discarded,
+}
+
+/// Converts a stream definition into an async-nats stream configuration
+pub fn stream_config(definition: &JetStreamStreamConfig) -> stream::Config {
+    stream::Config {
+        name: definition.name.clone(),
+        subjects: definition.subjects.clone(),
+        storage: match definition.storage {
+            JetStreamStorage::File => stream::StorageType::File,
+            JetStreamStorage::Memory => stream::StorageType::Memory,
+        },
+        retention: match definition.retention {
+            JetStreamRetention::Limits => stream::RetentionPolicy::Limits,
+            JetStreamRetention::Interest => stream::RetentionPolicy::Interest,
+            JetStreamRetention::WorkQueue => stream::RetentionPolicy::WorkQueue,
+        },
+        max_age: definition
+            .max_age_ms
+            .map(Duration::from_millis)
+            .unwrap_or_default(),
+        max_messages: definition.max_messages.unwrap_or(-1),
+        num_replicas: definition.replicas,
+        duplicate_window: definition
+            .duplicate_window_ms
+            .map(Duration::from_millis)
+            .unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+/// Creates every configured stream, updating streams that already exist
+pub async fn ensure_streams(
+    context: &jetstream::Context,
+    config: &NatsJetStreamConfig,
+) -> Result<()> {
+    for definition in &config.streams {
+        context
+            .create_or_update_stream(stream_config(definition))
+            .await
+            .map_err(|e| {
+                QollectiveError::nats_message(format!(
+                    "Failed to create JetStream stream {}: {}",
+                    definition.name, e
+                ))
+            })?;
+    }
+    Ok(())
+}
+
+/// Creates (or updates) a durable consumer and opens its delivery stream
+pub async fn open_consumer(
+    context: &jetstream::Context,
+    definition: &JetStreamConsumerConfig,
+) -> Result<DeliveryStream> {
+    let consumer_error = |e: &dyn std::fmt::Display| {
+        QollectiveError::nats_message(format!(
+            "Failed to open JetStream consumer {} on stream {}: {}",
+            definition.name, definition.stream, e
+        ))
+    };
+
+    let stream = context
+        .get_stream(&definition.stream)
+        .await
+        .map_err(|e| consumer_error(&e))?;
+    let durable_name = Some(definition.name.clone());
+    let filter_subject = definition.filter_subject.clone().unwrap_or_default();
+    let ack_wait = Duration::from_millis(definition.ack_wait_ms);
+    let max_ack_pending = definition.max_ack_pending.unwrap_or_default();
+
+    match &definition.delivery {
+        JetStreamDelivery::Pull => {
+            let consumer = stream
+                .create_consumer(consumer::pull::Config {
+                    durable_name,
+                    filter_subject,
+                    ack_policy: consumer::AckPolicy::Explicit,
+                    ack_wait,
+                    max_deliver: definition.max_deliver,
+                    max_ack_pending,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| consumer_error(&e))?;
+            let messages = consumer.messages().await.map_err(|e| consumer_error(&e))?;
+            Ok(Box::pin(messages.map(|delivery| {
+                delivery.map_err(|e| {
+                    QollectiveError::nats_message(format!("JetStream pull failed: {}", e))
+                })
+            })))
+        }
+        JetStreamDelivery::Push {
+            deliver_subject,
+            deliver_group,
+        } => {
+            let consumer = stream
+                .create_consumer(consumer::push::Config {
+                    deliver_subject: deliver_subject.clone(),
+                    deliver_group: deliver_group.clone(),
+                    durable_name,
+                    filter_subject,
+                    ack_policy: consumer::AckPolicy::Explicit,
+                    ack_wait,
+                    max_deliver: definition.max_deliver,
+                    max_ack_pending,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| consumer_error(&e))?;
+            let messages = consumer.messages().await.map_err(|e| consumer_error(&e))?;
+            Ok(Box::pin(messages.map(|delivery| {
+                delivery.map_err(|e| {
+                    QollectiveError::nats_message(format!("JetStream push delivery failed: {}", e))
+                })
+            })))
+        }
+    }
+}
+
+/// Settles a delivery with the server according to its handler outcome
+pub async fn settle(
+    client: &async_nats::Client,
+    definition: &JetStreamConsumerConfig,
+    message: &jetstream::Message,
+    outcome: &Result<()>,
+) -> Result<Settlement> {
+    let settle_error = |e: &dyn std::fmt::Display| {
+        QollectiveError::nats_message(format!(
+            "Failed to settle JetStream message for consumer {}: {}",
+            definition.name, e
+        ))
+    };
+
+    let error = match outcome {
+        Ok(()) => {
+            message.ack().await.map_err(|e| settle_error(&*e))?;
+            return Ok(Settlement::Acked);
+        }
+        Err(error) => error,
+    };
+
+    let info = message.info().map_err(|e| settle_error(&*e))?;
+    if info.delivered < definition.max_deliver {
+        let delay = definition.redelivery_delay_ms.map(Duration::from_millis);
+        message
+            .ack_with(AckKind::Nak(delay))
+            .await
+            .map_err(|e| settle_error(&*e))?;
+        return Ok(Settlement::Redelivered);
+    }
+
+    let Some(dead_letter_subject) = definition.dead_letter_subject.as_deref() else {
+        message
+            .ack_with(AckKind::Term)
+            .await
+            .map_err(|e| settle_error(&*e))?;
+        return Ok(Settlement::Discarded);
+    };
+    let headers = dead_letter_headers(
+        message.headers.as_ref(),
+        message.subject.as_str(),
+        info.stream,
+        info.stream_sequence,
+        info.delivered,
+        error,
+    );
+    client
+        .publish_with_headers(
+            dead_letter_subject.to_string(),
+            headers,
+            message.payload.clone(),
+        )
+        .await
+        .map_err(|e| settle_error(&e))?;
+    client.flush().await.map_err(|e| settle_error(&e))?;
+    message
+        .ack_with(AckKind::Term)
+        .await
+        .map_err(|e| settle_error(&*e))?;
+    Ok(Settlement::DeadLettered)
+}
+
+/// Builds the headers of a dead-lettered message from the original delivery
+///
+/// The original `Nats-Msg-Id` is dropped so a stream capturing the dead-letter
+/// subject does not discard the message as a duplicate.
+fn dead_letter_headers(
+    original: Option<&HeaderMap>,
+    subject: &str,
+    stream: &str,
+    sequence: u64,
+    delivered: i64,
+    error: &QollectiveError,
+) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(original) = original {
+        for (name, values) in original.iter() {
+            if name.as_ref() as &str == NATS_MESSAGE_ID.as_ref() as &str {
+                continue;
+            }
+            for value in values {
+                headers.append(name.clone(), value.clone());
+            }
+        }
+    }
+    headers.insert(metadata::JETSTREAM_DEAD_LETTER_SUBJECT_HEADER, subject);
+    headers.insert(metadata::JETSTREAM_DEAD_LETTER_STREAM_HEADER, stream);
+    headers.insert(
+        metadata::JETSTREAM_DEAD_LETTER_SEQUENCE_HEADER,
+        sequence.to_string().as_str(),
+    );
+    headers.insert(
+        metadata::JETSTREAM_DEAD_LETTER_DELIVERIES_HEADER,
+        delivered.to_string().as_str(),
+    );
+    // Header values cannot span lines
+    let reason = error.to_string().replace(['\r', '\n'], " ");
+    headers.insert(
+        metadata::JETSTREAM_DEAD_LETTER_ERROR_HEADER,
+        reason.as_str(),
+    );
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_config_maps_definition() {
+        let mut definition = JetStreamStreamConfig::new("ORDERS", vec!["orders.>".to_string()]);
+        definition.storage = JetStreamStorage::Memory;
+        definition.retention = JetStreamRetention::WorkQueue;
+        definition.max_age_ms = Some(60_000);
+        definition.duplicate_window_ms = Some(2_000);
+
+        let config = stream_config(&definition);
+        assert_eq!(config.name, "ORDERS");
+        assert_eq!(config.subjects, vec!["orders.>".to_string()]);
+        assert_eq!(config.storage, stream::StorageType::Memory);
+        assert_eq!(config.retention, stream::RetentionPolicy::WorkQueue);
+        assert_eq!(config.max_age, Duration::from_secs(60));
+        assert_eq!(config.max_messages, -1);
+        assert_eq!(config.duplicate_window, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_dead_letter_headers_keep_trace_and_drop_message_id() {
+        let mut original = HeaderMap::new();
+        original.insert("traceparent", "00-abc-def-01");
+        original.insert(NATS_MESSAGE_ID, "request-1");
+
+        let headers = dead_letter_headers(
+            Some(&original),
+            "orders.new",
+            "ORDERS",
+            42,
+            3,
+            &QollectiveError::nats_message("warp core\nbreach"),
+        );
+
+        assert_eq!(
+            headers.get("traceparent").map(|v| v.as_str()),
+            Some("00-abc-def-01")
+        );
+        assert!(headers.get(NATS_MESSAGE_ID).is_none());
+        assert_eq!(
+            headers
+                .get(metadata::JETSTREAM_DEAD_LETTER_SUBJECT_HEADER)
+                .map(|v| v.as_str()),
+            Some("orders.new")
+        );
+        assert_eq!(
+            headers
+                .get(metadata::JETSTREAM_DEAD_LETTER_SEQUENCE_HEADER)
+                .map(|v| v.as_str()),
+            Some("42")
+        );
+        assert_eq!(
+            headers
+                .get(metadata::JETSTREAM_DEAD_LETTER_DELIVERIES_HEADER)
+                .map(|v| v.as_str()),
+            Some("3")
+        );
+        let reason = headers
+            .get(metadata::JETSTREAM_DEAD_LETTER_ERROR_HEADER)
+            .unwrap()
+            .as_str();
+        assert!(reason.contains("warp core breach"));
+    }
+}
@@ -17,6 +17,7 @@
 use crate::envelope::Envelope;
 use crate::error::{QollectiveError, Result};
 use crate::traits::senders::UnifiedEnvelopeSender;
+use crate::transport::retry::RetryPolicy;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -26,8 +27,13 @@ use {
     crate::constants::{
         http::{CONTENT_TYPE_JSON, DEFAULT_USER_AGENT},
         limits::DEFAULT_RETRY_ATTEMPTS,
-        timeouts::DEFAULT_REST_REQUEST_TIMEOUT_MS,
+        timeouts::{
+            DEFAULT_REST_MAX_RETRY_DELAY_MS, DEFAULT_REST_REQUEST_TIMEOUT_MS,
+            DEFAULT_REST_RETRY_DELAY_MS,
+        },
     },
+    crate::envelope::deadline,
+    crate::transport::retry::{Jitter, RetryBudget},
     base64::prelude::*,
     reqwest::{Client, ClientBuilder},
     std::sync::Arc,
@@ -44,7 +50,7 @@ pub struct InternalHttpTransport {
     #[cfg(feature = "rest-client")]
     timeout: Duration,
     #[cfg(feature = "rest-client")]
-    retry_attempts: u32,
+    retry_policy: RetryPolicy,
 }
 
 /// Internal REST client implementation for transport layer (Step 15 - dependency injection pattern)
@@ -56,6 +62,7 @@ pub struct InternalHttpTransport {
 pub struct InternalRestClient {
     client: reqwest::Client,
     config: crate::client::rest::RestClientConfig,
+    retry_policy: RetryPolicy,
 }
 
 impl InternalHttpTransport {
@@ -89,7 +96,7 @@ impl InternalHttpTransport {
         Ok(Self {
             client: Arc::new(client),
             timeout,
-            retry_attempts,
+            retry_policy: Self::retry_policy_for(retry_attempts),
         })
     }
 
@@ -130,7 +137,7 @@ impl InternalHttpTransport {
         Ok(Self {
             client: Arc::new(client),
             timeout,
-            retry_attempts,
+            retry_policy: Self::retry_policy_for(retry_attempts),
         })
     }
 
@@ -179,7 +186,7 @@ impl InternalHttpTransport {
         Ok(Self {
             client: Arc::new(client),
             timeout,
-            retry_attempts,
+            retry_policy: Self::retry_policy_for(retry_attempts),
         })
     }
 
@@ -195,6 +202,19 @@ impl InternalHttpTransport {
         ))
     }
 
+    /// Replace the retry policy applied to every request.
+    #[cfg(feature = "rest-client")]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Retry policy for a retry count, backing off from 100ms with a per-client budget.
+    #[cfg(feature = "rest-client")]
+    fn retry_policy_for(retry_attempts: u32) -> RetryPolicy {
+        RetryPolicy::for_transport(retry_attempts)
+    }
+
     /// Send HTTP request with retry logic.
     #[cfg(feature = "rest-client")]
     async fn send_with_retry<T>(
@@ -205,52 +225,69 @@ impl InternalHttpTransport {
     where
         T: Serialize + Send + Sync + 'static,
     {
-        let mut last_error = None;
+        // Fix the deadline once so all attempts share the caller's time budget
+        let deadline = deadline::outgoing(envelope.meta.deadline, Some(self.timeout));
+
+        self.retry_policy
+            .retry(|attempt| async move {
+                deadline::check(deadline)?;
+                let mut meta = envelope.meta.clone();
+                meta.deadline = deadline;
+                if attempt > 1 {
+                    meta.set_retry_attempt(attempt);
+                }
+                let attempt_envelope = Envelope {
+                    meta,
+                    payload: &envelope.payload,
+                    error: envelope.error.clone(),
+                };
+
+                // Serialize envelope to JSON (envelope first!)
+                let json_payload = serde_json::to_vec(&attempt_envelope).map_err(|e| {
+                    QollectiveError::serialization(format!("Failed to serialize envelope: {}", e))
+                })?;
 
-        for attempt in 0..=self.retry_attempts {
-            // Serialize envelope to JSON (envelope first!)
-            let json_payload = serde_json::to_vec(envelope).map_err(|e| {
-                QollectiveError::serialization(format!("Failed to serialize envelope: {}", e))
-            })?;
+                // Build HTTP request with standard headers
+                let mut request = self
+                    .client
+                    .post(endpoint)
+                    .header("Content-Type", CONTENT_TYPE_JSON)
+                    .body(json_payload);
+                if let Some(deadline) = deadline {
+                    for (name, value) in deadline::header_pairs(deadline) {
+                        request = request.header(name, value);
+                    }
+                    request = request.timeout(deadline::remaining(deadline));
+                }
 
-            // Build HTTP request with standard headers
-            let request = self
-                .client
-                .post(endpoint)
-                .header("Content-Type", CONTENT_TYPE_JSON)
-                .body(json_payload);
-
-            match request.send().await {
-                Ok(response) => {
-                    if response.status().is_success() {
-                        return Ok(response);
-                    } else {
+                match request.send().await {
+                    Ok(response) if response.status().is_success() => Ok(response),
+                    Ok(response) => {
                         let status = response.status();
                         let error_text = response.text().await.unwrap_or_default();
-                        last_error = Some(QollectiveError::transport(format!(
+                        Err(QollectiveError::transport(format!(
                             "HTTP request failed with status {}: {}",
                             status, error_text
-                        )));
+                        )))
                     }
+                    Err(e) => Err(request_error(
+                        format!("HTTP transport request attempt {} failed", attempt),
+                        e,
+                    )),
                 }
-                Err(e) => {
-                    last_error = Some(QollectiveError::transport(format!(
-                        "HTTP request attempt {} failed: {}",
-                        attempt + 1,
-                        e
-                    )));
-                }
-            }
-
-            // Wait before retry (except on last attempt)
-            if attempt < self.retry_attempts {
-                tokio::time::sleep(Duration::from_millis(100 * (attempt + 1) as u64)).await;
-            }
-        }
+            })
+            .await
+    }
+}
 
-        Err(last_error.unwrap_or_else(|| {
-            QollectiveError::transport("All HTTP retry attempts failed".to_string())
-        }))
+/// Map a failed HTTP request to a connection error when it never reached the
+/// server or timed out, so retry policies treat it as transient.
+#[cfg(feature = "rest-client")]
+fn request_error(context: String, error: reqwest::Error) -> QollectiveError {
+    if error.is_connect() || error.is_timeout() {
+        QollectiveError::connection(format!("{}: {}", context, error))
+    } else {
+        QollectiveError::transport(format!("{}: {}", context, error))
     }
 }
 
@@ -390,7 +427,28 @@ impl InternalRestClient {
             QollectiveError::transport(format!("Failed to build HTTP client: {}", e))
         })?;
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            retry_policy: Self::retry_policy_for(&config),
+            config,
+        })
+    }
+
+    /// Retry policy for the configured attempts, which include the first request
+    fn retry_policy_for(config: &crate::client::rest::RestClientConfig) -> RetryPolicy {
+        RetryPolicy::exponential(
+            config.base.retry_attempts.saturating_sub(1),
+            Duration::from_millis(DEFAULT_REST_RETRY_DELAY_MS),
+        )
+        .with_max_delay(Duration::from_millis(DEFAULT_REST_MAX_RETRY_DELAY_MS))
+        .with_jitter(Jitter::Full)
+        .with_budget(RetryBudget::with_defaults())
+    }
+
+    /// Replace the retry policy applied to every request
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     /// Build headers from envelope metadata using centralized constants
@@ -461,6 +519,17 @@ impl InternalRestClient {
             );
         }
 
+        // Retry attempt - only present on retried requests
+        if let Some(attempt) = meta.retry_attempt() {
+            headers.insert(
+                HeaderName::from_bytes(envelope_headers::QOLLECTIVE_RETRY_ATTEMPT.as_bytes())
+                    .map_err(|e| {
+                        QollectiveError::transport(format!("Invalid header name: {}", e))
+                    })?,
+                HeaderValue::from(attempt),
+            );
+        }
+
         // Security metadata - use centralized constants
         if let Some(ref security) = meta.security {
             if let Some(ref user_id) = security.user_id {
@@ -518,6 +587,30 @@ impl InternalRestClient {
             // correlation ID would be handled separately if available in meta
         }
 
+        // W3C trace context, parented to the current span when one is active
+        if let Some(tracing) = crate::envelope::trace_context::outgoing(meta.tracing.as_ref()) {
+            for (name, value) in crate::envelope::trace_context::header_pairs(&tracing) {
+                headers.insert(
+                    HeaderName::from_static(name),
+                    HeaderValue::from_str(&value).map_err(|e| {
+                        QollectiveError::transport(format!("Invalid {} header: {}", name, e))
+                    })?,
+                );
+            }
+        }
+
+        // Time the server has left to respond
+        if let Some(deadline) = meta.deadline {
+            for (name, value) in crate::envelope::deadline::header_pairs(deadline) {
+                headers.insert(
+                    HeaderName::from_static(name),
+                    HeaderValue::from_str(&value).map_err(|e| {
+                        QollectiveError::transport(format!("Invalid {} header: {}", name, e))
+                    })?,
+                );
+            }
+        }
+
         // Tenant context forwarding
         self.add_tenant_context_headers(&mut headers, meta)?;
 
@@ -631,6 +724,68 @@ impl InternalRestClient {
         Ok(())
     }
 
+    /// Set the JWT header from the configured credential provider.
+    ///
+    /// Returns whether a provider is configured, i.e. whether a 401 response
+    /// can be retried with a refreshed token.
+    #[cfg(feature = "security")]
+    async fn apply_credentials(
+        &self,
+        headers: &mut reqwest::header::HeaderMap,
+    ) -> crate::error::Result<bool> {
+        use crate::error::QollectiveError;
+        use reqwest::header::{HeaderName, HeaderValue};
+
+        let jwt_config = &self.config.jwt_config;
+        let Some(provider) = &jwt_config.credential_provider else {
+            return Ok(false);
+        };
+
+        let token = provider.access_token().await.map_err(|e| {
+            QollectiveError::security(format!("Failed to obtain access token: {}", e))
+        })?;
+        let header_name =
+            HeaderName::from_bytes(jwt_config.header_name.as_bytes()).map_err(|e| {
+                QollectiveError::transport(format!(
+                    "Invalid JWT header name '{}': {}",
+                    jwt_config.header_name, e
+                ))
+            })?;
+        let header_value = HeaderValue::from_str(&format!("{}{}", jwt_config.header_prefix, token))
+            .map_err(|e| QollectiveError::transport(format!("Invalid JWT header value: {}", e)))?;
+        headers.insert(header_name, header_value);
+
+        Ok(true)
+    }
+
+    #[cfg(not(feature = "security"))]
+    async fn apply_credentials(
+        &self,
+        _headers: &mut reqwest::header::HeaderMap,
+    ) -> crate::error::Result<bool> {
+        Ok(false)
+    }
+
+    /// Drop the rejected access token and set a freshly obtained one
+    #[cfg(feature = "security")]
+    async fn refresh_credentials(
+        &self,
+        headers: &mut reqwest::header::HeaderMap,
+    ) -> crate::error::Result<bool> {
+        if let Some(provider) = &self.config.jwt_config.credential_provider {
+            provider.invalidate().await;
+        }
+        self.apply_credentials(headers).await
+    }
+
+    #[cfg(not(feature = "security"))]
+    async fn refresh_credentials(
+        &self,
+        _headers: &mut reqwest::header::HeaderMap,
+    ) -> crate::error::Result<bool> {
+        Ok(false)
+    }
+
     /// Send HTTP request with envelope in body and retry logic (shared by POST/PUT/PATCH)
     async fn send_envelope_request<Req, Res>(
         &self,
@@ -644,41 +799,59 @@ impl InternalRestClient {
     {
         use crate::error::QollectiveError;
 
-        let headers = self.build_headers_from_envelope(envelope)?;
-        let request_body = serde_json::to_string(envelope).map_err(|e| {
-            QollectiveError::serialization(format!("Failed to serialize request envelope: {}", e))
-        })?;
-
-        let mut attempt = 0;
-        let max_attempts = self.config.base.retry_attempts;
-
-        loop {
-            attempt += 1;
-
-            let response = self
-                .client
-                .request(method.clone(), url)
-                .headers(headers.clone())
-                .body(request_body.clone())
-                .send()
-                .await;
+        let can_reauthenticate = std::sync::atomic::AtomicBool::new(true);
+        let deadline = self.outgoing_deadline(envelope);
+
+        self.retry_policy
+            .retry(|attempt| {
+                let method = method.clone();
+                let can_reauthenticate = &can_reauthenticate;
+                async move {
+                    crate::envelope::deadline::check(deadline)?;
+                    let envelope = Self::envelope_for_attempt(envelope, attempt, deadline);
+                    let mut headers = self.build_headers_from_envelope(&envelope)?;
+                    let request_body = serde_json::to_string(&envelope).map_err(|e| {
+                        QollectiveError::serialization(format!(
+                            "Failed to serialize request envelope: {}",
+                            e
+                        ))
+                    })?;
+                    let reauthenticate = self.apply_credentials(&mut headers).await?;
+
+                    let send = |headers: reqwest::header::HeaderMap| {
+                        self.client
+                            .request(method.clone(), url)
+                            .headers(headers)
+                            .timeout(self.attempt_timeout(deadline))
+                            .body(request_body.clone())
+                            .send()
+                    };
+                    let mut response = send(headers.clone()).await;
+
+                    if let Ok(resp) = &response {
+                        if reauthenticate
+                            && resp.status() == reqwest::StatusCode::UNAUTHORIZED
+                            && can_reauthenticate.swap(false, std::sync::atomic::Ordering::SeqCst)
+                        {
+                            // Token was rejected - retry once with a freshly obtained one
+                            self.refresh_credentials(&mut headers).await?;
+                            response = send(headers).await;
+                        }
+                    }
 
-            match response {
-                Ok(resp) => {
-                    return self.extract_envelope_from_response(resp).await;
-                }
-                Err(e) if attempt < max_attempts && e.is_timeout() => {
-                    // Retry on timeout
-                    continue;
-                }
-                Err(e) => {
-                    return Err(QollectiveError::transport(format!(
-                        "{} request failed after {} attempts: {}",
-                        method, attempt, e
-                    )));
+                    match response {
+                        Ok(resp) => self.extract_envelope_from_response(resp).await,
+                        Err(e) => Err(request_error(
+                            format!(
+                                "REST transport {} request attempt {} failed",
+                                method, attempt
+                            ),
+                            e,
+                        )),
+                    }
                 }
-            }
-        }
+            })
+            .await
     }
 
     /// Send HTTP request with envelope data as query parameters and retry logic (shared by GET/DELETE/OPTIONS)
@@ -694,8 +867,6 @@ impl InternalRestClient {
     {
         use crate::error::QollectiveError;
 
-        let headers = self.build_headers_from_envelope(envelope)?;
-
         // For GET/DELETE/OPTIONS requests, serialize envelope data as a query parameter
         // This preserves the envelope-first principle while being HTTP-compliant
         let data_json = serde_json::to_string(&envelope.payload).map_err(|e| {
@@ -704,36 +875,90 @@ impl InternalRestClient {
                 method, e
             ))
         })?;
+        let can_reauthenticate = std::sync::atomic::AtomicBool::new(true);
+        let deadline = self.outgoing_deadline(envelope);
+
+        self.retry_policy
+            .retry(|attempt| {
+                let method = method.clone();
+                let data_json = &data_json;
+                let can_reauthenticate = &can_reauthenticate;
+                async move {
+                    crate::envelope::deadline::check(deadline)?;
+                    let envelope = Self::envelope_for_attempt(envelope, attempt, deadline);
+                    let mut headers = self.build_headers_from_envelope(&envelope)?;
+                    let reauthenticate = self.apply_credentials(&mut headers).await?;
+
+                    let send = |headers: reqwest::header::HeaderMap| {
+                        self.client
+                            .request(method.clone(), url)
+                            .headers(headers)
+                            .timeout(self.attempt_timeout(deadline))
+                            .query(&[("envelope_data", data_json)])
+                            .send()
+                    };
+                    let mut response = send(headers.clone()).await;
+
+                    if let Ok(resp) = &response {
+                        if reauthenticate
+                            && resp.status() == reqwest::StatusCode::UNAUTHORIZED
+                            && can_reauthenticate.swap(false, std::sync::atomic::Ordering::SeqCst)
+                        {
+                            // Token was rejected - retry once with a freshly obtained one
+                            self.refresh_credentials(&mut headers).await?;
+                            response = send(headers).await;
+                        }
+                    }
 
-        let mut attempt = 0;
-        let max_attempts = self.config.base.retry_attempts;
+                    match response {
+                        Ok(resp) => self.extract_envelope_from_response(resp).await,
+                        Err(e) => Err(request_error(
+                            format!(
+                                "REST transport {} request attempt {} failed",
+                                method, attempt
+                            ),
+                            e,
+                        )),
+                    }
+                }
+            })
+            .await
+    }
 
-        loop {
-            attempt += 1;
+    /// Deadline for a request, fixed once so all attempts share the caller's time budget
+    fn outgoing_deadline<Req>(
+        &self,
+        envelope: &crate::envelope::Envelope<Req>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::envelope::deadline::outgoing(
+            envelope.meta.deadline,
+            Some(Duration::from_secs(self.config.base.timeout_seconds)),
+        )
+    }
 
-            let response = self
-                .client
-                .request(method.clone(), url)
-                .headers(headers.clone())
-                .query(&[("envelope_data", data_json.clone())])
-                .send()
-                .await;
+    /// Time a single attempt may take: what is left until the deadline
+    fn attempt_timeout(&self, deadline: Option<chrono::DateTime<chrono::Utc>>) -> Duration {
+        deadline
+            .map(crate::envelope::deadline::remaining)
+            .unwrap_or_else(|| Duration::from_secs(self.config.base.timeout_seconds))
+    }
 
-            match response {
-                Ok(resp) => {
-                    return self.extract_envelope_from_response(resp).await;
-                }
-                Err(e) if attempt < max_attempts && e.is_timeout() => {
-                    // Retry on timeout
-                    continue;
-                }
-                Err(e) => {
-                    return Err(QollectiveError::transport(format!(
-                        "{} request failed after {} attempts: {}",
-                        method, attempt, e
-                    )));
-                }
-            }
+    /// Borrow the request envelope for an attempt, recording the deadline and the
+    /// attempt number on retries
+    fn envelope_for_attempt<Req>(
+        envelope: &crate::envelope::Envelope<Req>,
+        attempt: u32,
+        deadline: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> crate::envelope::Envelope<&Req> {
+        let mut meta = envelope.meta.clone();
+        meta.deadline = deadline;
+        if attempt > 1 {
+            meta.set_retry_attempt(attempt);
+        }
+        crate::envelope::Envelope {
+            meta,
+            payload: &envelope.payload,
+            error: envelope.error.clone(),
         }
     }
 
@@ -900,7 +1125,11 @@ impl InternalRestClient {
             QollectiveError::transport(format!("Failed to build HTTP client: {}", e))
         })?;
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            retry_policy: Self::retry_policy_for(&config),
+            config,
+        })
     }
 
     /// Configure reqwest client builder with unified TLS configuration
@@ -1064,7 +1293,7 @@ mod tests {
             );
             let transport = result.unwrap();
             assert_eq!(transport.timeout, timeout);
-            assert_eq!(transport.retry_attempts, retry_attempts);
+            assert_eq!(transport.retry_policy.max_retries, retry_attempts);
         }
 
         #[cfg(not(feature = "rest-client"))]
@@ -1090,7 +1319,7 @@ mod tests {
             );
             let transport = result.unwrap();
             assert_eq!(transport.timeout, timeout);
-            assert_eq!(transport.retry_attempts, retry_attempts);
+            assert_eq!(transport.retry_policy.max_retries, retry_attempts);
         }
 
         #[cfg(not(feature = "rest-client"))]
@@ -0,0 +1,581 @@
+// ABOUTME: Shared retry policy with exponential backoff, jitter and per-client retry budgets
+// ABOUTME: Classifies QollectiveError, HTTP and gRPC failures as retryable and wraps envelope senders
+
+//! Retry policies for envelope transports.
+//!
+//! A [`RetryPolicy`] describes how a client retries failed requests:
+//!
+//! - the number of retries and the exponential backoff curve between them,
+//! - the [`Jitter`] applied to each delay so clients do not retry in lockstep,
+//! - which failures are worth retrying (see [`is_retryable`]),
+//! - an optional [`RetryBudget`] shared by all requests of a client that caps
+//!   retries to a fraction of the request volume, so retries cannot amplify an
+//!   outage.
+//!
+//! Retried requests carry their attempt number in their metadata (see
+//! [`Meta::retry_attempt`](crate::envelope::Meta::retry_attempt)); first attempts
+//! leave the metadata untouched. [`RetryingSender`] applies a policy to any
+//! [`UnifiedEnvelopeSender`].
+
+use crate::config::transport::RetryConfig;
+use crate::constants::limits::{DEFAULT_MIN_RETRIES_PER_SECOND, DEFAULT_RETRY_BUDGET_RATIO};
+use crate::envelope::Envelope;
+use crate::error::{QollectiveError, Result};
+use crate::traits::senders::UnifiedEnvelopeSender;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Upper bound of the budget balance, in retries
+const MAX_BUDGET_BALANCE: f64 = 1000.0;
+
+/// Randomization applied to backoff delays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// Use the backoff delay as is
+    None,
+    /// Pick a delay uniformly between zero and the backoff delay
+    Full,
+    /// Keep half of the backoff delay and randomize the other half
+    Equal,
+}
+
+/// Retry policy shared by the envelope transports
+///
+/// Cloning a policy shares its retry budget, so clones used by the same client
+/// draw from one budget.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Upper bound of any delay
+    pub max_delay: Duration,
+    /// Factor applied to the delay after every retry
+    pub multiplier: f64,
+    /// Randomization of each delay
+    pub jitter: Jitter,
+    budget: Option<RetryBudget>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from(&RetryConfig::default())
+    }
+}
+
+impl From<&RetryConfig> for RetryPolicy {
+    fn from(config: &RetryConfig) -> Self {
+        Self {
+            // The first attempt is not a retry
+            max_retries: config.max_attempts.saturating_sub(1),
+            initial_delay: Duration::from_millis(config.initial_delay_ms),
+            max_delay: Duration::from_millis(config.max_delay_ms),
+            multiplier: config.backoff_multiplier,
+            jitter: if config.use_jitter {
+                Jitter::Full
+            } else {
+                Jitter::None
+            },
+            budget: Some(RetryBudget::new(
+                config.retry_budget_ratio,
+                config.min_retries_per_second,
+            )),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self::exponential(0, Duration::ZERO)
+    }
+
+    /// Exponential backoff doubling from `initial_delay`, without jitter or budget
+    pub fn exponential(max_retries: u32, initial_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_delay,
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: Jitter::None,
+            budget: None,
+        }
+    }
+
+    /// Policy of the envelope transports: backoff from 100ms with equal jitter and a per-client budget
+    pub fn for_transport(max_retries: u32) -> Self {
+        Self::exponential(max_retries, Duration::from_millis(100))
+            .with_jitter(Jitter::Equal)
+            .with_budget(RetryBudget::with_defaults())
+    }
+
+    /// Set the upper bound of any delay
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the randomization of each delay
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Limit retries with a budget shared by all clones of this policy
+    pub fn with_budget(mut self, budget: RetryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Retry budget of this policy, if any
+    pub fn budget(&self) -> Option<&RetryBudget> {
+        self.budget.as_ref()
+    }
+
+    /// Backoff delay before the given retry (1 for the first retry), without jitter
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay_ms = self.initial_delay.as_millis() as f64 * self.multiplier.powi(exponent);
+        let max_ms = self.max_delay.as_millis() as f64;
+        Duration::from_millis(delay_ms.min(max_ms).max(0.0) as u64)
+    }
+
+    /// Delay before the given retry (1 for the first retry), including jitter
+    pub fn delay(&self, retry: u32) -> Duration {
+        use rand::Rng;
+
+        let backoff = self.backoff(retry);
+        match self.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => backoff.mul_f64(rand::rng().random::<f64>()),
+            Jitter::Equal => {
+                let half = backoff / 2;
+                half + half.mul_f64(rand::rng().random::<f64>())
+            }
+        }
+    }
+
+    /// Run `operation` until it succeeds, fails permanently or retries run out
+    ///
+    /// The operation receives the attempt number, starting at 1. Failures are
+    /// retried when [`is_retryable`] classifies them as transient and the
+    /// budget, if any, allows another retry.
+    pub async fn retry<T, F, Fut>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if let Some(budget) = &self.budget {
+            budget.deposit();
+        }
+
+        let mut attempt = 1;
+        loop {
+            let error = match operation(attempt).await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            let retry = attempt;
+            if retry > self.max_retries || !is_retryable(&error) {
+                return Err(error);
+            }
+            if let Some(budget) = &self.budget {
+                if !budget.try_withdraw() {
+                    tracing::debug!("Retry budget exhausted, not retrying: {}", error);
+                    return Err(error);
+                }
+            }
+
+            let delay = self.delay(retry);
+            tracing::debug!(
+                "Attempt {} failed, retrying in {:?}: {}",
+                attempt,
+                delay,
+                error
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send an envelope through `sender`, retrying according to this policy
+    pub async fn send_envelope<S, T, R>(
+        &self,
+        sender: &S,
+        endpoint: &str,
+        envelope: Envelope<T>,
+    ) -> Result<Envelope<R>>
+    where
+        S: UnifiedEnvelopeSender<serde_json::Value, R> + ?Sized,
+        T: Serialize,
+        R: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        self.retry_envelope(envelope, |envelope| {
+            sender.send_envelope(endpoint, envelope)
+        })
+        .await
+    }
+
+    /// Run `send` with the envelope until it succeeds, fails permanently or retries run out
+    ///
+    /// The payload is serialized once so the envelope can be resent; retries
+    /// carry their attempt number in the envelope metadata.
+    pub async fn retry_envelope<T, R, F, Fut>(
+        &self,
+        envelope: Envelope<T>,
+        mut send: F,
+    ) -> Result<Envelope<R>>
+    where
+        T: Serialize,
+        F: FnMut(Envelope<serde_json::Value>) -> Fut,
+        Fut: Future<Output = Result<Envelope<R>>>,
+    {
+        let (meta, payload, error) = envelope.extract_all();
+        let payload = serde_json::to_value(payload).map_err(|e| {
+            QollectiveError::serialization(format!("Failed to serialize envelope payload: {}", e))
+        })?;
+
+        self.retry(|attempt| {
+            let mut meta = meta.clone();
+            if attempt > 1 {
+                meta.set_retry_attempt(attempt);
+            }
+            send(Envelope {
+                meta,
+                payload: payload.clone(),
+                error: error.clone(),
+            })
+        })
+        .await
+    }
+}
+
+/// Retry budget shared by all requests of a client
+///
+/// Every request deposits `ratio` retries into the budget and every retry
+/// withdraws one, so retries stay below `ratio` of the request volume. A reserve
+/// of `min_retries_per_second` retries keeps low-traffic clients able to retry.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    ratio: f64,
+    min_retries_per_second: u32,
+    state: Arc<Mutex<BudgetState>>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    balance: f64,
+    reserve_window: Instant,
+    reserve_used: u32,
+}
+
+impl RetryBudget {
+    pub fn new(ratio: f64, min_retries_per_second: u32) -> Self {
+        Self {
+            ratio: ratio.max(0.0),
+            min_retries_per_second,
+            state: Arc::new(Mutex::new(BudgetState {
+                balance: 0.0,
+                reserve_window: Instant::now(),
+                reserve_used: 0,
+            })),
+        }
+    }
+
+    /// Budget with the default ratio and reserve from the constants
+    pub fn with_defaults() -> Self {
+        Self::new(DEFAULT_RETRY_BUDGET_RATIO, DEFAULT_MIN_RETRIES_PER_SECOND)
+    }
+
+    /// Record a request, earning `ratio` retries
+    pub fn deposit(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.balance = (state.balance + self.ratio).min(MAX_BUDGET_BALANCE);
+    }
+
+    /// Take one retry from the budget, returning whether it was available
+    pub fn try_withdraw(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.balance >= 1.0 {
+            state.balance -= 1.0;
+            return true;
+        }
+
+        if state.reserve_window.elapsed() >= Duration::from_secs(1) {
+            state.reserve_window = Instant::now();
+            state.reserve_used = 0;
+        }
+        if state.reserve_used < self.min_retries_per_second {
+            state.reserve_used += 1;
+            return true;
+        }
+        false
+    }
+}
+
+/// Whether a failed request may succeed when retried
+///
+/// Connection failures and timeouts are retryable. Transport and gRPC errors
+/// carrying an HTTP status are classified by [`is_retryable_http_status`];
+/// gRPC calls failing with a [retryable code](is_retryable_grpc_code) surface
+/// as connection errors. Other transport errors describe a misconfigured or
+/// invalid request and are not retried, like validation, serialization or
+/// security failures.
+pub fn is_retryable(error: &QollectiveError) -> bool {
+    match error {
+        QollectiveError::Transport(message) | QollectiveError::Grpc(message) => {
+            http_status_in(message).is_some_and(is_retryable_http_status)
+        }
+        QollectiveError::Connection(_) => true,
+        #[cfg(any(feature = "nats-client", feature = "nats-server"))]
+        QollectiveError::NatsConnection(_)
+        | QollectiveError::NatsTimeout(_)
+        | QollectiveError::NatsDiscovery(_) => true,
+        #[cfg(any(feature = "mcp-client", feature = "mcp-server"))]
+        QollectiveError::McpClientConnection(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether an HTTP response status signals a transient failure
+///
+/// Request timeouts, rate limiting and server unavailability are retryable;
+/// other client and server errors are not.
+pub fn is_retryable_http_status(status: u16) -> bool {
+    matches!(status, 408 | 425 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// Whether a gRPC status code signals a transient failure
+///
+/// `DeadlineExceeded` is not among them: the caller's deadline has passed and
+/// a retry cannot finish in time.
+#[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+pub fn is_retryable_grpc_code(code: tonic::Code) -> bool {
+    matches!(
+        code,
+        tonic::Code::Unavailable | tonic::Code::ResourceExhausted | tonic::Code::Aborted
+    )
+}
+
+/// HTTP status in a transport error message such as "... with status 503 ..."
+fn http_status_in(message: &str) -> Option<u16> {
+    let (_, rest) = message.split_once("status ")?;
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    if digits.len() != 3 {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Envelope sender applying a [`RetryPolicy`] to another sender
+#[derive(Debug, Clone)]
+pub struct RetryingSender<S> {
+    inner: S,
+    policy: RetryPolicy,
+}
+
+impl<S> RetryingSender<S> {
+    pub fn new(inner: S, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// Retry policy applied to every envelope
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Sender the envelopes are delegated to
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<S, T, R> UnifiedEnvelopeSender<T, R> for RetryingSender<S>
+where
+    S: UnifiedEnvelopeSender<serde_json::Value, R> + Send + Sync,
+    T: Serialize + Send + 'static,
+    R: for<'de> Deserialize<'de> + Send + 'static,
+{
+    async fn send_envelope(&self, endpoint: &str, envelope: Envelope<T>) -> Result<Envelope<R>> {
+        self.policy
+            .send_envelope(&self.inner, endpoint, envelope)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envelope::Meta;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_backoff_grows_exponentially_up_to_max_delay() {
+        let policy = RetryPolicy::exponential(5, Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+
+        let jittered = policy.clone().with_jitter(Jitter::Equal);
+        for _ in 0..20 {
+            let delay = jittered.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_config_attempts_include_the_first_attempt() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            ..RetryConfig::default()
+        };
+        assert_eq!(RetryPolicy::from(&config).max_retries, 4);
+
+        let config = RetryConfig {
+            max_attempts: 1,
+            ..RetryConfig::default()
+        };
+        assert_eq!(RetryPolicy::from(&config).max_retries, 0);
+
+        assert_eq!(
+            RetryPolicy::default().max_retries,
+            crate::constants::limits::DEFAULT_MAX_RETRIES
+        );
+    }
+
+    #[test]
+    fn test_error_classification() {
+        assert!(is_retryable(&QollectiveError::transport(
+            "HTTP request failed with status 503 Service Unavailable: down"
+        )));
+        assert!(!is_retryable(&QollectiveError::transport(
+            "HTTP request failed with status 404 Not Found: missing"
+        )));
+        assert!(!is_retryable(&QollectiveError::transport(
+            "REST client not available"
+        )));
+        assert!(is_retryable(&QollectiveError::connection("reset")));
+        assert!(!is_retryable(&QollectiveError::validation("bad input")));
+        assert!(!is_retryable(&QollectiveError::security("forbidden")));
+        assert!(!is_retryable(&QollectiveError::deadline_exceeded(
+            "expired"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_on_success_and_permanent_errors() {
+        let policy = RetryPolicy::exponential(3, Duration::ZERO);
+
+        let calls = AtomicU32::new(0);
+        let result = policy
+            .retry(|attempt| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 3 {
+                        Err(QollectiveError::connection("connection reset"))
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = policy
+            .retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(QollectiveError::validation("bad input")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = policy
+            .retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(QollectiveError::connection("connection reset")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_budget_limits_retries_to_request_ratio() {
+        let budget = RetryBudget::new(0.5, 0);
+        let policy = RetryPolicy::exponential(5, Duration::ZERO).with_budget(budget.clone());
+
+        // Two requests earn one retry
+        budget.deposit();
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = policy
+            .retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(QollectiveError::connection("connection reset")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(!budget.try_withdraw());
+
+        let reserve = RetryBudget::new(0.0, 2);
+        assert!(reserve.try_withdraw());
+        assert!(reserve.try_withdraw());
+        assert!(!reserve.try_withdraw());
+    }
+
+    struct FlakySender {
+        failures: u32,
+        attempts: Mutex<Vec<Option<u32>>>,
+    }
+
+    #[async_trait]
+    impl UnifiedEnvelopeSender<serde_json::Value, serde_json::Value> for FlakySender {
+        async fn send_envelope(
+            &self,
+            _endpoint: &str,
+            envelope: Envelope<serde_json::Value>,
+        ) -> Result<Envelope<serde_json::Value>> {
+            let mut attempts = self.attempts.lock().unwrap();
+            attempts.push(envelope.meta.retry_attempt());
+            if attempts.len() as u32 <= self.failures {
+                return Err(QollectiveError::connection("connection reset"));
+            }
+            Ok(envelope)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retrying_sender_records_attempt_in_meta() {
+        let sender = RetryingSender::new(
+            FlakySender {
+                failures: 2,
+                attempts: Mutex::new(Vec::new()),
+            },
+            RetryPolicy::exponential(3, Duration::ZERO),
+        );
+
+        let response: Envelope<serde_json::Value> = sender
+            .send_envelope("test", Envelope::new(Meta::default(), "ping"))
+            .await
+            .unwrap();
+        assert_eq!(response.payload, "ping");
+        assert_eq!(response.meta.retry_attempt(), Some(3));
+        assert_eq!(
+            *sender.inner().attempts.lock().unwrap(),
+            vec![None, Some(2), Some(3)]
+        );
+    }
+}
@@ -13,26 +13,33 @@
 //! - Connection lifecycle management
 //! - Subprotocol and extension support
 //! - Real-time bidirectional communication
+//! - One persistent connection per endpoint, multiplexing concurrent requests
+//!   by request id and reconnecting with backoff (see [`super::websocket_connection`])
+//! - Subscription streams for envelopes pushed by the server
 
-use crate::envelope::Envelope;
+use crate::constants::limits;
+use crate::envelope::{deadline, trace_context, Envelope};
 use crate::error::{QollectiveError, Result};
 use crate::traits::senders::UnifiedEnvelopeSender;
+use crate::transport::retry::RetryPolicy;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 #[cfg(feature = "websocket-client")]
-use futures_util::{SinkExt, StreamExt};
+use super::websocket_connection::{
+    ConnectFn, EnvelopePushStream, MultiplexedConnection, ReconnectPolicy, WsStream,
+};
 #[cfg(feature = "websocket-client")]
-use std::sync::Arc;
+use crate::client::websocket::{WebSocketFrame, WebSocketMessageType};
 #[cfg(feature = "websocket-client")]
-use tokio::net::TcpStream;
+use std::sync::Arc;
 #[cfg(feature = "websocket-client")]
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 #[cfg(feature = "websocket-client")]
-use tokio_tungstenite::tungstenite::{protocol::CloseFrame, Message};
+use tokio_tungstenite::tungstenite::Message;
 #[cfg(feature = "websocket-client")]
-use tokio_tungstenite::{Connector, MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::Connector;
 #[cfg(feature = "websocket-client")]
 use url::Url;
 
@@ -51,6 +58,12 @@ pub struct WebSocketConfig {
     pub subprotocols: Vec<String>,
     /// Enable compression
     pub enable_compression: bool,
+    /// Backoff for re-establishing dropped connections
+    pub reconnect: ReconnectPolicy,
+    /// Headers sent with every opening handshake, such as `Authorization`
+    pub handshake_headers: Vec<(String, String)>,
+    /// Outgoing frames queued per connection before senders wait for the socket
+    pub send_queue_capacity: usize,
 }
 
 impl Default for WebSocketConfig {
@@ -62,6 +75,9 @@ impl Default for WebSocketConfig {
             max_message_size: 16 * 1024 * 1024, // 16MB
             subprotocols: vec!["qollective.v1".to_string()],
             enable_compression: true,
+            reconnect: ReconnectPolicy::default(),
+            handshake_headers: Vec::new(),
+            send_queue_capacity: limits::DEFAULT_WEBSOCKET_SEND_QUEUE_CAPACITY,
         }
     }
 }
@@ -74,16 +90,11 @@ pub struct WebSocketTransport {
     /// TLS configuration for secure connections
     #[cfg(feature = "tls")]
     tls_config: Option<crate::config::tls::TlsConfig>,
-    /// Active connections (endpoint -> connection)
+    /// Active connections (endpoint -> connection, opened once on first use)
     #[cfg(feature = "websocket-client")]
-    connections: Arc<
-        Mutex<
-            std::collections::HashMap<
-                String,
-                Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>,
-            >,
-        >,
-    >,
+    connections: Arc<Mutex<std::collections::HashMap<String, Arc<ConnectionSlot>>>>,
+    /// Retry policy applied to every envelope
+    retry_policy: RetryPolicy,
 }
 
 impl WebSocketTransport {
@@ -95,6 +106,7 @@ impl WebSocketTransport {
             tls_config: None,
             #[cfg(feature = "websocket-client")]
             connections: Arc::new(Mutex::new(std::collections::HashMap::new())),
+            retry_policy: RetryPolicy::for_transport(limits::DEFAULT_MAX_RETRIES),
         }
     }
 
@@ -109,9 +121,16 @@ impl WebSocketTransport {
             tls_config: tls_config.cloned(),
             #[cfg(feature = "websocket-client")]
             connections: Arc::new(Mutex::new(std::collections::HashMap::new())),
+            retry_policy: RetryPolicy::for_transport(limits::DEFAULT_MAX_RETRIES),
         }
     }
 
+    /// Replace the retry policy applied to every envelope
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Parse WebSocket URL and validate scheme
     #[cfg(feature = "websocket-client")]
     pub fn parse_websocket_url(&self, endpoint: &str) -> Result<Url> {
@@ -139,47 +158,94 @@ impl WebSocketTransport {
         }
     }
 
-    /// Establish WebSocket connection
+    /// Get the persistent connection for an endpoint, opening it on first use
+    ///
+    /// A cached connection that was closed or gave up reconnecting is replaced.
     #[cfg(feature = "websocket-client")]
-    async fn establish_connection(
-        &self,
-        endpoint: &str,
-    ) -> Result<Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>> {
+    async fn establish_connection(&self, endpoint: &str) -> Result<Arc<MultiplexedConnection>> {
         let url = self.parse_websocket_url(endpoint)?;
 
-        // Check if connection already exists
-        {
-            let connections = self.connections.lock().await;
-            if let Some(connection) = connections.get(endpoint) {
-                return Ok(connection.clone());
+        // Only the endpoint's slot is held while connecting, so concurrent first
+        // requests share one socket without blocking requests to other endpoints
+        let slot = {
+            let mut connections = self.connections.lock().await;
+            let slot = connections.entry(endpoint.to_string()).or_default();
+            if slot.get().is_some_and(|connection| connection.is_closed()) {
+                *slot = Arc::default();
             }
-        }
+            slot.clone()
+        };
 
+        slot.get_or_try_init(|| self.open_connection(endpoint, url))
+            .await
+            .cloned()
+    }
+
+    /// Open a new multiplexed connection to `url`
+    #[cfg(feature = "websocket-client")]
+    async fn open_connection(
+        &self,
+        endpoint: &str,
+        url: Url,
+    ) -> Result<Arc<MultiplexedConnection>> {
         // Determine if TLS is enabled and create connector
         let connector = self.create_tls_connector().await?;
-
-        // Establish new connection with timeout
-        let connection_result = tokio::time::timeout(
-            self.config.connection_timeout,
-            self.connect_with_tls(url.as_str(), connector),
+        let timeout = self.config.connection_timeout;
+        let headers = self.config.handshake_headers.clone();
+        let connect: ConnectFn = Arc::new(move || {
+            Box::pin(connect_with_tls(
+                url.to_string(),
+                headers.clone(),
+                connector.clone(),
+                timeout,
+            ))
+        });
+
+        let connection = MultiplexedConnection::open(
+            endpoint,
+            connect,
+            self.config.reconnect.clone(),
+            self.config.ping_interval,
+            self.config.send_queue_capacity,
         )
-        .await;
+        .await?;
+
+        Ok(Arc::new(connection))
+    }
 
-        let (ws_stream, _response) = connection_result
-            .map_err(|_| QollectiveError::transport("WebSocket connection timeout".to_string()))?
-            .map_err(|e| {
-                QollectiveError::connection(format!("WebSocket connection failed: {}", e))
-            })?;
+    /// Send an envelope once over the socket of `endpoint` and wait for its response
+    #[cfg(feature = "websocket-client")]
+    async fn send_once<R>(
+        &self,
+        endpoint: &str,
+        envelope: Envelope<serde_json::Value>,
+    ) -> Result<Envelope<R>>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        deadline::check(envelope.meta.deadline)?;
+        let request_deadline = envelope.meta.deadline;
 
-        let connection = Arc::new(Mutex::new(ws_stream));
+        // Establish connection
+        let connection = self.establish_connection(endpoint).await?;
 
-        // Cache the connection
-        {
-            let mut connections = self.connections.lock().await;
-            connections.insert(endpoint.to_string(), connection.clone());
-        }
+        // Tag the request so its response can be picked out of the shared socket
+        let request_id = uuid::Uuid::now_v7().to_string();
+        let request_message =
+            self.envelope_to_websocket_frame(Some(request_id.clone()), envelope)?;
+
+        // Send and wait for the correlated response until the deadline or message timeout
+        let response_message = connection
+            .request(
+                request_id,
+                request_message,
+                request_deadline,
+                self.config.message_timeout,
+            )
+            .await?;
 
-        Ok(connection)
+        // Convert response message to envelope
+        message_type_to_envelope(response_message)
     }
 
     /// Create TLS connector based on configuration
@@ -205,52 +271,102 @@ impl WebSocketTransport {
         Ok(None)
     }
 
-    /// Connect with TLS support
+    /// Subscribe to envelopes the server at `endpoint` pushes without a request
+    ///
+    /// Opens the endpoint's persistent connection if needed. The stream keeps
+    /// yielding across reconnects and ends once the connection is closed.
     #[cfg(feature = "websocket-client")]
-    async fn connect_with_tls(
-        &self,
-        url: &str,
-        connector: Option<Connector>,
-    ) -> Result<(
-        WebSocketStream<MaybeTlsStream<TcpStream>>,
-        tokio_tungstenite::tungstenite::handshake::client::Response,
-    )> {
-        use tokio_tungstenite::{connect_async, connect_async_tls_with_config};
+    pub async fn subscribe(&self, endpoint: &str) -> Result<EnvelopePushStream> {
+        let connection = self.establish_connection(endpoint).await?;
+        Ok(connection.subscribe())
+    }
 
-        match connector {
-            Some(connector) => {
-                // Use custom TLS connector
-                connect_async_tls_with_config(url, None, false, Some(connector))
-                    .await
-                    .map_err(|e| {
-                        QollectiveError::connection(format!(
-                            "WebSocket TLS connection failed: {}",
-                            e
-                        ))
-                    })
-            }
-            None => {
-                // Use default connection (may still be TLS if wss:// scheme)
-                connect_async(url).await.map_err(|e| {
-                    QollectiveError::connection(format!("WebSocket connection failed: {}", e))
-                })
+    /// Join a room on the server at `endpoint` so its room pushes reach this connection
+    #[cfg(feature = "websocket-client")]
+    pub async fn join_room(&self, endpoint: &str, room: &str) -> Result<()> {
+        self.room_request(
+            endpoint,
+            WebSocketMessageType::Join {
+                room: room.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Leave a room previously joined on the server at `endpoint`
+    #[cfg(feature = "websocket-client")]
+    pub async fn leave_room(&self, endpoint: &str, room: &str) -> Result<()> {
+        self.room_request(
+            endpoint,
+            WebSocketMessageType::Leave {
+                room: room.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Send a join/leave frame and wait for the server to echo it back
+    #[cfg(feature = "websocket-client")]
+    async fn room_request(&self, endpoint: &str, message: WebSocketMessageType) -> Result<()> {
+        let connection = self.establish_connection(endpoint).await?;
+        let request_id = uuid::Uuid::now_v7().to_string();
+        let frame = serde_json::to_string(&WebSocketFrame {
+            id: Some(request_id.clone()),
+            timeout_ms: None,
+            message,
+        })
+        .map_err(|e| {
+            QollectiveError::serialization(format!("Failed to serialize WebSocket message: {}", e))
+        })?;
+
+        match connection
+            .request(
+                request_id,
+                Message::Text(frame.into()),
+                None,
+                self.config.message_timeout,
+            )
+            .await?
+        {
+            WebSocketMessageType::Join { .. } | WebSocketMessageType::Leave { .. } => Ok(()),
+            WebSocketMessageType::Error { message, code } => {
+                Err(QollectiveError::transport(format!(
+                    "Received WebSocket error message: {} (code: {:?})",
+                    message, code
+                )))
             }
+            other => Err(QollectiveError::transport(format!(
+                "Unexpected reply to room request: {:?}",
+                other
+            ))),
         }
     }
 
-    /// Convert envelope to WebSocket message
-    fn envelope_to_websocket_message<T>(&self, envelope: Envelope<T>) -> Result<Message>
+    /// Convert envelope to a WebSocket frame tagged with an optional request id
+    fn envelope_to_websocket_frame<T>(
+        &self,
+        id: Option<String>,
+        mut envelope: Envelope<T>,
+    ) -> Result<Message>
     where
         T: Serialize,
     {
+        // Frames have no headers, so the trace context travels in the envelope metadata
+        envelope.meta.tracing = trace_context::outgoing(envelope.meta.tracing.as_ref());
+
         // First convert envelope to JSON Value
         let envelope_value = serde_json::to_value(&envelope).map_err(|e| {
             QollectiveError::serialization(format!("Failed to serialize envelope: {}", e))
         })?;
 
-        // Wrap the envelope data in WebSocketMessageType::Envelope format
-        let websocket_message = crate::client::websocket::WebSocketMessageType::Envelope {
-            payload: envelope_value,
+        // Wrap the envelope data in WebSocketMessageType::Envelope format; the deadline
+        // is not part of the envelope on the wire, so the frame carries the time left
+        let websocket_message = WebSocketFrame {
+            id,
+            timeout_ms: envelope.meta.deadline.map(deadline::remaining_millis),
+            message: WebSocketMessageType::Envelope {
+                payload: envelope_value,
+            },
         };
 
         // Serialize the wrapped message
@@ -270,104 +386,117 @@ impl WebSocketTransport {
         Ok(Message::Text(json_data.into()))
     }
 
-    /// Convert WebSocket message to envelope
-    fn websocket_message_to_envelope<R>(&self, message: Message) -> Result<Envelope<R>>
-    where
-        R: for<'de> Deserialize<'de>,
-    {
-        let text_data = match message {
-            Message::Text(text) => text.to_string(),
-            Message::Binary(data) => String::from_utf8(data.to_vec()).map_err(|e| {
-                QollectiveError::deserialization(format!("Invalid UTF-8 in binary message: {}", e))
-            })?,
-            Message::Close(_) => {
-                return Err(QollectiveError::connection(
-                    "WebSocket connection closed by remote",
-                ));
-            }
-            Message::Ping(_) | Message::Pong(_) => {
-                return Err(QollectiveError::transport(
-                    "Received ping/pong frame instead of data".to_string(),
-                ));
-            }
-            Message::Frame(_) => {
-                return Err(QollectiveError::transport(
-                    "Received raw frame instead of data".to_string(),
-                ));
-            }
-        };
+    /// Close WebSocket connection
+    #[cfg(feature = "websocket-client")]
+    pub async fn close_connection(&self, endpoint: &str) -> Result<()> {
+        let slot = self.connections.lock().await.remove(endpoint);
+        if let Some(connection) = slot.as_deref().and_then(OnceCell::get) {
+            connection.close().await;
+        }
+        Ok(())
+    }
+}
 
-        // First parse as WebSocketMessageType to unwrap the envelope
-        let websocket_message: crate::client::websocket::WebSocketMessageType =
-            serde_json::from_str(&text_data).map_err(|e| {
-                QollectiveError::deserialization(format!(
-                    "Failed to deserialize WebSocket message: {}",
-                    e
-                ))
-            })?;
-
-        // Extract the envelope data from the WebSocketMessageType
-        let websocket_message_type_value = match websocket_message {
-            crate::client::websocket::WebSocketMessageType::Envelope { payload } => payload,
-            crate::client::websocket::WebSocketMessageType::Ping { .. } => {
-                return Err(QollectiveError::transport(
-                    "Received ping message instead of envelope".to_string(),
-                ));
-            }
-            crate::client::websocket::WebSocketMessageType::Pong { .. } => {
-                return Err(QollectiveError::transport(
-                    "Received pong message instead of envelope".to_string(),
-                ));
-            }
-            crate::client::websocket::WebSocketMessageType::Error { message, code } => {
-                return Err(QollectiveError::transport(format!(
-                    "Received WebSocket error message: {} (code: {:?})",
-                    message, code
-                )));
-            }
-        };
+/// Connection to one endpoint, empty until its first connect succeeds
+#[cfg(feature = "websocket-client")]
+type ConnectionSlot = OnceCell<Arc<MultiplexedConnection>>;
 
-        // Now deserialize the envelope data as a Qollective envelope
-        let envelope: Envelope<R> = serde_json::from_value(websocket_message_type_value).map_err(|e| {
-            QollectiveError::deserialization(format!("Failed to deserialize envelope: {}", e))
+/// Open a socket to `url`, using the custom TLS connector when one is configured
+#[cfg(feature = "websocket-client")]
+async fn connect_with_tls(
+    url: String,
+    headers: Vec<(String, String)>,
+    connector: Option<Connector>,
+    timeout: Duration,
+) -> Result<WsStream> {
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+    use tokio_tungstenite::{connect_async, connect_async_tls_with_config};
+
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| QollectiveError::connection(format!("Invalid WebSocket request: {}", e)))?;
+    for (name, value) in headers {
+        let name = HeaderName::try_from(name.as_str()).map_err(|e| {
+            QollectiveError::config(format!("Invalid handshake header name '{}': {}", name, e))
         })?;
-
-        Ok(envelope)
+        let value = HeaderValue::try_from(value.as_str()).map_err(|e| {
+            QollectiveError::config(format!(
+                "Invalid handshake header value for '{}': {}",
+                name, e
+            ))
+        })?;
+        request.headers_mut().insert(name, value);
     }
 
-    /// Send ping frame to keep connection alive
-    #[cfg(feature = "websocket-client")]
-    async fn send_ping(
-        &self,
-        connection: &Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>,
-    ) -> Result<()> {
-        let mut ws_stream = connection.lock().await;
-        ws_stream
-            .send(Message::Ping(vec![].into()))
-            .await
-            .map_err(|e| QollectiveError::connection(format!("Failed to send ping: {}", e)))?;
-        Ok(())
-    }
+    let connection_result = tokio::time::timeout(timeout, async {
+        match connector {
+            Some(connector) => {
+                // Use custom TLS connector
+                connect_async_tls_with_config(request, None, false, Some(connector))
+                    .await
+                    .map_err(|e| {
+                        QollectiveError::connection(format!(
+                            "WebSocket TLS connection failed: {}",
+                            e
+                        ))
+                    })
+            }
+            None => {
+                // Use default connection (may still be TLS if wss:// scheme)
+                connect_async(request).await.map_err(|e| {
+                    QollectiveError::connection(format!("WebSocket connection failed: {}", e))
+                })
+            }
+        }
+    })
+    .await;
 
-    /// Close WebSocket connection
-    #[cfg(feature = "websocket-client")]
-    pub async fn close_connection(&self, endpoint: &str) -> Result<()> {
-        let mut connections = self.connections.lock().await;
-        if let Some(connection) = connections.remove(endpoint) {
-            let mut ws_stream = connection.lock().await;
-            let close_frame = CloseFrame {
-                code: tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode::Normal,
-                reason: "Closing connection".into(),
-            };
-            ws_stream
-                .send(Message::Close(Some(close_frame)))
-                .await
-                .map_err(|e| {
-                    QollectiveError::connection(format!("Failed to close connection: {}", e))
-                })?;
+    let (ws_stream, _response) = connection_result
+        .map_err(|_| QollectiveError::transport("WebSocket connection timeout".to_string()))??;
+    Ok(ws_stream)
+}
+
+/// Unwrap the envelope carried by a protocol message
+#[cfg(feature = "websocket-client")]
+fn message_type_to_envelope<R>(websocket_message: WebSocketMessageType) -> Result<Envelope<R>>
+where
+    R: for<'de> Deserialize<'de>,
+{
+    // Extract the envelope data from the WebSocketMessageType
+    let websocket_message_type_value = match websocket_message {
+        WebSocketMessageType::Envelope { payload } => payload,
+        WebSocketMessageType::Ping { .. } => {
+            return Err(QollectiveError::transport(
+                "Received ping message instead of envelope".to_string(),
+            ));
         }
-        Ok(())
-    }
+        WebSocketMessageType::Pong { .. } => {
+            return Err(QollectiveError::transport(
+                "Received pong message instead of envelope".to_string(),
+            ));
+        }
+        WebSocketMessageType::Error { message, code } => {
+            return Err(QollectiveError::transport(format!(
+                "Received WebSocket error message: {} (code: {:?})",
+                message, code
+            )));
+        }
+        WebSocketMessageType::Join { .. } | WebSocketMessageType::Leave { .. } => {
+            return Err(QollectiveError::transport(
+                "Received room acknowledgement instead of envelope".to_string(),
+            ));
+        }
+    };
+
+    // Now deserialize the envelope data as a Qollective envelope
+    let envelope: Envelope<R> =
+        serde_json::from_value(websocket_message_type_value).map_err(|e| {
+            QollectiveError::deserialization(format!("Failed to deserialize envelope: {}", e))
+        })?;
+
+    Ok(envelope)
 }
 
 #[cfg(feature = "websocket-client")]
@@ -377,38 +506,18 @@ where
     T: Serialize + Send + 'static,
     R: for<'de> Deserialize<'de> + Send + 'static,
 {
-    async fn send_envelope(&self, endpoint: &str, envelope: Envelope<T>) -> Result<Envelope<R>> {
-        // Establish connection
-        let connection = self.establish_connection(endpoint).await?;
-
-        // Convert envelope to WebSocket message
-        let request_message = self.envelope_to_websocket_message(envelope)?;
-
-        // Send message with timeout
-        {
-            let mut ws_stream = connection.lock().await;
-            tokio::time::timeout(self.config.message_timeout, ws_stream.send(request_message))
-                .await
-                .map_err(|_| QollectiveError::transport("WebSocket send timeout".to_string()))?
-                .map_err(|e| {
-                    QollectiveError::connection(format!("Failed to send WebSocket message: {}", e))
-                })?;
-        }
-
-        // Wait for response with timeout
-        let response_message = tokio::time::timeout(self.config.message_timeout, async {
-            let mut ws_stream = connection.lock().await;
-            ws_stream.next().await
-        })
-        .await
-        .map_err(|_| QollectiveError::transport("WebSocket receive timeout".to_string()))?
-        .ok_or_else(|| QollectiveError::connection("WebSocket stream ended unexpectedly"))?
-        .map_err(|e| QollectiveError::connection(format!("WebSocket receive error: {}", e)))?;
-
-        // Convert response message to envelope
-        let response_envelope = self.websocket_message_to_envelope(response_message)?;
-
-        Ok(response_envelope)
+    async fn send_envelope(
+        &self,
+        endpoint: &str,
+        mut envelope: Envelope<T>,
+    ) -> Result<Envelope<R>> {
+        // The deadline travels in the envelope metadata; all attempts share it
+        envelope.meta.deadline =
+            deadline::outgoing(envelope.meta.deadline, Some(self.config.message_timeout));
+
+        self.retry_policy
+            .retry_envelope(envelope, |envelope| self.send_once(endpoint, envelope))
+            .await
     }
 }
 
@@ -488,7 +597,7 @@ mod tests {
         };
         let envelope = Envelope::new(Meta::default(), request);
 
-        let result = transport.envelope_to_websocket_message(envelope);
+        let result = transport.envelope_to_websocket_frame(None, envelope);
         assert!(result.is_ok());
 
         let message = result.unwrap();
@@ -502,20 +611,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_frame_carries_time_remaining_instead_of_deadline() {
+        let transport = WebSocketTransport::new(WebSocketConfig::default());
+        let mut meta = Meta::default();
+        meta.deadline = Some(deadline::after(Duration::from_secs(2)));
+        let envelope = Envelope::new(
+            meta,
+            TestRequest {
+                message: "Engage".to_string(),
+            },
+        );
+
+        let Message::Text(text) = transport
+            .envelope_to_websocket_frame(Some("req-1".to_string()), envelope)
+            .unwrap()
+        else {
+            panic!("Expected text message");
+        };
+        let frame: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        let timeout_ms = frame["timeout_ms"].as_u64().unwrap();
+        assert!(timeout_ms > 0 && timeout_ms <= 2000);
+        assert!(frame["payload"]["meta"].get("deadline").is_none());
+    }
+
     // TDD Step 4: Write failing test for WebSocket message to envelope conversion
     #[test]
     fn test_websocket_message_to_envelope_conversion() {
-        let config = WebSocketConfig::default();
-        let transport = WebSocketTransport::new(config);
-
         // Create a JSON message that represents a WebSocketMessageType::Envelope containing an envelope
-        // This is the format that the websocket_message_to_envelope method expects (tagged enum with "type" field)
         let envelope_data = r#"{"meta":{"id":"test-id","timestamp":"2023-01-01T00:00:00Z","version":"1.0","context":{},"routing":{"source":"test","destination":"test","protocol":"websocket"}},"payload":{"result":"Hello from WebSocket"}}"#;
         let websocket_message_json = format!(r#"{{"type":"envelope","payload":{}}}"#, envelope_data);
-        let message = Message::Text(websocket_message_json.into());
+        let message: WebSocketMessageType = serde_json::from_str(&websocket_message_json).unwrap();
 
-        let result: Result<Envelope<TestResponse>> =
-            transport.websocket_message_to_envelope(message);
+        let result: Result<Envelope<TestResponse>> = message_type_to_envelope(message);
         assert!(result.is_ok());
 
         let envelope = result.unwrap();
@@ -535,7 +664,7 @@ mod tests {
         };
         let envelope = Envelope::new(Meta::default(), large_request);
 
-        let result = transport.envelope_to_websocket_message(envelope);
+        let result = transport.envelope_to_websocket_frame(None, envelope);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("exceeds maximum"));
     }
@@ -543,32 +672,30 @@ mod tests {
     // TDD Step 6: Write failing test for WebSocket protocol message handling
     #[test]
     fn test_websocket_protocol_message_handling() {
-        let config = WebSocketConfig::default();
-        let transport = WebSocketTransport::new(config);
-
-        // Test close frame handling
-        let close_message = Message::Close(None);
-        let result: Result<Envelope<TestResponse>> =
-            transport.websocket_message_to_envelope(close_message);
+        // Test ping message handling
+        let ping_message = WebSocketMessageType::Ping {
+            timestamp: chrono::Utc::now(),
+        };
+        let result: Result<Envelope<TestResponse>> = message_type_to_envelope(ping_message);
         assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("connection closed"));
-
-        // Test ping frame handling
-        let ping_message = Message::Ping(vec![].into());
-        let result: Result<Envelope<TestResponse>> =
-            transport.websocket_message_to_envelope(ping_message);
+        assert!(result.unwrap_err().to_string().contains("ping message"));
+
+        // Test pong message handling
+        let pong_message = WebSocketMessageType::Pong {
+            timestamp: chrono::Utc::now(),
+        };
+        let result: Result<Envelope<TestResponse>> = message_type_to_envelope(pong_message);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("ping/pong frame"));
+        assert!(result.unwrap_err().to_string().contains("pong message"));
 
-        // Test pong frame handling
-        let pong_message = Message::Pong(vec![].into());
-        let result: Result<Envelope<TestResponse>> =
-            transport.websocket_message_to_envelope(pong_message);
+        // Test error message handling
+        let error_message = WebSocketMessageType::Error {
+            message: "handler failed".to_string(),
+            code: Some(500),
+        };
+        let result: Result<Envelope<TestResponse>> = message_type_to_envelope(error_message);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("ping/pong frame"));
+        assert!(result.unwrap_err().to_string().contains("handler failed"));
     }
 
     // TDD Step 7: Write failing test for UnifiedEnvelopeSender trait implementation
@@ -683,9 +810,11 @@ mod tests {
         // - Connection refused (no server running)
         // - Connection timeout
         // - WebSocket error (if some server is running but not handling our request properly)
+        // - Deadline exceeded (retries ran past the message timeout)
         assert!(
             error_str.contains("connection")
                 || error_str.contains("timeout")
+                || error_str.contains("deadline exceeded")
                 || error_str.contains("Connection refused")
                 || error_str.contains("os error 61") // Connection refused on macOS
                 || error_str.contains("os error 111") // Connection refused on Linux
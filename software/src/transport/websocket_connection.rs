@@ -0,0 +1,468 @@
+// ABOUTME: Long-lived multiplexed WebSocket connection shared by concurrent envelope requests
+// ABOUTME: Correlates responses by request id, reconnects with backoff and fans out server pushes
+
+//! Multiplexed WebSocket connection.
+//!
+//! One [`MultiplexedConnection`] owns a single socket to an endpoint. Concurrent
+//! requests are tagged with a request id and matched to their responses as
+//! they arrive, in any order. Frames without an id are server-initiated pushes
+//! and are broadcast to every [`MultiplexedConnection::subscribe`] stream. When
+//! the socket drops, in-flight requests fail with a connection error and the
+//! connection is re-established in the background with exponential backoff.
+
+use crate::client::websocket::{WebSocketFrame, WebSocketMessageType};
+use crate::envelope::{deadline, Envelope};
+use crate::error::{QollectiveError, Result};
+use chrono::{DateTime, Utc};
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tracing::{debug, warn};
+
+/// Socket type produced by the transport's connect function
+pub(crate) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Opens a fresh socket to the connection's endpoint
+pub(crate) type ConnectFn =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<WsStream>> + Send>> + Send + Sync>;
+
+/// Stream of envelopes pushed by the server without a preceding request
+pub type EnvelopePushStream = Pin<Box<dyn Stream<Item = Envelope<Value>> + Send>>;
+
+/// Number of pushed envelopes buffered per subscriber before the oldest are dropped
+const PUSH_BUFFER_SIZE: usize = 256;
+
+/// Backoff policy for re-establishing a dropped connection
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnection attempt
+    pub initial_delay: Duration,
+    /// Upper bound for the doubling delay between attempts
+    pub max_delay: Duration,
+    /// Give up after this many consecutive failed attempts; `None` retries forever
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before the given zero-based reconnection attempt
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+type PendingMap = Arc<Mutex<HashMap<String, oneshot::Sender<WebSocketMessageType>>>>;
+
+/// Work queued for the connection's socket task
+enum Outgoing {
+    Frame(Message),
+    Close,
+}
+
+/// State shared between the connection handle and its socket task
+struct Shared {
+    endpoint: String,
+    pending: PendingMap,
+    pushes: broadcast::Sender<Envelope<Value>>,
+    connected: AtomicBool,
+    closed: AtomicBool,
+}
+
+impl Shared {
+    /// Fail every in-flight request; their frames may or may not have reached the server
+    fn fail_pending(&self) {
+        let pending: Vec<_> = self.pending.lock().unwrap().drain().collect();
+        for (_, responder) in pending {
+            // Dropping the sender wakes the caller with a connection error
+            drop(responder);
+        }
+    }
+
+    fn dispatch(&self, text: &str) {
+        let frame: WebSocketFrame = match serde_json::from_str(text) {
+            Ok(frame) => frame,
+            Err(e) => {
+                warn!(
+                    "Ignoring malformed WebSocket frame from {}: {}",
+                    self.endpoint, e
+                );
+                return;
+            }
+        };
+
+        if let Some(id) = frame.id {
+            match self.pending.lock().unwrap().remove(&id) {
+                Some(responder) => {
+                    let _ = responder.send(frame.message);
+                }
+                None => debug!("Dropping response for unknown request id {}", id),
+            }
+            return;
+        }
+
+        match frame.message {
+            WebSocketMessageType::Envelope { payload } => {
+                match serde_json::from_value::<Envelope<Value>>(payload) {
+                    // Nobody subscribed is fine; the push is simply dropped
+                    Ok(envelope) => {
+                        let _ = self.pushes.send(envelope);
+                    }
+                    Err(e) => warn!("Ignoring malformed pushed envelope: {}", e),
+                }
+            }
+            WebSocketMessageType::Error { message, code } => {
+                warn!(
+                    "Uncorrelated WebSocket error from {}: {} (code: {:?})",
+                    self.endpoint, message, code
+                );
+            }
+            WebSocketMessageType::Ping { .. }
+            | WebSocketMessageType::Pong { .. }
+            | WebSocketMessageType::Join { .. }
+            | WebSocketMessageType::Leave { .. } => {}
+        }
+    }
+}
+
+/// One persistent, multiplexed WebSocket connection to an endpoint
+pub struct MultiplexedConnection {
+    shared: Arc<Shared>,
+    outgoing: mpsc::Sender<Outgoing>,
+    task: JoinHandle<()>,
+}
+
+impl std::fmt::Debug for MultiplexedConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiplexedConnection")
+            .field("endpoint", &self.shared.endpoint)
+            .field("connected", &self.is_connected())
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MultiplexedConnection {
+    /// Connect to the endpoint and start the socket task
+    ///
+    /// The first connection attempt is made eagerly so an unreachable endpoint
+    /// is reported to the caller; later drops are recovered in the background.
+    pub(crate) async fn open(
+        endpoint: &str,
+        connect: ConnectFn,
+        policy: ReconnectPolicy,
+        ping_interval: Duration,
+        send_queue_capacity: usize,
+    ) -> Result<Self> {
+        if send_queue_capacity == 0 {
+            return Err(QollectiveError::config("send_queue_capacity cannot be 0"));
+        }
+        let stream = connect().await?;
+
+        let (pushes, _) = broadcast::channel(PUSH_BUFFER_SIZE);
+        let shared = Arc::new(Shared {
+            endpoint: endpoint.to_string(),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            pushes,
+            connected: AtomicBool::new(true),
+            closed: AtomicBool::new(false),
+        });
+        let (outgoing, outgoing_rx) = mpsc::channel(send_queue_capacity);
+
+        let task = tokio::spawn(supervise(
+            stream,
+            shared.clone(),
+            outgoing_rx,
+            connect,
+            policy,
+            ping_interval,
+        ));
+
+        Ok(Self {
+            shared,
+            outgoing,
+            task,
+        })
+    }
+
+    /// Endpoint this connection is bound to
+    pub fn endpoint(&self) -> &str {
+        &self.shared.endpoint
+    }
+
+    /// Whether the socket is currently up
+    pub fn is_connected(&self) -> bool {
+        self.shared.connected.load(Ordering::SeqCst)
+    }
+
+    /// Whether the connection was closed or gave up reconnecting
+    pub fn is_closed(&self) -> bool {
+        self.shared.closed.load(Ordering::SeqCst)
+    }
+
+    /// Send a frame tagged with `id` and wait for the response carrying the same id
+    ///
+    /// Waits until `request_deadline` when there is one, otherwise for `timeout`.
+    pub(crate) async fn request(
+        &self,
+        id: String,
+        frame: Message,
+        request_deadline: Option<DateTime<Utc>>,
+        timeout: Duration,
+    ) -> Result<WebSocketMessageType> {
+        if self.is_closed() {
+            return Err(QollectiveError::connection(format!(
+                "WebSocket connection to {} is closed",
+                self.shared.endpoint
+            )));
+        }
+
+        let (responder, response) = oneshot::channel();
+        self.shared
+            .pending
+            .lock()
+            .unwrap()
+            .insert(id.clone(), responder);
+
+        // Time spent waiting for room in a full send queue counts against the timeout
+        let exchange = async {
+            if self.outgoing.send(Outgoing::Frame(frame)).await.is_err() {
+                return Err(QollectiveError::connection(format!(
+                    "WebSocket connection to {} is closed",
+                    self.shared.endpoint
+                )));
+            }
+            response.await.map_err(|_| {
+                QollectiveError::connection(format!(
+                    "WebSocket connection to {} dropped before response to request {}",
+                    self.shared.endpoint, id
+                ))
+            })
+        };
+
+        let wait = request_deadline.map(deadline::remaining).unwrap_or(timeout);
+        match tokio::time::timeout(wait, exchange).await {
+            Ok(Ok(message)) => Ok(message),
+            Ok(Err(e)) => {
+                self.shared.pending.lock().unwrap().remove(&id);
+                Err(e)
+            }
+            Err(_) => {
+                self.shared.pending.lock().unwrap().remove(&id);
+                Err(match request_deadline {
+                    Some(request_deadline) => deadline::exceeded(request_deadline),
+                    None => QollectiveError::transport("WebSocket receive timeout".to_string()),
+                })
+            }
+        }
+    }
+
+    /// Subscribe to envelopes the server pushes without a request
+    ///
+    /// Each subscriber sees pushes received after it subscribed. A subscriber that
+    /// falls more than the buffer size behind skips the oldest pushes.
+    pub fn subscribe(&self) -> EnvelopePushStream {
+        let receiver = self.shared.pushes.subscribe();
+        Box::pin(futures_util::stream::unfold(
+            receiver,
+            |mut receiver| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(envelope) => return Some((envelope, receiver)),
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!(
+                                "WebSocket push subscriber lagged, skipped {} envelopes",
+                                skipped
+                            );
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Close the socket and stop reconnecting
+    pub async fn close(&self) {
+        self.shared.closed.store(true, Ordering::SeqCst);
+        let _ = self.outgoing.send(Outgoing::Close).await;
+    }
+}
+
+impl Drop for MultiplexedConnection {
+    fn drop(&mut self) {
+        // After close() the task finishes the close handshake on its own
+        if !self.is_closed() {
+            self.task.abort();
+        }
+    }
+}
+
+/// Socket task: run sessions back to back, reconnecting with backoff in between
+async fn supervise(
+    mut stream: WsStream,
+    shared: Arc<Shared>,
+    mut outgoing: mpsc::Receiver<Outgoing>,
+    connect: ConnectFn,
+    policy: ReconnectPolicy,
+    ping_interval: Duration,
+) {
+    loop {
+        shared.connected.store(true, Ordering::SeqCst);
+        run_session(stream, &shared, &mut outgoing, ping_interval).await;
+        shared.connected.store(false, Ordering::SeqCst);
+
+        // Frames queued for the dead socket belong to requests that are failed next;
+        // anything queued after this point goes out on the new socket
+        while outgoing.try_recv().is_ok() {}
+        shared.fail_pending();
+
+        if shared.closed.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let mut attempt = 0;
+        stream = loop {
+            if shared.closed.load(Ordering::SeqCst) {
+                return;
+            }
+            if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                warn!(
+                    "Giving up on WebSocket connection to {} after {} attempts",
+                    shared.endpoint, attempt
+                );
+                shared.closed.store(true, Ordering::SeqCst);
+                shared.fail_pending();
+                return;
+            }
+
+            tokio::time::sleep(policy.delay_for(attempt)).await;
+            attempt += 1;
+
+            match connect().await {
+                Ok(stream) => break stream,
+                Err(e) => debug!(
+                    "WebSocket reconnect {} to {} failed: {}",
+                    attempt, shared.endpoint, e
+                ),
+            }
+        };
+        debug!("WebSocket connection to {} re-established", shared.endpoint);
+    }
+}
+
+/// Pump one socket until it drops or the connection is closed
+async fn run_session(
+    stream: WsStream,
+    shared: &Shared,
+    outgoing: &mut mpsc::Receiver<Outgoing>,
+    ping_interval: Duration,
+) {
+    let (mut sink, mut incoming) = stream.split();
+    let mut ping =
+        tokio::time::interval_at(tokio::time::Instant::now() + ping_interval, ping_interval);
+
+    loop {
+        tokio::select! {
+            message = incoming.next() => match message {
+                Some(Ok(Message::Text(text))) => shared.dispatch(&text),
+                Some(Ok(Message::Binary(data))) => match std::str::from_utf8(&data) {
+                    Ok(text) => shared.dispatch(text),
+                    Err(e) => warn!("Ignoring non UTF-8 binary frame: {}", e),
+                },
+                Some(Ok(Message::Close(_))) | None => return,
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    debug!("WebSocket connection to {} failed: {}", shared.endpoint, e);
+                    return;
+                }
+            },
+            work = outgoing.recv() => match work {
+                Some(Outgoing::Frame(frame)) => {
+                    if let Err(e) = sink.send(frame).await {
+                        debug!("WebSocket send to {} failed: {}", shared.endpoint, e);
+                        return;
+                    }
+                }
+                Some(Outgoing::Close) | None => {
+                    shared.closed.store(true, Ordering::SeqCst);
+                    let _ = sink
+                        .send(Message::Close(Some(CloseFrame {
+                            code: CloseCode::Normal,
+                            reason: "Closing connection".into(),
+                        })))
+                        .await;
+                    return;
+                }
+            },
+            _ = ping.tick() => {
+                if let Err(e) = sink.send(Message::Ping(Vec::new().into())).await {
+                    debug!("WebSocket ping to {} failed: {}", shared.endpoint, e);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_delay_doubles_up_to_max() {
+        let policy = ReconnectPolicy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            max_attempts: None,
+        };
+
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(800));
+        assert_eq!(policy.delay_for(4), Duration::from_secs(1));
+        assert_eq!(policy.delay_for(40), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_frame_id_is_optional_on_the_wire() {
+        let push: WebSocketFrame =
+            serde_json::from_str(r#"{"type":"envelope","payload":{"meta":{},"payload":1}}"#)
+                .unwrap();
+        assert!(push.id.is_none());
+
+        let response = WebSocketFrame {
+            id: Some("req-1".to_string()),
+            timeout_ms: None,
+            message: WebSocketMessageType::Error {
+                message: "boom".to_string(),
+                code: Some(500),
+            },
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["id"], "req-1");
+        assert_eq!(json["type"], "error");
+    }
+}
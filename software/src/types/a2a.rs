@@ -65,6 +65,30 @@ pub struct RegistryEvent {
     pub metadata: Option<HashMap<String, String>>,
 }
 
+impl RegistryEvent {
+    /// An agent joined the registry
+    pub const AGENT_REGISTERED: &'static str = "agent_registered";
+    /// An agent left the registry on request
+    pub const AGENT_DEREGISTERED: &'static str = "agent_deregistered";
+    /// An agent was removed after missing heartbeats for longer than the agent TTL
+    pub const AGENT_EXPIRED: &'static str = "agent_expired";
+    /// An agent reported a different health status
+    pub const AGENT_HEALTH_CHANGED: &'static str = "agent_health_changed";
+
+    /// Metadata key identifying the registry node that published the event
+    pub const ORIGIN_METADATA_KEY: &'static str = "qollective.registry.origin";
+    /// Metadata key carrying the agent's health status
+    pub const HEALTH_STATUS_METADATA_KEY: &'static str = "qollective.registry.health_status";
+
+    /// Registry node that published the event, if recorded
+    pub fn origin(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(Self::ORIGIN_METADATA_KEY))
+            .map(String::as_str)
+    }
+}
+
 /// Agent deregistration request structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeregistrationRequest {
@@ -137,6 +161,7 @@ pub enum LoadBalancingStrategy {
     LeastConnections,
     Random,
     HealthBased,
+    LatencyWeighted,
 }
 
 /// Routing decision result from capability query
@@ -147,3 +172,322 @@ pub struct RoutingDecision {
     pub estimated_response_time_ms: Option<u64>,
     pub fallback_agents: Vec<AgentInfo>,
 }
+
+/// Lifecycle state of an A2A task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskState {
+    Submitted,
+    Working,
+    InputRequired,
+    Completed,
+    Failed,
+    Canceled,
+}
+
+impl TaskState {
+    /// Whether the task has finished and accepts no further updates
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Canceled)
+    }
+
+    /// Whether a task in this state may move to `next`
+    ///
+    /// Non-terminal tasks may move to any state except back to submitted;
+    /// terminal tasks never change state again.
+    pub fn can_transition_to(&self, next: TaskState) -> bool {
+        !self.is_terminal() && next != Self::Submitted
+    }
+}
+
+/// Point-in-time status of an A2A task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatus {
+    pub state: TaskState,
+    pub message: Option<String>,
+    pub timestamp: SystemTime,
+}
+
+impl TaskStatus {
+    /// Create a status in `state` stamped with the current time
+    pub fn new(state: TaskState, message: Option<String>) -> Self {
+        Self {
+            state,
+            message,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+/// Output produced by an A2A task
+///
+/// Large outputs may be streamed in chunks sharing an `artifact_id`; chunks
+/// with `append` set extend the previous chunk and `last_chunk` marks the end.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskArtifact {
+    pub artifact_id: String,
+    pub name: Option<String>,
+    pub data: serde_json::Value,
+    pub append: bool,
+    pub last_chunk: bool,
+}
+
+/// A2A task tracking a long-running agent job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct A2ATask {
+    pub id: String,
+    pub context_id: Option<String>,
+    pub status: TaskStatus,
+    pub history: Vec<TaskStatus>,
+    pub artifacts: Vec<TaskArtifact>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Status or artifact update streamed to the caller of an A2A task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum TaskUpdate {
+    Status {
+        task_id: String,
+        status: TaskStatus,
+        /// Set on the update that moves the task into a terminal state
+        #[serde(rename = "final")]
+        is_final: bool,
+    },
+    Artifact {
+        task_id: String,
+        artifact: TaskArtifact,
+    },
+}
+
+impl TaskUpdate {
+    /// Task the update belongs to
+    pub fn task_id(&self) -> &str {
+        match self {
+            Self::Status { task_id, .. } | Self::Artifact { task_id, .. } => task_id,
+        }
+    }
+
+    /// Whether no further updates follow for the task
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Status { is_final: true, .. })
+    }
+}
+
+/// Parameters of `tasks/get` and `tasks/cancel` requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskIdParams {
+    pub task_id: String,
+}
+
+/// Conversion between Qollective A2A tasks and the a2a-rs standard types
+#[cfg(feature = "a2a-standard")]
+pub(crate) mod standard {
+    use super::{A2ATask, TaskArtifact, TaskState, TaskStatus, TaskUpdate};
+    use a2a_rs::{
+        Artifact, Message, Part, Role, Task, TaskArtifactUpdateEvent, TaskStatusUpdateEvent,
+    };
+    use std::time::SystemTime;
+
+    /// Convert an a2a-rs task, mapping states without a Qollective equivalent
+    pub(crate) fn task_from_standard(task: Task) -> A2ATask {
+        let status = status_from_standard(task.status);
+        let artifacts = task
+            .artifacts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|artifact| artifact_from_standard(artifact, false, true))
+            .collect();
+        let metadata = task
+            .metadata
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| match value {
+                serde_json::Value::String(text) => (key, text),
+                other => (key, other.to_string()),
+            })
+            .collect();
+
+        A2ATask {
+            id: task.id,
+            context_id: Some(task.context_id).filter(|id| !id.is_empty()),
+            history: vec![status.clone()],
+            status,
+            artifacts,
+            metadata,
+        }
+    }
+
+    /// Convert a task into the a2a-rs form served to standard clients
+    pub(crate) fn task_to_standard(task: &A2ATask) -> Task {
+        Task {
+            id: task.id.clone(),
+            context_id: task.context_id.clone().unwrap_or_default(),
+            status: status_to_standard(&task.id, &task.status),
+            artifacts: Some(task.artifacts.iter().map(artifact_to_standard).collect()),
+            history: None,
+            metadata: Some(
+                task.metadata
+                    .iter()
+                    .map(|(key, value)| (key.clone(), serde_json::Value::String(value.clone())))
+                    .collect(),
+            ),
+            kind: "task".to_string(),
+        }
+    }
+
+    /// Convert an update into a standard `status-update` or `artifact-update` event
+    pub(crate) fn update_to_standard(update: &TaskUpdate, context_id: &str) -> serde_json::Value {
+        let event = match update {
+            TaskUpdate::Status {
+                task_id,
+                status,
+                is_final,
+            } => serde_json::to_value(TaskStatusUpdateEvent {
+                task_id: task_id.clone(),
+                context_id: context_id.to_string(),
+                kind: "status-update".to_string(),
+                status: status_to_standard(task_id, status),
+                final_: *is_final,
+                metadata: None,
+            }),
+            TaskUpdate::Artifact { task_id, artifact } => {
+                serde_json::to_value(TaskArtifactUpdateEvent {
+                    task_id: task_id.clone(),
+                    context_id: context_id.to_string(),
+                    kind: "artifact-update".to_string(),
+                    artifact: artifact_to_standard(artifact),
+                    append: Some(artifact.append),
+                    last_chunk: Some(artifact.last_chunk),
+                    metadata: None,
+                })
+            }
+        };
+        event.unwrap_or_default()
+    }
+
+    /// Convert a standard streaming event, ignoring kinds other than task updates
+    pub(crate) fn update_from_standard(event: serde_json::Value) -> Option<TaskUpdate> {
+        match event.get("kind")?.as_str()? {
+            "status-update" => {
+                let event: TaskStatusUpdateEvent = serde_json::from_value(event).ok()?;
+                Some(TaskUpdate::Status {
+                    task_id: event.task_id,
+                    status: status_from_standard(event.status),
+                    is_final: event.final_,
+                })
+            }
+            "artifact-update" => {
+                let event: TaskArtifactUpdateEvent = serde_json::from_value(event).ok()?;
+                Some(TaskUpdate::Artifact {
+                    task_id: event.task_id,
+                    artifact: artifact_from_standard(
+                        event.artifact,
+                        event.append.unwrap_or(false),
+                        event.last_chunk.unwrap_or(true),
+                    ),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn status_from_standard(status: a2a_rs::TaskStatus) -> TaskStatus {
+        let state = match status.state {
+            a2a_rs::TaskState::Submitted | a2a_rs::TaskState::Unknown => TaskState::Submitted,
+            a2a_rs::TaskState::Working => TaskState::Working,
+            a2a_rs::TaskState::InputRequired | a2a_rs::TaskState::AuthRequired => {
+                TaskState::InputRequired
+            }
+            a2a_rs::TaskState::Completed => TaskState::Completed,
+            a2a_rs::TaskState::Failed | a2a_rs::TaskState::Rejected => TaskState::Failed,
+            a2a_rs::TaskState::Canceled => TaskState::Canceled,
+        };
+        TaskStatus {
+            state,
+            message: status.message.as_ref().and_then(message_text),
+            timestamp: status
+                .timestamp
+                .map(SystemTime::from)
+                .unwrap_or_else(SystemTime::now),
+        }
+    }
+
+    fn status_to_standard(task_id: &str, status: &TaskStatus) -> a2a_rs::TaskStatus {
+        let state = match status.state {
+            TaskState::Submitted => a2a_rs::TaskState::Submitted,
+            TaskState::Working => a2a_rs::TaskState::Working,
+            TaskState::InputRequired => a2a_rs::TaskState::InputRequired,
+            TaskState::Completed => a2a_rs::TaskState::Completed,
+            TaskState::Failed => a2a_rs::TaskState::Failed,
+            TaskState::Canceled => a2a_rs::TaskState::Canceled,
+        };
+        a2a_rs::TaskStatus {
+            state,
+            message: status.message.as_ref().map(|text| Message {
+                role: Role::Agent,
+                parts: vec![Part::text(text.clone())],
+                metadata: None,
+                reference_task_ids: None,
+                message_id: uuid::Uuid::now_v7().to_string(),
+                task_id: Some(task_id.to_string()),
+                context_id: None,
+                kind: "message".to_string(),
+            }),
+            timestamp: Some(status.timestamp.into()),
+        }
+    }
+
+    fn message_text(message: &Message) -> Option<String> {
+        let text: Vec<&str> = message
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                Part::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        (!text.is_empty()).then(|| text.join("\n"))
+    }
+
+    /// Artifacts holding a single text or data part map to that value
+    fn artifact_from_standard(artifact: Artifact, append: bool, last_chunk: bool) -> TaskArtifact {
+        let data = match artifact.parts.as_slice() {
+            [Part::Text { text, .. }] => serde_json::Value::String(text.clone()),
+            [Part::Data { data, .. }] => serde_json::Value::Object(data.clone()),
+            parts => serde_json::to_value(parts).unwrap_or_default(),
+        };
+        TaskArtifact {
+            artifact_id: artifact.artifact_id,
+            name: artifact.name,
+            data,
+            append,
+            last_chunk,
+        }
+    }
+
+    fn artifact_to_standard(artifact: &TaskArtifact) -> Artifact {
+        let parts = match &artifact.data {
+            serde_json::Value::String(text) => vec![Part::text(text.clone())],
+            serde_json::Value::Object(data) => vec![Part::Data {
+                data: data.clone(),
+                metadata: None,
+            }],
+            other => serde_json::from_value(other.clone()).unwrap_or_else(|_| {
+                let mut data = serde_json::Map::new();
+                data.insert("value".to_string(), other.clone());
+                vec![Part::Data {
+                    data,
+                    metadata: None,
+                }]
+            }),
+        };
+        Artifact {
+            artifact_id: artifact.artifact_id.clone(),
+            name: artifact.name.clone(),
+            description: None,
+            parts,
+            metadata: None,
+        }
+    }
+}
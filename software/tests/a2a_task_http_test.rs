@@ -0,0 +1,231 @@
+// ABOUTME: Integration tests for the standard A2A task endpoint served over HTTP
+// ABOUTME: Streams task updates over tasks/resubscribe and checks verified ownership on tasks/cancel
+
+#![cfg(all(
+    feature = "a2a-server",
+    feature = "a2a-standard",
+    feature = "rest-server",
+    feature = "tenant-extraction"
+))]
+
+use base64::prelude::*;
+use qollective::client::a2a::A2AMessage;
+use qollective::config::a2a::A2AClientConfig;
+use qollective::server::a2a::{TaskOwner, TaskStore};
+use qollective::server::a2a_http::task_router;
+use qollective::server::common::TenantAuthenticator;
+use qollective::tenant::extraction::ExtractionConfig;
+use qollective::tenant::{JwtKeySource, JwtVerificationConfig};
+use qollective::transport::InternalA2AClient;
+use qollective::types::a2a::{A2ATask, TaskArtifact, TaskState, TaskUpdate};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::time::timeout;
+
+const SECRET: &[u8] = b"starfleet-secret";
+
+/// Serve `tasks` on a random port and return its endpoint
+async fn serve_task_endpoint(tasks: Arc<TaskStore>) -> String {
+    let authenticator = TenantAuthenticator::from_extraction_config(&ExtractionConfig {
+        jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+            secret: String::from_utf8(SECRET.to_vec()).unwrap(),
+        })),
+        ..Default::default()
+    })
+    .unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, task_router(tasks, authenticator))
+            .await
+            .unwrap();
+    });
+    endpoint
+}
+
+/// Serve `tasks` on a random port and return a client pointed at it
+async fn start_task_endpoint(tasks: Arc<TaskStore>) -> InternalA2AClient {
+    let mut config = A2AClientConfig::default();
+    config.client.endpoint = Some(serve_task_endpoint(tasks).await);
+    InternalA2AClient::new(config).await.unwrap()
+}
+
+/// Bearer token for user `picard` of `tenant`, signed with `secret`
+fn bearer(tenant: &str, secret: &[u8]) -> String {
+    let claims = serde_json::json!({
+        "sub": "picard",
+        "tenantkey": tenant,
+        "exp": jsonwebtoken::get_current_timestamp() + 600,
+    });
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(secret),
+    )
+    .unwrap();
+    format!("Bearer {}", token)
+}
+
+fn owner(tenant: &str) -> TaskOwner {
+    TaskOwner {
+        tenant: Some(tenant.to_string()),
+        user_id: Some("picard".to_string()),
+    }
+}
+
+async fn submit_for(tasks: &TaskStore, tenant: &str) -> A2ATask {
+    let message = A2AMessage {
+        role: "user".to_string(),
+        parts: vec![],
+        message_id: uuid::Uuid::now_v7().to_string(),
+        task_id: None,
+        context_id: Some("away-mission".to_string()),
+        kind: "message".to_string(),
+        metadata: HashMap::new(),
+    };
+    tasks.submit_for(&message, owner(tenant)).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_watch_task_streams_updates_until_final() {
+    let tasks = Arc::new(TaskStore::new());
+    let client = start_task_endpoint(tasks.clone()).await;
+    let task = submit_for(&tasks, "enterprise").await;
+
+    let mut updates = client
+        .watch_task(&task.id, Some(&bearer("enterprise", SECRET)))
+        .await
+        .expect("the owner should be able to watch its task");
+
+    // The stream opens with the current status
+    let first = timeout(Duration::from_secs(5), updates.recv())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        first,
+        TaskUpdate::Status { ref status, .. } if status.state == TaskState::Submitted
+    ));
+
+    tasks
+        .update_status(&task.id, TaskState::Working, None)
+        .await
+        .unwrap();
+    tasks
+        .add_artifact(
+            &task.id,
+            TaskArtifact {
+                artifact_id: "scan".to_string(),
+                name: Some("sensor sweep".to_string()),
+                data: serde_json::json!({"lifeforms": 3}),
+                append: false,
+                last_chunk: true,
+            },
+        )
+        .await
+        .unwrap();
+    tasks
+        .update_status(&task.id, TaskState::Completed, Some("done".to_string()))
+        .await
+        .unwrap();
+
+    let mut received = Vec::new();
+    while let Some(update) = timeout(Duration::from_secs(5), updates.recv())
+        .await
+        .expect("updates should arrive without polling")
+    {
+        received.push(update.unwrap());
+    }
+
+    assert_eq!(received.len(), 3);
+    assert!(matches!(
+        received[0],
+        TaskUpdate::Status { ref status, is_final: false, .. } if status.state == TaskState::Working
+    ));
+    match &received[1] {
+        TaskUpdate::Artifact { task_id, artifact } => {
+            assert_eq!(task_id, &task.id);
+            assert_eq!(artifact.data, serde_json::json!({"lifeforms": 3}));
+        }
+        other => panic!("expected an artifact update, got {:?}", other),
+    }
+    match &received[2] {
+        TaskUpdate::Status {
+            status, is_final, ..
+        } => {
+            assert!(is_final);
+            assert_eq!(status.state, TaskState::Completed);
+            assert_eq!(status.message.as_deref(), Some("done"));
+        }
+        other => panic!("expected the final status, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_task_cancel_over_http_requires_the_owning_tenant() {
+    let tasks = Arc::new(TaskStore::new());
+    let client = start_task_endpoint(tasks.clone()).await;
+    let task = submit_for(&tasks, "enterprise").await;
+
+    let intruders = [
+        Some(bearer("borg", SECRET)),
+        Some(bearer("enterprise", b"borg-secret")),
+        None,
+    ];
+    for intruder in intruders.iter().map(Option::as_deref) {
+        assert!(client.cancel_task(&task.id, intruder).await.is_err());
+        assert!(client.get_task(&task.id, intruder).await.is_err());
+        assert!(client.watch_task(&task.id, intruder).await.is_err());
+    }
+    assert_eq!(
+        tasks.get(&task.id).await.unwrap().status.state,
+        TaskState::Submitted
+    );
+
+    let canceled = client
+        .cancel_task(&task.id, Some(&bearer("enterprise", SECRET)))
+        .await
+        .expect("the owning tenant should cancel its task");
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+    assert_eq!(canceled.context_id.as_deref(), Some("away-mission"));
+
+    let fetched = client
+        .get_task(&task.id, Some(&bearer("enterprise", SECRET)))
+        .await
+        .unwrap();
+    assert_eq!(fetched.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_task_endpoint_refuses_forged_tenant_header() {
+    let tasks = Arc::new(TaskStore::new());
+    let endpoint = serve_task_endpoint(tasks.clone()).await;
+    let task = submit_for(&tasks, "enterprise").await;
+
+    // Claim the owning tenant through the envelope header instead of a token
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(&endpoint)
+        .header("X-Qollective-Tenant", BASE64_STANDARD.encode("enterprise"))
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tasks/cancel",
+            "params": { "id": task.id },
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(response.get("error").is_some());
+    assert!(response.get("result").is_none());
+    assert_eq!(
+        tasks.get(&task.id).await.unwrap().status.state,
+        TaskState::Submitted
+    );
+}
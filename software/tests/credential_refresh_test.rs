@@ -0,0 +1,217 @@
+// ABOUTME: Integration tests for OAuth2 credential providers and automatic token refresh
+// ABOUTME: Runs a mock token endpoint and API server to exercise caching, refresh and 401 retry
+
+#![cfg(all(feature = "security", feature = "rest-server", feature = "rest-client"))]
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Form, Json, Router};
+use qollective::client::rest::RestClientBuilder;
+use qollective::envelope::{Envelope, Meta};
+use qollective::security::{
+    CredentialProvider, OAuth2Config, OAuth2CredentialProvider, Token, TokenExpirationChecker,
+    TokenValidationError,
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::net::TcpListener;
+
+#[derive(Default)]
+struct MockIssuer {
+    issued: AtomicUsize,
+    api_calls: AtomicUsize,
+}
+
+/// Start the mock token endpoint and API on a random port and return its base URL
+async fn start_mock_server() -> (String, Arc<MockIssuer>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let state = Arc::new(MockIssuer::default());
+
+    let app = Router::new()
+        .route("/token", post(token))
+        .route("/api/scan", post(scan))
+        .with_state(state.clone());
+
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (base_url, state)
+}
+
+async fn token(
+    State(state): State<Arc<MockIssuer>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> (StatusCode, Json<Value>) {
+    let issued = state.issued.fetch_add(1, Ordering::SeqCst) + 1;
+    let access_token = format!("access-{}", issued);
+
+    match form.get("grant_type").map(String::as_str) {
+        Some("client_credentials") => {
+            let expires_in = match form.get("scope").map(String::as_str) {
+                Some("short") => 60,
+                _ => 3600,
+            };
+            (
+                StatusCode::OK,
+                Json(json!({
+                    "access_token": access_token,
+                    "token_type": "Bearer",
+                    "expires_in": expires_in,
+                    "scope": form.get("scope"),
+                })),
+            )
+        }
+        Some("refresh_token")
+            if form.get("refresh_token").map(String::as_str) != Some("rt-bad") =>
+        {
+            (
+                StatusCode::OK,
+                Json(json!({
+                    "access_token": access_token,
+                    "token_type": "Bearer",
+                    "expires_in": 3600,
+                    "refresh_token": format!("rt-{}", issued),
+                })),
+            )
+        }
+        _ => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"error": "invalid_grant", "error_description": "refresh token revoked"})),
+        ),
+    }
+}
+
+/// Rejects the first issued token as if it had been revoked server-side
+async fn scan(
+    State(state): State<Arc<MockIssuer>>,
+    headers: HeaderMap,
+    Json(envelope): Json<Envelope<Value>>,
+) -> Result<Json<Envelope<Value>>, StatusCode> {
+    state.api_calls.fetch_add(1, Ordering::SeqCst);
+
+    match headers.get("authorization").and_then(|v| v.to_str().ok()) {
+        Some("Bearer access-1") | None => Err(StatusCode::UNAUTHORIZED),
+        Some(authorization) => {
+            let (meta, payload) = envelope.extract();
+            Ok(Json(Envelope::new(
+                meta,
+                json!({"sector": payload["sector"], "authorization": authorization}),
+            )))
+        }
+    }
+}
+
+fn oauth2_config(base_url: &str) -> OAuth2Config {
+    OAuth2Config::new()
+        .client_id("tricorder")
+        .client_secret("tricorder-secret")
+        .token_endpoint(&format!("{}/token", base_url))
+        .build()
+}
+
+#[tokio::test]
+async fn test_client_credentials_token_is_cached() {
+    let (base_url, issuer) = start_mock_server().await;
+    let provider =
+        OAuth2CredentialProvider::client_credentials(oauth2_config(&base_url), vec!["scan".into()])
+            .unwrap();
+
+    assert_eq!(provider.access_token().await.unwrap(), "access-1");
+    assert_eq!(provider.access_token().await.unwrap(), "access-1");
+    assert_eq!(issuer.issued.load(Ordering::SeqCst), 1);
+
+    provider.invalidate().await;
+    assert_eq!(provider.access_token().await.unwrap(), "access-2");
+}
+
+#[tokio::test]
+async fn test_token_near_expiration_is_refreshed_proactively() {
+    let (base_url, issuer) = start_mock_server().await;
+    let provider = OAuth2CredentialProvider::client_credentials(
+        oauth2_config(&base_url),
+        vec!["short".into()],
+    )
+    .unwrap();
+
+    // A 60 second token is within the default 5 minute refresh threshold
+    assert_eq!(provider.access_token().await.unwrap(), "access-1");
+    assert_eq!(provider.access_token().await.unwrap(), "access-2");
+
+    let relaxed = OAuth2CredentialProvider::client_credentials(
+        oauth2_config(&base_url),
+        vec!["short".into()],
+    )
+    .unwrap()
+    .with_expiration_checker(
+        TokenExpirationChecker::new().with_refresh_threshold(Duration::from_secs(10)),
+    );
+    assert_eq!(relaxed.access_token().await.unwrap(), "access-3");
+    assert_eq!(relaxed.access_token().await.unwrap(), "access-3");
+    assert_eq!(issuer.issued.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_refresh_token_grant_uses_rotated_refresh_token() {
+    let (base_url, _issuer) = start_mock_server().await;
+    let provider =
+        OAuth2CredentialProvider::refresh_token(oauth2_config(&base_url), "rt-0").unwrap();
+
+    assert_eq!(provider.access_token().await.unwrap(), "access-1");
+    provider.invalidate().await;
+    assert_eq!(provider.access_token().await.unwrap(), "access-2");
+
+    let revoked =
+        OAuth2CredentialProvider::refresh_token(oauth2_config(&base_url), "rt-bad").unwrap();
+    assert!(matches!(
+        revoked.access_token().await,
+        Err(TokenValidationError::RefreshFailed(reason)) if reason.contains("invalid_grant")
+    ));
+}
+
+#[tokio::test]
+async fn test_rest_client_retries_once_after_unauthorized() {
+    let (base_url, issuer) = start_mock_server().await;
+    let provider = Arc::new(
+        OAuth2CredentialProvider::client_credentials(oauth2_config(&base_url), Vec::new()).unwrap(),
+    );
+
+    let client = RestClientBuilder::new()
+        .base_url(&base_url)
+        .credential_provider(provider)
+        .build()
+        .await
+        .unwrap();
+
+    let response: Envelope<Value> = client
+        .post(
+            "/api/scan",
+            Envelope::new(Meta::default(), json!({"sector": "001"})),
+        )
+        .await
+        .expect("request should succeed after refreshing the token");
+
+    assert_eq!(response.payload["sector"], "001");
+    assert_eq!(response.payload["authorization"], "Bearer access-2");
+    assert_eq!(issuer.api_calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_expiration_checker_refresh_threshold() {
+    let token = Token::new(
+        "token".to_string(),
+        "data".to_string(),
+        SystemTime::now() + Duration::from_secs(120),
+        Vec::new(),
+    );
+
+    assert!(TokenExpirationChecker::new().is_near_expiration(&token));
+    assert!(!TokenExpirationChecker::new()
+        .with_refresh_threshold(Duration::from_secs(60))
+        .is_near_expiration(&token));
+}
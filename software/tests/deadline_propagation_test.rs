@@ -0,0 +1,382 @@
+// ABOUTME: Integration tests for request deadline propagation across REST and gRPC
+// ABOUTME: Verifies clients send the remaining time and servers expose and enforce it in handlers
+
+#![cfg(all(
+    feature = "rest-client",
+    feature = "grpc-client",
+    feature = "grpc-server"
+))]
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::{Json, Router};
+use futures_util::{stream, StreamExt};
+use qollective::client::grpc::GrpcClient;
+use qollective::client::rest::RestClientBuilder;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::envelope::{deadline, Context};
+use qollective::error::{QollectiveError, Result};
+use qollective::prelude::{
+    ContextDataHandler, Envelope, Meta, UnifiedEnvelopeReceiver, UnifiedEnvelopeSender,
+};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{
+    BidirectionalStreamingHandler, ClientStreamingHandler, GrpcServer, QollectiveServiceImpl,
+};
+use qollective::transport::grpc::{handler_type_key, EnvelopeStream, GrpcTransport};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+fn meta_with_deadline(timeout: Duration) -> Meta {
+    let mut meta = Meta::for_new_request();
+    meta.deadline = Some(deadline::after(timeout));
+    meta
+}
+
+/// Echo the timeout header the server received back to the client
+async fn echo_timeout_header(
+    headers: HeaderMap,
+    Json(envelope): Json<Envelope<Value>>,
+) -> Json<Envelope<Value>> {
+    let timeout_ms = headers
+        .get(deadline::TIMEOUT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    let (meta, _) = envelope.extract();
+    Json(Envelope::new(meta, json!({ "timeout_ms": timeout_ms })))
+}
+
+#[tokio::test]
+async fn test_rest_client_sends_remaining_time() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let app = Router::new().route("/api/deadline", post(echo_timeout_header));
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let client = RestClientBuilder::new()
+        .base_url(&base_url)
+        .build()
+        .await
+        .unwrap();
+
+    let response: Envelope<Value> = client
+        .post(
+            "/api/deadline",
+            Envelope::new(meta_with_deadline(Duration::from_secs(2)), json!({})),
+        )
+        .await
+        .expect("request should succeed");
+
+    let timeout_ms = response.payload["timeout_ms"]
+        .as_u64()
+        .expect("timeout header should be sent");
+    assert!(timeout_ms > 0 && timeout_ms <= 2000);
+}
+
+#[tokio::test]
+async fn test_rest_client_rejects_expired_deadline() {
+    let client = RestClientBuilder::new()
+        .base_url("http://127.0.0.1:9")
+        .build()
+        .await
+        .unwrap();
+
+    let mut meta = Meta::for_new_request();
+    meta.deadline = Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+    let result: Result<Envelope<Value>> = client
+        .post("/api/deadline", Envelope::new(meta, json!({})))
+        .await;
+
+    assert!(matches!(result, Err(QollectiveError::DeadlineExceeded(_))));
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeadlineProbe {
+    delay_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeadlineReport {
+    remaining_ms: Option<u64>,
+    inherited_ms: Option<u64>,
+}
+
+/// Reports the deadline visible to the handler after an optional delay
+struct DeadlineReportingHandler;
+
+#[async_trait]
+impl ContextDataHandler<DeadlineProbe, DeadlineReport> for DeadlineReportingHandler {
+    async fn handle(
+        &self,
+        context: Option<Context>,
+        data: DeadlineProbe,
+    ) -> Result<DeadlineReport> {
+        sleep(Duration::from_millis(data.delay_ms)).await;
+        let remaining = |deadline| deadline::remaining(deadline).as_millis() as u64;
+        Ok(DeadlineReport {
+            remaining_ms: context.and_then(|ctx| ctx.meta().deadline).map(remaining),
+            // What an outbound call made from here would send
+            inherited_ms: deadline::outgoing(None, None).map(remaining),
+        })
+    }
+}
+
+fn deadline_report(context: Option<Context>) -> DeadlineReport {
+    let remaining = |deadline| deadline::remaining(deadline).as_millis() as u64;
+    DeadlineReport {
+        remaining_ms: context.and_then(|ctx| ctx.meta().deadline).map(remaining),
+        inherited_ms: deadline::outgoing(None, None).map(remaining),
+    }
+}
+
+#[async_trait]
+impl ClientStreamingHandler<DeadlineProbe, DeadlineReport> for DeadlineReportingHandler {
+    async fn handle(
+        &self,
+        mut probes: EnvelopeStream<DeadlineProbe>,
+    ) -> Result<Envelope<DeadlineReport>> {
+        while let Some(probe) = probes.next().await {
+            sleep(Duration::from_millis(probe?.payload.delay_ms)).await;
+        }
+        Ok(Envelope::new(
+            Meta::for_new_request(),
+            deadline_report(Context::current()),
+        ))
+    }
+}
+
+#[async_trait]
+impl BidirectionalStreamingHandler<DeadlineProbe, DeadlineReport> for DeadlineReportingHandler {
+    async fn handle(
+        &self,
+        probes: EnvelopeStream<DeadlineProbe>,
+    ) -> Result<EnvelopeStream<DeadlineReport>> {
+        Ok(Box::pin(probes.then(|probe| async move {
+            let (meta, probe) = probe?.extract();
+            sleep(Duration::from_millis(probe.delay_ms)).await;
+            Ok(Envelope::new(
+                Meta::for_new_request(),
+                deadline_report(Some(Context::new(meta))),
+            ))
+        })))
+    }
+}
+
+async fn start_grpc_server() -> (u16, tokio::task::JoinHandle<()>) {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+
+    let service_impl = QollectiveServiceImpl::new();
+    let type_key = handler_type_key::<DeadlineProbe, DeadlineReport>();
+    service_impl
+        .register_client_streaming_handler(type_key.clone(), DeadlineReportingHandler)
+        .await
+        .expect("client streaming handler registration should succeed");
+    service_impl
+        .register_bidirectional_streaming_handler(type_key, DeadlineReportingHandler)
+        .await
+        .expect("bidirectional streaming handler registration should succeed");
+    server
+        .register_service(service_impl)
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope_at("/deadline/report", DeadlineReportingHandler)
+        .await
+        .expect("route registration should succeed");
+
+    let server_handle = tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            println!("❌ gRPC server failed: {}", e);
+        }
+    });
+
+    // Give server time to start
+    sleep(Duration::from_millis(100)).await;
+    (server_port, server_handle)
+}
+
+async fn grpc_client(server_port: u16) -> GrpcClient {
+    grpc_client_with_timeout(server_port, 5000).await
+}
+
+async fn grpc_client_with_timeout(server_port: u16, timeout_ms: u64) -> GrpcClient {
+    GrpcClient::new(GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", server_port)),
+        timeout_ms,
+        ..Default::default()
+    })
+    .await
+    .expect("gRPC client should connect")
+}
+
+#[tokio::test]
+async fn test_grpc_handler_sees_and_inherits_caller_deadline() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let client = grpc_client(server_port).await;
+
+    let response: Envelope<DeadlineReport> = client
+        .send_envelope_to(
+            "/deadline/report",
+            Envelope::new(
+                meta_with_deadline(Duration::from_secs(2)),
+                DeadlineProbe { delay_ms: 0 },
+            ),
+        )
+        .await
+        .expect("deadline call should succeed");
+
+    let remaining_ms = response
+        .payload
+        .remaining_ms
+        .expect("handler should see the deadline");
+    assert!(remaining_ms > 0 && remaining_ms <= 2000);
+    let inherited_ms = response
+        .payload
+        .inherited_ms
+        .expect("outbound calls should inherit it");
+    assert!(inherited_ms <= remaining_ms);
+    assert!(response.meta.deadline.is_none());
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_call_fails_once_deadline_passes() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let client = grpc_client(server_port).await;
+
+    let result: Result<Envelope<DeadlineReport>> = client
+        .send_envelope_to(
+            "/deadline/report",
+            Envelope::new(
+                meta_with_deadline(Duration::from_millis(200)),
+                DeadlineProbe { delay_ms: 2000 },
+            ),
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(QollectiveError::DeadlineExceeded(_))),
+        "{:?}",
+        result.map(|_| ())
+    );
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_transport_reports_deadline_expiry() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let transport = GrpcTransport::new(&format!("http://127.0.0.1:{}", server_port))
+        .await
+        .expect("gRPC transport should connect")
+        .with_route_dispatch(true);
+
+    let result: Result<Envelope<DeadlineReport>> = transport
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/deadline/report", server_port),
+            Envelope::new(
+                meta_with_deadline(Duration::from_millis(200)),
+                DeadlineProbe { delay_ms: 2000 },
+            ),
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(QollectiveError::DeadlineExceeded(_))),
+        "{:?}",
+        result.map(|_| ())
+    );
+
+    server_handle.abort();
+}
+
+fn probe(meta: Meta, delay_ms: u64) -> Envelope<DeadlineProbe> {
+    Envelope::new(meta, DeadlineProbe { delay_ms })
+}
+
+#[tokio::test]
+async fn test_grpc_client_streaming_sends_first_request_deadline() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let client = grpc_client(server_port).await;
+
+    let probes = stream::iter(vec![
+        probe(meta_with_deadline(Duration::from_secs(2)), 0),
+        probe(Meta::for_new_request(), 0),
+    ]);
+    let response: Envelope<DeadlineReport> = client
+        .send_client_streaming(probes)
+        .await
+        .expect("client streaming call should succeed");
+
+    let remaining_ms = response
+        .payload
+        .remaining_ms
+        .expect("handler should see the deadline");
+    assert!(remaining_ms > 0 && remaining_ms <= 2000);
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_client_streaming_fails_after_request_timeout() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let client = grpc_client_with_timeout(server_port, 200).await;
+
+    let result: Result<Envelope<DeadlineReport>> = client
+        .send_client_streaming(stream::iter(vec![probe(Meta::for_new_request(), 2000)]))
+        .await;
+
+    assert!(
+        matches!(result, Err(QollectiveError::DeadlineExceeded(_))),
+        "{:?}",
+        result.map(|_| ())
+    );
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_bidirectional_streaming_fails_once_deadline_passes() {
+    let (server_port, server_handle) = start_grpc_server().await;
+    let client = grpc_client(server_port).await;
+
+    let probes = stream::iter(vec![probe(
+        meta_with_deadline(Duration::from_millis(200)),
+        2000,
+    )]);
+    let responses: Vec<Result<Envelope<DeadlineReport>>> = client
+        .send_bidirectional_streaming::<DeadlineProbe, DeadlineReport, _>(probes)
+        .await
+        .expect("bidirectional streaming call should start")
+        .collect()
+        .await;
+
+    assert!(
+        matches!(
+            responses.last(),
+            Some(Err(QollectiveError::DeadlineExceeded(_)))
+        ),
+        "{:?}",
+        responses
+            .iter()
+            .map(|r| r.as_ref().map(|_| ()))
+            .collect::<Vec<_>>()
+    );
+
+    server_handle.abort();
+}
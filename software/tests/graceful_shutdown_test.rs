@@ -0,0 +1,179 @@
+// ABOUTME: Integration tests for draining REST and gRPC servers through a shared ShutdownCoordinator
+// ABOUTME: Verifies in-flight requests finish, new work is refused and overrunning requests are reported
+
+#![cfg(all(
+    feature = "rest-server",
+    feature = "rest-client",
+    feature = "grpc-server",
+    feature = "grpc-client"
+))]
+
+use async_trait::async_trait;
+use qollective::client::grpc::GrpcClient;
+use qollective::client::rest::RestClientBuilder;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::envelope::{Context, Envelope, Meta};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, UnifiedEnvelopeReceiver};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{GrpcServer, QollectiveServiceImpl};
+use qollective::server::rest::{RestServer, RestServerConfig};
+use qollective::server::ShutdownCoordinator;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+/// Answers after sleeping for `delay_ms` from the request payload
+struct SlowHandler;
+
+#[async_trait]
+impl ContextDataHandler<Value, Value> for SlowHandler {
+    async fn handle(&self, _context: Option<Context>, data: Value) -> Result<Value> {
+        sleep(Duration::from_millis(
+            data["delay_ms"].as_u64().unwrap_or(0),
+        ))
+        .await;
+        Ok(json!({ "done": true }))
+    }
+}
+
+fn server_config(port: u16) -> ServerConfig {
+    ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port,
+        max_connections: 100,
+    }
+}
+
+#[tokio::test]
+async fn test_rest_server_finishes_in_flight_requests_and_refuses_new_ones() {
+    let port = get_available_port();
+    let coordinator = ShutdownCoordinator::with_grace_period(Duration::from_secs(5));
+
+    let mut server = RestServer::new(RestServerConfig {
+        base: server_config(port),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .with_shutdown(coordinator.clone());
+    server
+        .receive_envelope_at("/drain/slow", SlowHandler)
+        .await
+        .unwrap();
+    let server_handle = tokio::spawn(async move { server.start().await });
+    sleep(Duration::from_millis(200)).await;
+
+    let base_url = format!("http://127.0.0.1:{}", port);
+    let client = RestClientBuilder::new()
+        .base_url(&base_url)
+        .build()
+        .await
+        .unwrap();
+    let in_flight = tokio::spawn(async move {
+        client
+            .post::<Value, Value>(
+                "/drain/slow",
+                Envelope::new(Meta::for_new_request(), json!({ "delay_ms": 500 })),
+            )
+            .await
+    });
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(coordinator.in_flight().len(), 1);
+
+    let drain = tokio::spawn({
+        let coordinator = coordinator.clone();
+        async move { coordinator.shutdown().await }
+    });
+    sleep(Duration::from_millis(50)).await;
+
+    // Draining closes the listener, so a new request is refused or unanswered
+    let refused = reqwest::Client::new()
+        .post(format!("{}/drain/slow", base_url))
+        .json(&Envelope::new(
+            Meta::for_new_request(),
+            json!({ "delay_ms": 0 }),
+        ))
+        .send()
+        .await;
+    assert!(refused.map_or(true, |response| response.status() == 503));
+
+    let response = in_flight
+        .await
+        .unwrap()
+        .expect("in-flight request should finish");
+    assert_eq!(response.payload["done"], true);
+
+    let report = drain.await.unwrap();
+    assert!(report.is_clean(), "{:?}", report);
+    assert_eq!(report.completed, 1);
+    timeout(Duration::from_secs(2), server_handle)
+        .await
+        .expect("server should stop once drained")
+        .unwrap()
+        .unwrap();
+}
+
+async fn start_grpc_server(coordinator: ShutdownCoordinator) -> u16 {
+    setup_test_environment();
+    let port = get_available_port();
+    let mut server = GrpcServer::new(server_config(port)).with_shutdown(coordinator);
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .unwrap();
+    server
+        .receive_envelope_at("/drain/slow", SlowHandler)
+        .await
+        .unwrap();
+    tokio::spawn(async move { server.serve().await });
+    sleep(Duration::from_millis(100)).await;
+    port
+}
+
+async fn grpc_client(port: u16) -> GrpcClient {
+    GrpcClient::new(GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", port)),
+        timeout_ms: 5000,
+        ..Default::default()
+    })
+    .await
+    .expect("gRPC client should connect")
+}
+
+#[tokio::test]
+async fn test_grpc_server_reports_requests_cut_off_after_grace_period() {
+    let coordinator = ShutdownCoordinator::with_grace_period(Duration::from_millis(200));
+    let port = start_grpc_server(coordinator.clone()).await;
+    let client = grpc_client(port).await;
+
+    let slow_client = grpc_client(port).await;
+    let slow_call = tokio::spawn(async move {
+        slow_client
+            .send_envelope_to::<Value, Value>(
+                "/drain/slow",
+                Envelope::new(Meta::for_new_request(), json!({ "delay_ms": 3000 })),
+            )
+            .await
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let report = coordinator.shutdown().await;
+    assert_eq!(report.completed, 0);
+    assert_eq!(report.cut_off.len(), 1);
+    assert_eq!(report.cut_off[0].server, "grpc");
+    assert_eq!(report.cut_off[0].operation, "/drain/slow");
+    assert!(report.elapsed >= Duration::from_millis(200));
+
+    // Refused while the old connection is still open
+    let refused: Result<Envelope<Value>> = client
+        .send_envelope_to(
+            "/drain/slow",
+            Envelope::new(Meta::for_new_request(), json!({ "delay_ms": 0 })),
+        )
+        .await;
+    assert!(refused.is_err());
+    slow_call.abort();
+}
@@ -0,0 +1,322 @@
+// ABOUTME: Integration tests for route-based gRPC envelope dispatch
+// ABOUTME: Validates UnifiedEnvelopeReceiver::receive_envelope_at over a real tonic server
+
+use async_trait::async_trait;
+use qollective::client::grpc::GrpcClient;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::envelope::Context;
+use qollective::error::Result;
+use qollective::prelude::{
+    ContextDataHandler, Envelope, Meta, UnifiedEnvelopeReceiver, UnifiedEnvelopeSender,
+};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{GrpcServer, QollectiveServiceImpl};
+use qollective::transport::grpc::{GrpcTransport, InternalGrpcClient};
+use qollective::transport::{HybridTransportClient, TransportDetectionConfig};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct OrderRequest {
+    item: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct OrderResponse {
+    handled_by: String,
+    item: String,
+}
+
+/// Handler that tags each response with the route it was registered at
+struct RouteHandler {
+    name: &'static str,
+}
+
+#[async_trait]
+impl ContextDataHandler<OrderRequest, OrderResponse> for RouteHandler {
+    async fn handle(&self, _context: Option<Context>, data: OrderRequest) -> Result<OrderResponse> {
+        Ok(OrderResponse {
+            handled_by: self.name.to_string(),
+            item: data.item,
+        })
+    }
+}
+
+fn order(item: &str) -> Envelope<OrderRequest> {
+    Envelope::new(
+        Meta::for_new_request(),
+        OrderRequest {
+            item: item.to_string(),
+        },
+    )
+}
+
+async fn start_route_server() -> (u16, tokio::task::JoinHandle<()>) {
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope_at("/orders/create", RouteHandler { name: "create" })
+        .await
+        .expect("create route registration should succeed");
+    server
+        .receive_envelope_at("orders/cancel", RouteHandler { name: "cancel" })
+        .await
+        .expect("cancel route registration should succeed");
+
+    let handle = tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            println!("❌ gRPC server failed: {}", e);
+        }
+    });
+
+    // Give server time to start
+    sleep(Duration::from_millis(100)).await;
+
+    (server_port, handle)
+}
+
+async fn connect_client(server_port: u16) -> GrpcClient {
+    let mut client_config = GrpcClientConfig::default();
+    client_config.base_url = Some(format!("http://127.0.0.1:{}", server_port));
+    client_config.timeout_ms = 5000;
+
+    GrpcClient::new(client_config)
+        .await
+        .expect("gRPC client should connect")
+}
+
+#[tokio::test]
+async fn test_grpc_envelopes_dispatch_to_registered_routes() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_route_server().await;
+    let client = connect_client(server_port).await;
+
+    let created: Envelope<OrderResponse> = client
+        .send_envelope_to("orders/create", order("phaser"))
+        .await
+        .expect("create route call should succeed");
+    let cancelled: Envelope<OrderResponse> = client
+        .send_envelope_to("/orders/cancel", order("tricorder"))
+        .await
+        .expect("cancel route call should succeed");
+
+    assert_eq!(created.payload.handled_by, "create");
+    assert_eq!(created.payload.item, "phaser");
+    assert_eq!(cancelled.payload.handled_by, "cancel");
+    assert_eq!(cancelled.payload.item, "tricorder");
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_unknown_route_is_not_found() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_route_server().await;
+    let client = connect_client(server_port).await;
+
+    let result: Result<Envelope<OrderResponse>> = client
+        .send_envelope_to("/orders/refund", order("shuttle"))
+        .await;
+
+    let error = result.expect_err("unknown route should fail");
+    let message = error.to_string();
+    assert!(
+        message.contains("NotFound") || message.contains("not found"),
+        "unexpected error: {}",
+        message
+    );
+    assert!(
+        message.contains("/orders/refund"),
+        "unexpected error: {}",
+        message
+    );
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_transport_reaches_type_keyed_handler_at_service_method_endpoint() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope(RouteHandler { name: "typed" })
+        .await
+        .expect("type-keyed registration should succeed");
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let transport = GrpcTransport::new(&format!("http://127.0.0.1:{}", server_port))
+        .await
+        .expect("gRPC transport should connect");
+    let response: Envelope<OrderResponse> = transport
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/OrderService/Create", server_port),
+            order("warp-coil"),
+        )
+        .await
+        .expect("without route dispatch the type-keyed handler should answer");
+
+    assert_eq!(response.payload.handled_by, "typed");
+    assert_eq!(response.payload.item, "warp-coil");
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_unknown_route_is_not_found_despite_type_keyed_handler() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope(RouteHandler { name: "typed" })
+        .await
+        .expect("type-keyed registration should succeed");
+    server
+        .receive_envelope_at("/orders/create", RouteHandler { name: "create" })
+        .await
+        .expect("create route registration should succeed");
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let transport = GrpcTransport::new(&format!("http://127.0.0.1:{}", server_port))
+        .await
+        .expect("gRPC transport should connect")
+        .with_route_dispatch(true);
+    let routed: Envelope<OrderResponse> = transport
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/orders/create", server_port),
+            order("phaser"),
+        )
+        .await
+        .expect("the route handler should answer");
+    assert_eq!(routed.payload.handled_by, "create");
+
+    let result: Result<Envelope<OrderResponse>> = transport
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/OrderService/Create", server_port),
+            order("warp-coil"),
+        )
+        .await;
+
+    let message = result
+        .expect_err("an unknown route must not reach the type-keyed handler")
+        .to_string();
+    assert!(
+        message.contains("NotFound") || message.contains("not found"),
+        "unexpected error: {}",
+        message
+    );
+    assert!(
+        message.contains("/OrderService/Create"),
+        "unexpected error: {}",
+        message
+    );
+
+    server_handle.abort();
+}
+
+async fn hybrid_client(server_port: u16) -> HybridTransportClient {
+    let grpc_client = InternalGrpcClient::new(GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", server_port)),
+        timeout_ms: 5000,
+        ..Default::default()
+    })
+    .await
+    .expect("gRPC client should connect");
+
+    HybridTransportClient::new(TransportDetectionConfig::default())
+        .with_internal_grpc_client(Arc::new(grpc_client))
+}
+
+#[tokio::test]
+async fn test_hybrid_client_reaches_type_keyed_handler_at_service_method_endpoint() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope(RouteHandler { name: "typed" })
+        .await
+        .expect("type-keyed registration should succeed");
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let client = hybrid_client(server_port).await;
+    let response: Envelope<OrderResponse> = client
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/OrderService/Create", server_port),
+            order("warp-coil"),
+        )
+        .await
+        .expect("without route dispatch the type-keyed handler should answer");
+
+    assert_eq!(response.payload.handled_by, "typed");
+    assert_eq!(response.payload.item, "warp-coil");
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_hybrid_client_dispatches_by_route_when_enabled() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_route_server().await;
+
+    let client = hybrid_client(server_port)
+        .await
+        .with_grpc_route_dispatch(true);
+    let response: Envelope<OrderResponse> = client
+        .send_envelope(
+            &format!("grpc://127.0.0.1:{}/orders/cancel", server_port),
+            order("tricorder"),
+        )
+        .await
+        .expect("the route handler should answer");
+
+    assert_eq!(response.payload.handled_by, "cancel");
+    assert_eq!(response.payload.item, "tricorder");
+
+    server_handle.abort();
+}
@@ -0,0 +1,204 @@
+// ABOUTME: Integration tests for service chain tracking over gRPC
+// ABOUTME: Validates that server hops are appended to Meta and survive the protobuf round-trip
+
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use qollective::client::grpc::GrpcClient;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::envelope::{Context, ServiceChainEntry};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, Envelope, Meta, UnifiedEnvelopeReceiver};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{
+    BidirectionalStreamingHandler, ClientStreamingHandler, GrpcServer, QollectiveServiceImpl,
+};
+use qollective::transport::grpc::{handler_type_key, EnvelopeStream};
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct ScanRequest {
+    sector: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct ScanResponse {
+    hops_seen: usize,
+}
+
+/// Handler that reports how many hops it saw in its context
+struct ChainAwareHandler;
+
+#[async_trait]
+impl ContextDataHandler<ScanRequest, ScanResponse> for ChainAwareHandler {
+    async fn handle(&self, context: Option<Context>, _data: ScanRequest) -> Result<ScanResponse> {
+        let hops_seen = context
+            .map(|ctx| ctx.meta().service_chain.len())
+            .unwrap_or_default();
+        Ok(ScanResponse { hops_seen })
+    }
+}
+
+/// Reports how many hops each streamed request carried
+struct StreamedChainHandler;
+
+#[async_trait]
+impl ClientStreamingHandler<ScanRequest, ScanResponse> for StreamedChainHandler {
+    async fn handle(
+        &self,
+        mut requests: EnvelopeStream<ScanRequest>,
+    ) -> Result<Envelope<ScanResponse>> {
+        let mut hops_seen = 0;
+        while let Some(request) = requests.next().await {
+            hops_seen += request?.meta.service_chain.len();
+        }
+        Ok(Envelope::new(Meta::for_new_request(), ScanResponse { hops_seen }))
+    }
+}
+
+#[async_trait]
+impl BidirectionalStreamingHandler<ScanRequest, ScanResponse> for StreamedChainHandler {
+    async fn handle(
+        &self,
+        requests: EnvelopeStream<ScanRequest>,
+    ) -> Result<EnvelopeStream<ScanResponse>> {
+        Ok(Box::pin(requests.map(|request| {
+            let hops_seen = request?.meta.service_chain.len();
+            Ok(Envelope::new(Meta::for_new_request(), ScanResponse { hops_seen }))
+        })))
+    }
+}
+
+fn scan(sector: &str) -> Envelope<ScanRequest> {
+    Envelope::new(
+        Meta::for_new_request(),
+        ScanRequest {
+            sector: sector.to_string(),
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_grpc_streamed_requests_record_service_hop() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    let service_impl = QollectiveServiceImpl::new();
+    let type_key = handler_type_key::<ScanRequest, ScanResponse>();
+    service_impl
+        .register_client_streaming_handler(type_key.clone(), StreamedChainHandler)
+        .await
+        .unwrap();
+    service_impl
+        .register_bidirectional_streaming_handler(type_key, StreamedChainHandler)
+        .await
+        .unwrap();
+    server.register_service(service_impl).await.unwrap();
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let client = GrpcClient::new(GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", server_port)),
+        timeout_ms: 5000,
+        ..Default::default()
+    })
+    .await
+    .expect("gRPC client should connect");
+
+    // Each streamed request arrives with this server recorded as its one hop
+    let response: Envelope<ScanResponse> = client
+        .send_client_streaming(stream::iter(vec![scan("001"), scan("002")]))
+        .await
+        .expect("client streaming call should succeed");
+    assert_eq!(response.payload.hops_seen, 2);
+
+    let hops: Vec<usize> = client
+        .send_bidirectional_streaming::<ScanRequest, ScanResponse, _>(stream::iter(vec![
+            scan("003"),
+            scan("004"),
+        ]))
+        .await
+        .expect("bidirectional streaming call should succeed")
+        .map(|response| response.unwrap().payload.hops_seen)
+        .collect()
+        .await;
+    assert_eq!(hops, vec![1, 1]);
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_server_appends_service_hop() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope_at("/sensors/scan", ChainAwareHandler)
+        .await
+        .expect("route registration should succeed");
+
+    let server_handle = tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            println!("❌ gRPC server failed: {}", e);
+        }
+    });
+
+    // Give server time to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_config = GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", server_port)),
+        timeout_ms: 5000,
+        ..Default::default()
+    };
+    let client = GrpcClient::new(client_config)
+        .await
+        .expect("gRPC client should connect");
+
+    // The request already passed through an upstream gateway
+    let mut meta = Meta::for_new_request();
+    let mut gateway = ServiceChainEntry::new("gateway", "1.0.0").with_request_id(meta.request_id);
+    gateway.complete();
+    meta.service_chain.push(gateway.clone());
+
+    let response: Envelope<ScanResponse> = client
+        .send_envelope_to(
+            "/sensors/scan",
+            Envelope::new(
+                meta,
+                ScanRequest {
+                    sector: "001".to_string(),
+                },
+            ),
+        )
+        .await
+        .expect("scan call should succeed");
+
+    assert_eq!(response.payload.hops_seen, 2);
+
+    let chain = &response.meta.service_chain;
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[0], gateway);
+    assert_eq!(chain[1].request_id, response.meta.request_id);
+    assert!(chain[1].duration.is_some());
+
+    server_handle.abort();
+}
@@ -0,0 +1,310 @@
+// ABOUTME: Integration tests for gRPC streaming handlers and client-side envelope streams
+// ABOUTME: Validates server, client and bidirectional streaming over a real tonic server
+
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use qollective::client::grpc::GrpcClient;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::error::Result;
+use qollective::prelude::{Envelope, Meta};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{
+    BidirectionalStreamingHandler, ClientStreamingHandler, GrpcServer, QollectiveServiceImpl,
+    ServerStreamingHandler,
+};
+use qollective::transport::grpc::{handler_type_key, EnvelopeStream};
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct JobRequest {
+    name: String,
+    steps: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct JobProgress {
+    name: String,
+    step: u32,
+}
+
+/// Emits one progress envelope per requested step
+struct ProgressHandler;
+
+#[async_trait]
+impl ServerStreamingHandler<JobRequest, JobProgress> for ProgressHandler {
+    async fn handle(&self, request: Envelope<JobRequest>) -> Result<EnvelopeStream<JobProgress>> {
+        let (meta, job) = request.extract();
+        let updates = (1..=job.steps).map(move |step| {
+            Ok(Envelope::new(
+                Meta::preserve_for_response(Some(&meta)),
+                JobProgress {
+                    name: job.name.clone(),
+                    step,
+                },
+            ))
+        });
+        Ok(Box::pin(stream::iter(updates.collect::<Vec<_>>())))
+    }
+}
+
+/// Sums the steps of all received jobs
+struct TotalStepsHandler;
+
+#[async_trait]
+impl ClientStreamingHandler<JobRequest, JobProgress> for TotalStepsHandler {
+    async fn handle(
+        &self,
+        mut requests: EnvelopeStream<JobRequest>,
+    ) -> Result<Envelope<JobProgress>> {
+        let mut total = 0;
+        let mut names = Vec::new();
+        while let Some(envelope) = requests.next().await {
+            let (_, job) = envelope?.extract();
+            total += job.steps;
+            names.push(job.name);
+        }
+        Ok(Envelope::new(
+            Meta::for_new_request(),
+            JobProgress {
+                name: names.join(","),
+                step: total,
+            },
+        ))
+    }
+}
+
+/// Answers each job with its final progress envelope
+struct EchoProgressHandler;
+
+#[async_trait]
+impl BidirectionalStreamingHandler<JobRequest, JobProgress> for EchoProgressHandler {
+    async fn handle(
+        &self,
+        requests: EnvelopeStream<JobRequest>,
+    ) -> Result<EnvelopeStream<JobProgress>> {
+        Ok(Box::pin(requests.map(|envelope| {
+            let (meta, job) = envelope?.extract();
+            Ok(Envelope::new(
+                Meta::preserve_for_response(Some(&meta)),
+                JobProgress {
+                    name: job.name,
+                    step: job.steps,
+                },
+            ))
+        })))
+    }
+}
+
+fn job(name: &str, steps: u32) -> Envelope<JobRequest> {
+    Envelope::new(
+        Meta::for_new_request(),
+        JobRequest {
+            name: name.to_string(),
+            steps,
+        },
+    )
+}
+
+async fn start_streaming_server() -> (u16, tokio::task::JoinHandle<()>) {
+    let server_port = get_available_port();
+    let server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+
+    let service_impl = QollectiveServiceImpl::new();
+    let type_key = handler_type_key::<JobRequest, JobProgress>();
+    service_impl
+        .register_server_streaming_handler(type_key.clone(), ProgressHandler)
+        .await
+        .expect("server streaming handler registration should succeed");
+    service_impl
+        .register_client_streaming_handler(type_key.clone(), TotalStepsHandler)
+        .await
+        .expect("client streaming handler registration should succeed");
+    service_impl
+        .register_bidirectional_streaming_handler(type_key, EchoProgressHandler)
+        .await
+        .expect("bidirectional streaming handler registration should succeed");
+    server
+        .register_service(service_impl)
+        .await
+        .expect("service registration should succeed");
+
+    let handle = tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            println!("❌ gRPC server failed: {}", e);
+        }
+    });
+
+    // Give server time to start
+    sleep(Duration::from_millis(100)).await;
+
+    (server_port, handle)
+}
+
+async fn connect_client(server_port: u16) -> GrpcClient {
+    let mut client_config = GrpcClientConfig::default();
+    client_config.base_url = Some(format!("http://127.0.0.1:{}", server_port));
+    client_config.timeout_ms = 5000;
+
+    GrpcClient::new(client_config)
+        .await
+        .expect("gRPC client should connect")
+}
+
+#[tokio::test]
+async fn test_grpc_server_streaming_roundtrip() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_streaming_server().await;
+    let client = connect_client(server_port).await;
+
+    let request = job("warp-core-diagnostic", 3);
+    let request_id = request.meta.request_id;
+
+    let responses: Vec<Envelope<JobProgress>> = client
+        .send_server_streaming::<JobRequest, JobProgress>(request)
+        .await
+        .expect("server streaming call should succeed")
+        .map(|item| item.expect("stream item should decode"))
+        .collect()
+        .await;
+
+    let steps: Vec<u32> = responses.iter().map(|e| e.payload.step).collect();
+    assert_eq!(steps, vec![1, 2, 3]);
+    assert!(responses
+        .iter()
+        .all(|e| e.payload.name == "warp-core-diagnostic"));
+    assert!(responses.iter().all(|e| e.meta.request_id == request_id));
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_client_streaming_roundtrip() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_streaming_server().await;
+    let client = connect_client(server_port).await;
+
+    let requests = stream::iter(vec![job("shields", 2), job("sensors", 5), job("comms", 1)]);
+
+    let response: Envelope<JobProgress> = client
+        .send_client_streaming(requests)
+        .await
+        .expect("client streaming call should succeed");
+
+    assert_eq!(response.payload.name, "shields,sensors,comms");
+    assert_eq!(response.payload.step, 8);
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_bidirectional_streaming_roundtrip() {
+    setup_test_environment();
+    let (server_port, server_handle) = start_streaming_server().await;
+    let client = connect_client(server_port).await;
+
+    let requests = stream::iter(vec![job("impulse", 4), job("transporter", 7)]);
+
+    let responses: Vec<JobProgress> = client
+        .send_bidirectional_streaming::<JobRequest, JobProgress, _>(requests)
+        .await
+        .expect("bidirectional streaming call should succeed")
+        .map(|item| item.expect("stream item should decode").payload)
+        .collect()
+        .await;
+
+    assert_eq!(
+        responses,
+        vec![
+            JobProgress {
+                name: "impulse".to_string(),
+                step: 4
+            },
+            JobProgress {
+                name: "transporter".to_string(),
+                step: 7
+            },
+        ]
+    );
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_server_registers_streaming_handlers_by_type() {
+    setup_test_environment();
+
+    let server_port = get_available_port();
+    let server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .register_server_streaming_handler(ProgressHandler)
+        .await
+        .expect("server streaming handler registration should succeed");
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let client = connect_client(server_port).await;
+    let steps: Vec<u32> = client
+        .send_server_streaming::<JobRequest, JobProgress>(job("deflector", 2))
+        .await
+        .expect("server streaming call should succeed")
+        .map(|item| item.expect("stream item should decode").payload.step)
+        .collect()
+        .await;
+    assert_eq!(steps, vec![1, 2]);
+
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_grpc_streaming_without_handler_is_unimplemented() {
+    setup_test_environment();
+
+    let server_port = get_available_port();
+    let server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    let server_handle = tokio::spawn(async move {
+        let _ = server.serve().await;
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let client = connect_client(server_port).await;
+    let result = client
+        .send_server_streaming::<JobRequest, JobProgress>(job("idle", 1))
+        .await;
+
+    let error = result.err().expect("streaming without handler should fail");
+    assert!(
+        error
+            .to_string()
+            .contains("No server streaming handler registered"),
+        "unexpected error: {}",
+        error
+    );
+
+    server_handle.abort();
+}
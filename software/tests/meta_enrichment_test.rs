@@ -0,0 +1,120 @@
+// ABOUTME: Integration tests for metadata enrichers registered on servers
+// ABOUTME: Verifies responses carry performance, monitoring and response size metadata
+
+#![cfg(all(feature = "rest-server", feature = "rest-client"))]
+
+use async_trait::async_trait;
+use qollective::client::rest::RestClientBuilder;
+use qollective::envelope::enrichment::PerformanceEnricher;
+use qollective::envelope::{Context, Envelope, Meta, MetaEnrichers, ServiceIdentity};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, UnifiedEnvelopeReceiver};
+use qollective::server::common::ServerConfig;
+use qollective::server::rest::{RestServer, RestServerConfig};
+use serde_json::{json, Value};
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::get_available_port;
+
+/// Answers with a fixed payload after a short delay
+struct SleepyHandler;
+
+#[async_trait]
+impl ContextDataHandler<Value, Value> for SleepyHandler {
+    async fn handle(&self, _context: Option<Context>, _data: Value) -> Result<Value> {
+        sleep(Duration::from_millis(20)).await;
+        Ok(json!({ "greeting": "hello" }))
+    }
+}
+
+async fn start_rest_server(enrichers: MetaEnrichers) -> String {
+    let port = get_available_port();
+    let mut server = RestServer::new(RestServerConfig {
+        base: ServerConfig {
+            bind_address: "127.0.0.1".to_string(),
+            port,
+            max_connections: 100,
+        },
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .with_enrichers(enrichers);
+    server
+        .receive_envelope_at("/enrich/hello", SleepyHandler)
+        .await
+        .unwrap();
+    tokio::spawn(async move { server.start().await });
+    sleep(Duration::from_millis(200)).await;
+    format!("http://127.0.0.1:{}", port)
+}
+
+async fn call(base_url: &str) -> Envelope<Value> {
+    RestClientBuilder::new()
+        .base_url(base_url)
+        .build()
+        .await
+        .unwrap()
+        .post::<Value, Value>(
+            "/enrich/hello",
+            Envelope::new(Meta::for_new_request(), json!({})),
+        )
+        .await
+        .expect("request should succeed")
+}
+
+#[tokio::test]
+async fn test_rest_responses_carry_enriched_metadata() {
+    let identity = ServiceIdentity::default()
+        .with_host("enrich-host")
+        .with_instance_id("enrich-1")
+        .with_build_version("9.9.9")
+        .with_environment("staging");
+    let base_url = start_rest_server(MetaEnrichers::standard(identity)).await;
+
+    let response = call(&base_url).await;
+    assert_eq!(response.payload["greeting"], "hello");
+
+    let performance = response
+        .meta
+        .performance
+        .as_ref()
+        .expect("performance metadata");
+    assert!(performance.processing_time_ms.unwrap() >= 20);
+    #[cfg(target_os = "linux")]
+    {
+        assert!(performance.memory_allocated.unwrap() > 0);
+        assert!(performance.thread_count.unwrap() > 0);
+    }
+
+    let monitoring = response
+        .meta
+        .monitoring
+        .as_ref()
+        .expect("monitoring metadata");
+    assert_eq!(monitoring.server_id.as_deref(), Some("enrich-host"));
+    assert_eq!(monitoring.deployment_id.as_deref(), Some("enrich-1"));
+    assert_eq!(monitoring.build_version.as_deref(), Some("9.9.9"));
+    assert!(monitoring.environment.is_some());
+
+    let size = &response
+        .meta
+        .extensions
+        .as_ref()
+        .expect("extensions")
+        .sections[PerformanceEnricher::RESPONSE_EXTENSION]["size_bytes"];
+    assert_eq!(
+        size.as_u64(),
+        Some(serde_json::to_vec(&response.payload).unwrap().len() as u64)
+    );
+}
+
+#[tokio::test]
+async fn test_rest_responses_are_not_enriched_without_enrichers() {
+    let base_url = start_rest_server(MetaEnrichers::new()).await;
+
+    let response = call(&base_url).await;
+    assert!(response.meta.performance.is_none());
+    assert!(response.meta.monitoring.is_none());
+}
@@ -0,0 +1,99 @@
+// ABOUTME: Integration test for the Prometheus metrics endpoint mounted on RestServer
+// ABOUTME: Sends envelope requests and checks the scraped exposition for counters, histograms and gauges
+
+#![cfg(all(feature = "rest-server", feature = "rest-client", feature = "metrics"))]
+
+use async_trait::async_trait;
+use qollective::client::rest::RestClientBuilder;
+use qollective::constants::metrics::DEFAULT_METRICS_PATH;
+use qollective::envelope::{Context, Envelope, Meta};
+use qollective::error::Result;
+use qollective::monitoring::MetricsExporter;
+use qollective::prelude::{ContextDataHandler, UnifiedEnvelopeReceiver};
+use qollective::server::common::ServerConfig;
+use qollective::server::rest::{RestServer, RestServerConfig};
+use serde_json::{json, Value};
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::get_available_port;
+
+struct ScanHandler;
+
+#[async_trait]
+impl ContextDataHandler<Value, Value> for ScanHandler {
+    async fn handle(&self, _context: Option<Context>, data: Value) -> Result<Value> {
+        Ok(json!({"scanned": data["sector"]}))
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_exposes_request_metrics() {
+    let exporter = MetricsExporter::install().expect("recorder should install");
+    let port = get_available_port();
+
+    let mut server = RestServer::new(RestServerConfig {
+        base: ServerConfig {
+            bind_address: "127.0.0.1".to_string(),
+            port,
+            max_connections: 100,
+        },
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    server
+        .receive_envelope_at("/metrics-test/scan", ScanHandler)
+        .await
+        .unwrap();
+    server
+        .mount_metrics(DEFAULT_METRICS_PATH, exporter)
+        .unwrap();
+    assert!(server
+        .mount_metrics("metrics", MetricsExporter::install().unwrap())
+        .is_err());
+
+    tokio::spawn(async move {
+        server.start().await.unwrap();
+    });
+    sleep(Duration::from_millis(200)).await;
+
+    let base_url = format!("http://127.0.0.1:{}", port);
+    let client = RestClientBuilder::new()
+        .base_url(&base_url)
+        .build()
+        .await
+        .unwrap();
+
+    let mut meta = Meta::for_new_request();
+    meta.tenant = Some("starfleet".to_string());
+    for _ in 0..2 {
+        let _: Envelope<Value> = client
+            .post(
+                "/metrics-test/scan",
+                Envelope::new(meta.clone(), json!({"sector": "001"})),
+            )
+            .await
+            .expect("scan should succeed");
+    }
+
+    let response = reqwest::get(format!("{}{}", base_url, DEFAULT_METRICS_PATH))
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+    let body = response.text().await.unwrap();
+
+    assert!(body.contains(
+        r#"qollective_requests_total{transport="rest",route="POST /metrics-test/scan",status="200",tenant="starfleet"} 2"#
+    ));
+    assert!(body.contains(
+        r#"qollective_request_duration_seconds_bucket{transport="rest",route="POST /metrics-test/scan",status="200",tenant="starfleet",le="+Inf"} 2"#
+    ));
+    assert!(body.contains(
+        r#"qollective_requests_in_flight{transport="rest",route="POST /metrics-test/scan"} 0"#
+    ));
+}
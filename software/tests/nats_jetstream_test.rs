@@ -0,0 +1,347 @@
+// ABOUTME: Integration tests for JetStream durable publishing, consumers and the NATS KV token store
+// ABOUTME: Spawns a local nats-server with JetStream enabled and skips when the binary is not installed
+
+#![cfg(all(feature = "nats-client", feature = "nats-server"))]
+
+use futures::StreamExt;
+use qollective::client::nats::NatsClient;
+use qollective::config::nats::{
+    JetStreamConsumerConfig, JetStreamDelivery, JetStreamRetention, JetStreamStreamConfig,
+    NatsConfig,
+};
+use qollective::constants::metadata;
+use qollective::envelope::{Envelope, Meta};
+use qollective::error::{QollectiveError, Result};
+use qollective::server::nats::NatsServer;
+use qollective::server::EnvelopeHandler;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+mod common;
+use common::get_available_port;
+
+/// A nats-server process with JetStream enabled, killed when dropped
+struct JetStreamServer {
+    process: Child,
+    store_dir: PathBuf,
+    url: String,
+}
+
+impl JetStreamServer {
+    /// Spawn nats-server on a free port, or `None` if it is not installed
+    async fn spawn() -> Option<Self> {
+        let port = get_available_port();
+        let store_dir =
+            std::env::temp_dir().join(format!("qollective-js-{}", uuid::Uuid::now_v7()));
+        let process = Command::new("nats-server")
+            .args(["-js", "-a", "127.0.0.1", "-p", &port.to_string(), "-sd"])
+            .arg(&store_dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let process = match process {
+            Ok(process) => process,
+            Err(e) => {
+                println!(
+                    "⚠️  Skipping JetStream test, nats-server is not available: {}",
+                    e
+                );
+                return None;
+            }
+        };
+
+        let server = Self {
+            process,
+            store_dir,
+            url: format!("nats://127.0.0.1:{}", port),
+        };
+        for _ in 0..50 {
+            if tokio::net::TcpStream::connect(("127.0.0.1", port))
+                .await
+                .is_ok()
+            {
+                return Some(server);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("nats-server did not start listening on port {}", port);
+    }
+}
+
+impl Drop for JetStreamServer {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+        let _ = std::fs::remove_dir_all(&self.store_dir);
+    }
+}
+
+fn jetstream_config(url: &str, consumer: JetStreamConsumerConfig) -> NatsConfig {
+    let mut stream = JetStreamStreamConfig::new("ORDERS", vec!["orders.new".to_string()]);
+    stream.retention = JetStreamRetention::WorkQueue;
+    stream.duplicate_window_ms = Some(60_000);
+
+    NatsConfig::builder()
+        .with_urls(vec![url.to_string()])
+        .with_jetstream_stream(stream)
+        .with_jetstream_consumer(consumer)
+        .build()
+        .expect("valid JetStream configuration")
+}
+
+fn order(item: &str) -> Envelope<Value> {
+    Envelope::new(Meta::for_new_request(), json!({ "item": item }))
+}
+
+/// Forwards every order it receives
+#[derive(Clone)]
+struct RecordingHandler {
+    received: mpsc::UnboundedSender<Value>,
+}
+
+impl EnvelopeHandler<Value, Value> for RecordingHandler {
+    async fn handle(&self, envelope: Envelope<Value>) -> Result<Envelope<Value>> {
+        let _ = self.received.send(envelope.payload.clone());
+        Ok(envelope)
+    }
+}
+
+/// Fails every delivery, counting the attempts
+#[derive(Clone)]
+struct FailingHandler {
+    attempts: Arc<AtomicUsize>,
+}
+
+impl EnvelopeHandler<Value, Value> for FailingHandler {
+    async fn handle(&self, _envelope: Envelope<Value>) -> Result<Envelope<Value>> {
+        self.attempts.fetch_add(1, Ordering::SeqCst);
+        Err(QollectiveError::nats_message("replicator offline"))
+    }
+}
+
+#[tokio::test]
+async fn test_durable_messages_wait_for_pull_consumer() {
+    let Some(nats) = JetStreamServer::spawn().await else {
+        return;
+    };
+    let config = jetstream_config(
+        &nats.url,
+        JetStreamConsumerConfig::new("order-workers", "ORDERS"),
+    );
+
+    // Publish before any consumer exists; the stream keeps the envelopes
+    let client = NatsClient::new(config.clone()).await.unwrap();
+    client.ensure_streams().await.unwrap();
+    let first = order("earl grey");
+    let ack = client
+        .publish_durable("orders.new", first.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        (ack.stream.as_str(), ack.sequence, ack.duplicate),
+        ("ORDERS", 1, false)
+    );
+    let ack = client
+        .publish_durable("orders.new", order("raktajino"))
+        .await
+        .unwrap();
+    assert_eq!(ack.sequence, 2);
+
+    // Republishing the same request id is deduplicated by the stream
+    let ack = client.publish_durable("orders.new", first).await.unwrap();
+    assert!(ack.duplicate);
+
+    let (received, mut orders) = mpsc::unbounded_channel();
+    let mut server = NatsServer::new(config).await.unwrap();
+    server
+        .consume("order-workers", RecordingHandler { received })
+        .await
+        .unwrap();
+    server.start().await.unwrap();
+
+    for item in ["earl grey", "raktajino"] {
+        let payload = timeout(Duration::from_secs(5), orders.recv())
+            .await
+            .expect("durable order should be delivered")
+            .unwrap();
+        assert_eq!(payload["item"], item);
+    }
+    assert!(timeout(Duration::from_millis(300), orders.recv())
+        .await
+        .is_err());
+
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_failed_messages_are_redelivered_then_dead_lettered() {
+    let Some(nats) = JetStreamServer::spawn().await else {
+        return;
+    };
+    let mut consumer = JetStreamConsumerConfig::new("order-replicators", "ORDERS");
+    consumer.delivery = JetStreamDelivery::Push {
+        deliver_subject: "deliver.orders".to_string(),
+        deliver_group: None,
+    };
+    consumer.max_deliver = 3;
+    consumer.dead_letter_subject = Some("orders.dead".to_string());
+    let config = jetstream_config(&nats.url, consumer);
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let mut server = NatsServer::new(config.clone()).await.unwrap();
+    let mut dead_letters = server.client().subscribe("orders.dead").await.unwrap();
+    server
+        .consume(
+            "order-replicators",
+            FailingHandler {
+                attempts: attempts.clone(),
+            },
+        )
+        .await
+        .unwrap();
+    server.start().await.unwrap();
+
+    let client = NatsClient::new(config).await.unwrap();
+    client
+        .publish_durable("orders.new", order("tea, earl grey, hot"))
+        .await
+        .unwrap();
+
+    let dead_letter = timeout(Duration::from_secs(10), dead_letters.next())
+        .await
+        .expect("message should be dead-lettered")
+        .unwrap();
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let headers = dead_letter.headers.expect("dead letter carries headers");
+    let header = |name: &str| headers.get(name).map(|value| value.as_str().to_string());
+    assert_eq!(
+        header(metadata::JETSTREAM_DEAD_LETTER_SUBJECT_HEADER).as_deref(),
+        Some("orders.new")
+    );
+    assert_eq!(
+        header(metadata::JETSTREAM_DEAD_LETTER_DELIVERIES_HEADER).as_deref(),
+        Some("3")
+    );
+    assert!(header(metadata::JETSTREAM_DEAD_LETTER_ERROR_HEADER)
+        .unwrap()
+        .contains("replicator offline"));
+
+    // The original envelope is preserved for inspection or replay
+    let envelope: Envelope<Value> =
+        qollective::envelope::NatsEnvelopeCodec::decode(&dead_letter.payload).unwrap();
+    assert_eq!(envelope.payload["item"], "tea, earl grey, hot");
+
+    // Terminated messages are not redelivered again
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    server.shutdown().await.unwrap();
+}
+
+#[cfg(feature = "security")]
+mod token_storage {
+    use super::JetStreamServer;
+    use base64::Engine as _;
+    use qollective::security::{
+        AsyncSecureTokenStorage, NatsKvTokenStorage, StorageError, TokenCipher,
+    };
+    use std::time::Duration;
+
+    const BUCKET: &str = "qollective-test-tokens";
+
+    async fn kv_storage(url: &str, cipher: TokenCipher) -> NatsKvTokenStorage {
+        let client = async_nats::connect(url).await.unwrap();
+        NatsKvTokenStorage::connect(client, BUCKET, cipher, Some(Duration::from_secs(600)))
+            .await
+            .unwrap()
+    }
+
+    async fn bucket(url: &str) -> async_nats::jetstream::kv::Store {
+        let client = async_nats::connect(url).await.unwrap();
+        async_nats::jetstream::new(client)
+            .get_key_value(BUCKET)
+            .await
+            .unwrap()
+    }
+
+    fn kv_key(user_id: &str) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(user_id)
+    }
+
+    #[tokio::test]
+    async fn test_nats_kv_token_storage_round_trip_and_eviction() {
+        let Some(nats) = JetStreamServer::spawn().await else {
+            return;
+        };
+        let key = TokenCipher::generate_key();
+        let storage = kv_storage(&nats.url, TokenCipher::from_base64_key(&key).unwrap()).await;
+
+        storage.store_token("picard", "engage", None).await.unwrap();
+        storage
+            .store_token("riker", "stale", Some(Duration::ZERO))
+            .await
+            .unwrap();
+
+        // Only ciphertext reaches the bucket
+        let raw = bucket(&nats.url)
+            .await
+            .get(kv_key("picard"))
+            .await
+            .unwrap()
+            .expect("token should be stored");
+        assert!(!String::from_utf8_lossy(&raw).contains("engage"));
+
+        // A second replica sharing the key reads the same token
+        let replica = kv_storage(&nats.url, TokenCipher::from_base64_key(&key).unwrap()).await;
+        assert_eq!(
+            replica.get_token("picard").await.unwrap(),
+            Some("engage".to_string())
+        );
+        assert_eq!(replica.get_token("riker").await.unwrap(), None);
+        assert_eq!(replica.evict_expired().await.unwrap(), 1);
+
+        assert!(replica.remove_token("picard").await.unwrap());
+        assert!(!replica.remove_token("picard").await.unwrap());
+        storage.store_token("data", "android", None).await.unwrap();
+        storage.clear_all().await.unwrap();
+        assert!(!storage.has_token("data").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_nats_kv_token_record_swapped_to_another_user_is_rejected() {
+        let Some(nats) = JetStreamServer::spawn().await else {
+            return;
+        };
+        let storage = kv_storage(
+            &nats.url,
+            TokenCipher::from_base64_key(&TokenCipher::generate_key()).unwrap(),
+        )
+        .await;
+        storage
+            .store_token("picard", "captain", None)
+            .await
+            .unwrap();
+        storage.store_token("wesley", "ensign", None).await.unwrap();
+
+        // Copy picard's record over wesley's directly in the bucket
+        let bucket = bucket(&nats.url).await;
+        let stolen = bucket.get(kv_key("picard")).await.unwrap().unwrap();
+        bucket.put(kv_key("wesley"), stolen).await.unwrap();
+
+        assert!(matches!(
+            storage.get_token("wesley").await,
+            Err(StorageError::CryptographyFailed(_))
+        ));
+        assert_eq!(
+            storage.get_token("picard").await.unwrap(),
+            Some("captain".to_string())
+        );
+    }
+}
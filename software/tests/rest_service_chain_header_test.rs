@@ -0,0 +1,64 @@
+// ABOUTME: Integration tests for the service chain carried in REST response headers
+// ABOUTME: Validates that long chains are cut to fit the header limit instead of failing
+
+#![cfg(feature = "rest-server")]
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use qollective::constants::http::envelope_headers;
+use qollective::envelope::{Meta, ServiceChainEntry};
+use qollective::server::rest::{inject_metadata_into_headers, MetadataHandlingConfig};
+use serde_json::Value;
+
+fn meta_with_hops(hops: usize) -> Meta {
+    Meta {
+        service_chain: (0..hops)
+            .map(|hop| ServiceChainEntry::new(format!("service-{}", hop), "1.0.0"))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn header_chain(header: &str) -> Vec<String> {
+    let decoded = BASE64_STANDARD.decode(header).unwrap();
+    let meta: Value = serde_json::from_slice(&decoded).unwrap();
+    meta["service_chain"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hop| hop["service_name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_long_service_chain_keeps_most_recent_hops_in_header() {
+    let config = MetadataHandlingConfig {
+        max_header_size: 1_024,
+        ..Default::default()
+    };
+
+    let headers = inject_metadata_into_headers(&meta_with_hops(100), &config)
+        .expect("a long service chain should not fail the response");
+
+    let header = headers
+        .get(envelope_headers::QOLLECTIVE_META)
+        .expect("the most recent hops should still fit")
+        .to_str()
+        .unwrap();
+    assert!(header.len() <= config.max_header_size);
+    let chain = header_chain(header);
+    assert!(chain.len() < 100);
+    assert_eq!(chain.last().map(String::as_str), Some("service-99"));
+}
+
+#[test]
+fn test_service_chain_header_is_left_out_when_no_hop_fits() {
+    let config = MetadataHandlingConfig {
+        max_header_size: 16,
+        ..Default::default()
+    };
+
+    let headers = inject_metadata_into_headers(&meta_with_hops(3), &config)
+        .expect("an oversized service chain should not fail the response");
+
+    assert!(headers.get(envelope_headers::QOLLECTIVE_META).is_none());
+}
@@ -0,0 +1,170 @@
+// ABOUTME: Integration tests for verified tenant tokens on the REST and WebSocket servers
+// ABOUTME: Checks forged tokens are refused and verified tenants replace the ones callers claim
+
+#![cfg(all(
+    feature = "rest-server",
+    feature = "websocket-server",
+    feature = "websocket-client",
+    feature = "tenant-extraction"
+))]
+
+use async_trait::async_trait;
+use base64::prelude::*;
+use qollective::envelope::{Context, Envelope, Meta};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, UnifiedEnvelopeReceiver, UnifiedEnvelopeSender};
+use qollective::server::common::ServerConfig;
+use qollective::server::rest::{RestServer, RestServerConfig};
+use qollective::server::websocket::{WebSocketServer, WebSocketServerConfig};
+use qollective::tenant::extraction::ExtractionConfig;
+use qollective::tenant::{JwtKeySource, JwtVerificationConfig};
+use qollective::transport::websocket::{WebSocketConfig, WebSocketTransport};
+use serde_json::{json, Value};
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::get_available_port;
+
+/// Answers with the tenant the server handed to the handler
+struct WhoAmIHandler;
+
+#[async_trait]
+impl ContextDataHandler<Value, Value> for WhoAmIHandler {
+    async fn handle(&self, context: Option<Context>, _data: Value) -> Result<Value> {
+        let tenant = context.and_then(|context| context.meta().tenant.clone());
+        Ok(json!({ "tenant": tenant }))
+    }
+}
+
+fn extraction_config() -> ExtractionConfig {
+    ExtractionConfig {
+        jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+            secret: "starfleet-secret".to_string(),
+        })),
+        ..Default::default()
+    }
+}
+
+fn bearer(secret: &[u8]) -> String {
+    let claims = json!({
+        "sub": "picard",
+        "tenantkey": "enterprise",
+        "exp": jsonwebtoken::get_current_timestamp() + 600,
+    });
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(secret),
+    )
+    .unwrap();
+    format!("Bearer {}", token)
+}
+
+fn server_config(port: u16) -> ServerConfig {
+    ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port,
+        max_connections: 100,
+    }
+}
+
+async fn start_rest_server() -> String {
+    let port = get_available_port();
+    let mut server = RestServer::new(RestServerConfig {
+        base: server_config(port),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .with_tenant_extraction_config(&extraction_config())
+    .unwrap();
+    server
+        .receive_envelope_at("/auth/whoami", WhoAmIHandler)
+        .await
+        .unwrap();
+    tokio::spawn(async move { server.start().await });
+    sleep(Duration::from_millis(200)).await;
+    format!("http://127.0.0.1:{}", port)
+}
+
+async fn post_whoami(base_url: &str, authorization: String) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/auth/whoami", base_url))
+        .header("Authorization", authorization)
+        .header("X-Qollective-Tenant", BASE64_STANDARD.encode("romulan"))
+        .json(&Envelope::new(Meta::for_new_request(), json!({})))
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_rest_tenant_comes_from_verified_token() {
+    let base_url = start_rest_server().await;
+
+    let response = post_whoami(&base_url, bearer(b"starfleet-secret")).await;
+    assert_eq!(response.status(), 200);
+    let envelope: Envelope<Value> = response.json().await.unwrap();
+    assert_eq!(envelope.payload["tenant"], "enterprise");
+}
+
+#[tokio::test]
+async fn test_rest_rejects_forged_tenant_token() {
+    let base_url = start_rest_server().await;
+
+    let response = post_whoami(&base_url, bearer(b"attacker-secret")).await;
+    assert_eq!(response.status(), 401);
+}
+
+async fn start_websocket_server() -> String {
+    let port = get_available_port();
+    let mut server = WebSocketServer::new(WebSocketServerConfig {
+        base: server_config(port),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .with_tenant_extraction_config(&extraction_config())
+    .unwrap();
+    server.receive_envelope(WhoAmIHandler).await.unwrap();
+    tokio::spawn(async move { server.start().await });
+    sleep(Duration::from_millis(100)).await;
+    format!("ws://127.0.0.1:{}/", port)
+}
+
+fn websocket_client(authorization: String) -> WebSocketTransport {
+    WebSocketTransport::new(WebSocketConfig {
+        connection_timeout: Duration::from_secs(2),
+        handshake_headers: vec![("Authorization".to_string(), authorization)],
+        ..Default::default()
+    })
+}
+
+fn claiming_romulan() -> Envelope<Value> {
+    let mut meta = Meta::for_new_request();
+    meta.tenant = Some("romulan".to_string());
+    Envelope::new(meta, json!({}))
+}
+
+#[tokio::test]
+async fn test_websocket_tenant_comes_from_verified_upgrade_token() {
+    let endpoint = start_websocket_server().await;
+    let client = websocket_client(bearer(b"starfleet-secret"));
+
+    let response: Envelope<Value> = client
+        .send_envelope(&endpoint, claiming_romulan())
+        .await
+        .unwrap();
+    assert_eq!(response.payload["tenant"], "enterprise");
+}
+
+#[tokio::test]
+async fn test_websocket_refuses_upgrade_with_forged_token() {
+    let endpoint = start_websocket_server().await;
+    let client = websocket_client(bearer(b"attacker-secret"));
+
+    let result: Result<Envelope<Value>> = client
+        .send_envelope(&endpoint, claiming_romulan())
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,187 @@
+// ABOUTME: Integration tests for W3C trace context propagation across REST and gRPC
+// ABOUTME: Verifies traceparent/baggage headers are sent by clients and reach server handlers
+
+#![cfg(all(
+    feature = "rest-client",
+    feature = "grpc-client",
+    feature = "grpc-server"
+))]
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::{Json, Router};
+use qollective::client::grpc::GrpcClient;
+use qollective::client::rest::RestClientBuilder;
+use qollective::config::grpc::GrpcClientConfig;
+use qollective::envelope::{Context, TraceParent, TracingMeta};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, Envelope, Meta, UnifiedEnvelopeReceiver};
+use qollective::server::common::ServerConfig;
+use qollective::server::grpc::{GrpcServer, QollectiveServiceImpl};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tokio::net::TcpListener;
+use tokio::time::{sleep, Duration};
+
+mod common;
+use common::{get_available_port, setup_test_environment};
+
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const SPAN_ID: &str = "00f067aa0ba902b7";
+
+fn traced_meta() -> Meta {
+    let mut meta = Meta::for_new_request();
+    meta.tracing = Some(TracingMeta {
+        trace_id: Some(TRACE_ID.to_string()),
+        span_id: Some(SPAN_ID.to_string()),
+        parent_span_id: None,
+        baggage: HashMap::from([("mission".to_string(), "deep space".to_string())]),
+        sampling_rate: None,
+        sampled: Some(true),
+        trace_state: Some("qollective=1".to_string()),
+        operation_name: None,
+        span_kind: None,
+        span_status: None,
+        tags: HashMap::new(),
+    });
+    meta
+}
+
+/// Echo the W3C headers the server received back to the client
+async fn echo_trace_headers(
+    headers: HeaderMap,
+    Json(envelope): Json<Envelope<Value>>,
+) -> Json<Envelope<Value>> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let (meta, _) = envelope.extract();
+    Json(Envelope::new(
+        meta,
+        json!({
+            "traceparent": header("traceparent"),
+            "tracestate": header("tracestate"),
+            "baggage": header("baggage"),
+        }),
+    ))
+}
+
+#[tokio::test]
+async fn test_rest_client_sends_w3c_trace_headers() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let app = Router::new().route("/api/trace", post(echo_trace_headers));
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let client = RestClientBuilder::new()
+        .base_url(&base_url)
+        .build()
+        .await
+        .unwrap();
+
+    let response: Envelope<Value> = client
+        .post("/api/trace", Envelope::new(traced_meta(), json!({})))
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(
+        response.payload["traceparent"],
+        format!("00-{}-{}-01", TRACE_ID, SPAN_ID)
+    );
+    assert_eq!(response.payload["tracestate"], "qollective=1");
+    assert_eq!(response.payload["baggage"], "mission=deep%20space");
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TraceProbe;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TraceReport {
+    trace_id: Option<String>,
+    parent_span_id: Option<String>,
+    baggage: HashMap<String, String>,
+}
+
+/// Reports the trace context visible to the handler
+struct TraceReportingHandler;
+
+#[async_trait]
+impl ContextDataHandler<TraceProbe, TraceReport> for TraceReportingHandler {
+    async fn handle(&self, context: Option<Context>, _data: TraceProbe) -> Result<TraceReport> {
+        let tracing = context.and_then(|ctx| ctx.meta().tracing.clone());
+        Ok(TraceReport {
+            trace_id: tracing.as_ref().and_then(|t| t.trace_id.clone()),
+            parent_span_id: tracing.as_ref().and_then(|t| t.span_id.clone()),
+            baggage: tracing.map(|t| t.baggage).unwrap_or_default(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_grpc_handler_sees_caller_trace_context() {
+    setup_test_environment();
+    let server_port = get_available_port();
+    let mut server = GrpcServer::new(ServerConfig {
+        bind_address: "127.0.0.1".to_string(),
+        port: server_port,
+        max_connections: 100,
+    });
+
+    server
+        .register_service(QollectiveServiceImpl::new())
+        .await
+        .expect("service registration should succeed");
+    server
+        .receive_envelope_at("/trace/report", TraceReportingHandler)
+        .await
+        .expect("route registration should succeed");
+
+    let server_handle = tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            println!("❌ gRPC server failed: {}", e);
+        }
+    });
+
+    // Give server time to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client = GrpcClient::new(GrpcClientConfig {
+        base_url: Some(format!("http://127.0.0.1:{}", server_port)),
+        timeout_ms: 5000,
+        ..Default::default()
+    })
+    .await
+    .expect("gRPC client should connect");
+
+    let response: Envelope<TraceReport> = client
+        .send_envelope_to("/trace/report", Envelope::new(traced_meta(), TraceProbe))
+        .await
+        .expect("trace call should succeed");
+
+    assert_eq!(response.payload.trace_id.as_deref(), Some(TRACE_ID));
+    assert_eq!(response.payload.parent_span_id.as_deref(), Some(SPAN_ID));
+    assert_eq!(response.payload.baggage["mission"], "deep space");
+
+    server_handle.abort();
+}
+
+#[test]
+fn test_traceparent_from_uuid_trace_id() {
+    let mut meta = traced_meta();
+    let tracing = meta.tracing.as_mut().unwrap();
+    tracing.trace_id = Some("0190B4A2-7C1E-7D3A-9F00-1234567890AB".to_string());
+
+    let traceparent = TraceParent::from_tracing_meta(tracing).unwrap();
+    assert_eq!(traceparent.trace_id, "0190b4a27c1e7d3a9f001234567890ab");
+    assert_eq!(
+        TraceParent::parse(&traceparent.to_string()),
+        Some(traceparent)
+    );
+}
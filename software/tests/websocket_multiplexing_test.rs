@@ -0,0 +1,259 @@
+// ABOUTME: Integration tests for the persistent multiplexed WebSocket client connection
+// ABOUTME: Covers out-of-order response correlation, server push subscriptions and reconnection
+
+#![cfg(feature = "websocket-client")]
+
+use futures_util::{SinkExt, StreamExt};
+use qollective::envelope::{deadline, Envelope, Meta};
+use qollective::error::QollectiveError;
+use qollective::prelude::UnifiedEnvelopeSender;
+use qollective::transport::retry::RetryPolicy;
+use qollective::transport::websocket::{WebSocketConfig, WebSocketTransport};
+use qollective::transport::websocket_connection::ReconnectPolicy;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+type ServerSocket = WebSocketStream<TcpStream>;
+
+/// Start a raw WebSocket server that runs `session` for every accepted connection
+async fn start_server<F, Fut>(session: F) -> String
+where
+    F: Fn(usize, ServerSocket) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut accepted = 0;
+        while let Ok((stream, _)) = listener.accept().await {
+            let socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            tokio::spawn(session(accepted, socket));
+            accepted += 1;
+        }
+    });
+    endpoint
+}
+
+/// Read the next request frame, returning its id and envelope
+async fn next_request(socket: &mut ServerSocket) -> Option<(String, Envelope<Value>)> {
+    while let Some(Ok(message)) = socket.next().await {
+        if let Message::Text(text) = message {
+            let frame: Value = serde_json::from_str(&text).unwrap();
+            let envelope = serde_json::from_value(frame["payload"].clone()).unwrap();
+            return Some((frame["id"].as_str().unwrap().to_string(), envelope));
+        }
+    }
+    None
+}
+
+/// Send an envelope frame, tagged with `id` for responses and untagged for pushes
+async fn send_frame(socket: &mut ServerSocket, id: Option<&str>, envelope: &Envelope<Value>) {
+    let mut frame = json!({"type": "envelope", "payload": envelope});
+    if let Some(id) = id {
+        frame["id"] = json!(id);
+    }
+    socket
+        .send(Message::Text(frame.to_string().into()))
+        .await
+        .unwrap();
+}
+
+fn transport() -> WebSocketTransport {
+    WebSocketTransport::new(WebSocketConfig {
+        message_timeout: Duration::from_secs(5),
+        reconnect: ReconnectPolicy {
+            initial_delay: Duration::from_millis(20),
+            max_delay: Duration::from_millis(200),
+            max_attempts: None,
+        },
+        ..Default::default()
+    })
+}
+
+fn request(sector: u32) -> Envelope<Value> {
+    Envelope::new(Meta::for_new_request(), json!({ "sector": sector }))
+}
+
+#[tokio::test]
+async fn test_concurrent_requests_are_correlated_out_of_order() {
+    let endpoint = start_server(|_, mut socket| async move {
+        // Collect three requests, then answer them in reverse order
+        let mut requests = Vec::new();
+        for _ in 0..3 {
+            requests.push(next_request(&mut socket).await.unwrap());
+        }
+        for (id, envelope) in requests.into_iter().rev() {
+            let reply = Envelope::new(
+                envelope.meta,
+                json!({"scanned": envelope.payload["sector"]}),
+            );
+            send_frame(&mut socket, Some(&id), &reply).await;
+        }
+        while socket.next().await.is_some() {}
+    })
+    .await;
+
+    let transport = transport();
+    let (first, second, third) = tokio::join!(
+        transport.send_envelope(&endpoint, request(1)),
+        transport.send_envelope(&endpoint, request(2)),
+        transport.send_envelope(&endpoint, request(3)),
+    );
+
+    for (sector, response) in [(1, first), (2, second), (3, third)] {
+        let response: Envelope<Value> = response.expect("request should be answered");
+        assert_eq!(response.payload["scanned"], sector);
+    }
+}
+
+#[tokio::test]
+async fn test_server_pushes_reach_subscribers() {
+    let endpoint = start_server(|_, mut socket| async move {
+        // Answer one request, then push two envelopes nobody asked for
+        let (id, envelope) = next_request(&mut socket).await.unwrap();
+        send_frame(&mut socket, Some(&id), &envelope).await;
+        for alert in ["red", "yellow"] {
+            let push = Envelope::new(Meta::for_new_request(), json!({ "alert": alert }));
+            send_frame(&mut socket, None, &push).await;
+        }
+        while socket.next().await.is_some() {}
+    })
+    .await;
+
+    let transport = transport();
+    let mut pushes = transport.subscribe(&endpoint).await.unwrap();
+
+    let response: Envelope<Value> = transport
+        .send_envelope(&endpoint, request(7))
+        .await
+        .unwrap();
+    assert_eq!(response.payload["sector"], 7);
+
+    for alert in ["red", "yellow"] {
+        let push = tokio::time::timeout(Duration::from_secs(5), pushes.next())
+            .await
+            .expect("push should arrive")
+            .expect("stream should stay open");
+        assert_eq!(push.payload["alert"], alert);
+    }
+
+    transport.close_connection(&endpoint).await.unwrap();
+    let ended = tokio::time::timeout(Duration::from_secs(5), pushes.next()).await;
+    assert!(matches!(ended, Ok(None)));
+}
+
+#[tokio::test]
+async fn test_dropped_connection_fails_in_flight_requests_and_reconnects() {
+    let endpoint = start_server(|connection, mut socket| async move {
+        let (id, envelope) = next_request(&mut socket).await.unwrap();
+        if connection == 0 {
+            // First connection dies without answering
+            drop(socket);
+            return;
+        }
+        send_frame(&mut socket, Some(&id), &envelope).await;
+        while socket.next().await.is_some() {}
+    })
+    .await;
+
+    let transport = transport().with_retry_policy(RetryPolicy::none());
+    let lost: qollective::error::Result<Envelope<Value>> =
+        transport.send_envelope(&endpoint, request(1)).await;
+    assert!(lost.unwrap_err().to_string().contains("dropped"));
+
+    // The same transport recovers on a fresh socket without being recreated
+    let response: Envelope<Value> = transport
+        .send_envelope(&endpoint, request(2))
+        .await
+        .expect("request after reconnect should succeed");
+    assert_eq!(response.payload["sector"], 2);
+}
+
+#[tokio::test]
+async fn test_dropped_request_is_retried_on_a_fresh_socket() {
+    let endpoint = start_server(|connection, mut socket| async move {
+        let (id, envelope) = next_request(&mut socket).await.unwrap();
+        if connection == 0 {
+            drop(socket);
+            return;
+        }
+        send_frame(&mut socket, Some(&id), &envelope).await;
+        while socket.next().await.is_some() {}
+    })
+    .await;
+
+    let transport =
+        transport().with_retry_policy(RetryPolicy::exponential(2, Duration::from_millis(10)));
+    let response: Envelope<Value> = transport
+        .send_envelope(&endpoint, request(7))
+        .await
+        .expect("the retry should be answered");
+
+    assert_eq!(response.payload["sector"], 7);
+    assert_eq!(response.meta.retry_attempt(), Some(2));
+}
+
+#[tokio::test]
+async fn test_stalled_connect_does_not_block_other_endpoints() {
+    // Accepts TCP connections but never completes the WebSocket handshake
+    let stalled = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let stalled_endpoint = format!("ws://{}", stalled.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((stream, _)) = stalled.accept().await {
+            held.push(stream);
+        }
+    });
+    let endpoint = start_server(|_, mut socket| async move {
+        while let Some((id, envelope)) = next_request(&mut socket).await {
+            send_frame(&mut socket, Some(&id), &envelope).await;
+        }
+    })
+    .await;
+
+    let transport = std::sync::Arc::new(transport().with_retry_policy(RetryPolicy::none()));
+    let stalled_transport = transport.clone();
+    let stalled_request = tokio::spawn(async move {
+        let _: qollective::error::Result<Envelope<Value>> = stalled_transport
+            .send_envelope(&stalled_endpoint, request(1))
+            .await;
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let response: Envelope<Value> = tokio::time::timeout(
+        Duration::from_secs(2),
+        transport.send_envelope(&endpoint, request(2)),
+    )
+    .await
+    .expect("a stalled endpoint should not hold up other endpoints")
+    .unwrap();
+    assert_eq!(response.payload["sector"], 2);
+
+    stalled_request.abort();
+}
+
+#[tokio::test]
+async fn test_unanswered_request_fails_with_deadline_exceeded() {
+    let endpoint = start_server(|_, mut socket| async move {
+        // Read requests but never answer them
+        while next_request(&mut socket).await.is_some() {}
+    })
+    .await;
+
+    let mut meta = Meta::for_new_request();
+    meta.deadline = Some(deadline::after(Duration::from_millis(200)));
+    let transport = transport().with_retry_policy(RetryPolicy::none());
+    let result: qollective::error::Result<Envelope<Value>> = transport
+        .send_envelope(&endpoint, Envelope::new(meta, json!({ "sector": 9 })))
+        .await;
+
+    assert!(
+        matches!(result, Err(QollectiveError::DeadlineExceeded(_))),
+        "{:?}",
+        result.map(|_| ())
+    );
+}
@@ -0,0 +1,190 @@
+// ABOUTME: Integration tests for server-initiated pushes, rooms and tenant broadcast on WebSocketServer
+// ABOUTME: Drives a real server with the multiplexed client and checks pushes reach the right connections
+
+#![cfg(all(
+    feature = "websocket-server",
+    feature = "websocket-client",
+    feature = "tenant-extraction"
+))]
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use qollective::envelope::{Context, Envelope, Meta, SecurityMeta};
+use qollective::error::Result;
+use qollective::prelude::{ContextDataHandler, UnifiedEnvelopeReceiver, UnifiedEnvelopeSender};
+use qollective::server::common::ServerConfig;
+use qollective::server::websocket::{WebSocketServer, WebSocketServerConfig};
+use qollective::server::websocket_connections::{connection_id, WebSocketConnections};
+use qollective::tenant::extraction::ExtractionConfig;
+use qollective::tenant::{JwtKeySource, JwtVerificationConfig};
+use qollective::transport::websocket::{WebSocketConfig, WebSocketTransport};
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Duration};
+
+mod common;
+use common::get_available_port;
+
+/// Puts the calling connection into the room named in the request and echoes its identity
+struct JoinRoomHandler {
+    connections: WebSocketConnections,
+}
+
+#[async_trait]
+impl ContextDataHandler<Value, Value> for JoinRoomHandler {
+    async fn handle(&self, context: Option<Context>, data: Value) -> Result<Value> {
+        let id = context
+            .as_ref()
+            .and_then(connection_id)
+            .expect("request should carry its connection id");
+        self.connections
+            .join(id, data["room"].as_str().unwrap())
+            .await?;
+        let meta = context.as_ref().map(Context::meta);
+        Ok(json!({
+            "connection": id,
+            "tenant": meta.and_then(|meta| meta.tenant.clone()),
+            "user_id": meta
+                .and_then(|meta| meta.security.as_ref())
+                .and_then(|security| security.user_id.clone()),
+        }))
+    }
+}
+
+/// Token for picard of starfleet, signed with the server's verification secret
+fn picard_token() -> String {
+    let claims = json!({
+        "sub": "picard",
+        "tenantkey": "starfleet",
+        "exp": jsonwebtoken::get_current_timestamp() + 600,
+    });
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(b"starfleet-secret"),
+    )
+    .unwrap();
+    format!("Bearer {}", token)
+}
+
+fn alert(level: &str) -> Envelope<Value> {
+    Envelope::new(Meta::for_new_request(), json!({ "alert": level }))
+}
+
+#[tokio::test]
+async fn test_server_pushes_to_connection_room_and_tenant() {
+    let port = get_available_port();
+    let mut server = WebSocketServer::new(WebSocketServerConfig {
+        base: ServerConfig {
+            bind_address: "127.0.0.1".to_string(),
+            port,
+            max_connections: 100,
+        },
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .with_tenant_extraction_config(&ExtractionConfig {
+        jwt_verification: Some(JwtVerificationConfig::new(JwtKeySource::Secret {
+            secret: "starfleet-secret".to_string(),
+        })),
+        ..Default::default()
+    })
+    .unwrap();
+    let connections = server.connections();
+    server
+        .receive_envelope(JoinRoomHandler {
+            connections: connections.clone(),
+        })
+        .await
+        .unwrap();
+    tokio::spawn(async move {
+        server.start().await.unwrap();
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let endpoint = format!("ws://127.0.0.1:{}/", port);
+    let client = WebSocketTransport::new(WebSocketConfig {
+        handshake_headers: vec![("Authorization".to_string(), picard_token())],
+        ..Default::default()
+    });
+    let mut pushes = client.subscribe(&endpoint).await.unwrap();
+
+    // A handler joins the bridge room on behalf of the caller's connection. The identity
+    // the envelope claims is ignored in favour of the one verified at upgrade
+    let mut meta = Meta::for_new_request();
+    meta.tenant = Some("romulan".to_string());
+    meta.security = Some(SecurityMeta {
+        user_id: Some("sela".to_string()),
+        ..Default::default()
+    });
+    let response: Envelope<Value> = client
+        .send_envelope(&endpoint, Envelope::new(meta, json!({ "room": "bridge" })))
+        .await
+        .unwrap();
+    let id = response.payload["connection"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(response.payload["tenant"], "starfleet");
+    assert_eq!(response.payload["user_id"], "picard");
+
+    // The client joins a second room itself
+    client.join_room(&endpoint, "engineering").await.unwrap();
+
+    let info = connections.connection(id).await.unwrap();
+    assert_eq!(info.tenant.as_deref(), Some("starfleet"));
+    assert_eq!(info.user_id.as_deref(), Some("picard"));
+    assert!(info.rooms.contains("bridge") && info.rooms.contains("engineering"));
+
+    assert_eq!(
+        connections
+            .push_to_room("bridge", alert("red"))
+            .await
+            .unwrap()
+            .delivered,
+        1
+    );
+    assert_eq!(
+        connections
+            .push_to_tenant("starfleet", alert("yellow"))
+            .await
+            .unwrap()
+            .delivered,
+        1
+    );
+    connections
+        .push_to_connection(id, alert("blue"))
+        .await
+        .unwrap();
+    assert_eq!(
+        connections
+            .push_to_tenant("romulan", alert("cloaked"))
+            .await
+            .unwrap()
+            .delivered,
+        0
+    );
+
+    for level in ["red", "yellow", "blue"] {
+        let push = timeout(Duration::from_secs(5), pushes.next())
+            .await
+            .expect("push should arrive")
+            .expect("stream should stay open");
+        assert_eq!(push.payload["alert"], level);
+    }
+
+    client.leave_room(&endpoint, "engineering").await.unwrap();
+    assert!(connections.room_members("engineering").await.is_empty());
+
+    // Closing the client removes it from the registry
+    client.close_connection(&endpoint).await.unwrap();
+    for _ in 0..50 {
+        if connections.connection_count().await == 0 {
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(connections.connection_count().await, 0);
+    assert!(connections.room_members("bridge").await.is_empty());
+}